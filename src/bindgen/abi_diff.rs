@@ -0,0 +1,516 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Comparison of the C ABI described by two sets of bindings.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::cdecl;
+use crate::bindgen::ir::{
+    Cfg, Constant, Enum, Field, Function, Item, ItemContainer, Literal, Static, Struct, Type,
+    Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::CLikeLanguageBackend;
+use crate::bindgen::writer::SourceWriter;
+
+/// Whether a change keeps code built against the old bindings working.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AbiChangeKind {
+    /// Something was added, existing users are unaffected.
+    Additive,
+    /// Code built against the old bindings may break.
+    Breaking,
+}
+
+impl fmt::Display for AbiChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AbiChangeKind::Additive => write!(f, "additive"),
+            AbiChangeKind::Breaking => write!(f, "breaking"),
+        }
+    }
+}
+
+/// A single difference between two sets of bindings.
+#[derive(Debug, Clone)]
+pub struct AbiChange {
+    pub kind: AbiChangeKind,
+    /// The exported name of the changed item.
+    pub item: String,
+    pub message: String,
+}
+
+impl fmt::Display for AbiChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: `{}` {}", self.kind, self.item, self.message)
+    }
+}
+
+/// The changes between the C ABI of two sets of bindings, see `AbiDiff::new`.
+#[derive(Debug, Clone, Default)]
+pub struct AbiDiff {
+    /// Breaking changes come first, each group in the order of the new bindings.
+    pub changes: Vec<AbiChange>,
+}
+
+impl AbiDiff {
+    /// Compares the functions, types, constants and globals of `old` and `new`.
+    ///
+    /// Items are matched by their exported name and `cfg`. Any change to an
+    /// existing item which can affect its layout, calling convention or value
+    /// is breaking, as are removals; new items and enum variants are additive,
+    /// unless a variant of the enum has fields.
+    /// Types are compared through typedefs, and the constness and nullability
+    /// of pointers are ignored, as they don't change the ABI.
+    pub fn new(old: &Bindings, new: &Bindings) -> AbiDiff {
+        let mut differ = Differ {
+            old,
+            new,
+            changes: Vec::new(),
+        };
+        differ.diff_functions();
+        differ.diff_constants();
+        differ.diff_globals();
+        differ.diff_items();
+
+        let mut changes = differ.changes;
+        changes.sort_by_key(|change| std::cmp::Reverse(change.kind));
+        AbiDiff { changes }
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.kind == AbiChangeKind::Breaking)
+    }
+}
+
+impl fmt::Display for AbiDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// The name an item is reported and matched with.
+fn key(name: &str, cfg: Option<&Cfg>) -> String {
+    match cfg {
+        Some(cfg) => format!("{} (cfg {})", name, cfg),
+        None => name.to_owned(),
+    }
+}
+
+/// Maps the items of `items` by `key`, keeping their order.
+fn index<'a, T>(
+    items: impl Iterator<Item = &'a T>,
+    key: impl Fn(&T) -> String,
+) -> Vec<(String, &'a T)>
+where
+    T: 'a,
+{
+    items.map(|item| (key(item), item)).collect()
+}
+
+/// The value of an integer literal, to compare enum discriminants with.
+fn literal_value(literal: &Literal) -> Option<i128> {
+    match *literal {
+        Literal::Expr(ref value) => value.parse().ok(),
+        Literal::PostfixUnaryOp { op: "-", ref value } => literal_value(value).map(|v| -v),
+        _ => None,
+    }
+}
+
+fn item_kind(item: &ItemContainer) -> &'static str {
+    match *item {
+        ItemContainer::Constant(..) => "constant",
+        ItemContainer::Static(..) => "static",
+        ItemContainer::OpaqueItem(..) => "opaque type",
+        ItemContainer::Struct(..) => "struct",
+        ItemContainer::Union(..) => "union",
+        ItemContainer::Enum(..) => "enum",
+        ItemContainer::Typedef(..) => "typedef",
+    }
+}
+
+struct Differ<'a> {
+    old: &'a Bindings,
+    new: &'a Bindings,
+    changes: Vec<AbiChange>,
+}
+
+impl<'a> Differ<'a> {
+    fn report(&mut self, kind: AbiChangeKind, item: &str, message: String) {
+        self.changes.push(AbiChange {
+            kind,
+            item: item.to_owned(),
+            message,
+        });
+    }
+
+    fn breaking(&mut self, item: &str, message: String) {
+        self.report(AbiChangeKind::Breaking, item, message);
+    }
+
+    /// Reports removed and added entries, and calls `compare` for the others.
+    fn diff_lists<T>(
+        &mut self,
+        what: &str,
+        old: Vec<(String, &'a T)>,
+        new: Vec<(String, &'a T)>,
+        mut compare: impl FnMut(&mut Self, &str, &'a T, &'a T),
+    ) {
+        let new_by_key: HashMap<_, _> = new.iter().map(|(key, x)| (key.as_str(), *x)).collect();
+        let old_by_key: HashMap<_, _> = old.iter().map(|(key, x)| (key.as_str(), *x)).collect();
+
+        for (key, old_item) in &old {
+            match new_by_key.get(key.as_str()) {
+                Some(new_item) => compare(self, key, old_item, new_item),
+                None => self.breaking(key, format!("{} was removed", what)),
+            }
+        }
+        for (key, _) in &new {
+            if !old_by_key.contains_key(key.as_str()) {
+                self.report(AbiChangeKind::Additive, key, format!("{} was added", what));
+            }
+        }
+    }
+
+    fn old_type(&self, ty: &Type) -> String {
+        render_type(self.old, ty)
+    }
+
+    fn new_type(&self, ty: &Type) -> String {
+        render_type(self.new, ty)
+    }
+
+    /// Whether `old` and `new` are laid out and passed the same way.
+    fn same_abi(&self, old: &Type, new: &Type) -> bool {
+        match (resolve(self.old, old), resolve(self.new, new)) {
+            (Type::Ptr { ty: old, .. }, Type::Ptr { ty: new, .. }) => self.same_abi(&old, &new),
+            (Type::Path(old), Type::Path(new)) => old.export_name() == new.export_name(),
+            (Type::Primitive(old), Type::Primitive(new)) => old == new,
+            (Type::Array(old, old_len), Type::Array(new, new_len)) => {
+                old_len == new_len && self.same_abi(&old, &new)
+            }
+            (
+                Type::FuncPtr {
+                    ret: old_ret,
+                    args: old_args,
+                    abi: old_abi,
+                    ..
+                },
+                Type::FuncPtr {
+                    ret: new_ret,
+                    args: new_args,
+                    abi: new_abi,
+                    ..
+                },
+            ) => {
+                old_abi == new_abi
+                    && self.same_abi(&old_ret, &new_ret)
+                    && old_args.len() == new_args.len()
+                    && old_args
+                        .iter()
+                        .zip(&new_args)
+                        .all(|((_, old), (_, new))| self.same_abi(old, new))
+            }
+            _ => false,
+        }
+    }
+
+    fn diff_type(&mut self, item: &str, what: &str, old: &Type, new: &Type) {
+        if self.same_abi(old, new) {
+            return;
+        }
+        let (old_name, new_name) = (self.old_type(old), self.new_type(new));
+        let message = if old_name != new_name {
+            format!("{} changed from `{}` to `{}`", what, old_name, new_name)
+        } else {
+            // The same name for another type, through a typedef or with a
+            // different calling convention.
+            let old_resolved = self.old_type(&resolve(self.old, old));
+            let new_resolved = self.new_type(&resolve(self.new, new));
+            if old_resolved != new_resolved {
+                format!(
+                    "{} changed from `{}` (`{}`) to `{}` (`{}`)",
+                    what, old_name, old_resolved, new_name, new_resolved
+                )
+            } else {
+                format!("{} (`{}`) changed its ABI", what, old_name)
+            }
+        };
+        self.breaking(item, message);
+    }
+
+    fn diff_functions(&mut self) {
+        let key = |f: &Function| key(f.path.name(), f.cfg.as_ref());
+        let old = index(self.old.functions.iter(), key);
+        let new = index(self.new.functions.iter(), key);
+        self.diff_lists("function", old, new, |this, name, old, new| {
+            if old.abi != new.abi {
+                this.breaking(
+                    name,
+                    format!(
+                        "calling convention changed from \"{}\" to \"{}\"",
+                        old.abi.as_deref().unwrap_or("C"),
+                        new.abi.as_deref().unwrap_or("C")
+                    ),
+                );
+            }
+            this.diff_type(name, "return type", &old.ret, &new.ret);
+            if old.args.len() != new.args.len() {
+                this.breaking(
+                    name,
+                    format!(
+                        "now takes {} arguments instead of {}",
+                        new.args.len(),
+                        old.args.len()
+                    ),
+                );
+            }
+            for (i, (old_arg, new_arg)) in old.args.iter().zip(&new.args).enumerate() {
+                let what = match old_arg.name {
+                    Some(ref arg) => format!("type of argument `{}`", arg),
+                    None => format!("type of argument {}", i + 1),
+                };
+                this.diff_type(name, &what, &old_arg.ty, &new_arg.ty);
+            }
+        });
+    }
+
+    fn diff_constants(&mut self) {
+        let key = |c: &Constant| key(c.export_name(), c.cfg.as_ref());
+        let old = index(self.old.constants.iter(), key);
+        let new = index(self.new.constants.iter(), key);
+        self.diff_lists("constant", old, new, |this, name, old, new| {
+            this.diff_type(name, "type", &old.ty, &new.ty);
+            let old_value = serde_json::to_value(&old.value).unwrap();
+            let new_value = serde_json::to_value(&new.value).unwrap();
+            if old_value != new_value {
+                this.breaking(name, "changed value".to_owned());
+            }
+        });
+    }
+
+    fn diff_globals(&mut self) {
+        let key = |s: &Static| key(s.export_name(), s.cfg.as_ref());
+        let old = index(self.old.globals.iter(), key);
+        let new = index(self.new.globals.iter(), key);
+        self.diff_lists("static", old, new, |this, name, old, new| {
+            this.diff_type(name, "type", &old.ty, &new.ty);
+            if old.mutable != new.mutable {
+                let message = if new.mutable {
+                    "became mutable"
+                } else {
+                    "is no longer mutable"
+                };
+                this.breaking(name, message.to_owned());
+            }
+        });
+    }
+
+    fn diff_items(&mut self) {
+        let key = |item: &ItemContainer| {
+            let item = item.deref();
+            key(item.export_name(), item.cfg())
+        };
+        let old = index(self.old.items.iter(), key);
+        let new = index(self.new.items.iter(), key);
+        self.diff_lists("type", old, new, |this, name, old, new| match (old, new) {
+            (ItemContainer::Struct(old), ItemContainer::Struct(new)) => {
+                this.diff_struct(name, old, new)
+            }
+            (ItemContainer::Union(old), ItemContainer::Union(new)) => {
+                this.diff_union(name, old, new)
+            }
+            (ItemContainer::Enum(old), ItemContainer::Enum(new)) => this.diff_enum(name, old, new),
+            (ItemContainer::Typedef(old), ItemContainer::Typedef(new)) => {
+                this.diff_typedef(name, old, new)
+            }
+            (ItemContainer::OpaqueItem(..), ItemContainer::OpaqueItem(..)) => {}
+            _ => this.breaking(
+                name,
+                format!("changed from a {} to a {}", item_kind(old), item_kind(new)),
+            ),
+        });
+    }
+
+    fn diff_struct(&mut self, name: &str, old: &Struct, new: &Struct) {
+        if old.alignment != new.alignment {
+            self.breaking(name, "changed alignment or packing".to_owned());
+        }
+        if old.is_transparent != new.is_transparent {
+            self.breaking(name, "changed whether it is transparent".to_owned());
+        }
+        self.diff_fields(name, "", &old.fields, &new.fields);
+    }
+
+    fn diff_union(&mut self, name: &str, old: &Union, new: &Union) {
+        if old.alignment != new.alignment {
+            self.breaking(name, "changed alignment or packing".to_owned());
+        }
+        self.diff_fields(name, "", &old.fields, &new.fields);
+    }
+
+    fn diff_typedef(&mut self, name: &str, old: &Typedef, new: &Typedef) {
+        self.diff_type(name, "aliased type", &old.aliased, &new.aliased);
+    }
+
+    fn diff_enum(&mut self, name: &str, old: &Enum, new: &Enum) {
+        if old.repr != new.repr {
+            self.breaking(name, "changed repr".to_owned());
+        }
+
+        let old_values = discriminants(old);
+        let new_values = discriminants(new);
+        let old_variants = index(old.variants.iter(), |v| v.export_name.clone());
+        let new_variants = index(new.variants.iter(), |v| v.export_name.clone());
+        for (i, (variant, old_variant)) in old_variants.iter().enumerate() {
+            let Some(j) = new_variants.iter().position(|(v, _)| v == variant) else {
+                self.breaking(name, format!("variant `{}` was removed", variant));
+                continue;
+            };
+            let new_variant = new_variants[j].1;
+
+            match (&old_values[i], &new_values[j]) {
+                (Some(old_value), Some(new_value)) if old_value != new_value => self.breaking(
+                    name,
+                    format!(
+                        "variant `{}` changed discriminant from {} to {}",
+                        variant, old_value, new_value
+                    ),
+                ),
+                (Some(_), Some(_)) => {}
+                _ => {
+                    let old_value = serde_json::to_value(&old_variant.discriminant).unwrap();
+                    let new_value = serde_json::to_value(&new_variant.discriminant).unwrap();
+                    if old_value != new_value {
+                        self.breaking(name, format!("variant `{}` changed discriminant", variant));
+                    }
+                }
+            }
+
+            match (&old_variant.body, &new_variant.body) {
+                (VariantBody::Empty(..), VariantBody::Empty(..)) => {}
+                (
+                    VariantBody::Body { body: old_body, .. },
+                    VariantBody::Body { body: new_body, .. },
+                ) => {
+                    let what = format!("variant `{}`: ", variant);
+                    self.diff_fields(name, &what, &old_body.fields, &new_body.fields);
+                }
+                _ => self.breaking(name, format!("variant `{}` changed its fields", variant)),
+            }
+        }
+        // A new variant can make the union of the variants with data bigger.
+        let has_data = |e: &Enum| {
+            e.variants
+                .iter()
+                .any(|v| matches!(v.body, VariantBody::Body { .. }))
+        };
+        let kind = if has_data(old) || has_data(new) {
+            AbiChangeKind::Breaking
+        } else {
+            AbiChangeKind::Additive
+        };
+        for (variant, _) in &new_variants {
+            if !old_variants.iter().any(|(v, _)| v == variant) {
+                self.report(kind, name, format!("variant `{}` was added", variant));
+            }
+        }
+    }
+
+    /// Compares the fields of a struct, union or enum variant. Fields are
+    /// matched by name; since any difference changes the layout, additions
+    /// are breaking too.
+    fn diff_fields(&mut self, name: &str, prefix: &str, old: &[Field], new: &[Field]) {
+        for (i, old_field) in old.iter().enumerate() {
+            let Some(j) = new.iter().position(|f| f.name == old_field.name) else {
+                let renamed = new.get(i).filter(|f| {
+                    self.same_abi(&old_field.ty, &f.ty) && !old.iter().any(|o| o.name == f.name)
+                });
+                let message = match renamed {
+                    Some(f) => format!(
+                        "{}field `{}` was renamed to `{}`",
+                        prefix, old_field.name, f.name
+                    ),
+                    None => format!("{}field `{}` was removed", prefix, old_field.name),
+                };
+                self.breaking(name, message);
+                continue;
+            };
+            if i != j {
+                self.breaking(
+                    name,
+                    format!(
+                        "{}field `{}` moved from position {} to {}",
+                        prefix, old_field.name, i, j
+                    ),
+                );
+            }
+            let what = format!("{}type of field `{}`", prefix, old_field.name);
+            self.diff_type(name, &what, &old_field.ty, &new[j].ty);
+        }
+        for (i, new_field) in new.iter().enumerate() {
+            let is_new = !old.iter().any(|f| f.name == new_field.name);
+            let is_rename = old.get(i).is_some_and(|f| {
+                self.same_abi(&f.ty, &new_field.ty) && !new.iter().any(|n| n.name == f.name)
+            });
+            if is_new && !is_rename {
+                self.breaking(
+                    name,
+                    format!("{}field `{}` was added", prefix, new_field.name),
+                );
+            }
+        }
+    }
+}
+
+/// The values of the variants of `e`, where they can be computed.
+fn discriminants(e: &Enum) -> Vec<Option<i128>> {
+    let mut next = Some(0);
+    e.variants
+        .iter()
+        .map(|variant| {
+            let value = match variant.discriminant {
+                Some(ref discriminant) => literal_value(discriminant),
+                None => next,
+            };
+            next = value.map(|v| v + 1);
+            value
+        })
+        .collect()
+}
+
+/// Looks through the typedefs and transparent structs `ty` names.
+fn resolve(bindings: &Bindings, ty: &Type) -> Type {
+    let mut ty = ty.clone();
+    // Bounded, in case typedefs refer to each other.
+    for _ in 0..32 {
+        let aliased = match ty {
+            Type::Path(ref path) => bindings.aliased_type(path.path()),
+            _ => None,
+        };
+        match aliased {
+            Some(aliased) => ty = aliased,
+            None => break,
+        }
+    }
+    ty
+}
+
+/// Writes `ty` as a C type, the way it appears in the bindings.
+fn render_type(bindings: &Bindings, ty: &Type) -> String {
+    let mut out = Vec::new();
+    {
+        let mut writer = SourceWriter::new(&mut out, bindings);
+        let mut backend = CLikeLanguageBackend::new(&bindings.config);
+        cdecl::write_type(&mut backend, &mut writer, ty, &bindings.config);
+    }
+    String::from_utf8(out).unwrap()
+}
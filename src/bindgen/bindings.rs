@@ -4,20 +4,32 @@
 
 use std::borrow::Cow;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::fs::File;
-use std::io::{Read, Write};
+use std::io::{self, Write};
 use std::path;
+use std::process;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::bindgen::config::{Config, Language};
+use crate::bindgen::diagnostics::{self, Diagnostic, DiagnosticCode};
+use crate::bindgen::error::Error;
+use crate::bindgen::explain::{self, Explanation, Provenance};
 use crate::bindgen::ir::{
-    Constant, Function, ItemContainer, ItemMap, Path as BindgenPath, Static, Struct, Type, Typedef,
+    Cfg, Constant, Field, Function, Item, ItemContainer, ItemMap, Path as BindgenPath, Static,
+    Struct, Type, Typedef, VariantBody,
 };
+use crate::bindgen::json::{JsonDocument, ParsedLibrary, JSON_SCHEMA_VERSION};
 use crate::bindgen::language_backend::{
-    CLikeLanguageBackend, CythonLanguageBackend, LanguageBackend,
+    self, CLikeLanguageBackend, CSharpLanguageBackend, CythonLanguageBackend, LanguageBackend,
+    PythonLanguageBackend, ZigLanguageBackend,
 };
+use crate::bindgen::layout_tests;
+use crate::bindgen::loader;
+use crate::bindgen::symbols::{self, SymbolFileFormat};
+use crate::bindgen::unreachable::{self, UnreachableReport};
 use crate::bindgen::writer::SourceWriter;
 
 /// A bindings header that can be written.
@@ -25,18 +37,25 @@ pub struct Bindings {
     pub config: Config,
     /// The map from path to struct, used to lookup whether a given type is a
     /// transparent struct. This is needed to generate code for constants.
-    struct_map: ItemMap<Struct>,
-    typedef_map: ItemMap<Typedef>,
+    pub(crate) struct_map: ItemMap<Struct>,
+    pub(crate) typedef_map: ItemMap<Typedef>,
     struct_fileds_memo: RefCell<HashMap<BindgenPath, Rc<Vec<String>>>>,
     pub globals: Vec<Static>,
     pub constants: Vec<Constant>,
     pub items: Vec<ItemContainer>,
     pub functions: Vec<Function>,
-    source_files: Vec<path::PathBuf>,
+    pub(crate) source_files: Vec<path::PathBuf>,
+    /// The items as parsed, which `write_json` includes so bindings can be
+    /// generated again from its output.
+    pub(crate) parsed: ParsedLibrary,
     /// Bindings are generated by a recursive call to cbindgen
     /// and shouldn't do anything when written anywhere.
-    noop: bool,
+    pub(crate) noop: bool,
     pub package_version: String,
+    /// The diagnostics reported while generating the bindings.
+    pub(crate) diagnostics: Vec<Diagnostic>,
+    /// What `explain` needs to know about the generation.
+    pub(crate) provenance: Provenance,
 }
 
 impl Bindings {
@@ -50,6 +69,7 @@ impl Bindings {
         items: Vec<ItemContainer>,
         functions: Vec<Function>,
         source_files: Vec<path::PathBuf>,
+        parsed: ParsedLibrary,
         noop: bool,
         package_version: String,
     ) -> Bindings {
@@ -63,8 +83,241 @@ impl Bindings {
             items,
             functions,
             source_files,
+            parsed,
             noop,
             package_version,
+            diagnostics: Vec::new(),
+            provenance: Provenance::default(),
+        }
+    }
+
+    /// The diagnostics reported while generating the bindings.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Explains why the item named `name` is, or isn't, in the bindings: the
+    /// chain of items through which an exported function, global, constant
+    /// or `export.include` entry pulled it in, or the filter which dropped
+    /// it.
+    pub fn explain(&self, name: &str) -> Explanation {
+        explain::explain(self, name)
+    }
+
+    /// Reports the `repr(C)` types and `no_mangle` statics which were parsed
+    /// but left out of the bindings, the `extern` functions which were
+    /// skipped, and the `export.include` entries which match nothing.
+    pub fn unreachable(&self) -> UnreachableReport {
+        unreachable::unreachable(self)
+    }
+
+    /// Reports the cfgs of the items, fields and variants which have no
+    /// condition to be written with.
+    pub(crate) fn report_missing_defines(&self) {
+        let config = &self.config;
+        let report = |cfg: &Option<Cfg>, item: &str| {
+            if let Some(ref cfg) = *cfg {
+                cfg.report_missing_defines(config, item);
+            }
+        };
+        let report_fields = |fields: &[Field], item: &str| {
+            for field in fields {
+                report(&field.cfg, &format!("{}::{}", item, field.name));
+            }
+        };
+
+        for item in &self.items {
+            match *item {
+                ItemContainer::Constant(ref c) => report(&c.cfg, c.path.name()),
+                ItemContainer::Static(ref s) => report(&s.cfg, s.path.name()),
+                ItemContainer::OpaqueItem(ref o) => report(&o.cfg, o.path.name()),
+                ItemContainer::Typedef(ref t) => report(&t.cfg, t.path.name()),
+                ItemContainer::Struct(ref s) => {
+                    report(&s.cfg, s.path.name());
+                    report_fields(&s.fields, s.path.name());
+                }
+                ItemContainer::Union(ref u) => {
+                    report(&u.cfg, u.path.name());
+                    report_fields(&u.fields, u.path.name());
+                }
+                ItemContainer::Enum(ref e) => {
+                    report(&e.cfg, e.path.name());
+                    for variant in &e.variants {
+                        let name = format!("{}::{}", e.path.name(), variant.name);
+                        report(&variant.cfg, &name);
+                        if let VariantBody::Body { ref body, .. } = variant.body {
+                            report_fields(&body.fields, &name);
+                        }
+                    }
+                }
+            }
+        }
+        for constant in &self.constants {
+            report(&constant.cfg, constant.path.name());
+        }
+        for global in &self.globals {
+            report(&global.cfg, global.path.name());
+        }
+        for function in &self.functions {
+            report(&function.cfg, function.path.name());
+        }
+    }
+
+    /// Reports the calling conventions of the functions and function pointers
+    /// which can't be written in the output language, and which are written
+    /// as the one of `extern "C"` instead.
+    pub(crate) fn report_unsupported_abis(&self) {
+        let language = self.config.language;
+        let language_name = match language {
+            Language::CSharp => "C#".to_owned(),
+            _ => format!("{:?}", language),
+        };
+        let report = |abi: &str, item: &str| {
+            if !language_backend::supports_abi(language, abi) {
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::UnsupportedAbi,
+                        format!(
+                            "`{}` uses the \"{}\" calling convention, which can't be written \
+                             in {}; the C calling convention is written instead",
+                            item, abi, language_name
+                        ),
+                    )
+                    .with_item(item),
+                );
+            }
+        };
+        let report_type = |ty: &Type, item: &str| {
+            fn visit(ty: &Type, report: &mut dyn FnMut(&str)) {
+                match *ty {
+                    Type::Ptr { ref ty, .. } | Type::Array(ref ty, _) => visit(ty, report),
+                    Type::FuncPtr {
+                        ref ret,
+                        ref args,
+                        ref abi,
+                        ..
+                    } => {
+                        if let Some(ref abi) = *abi {
+                            report(abi);
+                        }
+                        visit(ret, report);
+                        for (_, ty) in args {
+                            visit(ty, report);
+                        }
+                    }
+                    Type::Path(..) | Type::Primitive(..) => {}
+                }
+            }
+            visit(ty, &mut |abi| report(abi, item));
+        };
+        let report_fields = |fields: &[Field], item: &str| {
+            for field in fields {
+                report_type(&field.ty, &format!("{}::{}", item, field.name));
+            }
+        };
+
+        for item in &self.items {
+            match *item {
+                ItemContainer::Typedef(ref t) => report_type(&t.aliased, t.path.name()),
+                ItemContainer::Struct(ref s) => report_fields(&s.fields, s.path.name()),
+                ItemContainer::Union(ref u) => report_fields(&u.fields, u.path.name()),
+                ItemContainer::Enum(ref e) => {
+                    for variant in &e.variants {
+                        if let VariantBody::Body { ref body, .. } = variant.body {
+                            let name = format!("{}::{}", e.path.name(), variant.name);
+                            report_fields(&body.fields, &name);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        for global in &self.globals {
+            report_type(&global.ty, global.path.name());
+        }
+        for function in &self.functions {
+            let name = function.path.name();
+            if let Some(ref abi) = function.abi {
+                report(abi, name);
+            }
+            report_type(&function.ret, name);
+            for arg in &function.args {
+                report_type(&arg.ty, name);
+            }
+        }
+    }
+
+    /// Keeps only the first of the items, constants, globals and functions,
+    /// and of the fields and variants of an item, with the same name for the
+    /// languages which can't write them under a condition, so the others don't
+    /// clash with it, and reports them.
+    pub(crate) fn remove_cfg_variants(&mut self) {
+        let language = self.config.language;
+        if !matches!(language, Language::Zig | Language::Python) {
+            return;
+        }
+
+        fn keep_first<T>(
+            items: &mut Vec<T>,
+            parent: Option<&str>,
+            name: impl Fn(&T) -> &str,
+            language: Language,
+        ) {
+            let mut seen = HashSet::new();
+            items.retain(|item| {
+                let name = name(item);
+                if seen.insert(name.to_owned()) {
+                    return true;
+                }
+                let name = match parent {
+                    Some(parent) => format!("{}::{}", parent, name),
+                    None => name.to_owned(),
+                };
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::CfgVariant,
+                        format!(
+                            "`{}` has versions for different cfgs, which can't be written in \
+                             {:?}; only the first one is written",
+                            name, language
+                        ),
+                    )
+                    .with_item(name),
+                );
+                false
+            });
+        }
+        fn keep_first_fields(parent: String, fields: &mut Vec<Field>, language: Language) {
+            keep_first(fields, Some(&parent), |f| &f.name, language);
+        }
+        keep_first(&mut self.items, None, |i| i.deref().export_name(), language);
+        keep_first(&mut self.constants, None, |c| c.export_name(), language);
+        keep_first(&mut self.globals, None, |g| g.export_name(), language);
+        keep_first(&mut self.functions, None, |f| f.path.name(), language);
+
+        for item in &mut self.items {
+            match *item {
+                ItemContainer::Struct(ref mut s) => {
+                    keep_first_fields(s.export_name().to_owned(), &mut s.fields, language)
+                }
+                ItemContainer::Union(ref mut u) => {
+                    keep_first_fields(u.export_name().to_owned(), &mut u.fields, language)
+                }
+                ItemContainer::Enum(ref mut e) => {
+                    let parent = e.export_name().to_owned();
+                    keep_first(&mut e.variants, Some(&parent), |v| &v.export_name, language);
+                    for variant in &mut e.variants {
+                        if let VariantBody::Body { ref mut body, .. } = variant.body {
+                            keep_first_fields(
+                                body.export_name().to_owned(),
+                                &mut body.fields,
+                                language,
+                            );
+                        }
+                    }
+                }
+                _ => {}
+            }
         }
     }
 
@@ -75,6 +328,20 @@ impl Bindings {
         any
     }
 
+    /// Returns the type aliased by a typedef or a transparent struct, for
+    /// languages which have no way to declare type aliases.
+    pub fn aliased_type(&self, path: &BindgenPath) -> Option<Type> {
+        let mut aliased = None;
+        self.typedef_map
+            .for_items(path, |t| aliased = Some(t.aliased.clone()));
+        self.struct_map.for_items(path, |s| {
+            if s.is_transparent {
+                aliased = Some(s.fields[0].ty.clone());
+            }
+        });
+        aliased
+    }
+
     /// Peels through typedefs to allow resolving structs.
     fn resolved_struct_path<'a>(&self, path: &'a BindgenPath) -> Cow<'a, BindgenPath> {
         let mut resolved_path = Cow::Borrowed(path);
@@ -130,18 +397,25 @@ impl Bindings {
     }
 
     pub fn generate_depfile<P: AsRef<path::Path>>(&self, header_path: P, depfile_path: P) {
-        if let Some(dir) = depfile_path.as_ref().parent() {
-            if !dir.exists() {
-                std::fs::create_dir_all(dir).unwrap()
-            }
-        }
-        let canon_header_path = header_path.as_ref().canonicalize().unwrap();
-        let mut canon_source_files: Vec<_> = self
+        self.try_generate_depfile(header_path, depfile_path)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `generate_depfile`, but returns an error instead of panicking when
+    /// a path can't be canonicalized or the depfile can't be written.
+    pub fn try_generate_depfile<P: AsRef<path::Path>>(
+        &self,
+        header_path: P,
+        depfile_path: P,
+    ) -> Result<(), Error> {
+        let canonicalize = |path: &path::Path| path.canonicalize().map_err(|e| io_error(path, e));
+        let canon_header_path = canonicalize(header_path.as_ref())?;
+        let mut canon_source_files = self
             .source_files
             .iter()
             .chain(self.config.config_path.as_ref())
-            .map(|p| p.canonicalize().unwrap())
-            .collect();
+            .map(|p| canonicalize(p))
+            .collect::<Result<Vec<_>, _>>()?;
         // Sorting makes testing easier by ensuring the output is ordered.
         canon_source_files.sort_unstable();
 
@@ -150,56 +424,156 @@ impl Bindings {
         // It is not clear how to otherwise _correctly_ replace whitespace in a non-unicode
         // compliant slice, without knowing the encoding, so we lossy convert such cases,
         // to avoid panics.
-        let mut depfile = File::create(depfile_path).unwrap();
-        write!(
-            &mut depfile,
-            "{}:",
-            canon_header_path.to_string_lossy().replace(' ', "\\ ")
-        )
-        .expect("Writing header name to depfile failed");
-        canon_source_files.into_iter().for_each(|source_file| {
+        let mut depfile = canon_header_path.to_string_lossy().replace(' ', "\\ ");
+        depfile.push(':');
+        for source_file in canon_source_files {
             // Add line-continue and line-break and then indent with 4 spaces.
             // This makes the output more human-readable.
-            depfile.write_all(b" \\\n    ").unwrap();
-            let escaped_path = source_file.to_string_lossy().replace(' ', "\\ ");
-            depfile.write_all(escaped_path.as_bytes()).unwrap();
-        });
-
-        writeln!(&mut depfile).unwrap();
+            depfile.push_str(" \\\n    ");
+            depfile.push_str(&source_file.to_string_lossy().replace(' ', "\\ "));
+        }
+        depfile.push('\n');
 
-        depfile.flush().unwrap();
+        write_atomically(depfile_path.as_ref(), depfile.as_bytes())
     }
 
     pub fn write_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
-        if self.noop {
-            return false;
-        }
+        self.try_write_to_file(path)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        // Don't compare files if we've never written this file before
-        if !path.as_ref().is_file() {
-            if let Some(parent) = path::Path::new(path.as_ref()).parent() {
-                fs::create_dir_all(parent).unwrap();
-            }
-            self.write(File::create(path).unwrap());
-            return true;
+    /// Like `write_to_file`, but returns an error instead of panicking when the
+    /// file can't be written. Returns whether the file changed.
+    pub fn try_write_to_file<P: AsRef<path::Path>>(&self, path: P) -> Result<bool, Error> {
+        self.try_update_file(path, |out| {
+            self.write(out);
+            Ok(())
+        })
+    }
+
+    /// Like `write_to_file`, but writes the JSON IR. See `write_json`.
+    pub fn write_json_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        self.try_write_json_to_file(path)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `write_json_to_file`, but returns an error instead of panicking.
+    pub fn try_write_json_to_file<P: AsRef<path::Path>>(&self, path: P) -> Result<bool, Error> {
+        self.try_update_file(path, |out| self.write_json(out))
+    }
+
+    /// Like `write_to_file`, but writes Rust layout tests. See
+    /// `write_rust_layout_tests`.
+    pub fn write_rust_layout_tests_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        self.try_write_rust_layout_tests_to_file(path)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `write_rust_layout_tests_to_file`, but returns an error instead of
+    /// panicking.
+    pub fn try_write_rust_layout_tests_to_file<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+    ) -> Result<bool, Error> {
+        self.try_update_file(path, |out| {
+            self.write_rust_layout_tests(out);
+            Ok(())
+        })
+    }
+
+    /// Like `write_to_file`, but writes C layout tests. See
+    /// `write_c_layout_tests`.
+    pub fn write_c_layout_tests_to_file<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        header: &str,
+    ) -> bool {
+        self.try_write_c_layout_tests_to_file(path, header)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `write_c_layout_tests_to_file`, but returns an error instead of
+    /// panicking.
+    pub fn try_write_c_layout_tests_to_file<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        header: &str,
+    ) -> Result<bool, Error> {
+        self.try_update_file(path, |out| {
+            self.write_c_layout_tests(out, header);
+            Ok(())
+        })
+    }
+
+    /// Like `write_to_file`, but writes a dynamic loader. See `write_loader`.
+    pub fn write_loader_to_file<P: AsRef<path::Path>>(&self, path: P, header: &str) -> bool {
+        self.try_write_loader_to_file(path, header)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `write_loader_to_file`, but returns an error instead of panicking.
+    pub fn try_write_loader_to_file<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        header: &str,
+    ) -> Result<bool, Error> {
+        self.try_update_file(path, |out| {
+            self.write_loader(out, header);
+            Ok(())
+        })
+    }
+
+    /// Like `write_to_file`, but writes a list of the exported symbols. See
+    /// `write_symbol_file`.
+    pub fn write_symbol_file_to_file<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        format: SymbolFileFormat,
+        defines: &[&str],
+    ) -> bool {
+        self.try_write_symbol_file_to_file(path, format, defines)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `write_symbol_file_to_file`, but returns an error instead of
+    /// panicking.
+    pub fn try_write_symbol_file_to_file<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        format: SymbolFileFormat,
+        defines: &[&str],
+    ) -> Result<bool, Error> {
+        self.try_update_file(path, |out| {
+            self.write_symbol_file(out, format, defines);
+            Ok(())
+        })
+    }
+
+    /// Writes `path` with the output of `write`, unless it already has the
+    /// same contents. Returns whether the file changed.
+    fn try_update_file<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        write: impl Fn(&mut Vec<u8>) -> io::Result<()>,
+    ) -> Result<bool, Error> {
+        if self.noop {
+            return Ok(false);
         }
 
+        let path = path.as_ref();
         let mut new_file_contents = Vec::new();
-        self.write(&mut new_file_contents);
+        write(&mut new_file_contents).map_err(|e| io_error(path, e))?;
 
-        let mut old_file_contents = Vec::new();
-        {
-            let mut old_file = File::open(&path).unwrap();
-            old_file.read_to_end(&mut old_file_contents).unwrap();
+        match fs::read(path) {
+            Ok(old_file_contents) if old_file_contents == new_file_contents => return Ok(false),
+            Ok(_) => {}
+            // Don't compare files if we've never written this file before
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(io_error(path, e)),
         }
 
-        if old_file_contents != new_file_contents {
-            let mut new_file = File::create(&path).unwrap();
-            new_file.write_all(&new_file_contents).unwrap();
-            true
-        } else {
-            false
-        }
+        write_atomically(path, &new_file_contents)?;
+        Ok(true)
     }
 
     pub fn write<F: Write>(&self, file: F) {
@@ -210,7 +584,131 @@ impl Bindings {
             Language::Cython => {
                 self.write_with_backend(file, &mut CythonLanguageBackend::new(&self.config))
             }
+            Language::Zig => {
+                self.write_with_backend(file, &mut ZigLanguageBackend::new(&self.config))
+            }
+            Language::CSharp => {
+                self.write_with_backend(file, &mut CSharpLanguageBackend::new(&self.config))
+            }
+            Language::Python => {
+                self.write_with_backend(file, &mut PythonLanguageBackend::new(&self.config))
+            }
+        }
+    }
+
+    /// Like `write`, but returns an error instead of panicking when `file`
+    /// can't be written.
+    pub fn try_write<F: Write>(&self, file: F) -> Result<(), Error> {
+        let mut out = Vec::new();
+        self.write(&mut out);
+        write_all(file, &out)
+    }
+
+    /// Like `write_json`, but returns an error instead of panicking when `file`
+    /// can't be written.
+    pub fn try_write_json<F: Write>(&self, file: F) -> Result<(), Error> {
+        let mut out = Vec::new();
+        self.write_json(&mut out)
+            .map_err(|error| Error::Output { error })?;
+        write_all(file, &out)
+    }
+
+    /// Writes the resolved IR as a JSON document, for tools which want to
+    /// generate their own code from cbindgen's analysis.
+    ///
+    /// The document holds the same constants, globals, items and functions
+    /// the language backends get, after renaming, monomorphization and
+    /// dependency sorting. Its layout is versioned by `JSON_SCHEMA_VERSION`.
+    ///
+    /// The document also holds the items as they were parsed, which
+    /// `Builder::with_ir_file` reads to generate bindings without the crate.
+    pub fn write_json<F: Write>(&self, mut file: F) -> io::Result<()> {
+        if self.noop {
+            return Ok(());
         }
+
+        let document = JsonDocument {
+            schema_version: JSON_SCHEMA_VERSION,
+            cbindgen_version: crate::bindgen::config::VERSION,
+            package_version: &self.package_version,
+            constants: &self.constants,
+            globals: &self.globals,
+            items: &self.items,
+            functions: &self.functions,
+            library: &self.parsed,
+        };
+        serde_json::to_writer_pretty(&mut file, &document)?;
+        writeln!(file)
+    }
+
+    /// Writes Rust tests which check the size, alignment and field offsets of
+    /// the exported structs, unions and enums with fields against the layout C
+    /// gives them. The output is meant to be `include!`d in a module of the
+    /// crate where the exported types are in scope.
+    ///
+    /// Only 64-bit targets are checked. Types whose layout can't be known
+    /// from the IR, like opaque or generic ones, are skipped.
+    pub fn write_rust_layout_tests<F: Write>(&self, file: F) {
+        if self.noop {
+            return;
+        }
+
+        layout_tests::write_rust(self, file);
+    }
+
+    /// Writes a C or C++ source file with the static assertions matching
+    /// `write_rust_layout_tests`, which includes `header`, the generated
+    /// bindings.
+    pub fn write_c_layout_tests<F: Write>(&self, file: F, header: &str) {
+        if self.noop {
+            return;
+        }
+
+        match self.config.language {
+            Language::Cxx | Language::C => layout_tests::write_c(self, file, header),
+            _ => warn!(
+                "Layout tests can only be written for C and C++, not {:?}.",
+                self.config.language
+            ),
+        }
+    }
+
+    /// Writes a C or C++ header which loads the library at runtime, for hosts
+    /// which can't link to it. It includes `header`, the generated bindings,
+    /// for the types the functions use.
+    ///
+    /// The header defines a `<prefix>_api` struct with a pointer for each
+    /// function the crate defines, and `<prefix>_load` and `<prefix>_unload`
+    /// functions which fill it with `dlopen` and `dlsym`, and close it. The
+    /// prefix is `loader.prefix` in the config, or the file name of `header`.
+    pub fn write_loader<F: Write>(&self, file: F, header: &str) {
+        if self.noop {
+            return;
+        }
+
+        match self.config.language {
+            Language::Cxx | Language::C => loader::write(self, file, header),
+            _ => warn!(
+                "Loaders can only be written for C and C++, not {:?}.",
+                self.config.language
+            ),
+        }
+    }
+
+    /// Writes the names of the functions and globals the crate exports, in a
+    /// linker input `format`, so that a `cdylib` exports exactly what the
+    /// header declares.
+    ///
+    /// Items behind a `cfg` which maps to a define in the `[defines]` config
+    /// section are only listed if their condition holds when exactly
+    /// `defines` are defined, so one variant of the file can be written for
+    /// each configuration the library is built in.
+    pub fn write_symbol_file<F: Write>(&self, file: F, format: SymbolFileFormat, defines: &[&str]) {
+        if self.noop {
+            return;
+        }
+
+        symbols::write(self, file, format, defines);
     }
 
     fn write_with_backend<F: Write, LB: LanguageBackend>(
@@ -227,3 +725,61 @@ impl Bindings {
         language_backend.write_bindings(&mut out, self);
     }
 }
+
+fn io_error(path: &path::Path, error: io::Error) -> Error {
+    Error::Io {
+        path: path.display().to_string(),
+        error,
+    }
+}
+
+fn write_all<F: Write>(mut file: F, contents: &[u8]) -> Result<(), Error> {
+    file.write_all(contents)
+        .and_then(|()| file.flush())
+        .map_err(|error| Error::Output { error })
+}
+
+/// Writes `contents` to `path`, creating its directory if needed.
+///
+/// The contents are written to a temporary file next to `path` first, which
+/// then replaces it, so that an interrupted generation never leaves a
+/// truncated file behind for incremental builds to pick up.
+fn write_atomically(path: &path::Path, contents: &[u8]) -> Result<(), Error> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => path::Path::new("."),
+    };
+    fs::create_dir_all(dir).map_err(|e| io_error(dir, e))?;
+
+    let file_name = path.file_name().ok_or_else(|| Error::Io {
+        path: path.display().to_string(),
+        error: io::Error::new(io::ErrorKind::InvalidInput, "not a file path"),
+    })?;
+    // The process id and the counter keep the temporary files of concurrent
+    // writes apart, from other processes and from other threads.
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let mut temp_name = std::ffi::OsString::from(".");
+    temp_name.push(file_name);
+    temp_name.push(format!(
+        ".{}.{}.tmp",
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let temp_path = dir.join(temp_name);
+
+    let result = (|| {
+        let mut file = File::create(&temp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        // Keep the permissions of the file being replaced.
+        if let Ok(metadata) = fs::metadata(path) {
+            fs::set_permissions(&temp_path, metadata.permissions())?;
+        }
+        fs::rename(&temp_path, path)
+    })();
+    if let Err(e) = result {
+        let _ = fs::remove_file(&temp_path);
+        return Err(io_error(path, e));
+    }
+    Ok(())
+}
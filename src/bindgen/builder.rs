@@ -7,9 +7,14 @@ use std::path;
 use crate::bindgen::bindings::Bindings;
 use crate::bindgen::cargo::Cargo;
 use crate::bindgen::config::{Braces, Config, Language, Profile, Style};
+use crate::bindgen::diagnostics::{self, Severity};
 use crate::bindgen::error::Error;
+use crate::bindgen::json;
 use crate::bindgen::library::Library;
+use crate::bindgen::merge;
 use crate::bindgen::parser::{self, Parse};
+use crate::bindgen::rustdoc;
+use crate::bindgen::target;
 
 /// A builder for generating a bindings header.
 #[derive(Debug, Clone)]
@@ -18,6 +23,8 @@ pub struct Builder {
     srcs: Vec<path::PathBuf>,
     lib: Option<(path::PathBuf, Option<String>)>,
     lib_cargo: Option<Cargo>,
+    ir_file: Option<path::PathBuf>,
+    rustdoc_json: Vec<path::PathBuf>,
     std_types: bool,
     lockfile: Option<path::PathBuf>,
 }
@@ -30,6 +37,8 @@ impl Builder {
             srcs: Vec::new(),
             lib: None,
             lib_cargo: None,
+            ir_file: None,
+            rustdoc_json: Vec::new(),
             std_types: true,
             lockfile: None,
         }
@@ -309,6 +318,7 @@ impl Builder {
     pub fn with_crate<P: AsRef<path::Path>>(mut self, lib_dir: P) -> Builder {
         debug_assert!(self.lib.is_none());
         debug_assert!(self.lib_cargo.is_none());
+        debug_assert!(self.ir_file.is_none());
         self.lib = Some((path::PathBuf::from(lib_dir.as_ref()), None));
         self
     }
@@ -321,6 +331,7 @@ impl Builder {
     ) -> Builder {
         debug_assert!(self.lib.is_none());
         debug_assert!(self.lib_cargo.is_none());
+        debug_assert!(self.ir_file.is_none());
         self.lib = Some((
             path::PathBuf::from(lib_dir.as_ref()),
             Some(String::from(binding_lib_name.as_ref())),
@@ -332,10 +343,43 @@ impl Builder {
     pub(crate) fn with_cargo(mut self, lib: Cargo) -> Builder {
         debug_assert!(self.lib.is_none());
         debug_assert!(self.lib_cargo.is_none());
+        debug_assert!(self.ir_file.is_none());
         self.lib_cargo = Some(lib);
         self
     }
 
+    /// Generates the bindings from a JSON IR document written by
+    /// `Bindings::write_json`, instead of parsing a crate.
+    ///
+    /// The items are read back as they were parsed, so the rest of the config
+    /// (language, style, renaming, `export.include`, ...) applies as usual.
+    #[allow(unused)]
+    pub fn with_ir_file<P: AsRef<path::Path>>(mut self, ir_file: P) -> Builder {
+        debug_assert!(self.lib.is_none());
+        debug_assert!(self.lib_cargo.is_none());
+        self.ir_file = Some(path::PathBuf::from(ir_file.as_ref()));
+        self
+    }
+
+    /// Generates the bindings from a rustdoc JSON document
+    /// (`rustdoc --output-format json`), instead of parsing the source of a
+    /// crate.
+    ///
+    /// This may be called several times to read the documents of the
+    /// dependencies too, the first document being the one of the crate to
+    /// generate bindings for. The documents should include the private items
+    /// (`--document-private-items`), otherwise types with private fields are
+    /// opaque.
+    #[allow(unused)]
+    pub fn with_rustdoc_json<P: AsRef<path::Path>>(mut self, rustdoc_json: P) -> Builder {
+        debug_assert!(self.lib.is_none());
+        debug_assert!(self.lib_cargo.is_none());
+        debug_assert!(self.ir_file.is_none());
+        self.rustdoc_json
+            .push(path::PathBuf::from(rustdoc_json.as_ref()));
+        self
+    }
+
     #[allow(unused)]
     pub fn with_lockfile<P: AsRef<path::Path>>(mut self, lockfile: P) -> Builder {
         debug_assert!(self.lockfile.is_none());
@@ -345,6 +389,27 @@ impl Builder {
     }
 
     pub fn generate(self) -> Result<Bindings, Error> {
+        let config = self.config.diagnostics.clone();
+        let (bindings, diagnostics) = diagnostics::collect(&config, || {
+            let mut bindings = self.generate_bindings()?;
+            bindings.remove_cfg_variants();
+            bindings.report_missing_defines();
+            bindings.report_unsupported_abis();
+            Ok(bindings)
+        });
+        let mut bindings = bindings?;
+        let errors = diagnostics
+            .iter()
+            .filter(|d| d.severity == Severity::Error)
+            .count();
+        if errors > 0 {
+            return Err(Error::Diagnostics { errors });
+        }
+        bindings.diagnostics = diagnostics;
+        Ok(bindings)
+    }
+
+    fn generate_bindings(self) -> Result<Bindings, Error> {
         // If macro expansion is enabled, then cbindgen will attempt to build the crate
         // and will run its build script which may run cbindgen again. That second run may start
         // infinite recursion, or overwrite previously written files with bindings.
@@ -360,11 +425,23 @@ impl Builder {
                 Default::default(),
                 Default::default(),
                 Default::default(),
+                Default::default(),
                 true,
                 String::new(),
             ));
         }
 
+        if !self.config.parse.targets.is_empty() {
+            let mut generated = Vec::with_capacity(self.config.parse.targets.len());
+            for (target, cfg) in target::target_cfgs(&self.config)? {
+                let mut builder = self.clone();
+                builder.config.parse.target = Some(target);
+                builder.config.parse.targets.clear();
+                generated.push((cfg, builder.generate()?));
+            }
+            return Ok(merge::merge(generated));
+        }
+
         let mut result = Parse::new();
 
         if self.std_types {
@@ -375,7 +452,14 @@ impl Builder {
             result.extend_with(&parser::parse_src(x, &self.config)?);
         }
 
-        if let Some((lib_dir, binding_lib_name)) = self.lib.clone() {
+        if let Some(ref ir_file) = self.ir_file {
+            result.extend_with(&json::read_ir_file(ir_file, &self.config)?);
+        } else if !self.rustdoc_json.is_empty() {
+            result.extend_with(&rustdoc::parse_rustdoc_json(
+                &self.rustdoc_json,
+                &self.config,
+            )?);
+        } else if let Some((lib_dir, binding_lib_name)) = self.lib.clone() {
             let lockfile = self.lockfile.as_deref();
 
             let cargo = Cargo::load(
@@ -407,6 +491,7 @@ impl Builder {
             result.functions,
             result.source_files,
             result.package_version,
+            result.provenance,
         )
         .generate()
     }
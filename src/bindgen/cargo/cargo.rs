@@ -2,6 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::bindgen::cargo::cargo_expand;
@@ -243,6 +244,45 @@ impl Cargo {
             })
     }
 
+    /// Finds the `OUT_DIR` of the latest build script run of a package, in
+    /// `target/<profile>/build/<package>-<hash>/out` or the same under a
+    /// target triple directory.
+    pub(crate) fn find_out_dir(&self, package: &PackageRef) -> Option<PathBuf> {
+        let target_dir = match self.metadata.target_directory {
+            Some(ref dir) => PathBuf::from(dir),
+            None => Path::new(&self.metadata.workspace_root).join("target"),
+        };
+        let prefix = format!("{}-", package.name);
+
+        let mut build_dirs = Vec::new();
+        for profile_dir in fs::read_dir(target_dir).ok()?.flatten() {
+            let profile_dir = profile_dir.path();
+            build_dirs.push(profile_dir.join("build"));
+            if let Ok(triple_dirs) = fs::read_dir(&profile_dir) {
+                build_dirs.extend(triple_dirs.flatten().map(|dir| dir.path().join("build")));
+            }
+        }
+
+        build_dirs
+            .iter()
+            .filter_map(|build_dir| fs::read_dir(build_dir).ok())
+            .flat_map(|dirs| dirs.flatten())
+            .filter(|dir| {
+                // The rest is a hash, so `foo-bar-<hash>` isn't taken for `foo`.
+                let name = dir.file_name();
+                let name = name.to_string_lossy();
+                name.strip_prefix(&prefix)
+                    .is_some_and(|hash| hash.chars().all(|c| c.is_ascii_hexdigit()))
+            })
+            .map(|dir| dir.path().join("out"))
+            .filter_map(|out_dir| {
+                let modified = fs::metadata(&out_dir).ok()?.modified().ok()?;
+                Some((modified, out_dir))
+            })
+            .max()
+            .map(|(_, out_dir)| out_dir)
+    }
+
     pub(crate) fn expand_crate(
         &self,
         package: &PackageRef,
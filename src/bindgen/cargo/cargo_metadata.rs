@@ -29,6 +29,9 @@ pub struct Metadata {
     version: usize,
     /// path to the workspace containing the `Cargo.lock`
     pub workspace_root: String,
+    /// path to the directory cargo builds into
+    #[serde(default)]
+    pub target_directory: Option<String>,
 }
 
 /// A reference to a package including it's name and the specific version.
@@ -26,6 +26,7 @@ enum CDeclarator {
         args: Vec<(Option<String>, CDecl)>,
         layout: Layout,
         never_return: bool,
+        abi: Option<String>,
     },
 }
 
@@ -101,6 +102,7 @@ impl CDecl {
             args,
             layout,
             never_return: f.never_return,
+            abi: f.abi.clone(),
         });
         self.deprecated = f.annotations.deprecated.clone();
         self.build_type(&f.ret, false, config);
@@ -172,6 +174,8 @@ impl CDecl {
                 ref args,
                 is_nullable: _,
                 never_return,
+                ref abi,
+                rust_abi: _,
             } => {
                 let args = args
                     .iter()
@@ -186,6 +190,7 @@ impl CDecl {
                     args,
                     layout: config.function.args,
                     never_return: *never_return,
+                    abi: abi.clone(),
                 });
                 self.build_type(ret, false, config);
             }
@@ -259,10 +264,17 @@ impl CDecl {
                         out.write("(");
                     }
                 }
-                CDeclarator::Func { .. } => {
+                CDeclarator::Func { ref abi, .. } => {
                     if next_is_pointer {
                         out.write("(");
                     }
+                    // The calling convention goes right before the declarator
+                    // of the function, as in `int (CALLBACK *f)(int)`.
+                    if config.language != Language::Cython {
+                        if let Some(abi_macro) = config.function.abi_macro(abi.as_deref()) {
+                            write!(out, "{} ", abi_macro);
+                        }
+                    }
                 }
             }
         }
@@ -294,6 +306,7 @@ impl CDecl {
                     ref args,
                     ref layout,
                     never_return,
+                    ..
                 } => {
                     if last_was_pointer {
                         out.write(")");
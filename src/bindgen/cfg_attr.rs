@@ -0,0 +1,375 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Expansion of `#[cfg_attr]`s when bindings aren't generated for a single
+//! target.
+//!
+//! The attributes which change what is written for an item, like `repr` or
+//! `no_mangle`, split the item in two: one with the attribute under the
+//! predicate of the `cfg_attr`, and one without it under its negation. Fields,
+//! variants and foreign items are split the same way. Modules and impls only
+//! get the `cfg`s, combined with the predicate. The other attributes, like doc
+//! comments, apply whatever the predicate.
+//!
+//! When bindings are generated for a single target, the predicates are
+//! evaluated instead, see [`expand_for_target`].
+
+use std::mem;
+
+use quote::ToTokens;
+use syn::fold::{self, Fold};
+use syn::punctuated::Punctuated;
+
+use crate::bindgen::ir::Cfg;
+use crate::bindgen::modules::ParsedModule;
+use crate::bindgen::target::TargetCfg;
+use crate::bindgen::utilities::{unwrap_unsafe_attr, SynItemHelpers};
+
+/// The attributes an item is split on.
+const CONDITIONAL_ATTRS: &[&str] = &[
+    "cfg",
+    "repr",
+    "no_mangle",
+    "export_name",
+    "deprecated",
+    "must_use",
+];
+
+/// The attributes a `#[cfg_attr]` applies under the same predicate.
+struct Conditional {
+    predicate: syn::NestedMeta,
+    attrs: Vec<syn::Attribute>,
+}
+
+fn is_conditional(meta: &syn::Meta) -> bool {
+    let meta = unwrap_unsafe_attr(meta.clone());
+    CONDITIONAL_ATTRS
+        .iter()
+        .any(|&name| meta.path().is_ident(name))
+}
+
+/// Adds the attributes of a `#[cfg_attr]` with `predicate` either to `attrs`,
+/// or to the `conditionals`. With a `target`, the attributes are only added
+/// to `attrs` if `predicate` holds for it.
+fn expand_meta(
+    predicate: syn::NestedMeta,
+    nested: impl IntoIterator<Item = syn::NestedMeta>,
+    target: Option<&TargetCfg>,
+    attrs: &mut Vec<syn::Attribute>,
+    conditionals: &mut Vec<Conditional>,
+) {
+    if let Some(target) = target {
+        if !Cfg::load_single(&predicate).is_some_and(|cfg| target.matches(&cfg)) {
+            return;
+        }
+    }
+    for meta in nested {
+        let syn::NestedMeta::Meta(meta) = meta else {
+            continue;
+        };
+        if meta.path().is_ident("cfg_attr") {
+            let syn::Meta::List(list) = meta else {
+                continue;
+            };
+            let mut inner = list.nested.into_iter();
+            let Some(inner_predicate) = inner.next() else {
+                continue;
+            };
+            let predicate = parse_quote!(all(#predicate, #inner_predicate));
+            expand_meta(predicate, inner, target, attrs, conditionals);
+        } else if target.is_none() && is_conditional(&meta) {
+            let attr = parse_quote!(#[#meta]);
+            let key = predicate.to_token_stream().to_string();
+            match conditionals
+                .iter_mut()
+                .find(|c| c.predicate.to_token_stream().to_string() == key)
+            {
+                Some(conditional) => conditional.attrs.push(attr),
+                None => conditionals.push(Conditional {
+                    predicate: predicate.clone(),
+                    attrs: vec![attr],
+                }),
+            }
+        } else {
+            attrs.push(parse_quote!(#[#meta]));
+        }
+    }
+}
+
+/// Replaces the `#[cfg_attr]`s of `attrs` with the attributes they apply, and
+/// returns the ones the item has to be split on, which there are none of with
+/// a `target`.
+fn expand_cfg_attrs(
+    attrs: &mut Vec<syn::Attribute>,
+    target: Option<&TargetCfg>,
+) -> Vec<Conditional> {
+    let mut conditionals = Vec::new();
+    if !attrs.iter().any(|attr| attr.path.is_ident("cfg_attr")) {
+        return conditionals;
+    }
+    for attr in mem::take(attrs) {
+        if !attr.path.is_ident("cfg_attr") {
+            attrs.push(attr);
+            continue;
+        }
+        let Ok(syn::Meta::List(list)) = attr.parse_meta() else {
+            warn!("Ignoring unparsable `{}`.", attr.to_token_stream());
+            continue;
+        };
+        let mut nested = list.nested.into_iter();
+        let Some(predicate) = nested.next() else {
+            continue;
+        };
+        if Cfg::load_single(&predicate).is_none() {
+            warn!(
+                "Ignoring a `cfg_attr` with the unsupported predicate `{}`.",
+                predicate.to_token_stream()
+            );
+            continue;
+        }
+        expand_meta(predicate, nested, target, attrs, &mut conditionals);
+    }
+    conditionals
+}
+
+/// Replaces the `#[cfg_attr]`s of `attrs` with the attributes they apply for
+/// `target`.
+pub(crate) fn expand_for_target(attrs: &mut Vec<syn::Attribute>, target: &TargetCfg) {
+    let conditionals = expand_cfg_attrs(attrs, Some(target));
+    debug_assert!(conditionals.is_empty());
+}
+
+/// Returns the predicate `not(...)` negates, if it is one.
+fn negated(predicate: &syn::NestedMeta) -> Option<&syn::NestedMeta> {
+    match *predicate {
+        syn::NestedMeta::Meta(syn::Meta::List(ref list))
+            if list.path.is_ident("not") && list.nested.len() == 1 =>
+        {
+            list.nested.first()
+        }
+        _ => None,
+    }
+}
+
+/// Expands the `#[cfg_attr]`s of `item`, splitting it on the conditional
+/// attributes.
+fn split<T: Clone>(mut item: T, attrs: fn(&mut T) -> &mut Vec<syn::Attribute>) -> Vec<T> {
+    let conditionals = expand_cfg_attrs(attrs(&mut item), None);
+    // Each variant with whether it holds under the predicates it was split on,
+    // so it isn't split again on one of them or its negation.
+    let mut items = vec![(item, Vec::<(String, bool)>::new())];
+    for conditional in conditionals {
+        let (predicate, holds) = match negated(&conditional.predicate) {
+            Some(predicate) => (predicate, false),
+            None => (&conditional.predicate, true),
+        };
+        let key = predicate.to_token_stream().to_string();
+        items = items
+            .into_iter()
+            .flat_map(|(mut item, assumed)| {
+                let known = assumed.iter().find(|(k, _)| *k == key).map(|&(_, h)| h);
+                match known {
+                    Some(known) => {
+                        if known == holds {
+                            attrs(&mut item).extend(conditional.attrs.iter().cloned());
+                        }
+                        vec![(item, assumed)]
+                    }
+                    None => {
+                        let mut with = item.clone();
+                        attrs(&mut with).extend(conditional.attrs.iter().cloned());
+                        let mut without = item;
+                        let (mut with_assumed, mut without_assumed) = (assumed.clone(), assumed);
+                        with_assumed.push((key.clone(), holds));
+                        without_assumed.push((key.clone(), !holds));
+                        for (item, holds) in [(&mut with, holds), (&mut without, !holds)] {
+                            attrs(item).push(if holds {
+                                parse_quote!(#[cfg(#predicate)])
+                            } else {
+                                parse_quote!(#[cfg(not(#predicate))])
+                            });
+                        }
+                        vec![(with, with_assumed), (without, without_assumed)]
+                    }
+                }
+            })
+            .collect();
+    }
+    items.into_iter().map(|(item, _)| item).collect()
+}
+
+/// Splits a function or static, keeping only the exported variants unless
+/// none of them is.
+fn split_exported<T: Clone + SynItemHelpers>(
+    item: T,
+    attrs: fn(&mut T) -> &mut Vec<syn::Attribute>,
+) -> Vec<T> {
+    let mut items = split(item, attrs);
+    if items.len() > 1 && items.iter().any(|item| item.exported_name().is_some()) {
+        items.retain(|item| item.exported_name().is_some());
+    }
+    items
+}
+
+/// Expands the `#[cfg_attr]`s of a module or impl, which can't be split: a
+/// `cfg` only applies where the predicate holds, and the other conditional
+/// attributes don't change what is written for them.
+fn expand(attrs: &mut Vec<syn::Attribute>) {
+    for conditional in expand_cfg_attrs(attrs, None) {
+        let predicate = conditional.predicate;
+        for attr in conditional.attrs {
+            if !attr.path.is_ident("cfg") {
+                continue;
+            }
+            let Ok(syn::Meta::List(list)) = attr.parse_meta() else {
+                continue;
+            };
+            let cfg = list.nested;
+            attrs.push(parse_quote!(#[cfg(any(not(#predicate), #cfg))]));
+        }
+    }
+}
+
+/// Splits the fields of a struct, union or variant.
+fn split_fields(
+    fields: Punctuated<syn::Field, syn::Token![,]>,
+) -> Punctuated<syn::Field, syn::Token![,]> {
+    fields
+        .into_iter()
+        .flat_map(|field| split(field, |field| &mut field.attrs))
+        .collect()
+}
+
+struct CfgAttrs;
+
+impl CfgAttrs {
+    fn expand_items(&mut self, items: Vec<syn::Item>) -> Vec<syn::Item> {
+        items
+            .into_iter()
+            .flat_map(|item| match item {
+                syn::Item::Fn(item) => split_exported(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::Item::Fn)
+                    .collect(),
+                syn::Item::Static(item) => split_exported(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::Item::Static)
+                    .collect(),
+                syn::Item::Struct(item) => split(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::Item::Struct)
+                    .collect(),
+                syn::Item::Union(item) => split(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::Item::Union)
+                    .collect(),
+                syn::Item::Enum(item) => split(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::Item::Enum)
+                    .collect(),
+                syn::Item::Const(item) => split(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::Item::Const)
+                    .collect(),
+                syn::Item::Type(item) => split(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::Item::Type)
+                    .collect(),
+                mut item => {
+                    match item {
+                        syn::Item::ForeignMod(ref mut item) => expand(&mut item.attrs),
+                        syn::Item::Impl(ref mut item) => expand(&mut item.attrs),
+                        syn::Item::Mod(ref mut item) => expand(&mut item.attrs),
+                        _ => {}
+                    }
+                    vec![item]
+                }
+            })
+            .map(|item| self.fold_item(item))
+            .collect()
+    }
+}
+
+impl Fold for CfgAttrs {
+    fn fold_item_mod(&mut self, mut item: syn::ItemMod) -> syn::ItemMod {
+        if let Some((brace, items)) = item.content.take() {
+            item.content = Some((brace, self.expand_items(items)));
+        }
+        item
+    }
+
+    fn fold_item_enum(&mut self, mut item: syn::ItemEnum) -> syn::ItemEnum {
+        item.variants = mem::take(&mut item.variants)
+            .into_iter()
+            .flat_map(|variant| split(variant, |variant| &mut variant.attrs))
+            .collect();
+        fold::fold_item_enum(self, item)
+    }
+
+    fn fold_fields_named(&mut self, mut fields: syn::FieldsNamed) -> syn::FieldsNamed {
+        fields.named = split_fields(mem::take(&mut fields.named));
+        fields
+    }
+
+    fn fold_fields_unnamed(&mut self, mut fields: syn::FieldsUnnamed) -> syn::FieldsUnnamed {
+        fields.unnamed = split_fields(mem::take(&mut fields.unnamed));
+        fields
+    }
+
+    fn fold_item_foreign_mod(&mut self, mut item: syn::ItemForeignMod) -> syn::ItemForeignMod {
+        item.items = mem::take(&mut item.items)
+            .into_iter()
+            .flat_map(|item| match item {
+                syn::ForeignItem::Fn(item) => split(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::ForeignItem::Fn)
+                    .collect(),
+                syn::ForeignItem::Static(item) => split(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::ForeignItem::Static)
+                    .collect(),
+                syn::ForeignItem::Type(item) => split(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::ForeignItem::Type)
+                    .collect(),
+                item => vec![item],
+            })
+            .collect();
+        item
+    }
+
+    fn fold_item_impl(&mut self, mut item: syn::ItemImpl) -> syn::ItemImpl {
+        item.items = mem::take(&mut item.items)
+            .into_iter()
+            .flat_map(|item| match item {
+                syn::ImplItem::Method(item) => split_exported(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::ImplItem::Method)
+                    .collect(),
+                syn::ImplItem::Const(item) => split(item, |item| &mut item.attrs)
+                    .into_iter()
+                    .map(syn::ImplItem::Const)
+                    .collect(),
+                item => vec![item],
+            })
+            .collect();
+        item
+    }
+
+    // Statements don't contain items cbindgen loads.
+    fn fold_block(&mut self, block: syn::Block) -> syn::Block {
+        block
+    }
+}
+
+/// Expands the `#[cfg_attr]`s of the items of `modules`.
+pub(crate) fn expand_modules(modules: &[ParsedModule]) -> Vec<ParsedModule> {
+    modules
+        .iter()
+        .map(|module| ParsedModule {
+            items: CfgAttrs.expand_items(module.items.clone()),
+            ..module.clone()
+        })
+        .collect()
+}
@@ -23,6 +23,9 @@ pub enum Language {
     Cxx,
     C,
     Cython,
+    Zig,
+    CSharp,
+    Python,
 }
 
 impl FromStr for Language {
@@ -42,6 +45,15 @@ impl FromStr for Language {
             "C" => Ok(Language::C),
             "cython" => Ok(Language::Cython),
             "Cython" => Ok(Language::Cython),
+            "zig" => Ok(Language::Zig),
+            "Zig" => Ok(Language::Zig),
+            "csharp" => Ok(Language::CSharp),
+            "CSharp" => Ok(Language::CSharp),
+            "c#" => Ok(Language::CSharp),
+            "C#" => Ok(Language::CSharp),
+            "python" => Ok(Language::Python),
+            "Python" => Ok(Language::Python),
+            "ctypes" => Ok(Language::Python),
             _ => Err(format!("Unrecognized Language: '{}'.", s)),
         }
     }
@@ -54,6 +66,9 @@ impl Language {
         match self {
             Language::Cxx | Language::C => "typedef",
             Language::Cython => "ctypedef",
+            Language::Zig => "const",
+            Language::CSharp => "using",
+            Language::Python => "=",
         }
     }
 }
@@ -430,6 +445,9 @@ pub struct FunctionConfig {
     pub sort_by: Option<SortKey>,
     /// Optional text to output after functions which return `!`.
     pub no_return: Option<String>,
+    /// The macros to output for the calling conventions other than the
+    /// default one, keyed on the Rust ABI name, e.g. `stdcall = "CALLBACK"`.
+    pub abi_macros: HashMap<String, String>,
 }
 
 impl Default for FunctionConfig {
@@ -445,6 +463,7 @@ impl Default for FunctionConfig {
             swift_name_macro: None,
             sort_by: None,
             no_return: None,
+            abi_macros: HashMap::new(),
         }
     }
 }
@@ -463,6 +482,11 @@ impl FunctionConfig {
         }
         self.postfix.clone()
     }
+
+    /// The macro to output for the calling convention `abi`, if any.
+    pub(crate) fn abi_macro(&self, abi: Option<&str>) -> Option<&str> {
+        self.abi_macros.get(abi?).map(String::as_str)
+    }
 }
 
 /// Settings to apply to generated structs.
@@ -733,6 +757,9 @@ impl Default for ConstantConfig {
 pub struct MacroExpansionConfig {
     /// Whether the `bitflags` macro should be expanded.
     pub bitflags: bool,
+    /// Whether the `macro_rules!` macros defined in the parsed crates should
+    /// be expanded.
+    pub macro_rules: bool,
 }
 
 /// Controls which Cargo profile is used for macro expansion.
@@ -826,6 +853,31 @@ fn retrocomp_parse_expand_config_deserialize<'de, D: Deserializer<'de>>(
     deserializer.deserialize_any(ParseExpandVisitor)
 }
 
+/// How to handle types with the same name defined in different modules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameCollisions {
+    /// Keep the name of the first type parsed, and prefix the names of the
+    /// others with their module path.
+    #[default]
+    Prefix,
+    /// Fail to generate bindings.
+    Error,
+}
+
+impl FromStr for NameCollisions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "prefix" => Ok(NameCollisions::Prefix),
+            "error" => Ok(NameCollisions::Error),
+            _ => Err(format!("Unrecognized name collision handling: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(NameCollisions);
+
 /// Settings to apply when parsing.
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -854,6 +906,27 @@ pub struct ParseConfig {
     /// List of crate names which generate consts, statics, and fns. By default
     /// no dependent crates generate them.
     pub extra_bindings: Vec<String>,
+    /// What to do when types with the same name are defined in different
+    /// modules.
+    pub name_collisions: NameCollisions,
+    /// The prefixes to give to colliding types of a module, by module path
+    /// (e.g. `my_crate::net`), instead of one derived from the path.
+    pub module_prefixes: HashMap<String, String>,
+    /// The directory `env!("OUT_DIR")` stands for in `include!()` invocations,
+    /// relative to the config file. By default the `OUT_DIR` environment
+    /// variable is used, or the output directory of the crate's latest build
+    /// script run.
+    pub out_dir: Option<StdPathBuf>,
+    /// The target triple to evaluate `#[cfg]`s for, instead of writing them
+    /// as preprocessor conditions.
+    pub target: Option<String>,
+    /// The target triples to generate the bindings for, merged into a single
+    /// header where the items which differ are conditional on the target.
+    /// Takes precedence over `target`.
+    pub targets: Vec<String>,
+    /// Additional cfgs set for `target` or `targets`, like the `--cfg` flags
+    /// of rustc (e.g. `feature = "foo"`).
+    pub cfg: Vec<String>,
 }
 
 impl ParseConfig {
@@ -894,6 +967,77 @@ pub struct CythonConfig {
     pub cimports: BTreeMap<String, Vec<String>>,
 }
 
+/// Settings specific to C# bindings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct CSharpConfig {
+    /// Name of the native library passed to `[DllImport]`.
+    pub library: Option<String>,
+    /// Namespace enclosing the generated class.
+    pub namespace: Option<String>,
+    /// Name of the static partial class holding the declarations.
+    pub class_name: Option<String>,
+}
+
+impl CSharpConfig {
+    pub(crate) fn class_name(&self) -> &str {
+        self.class_name.as_deref().unwrap_or("NativeMethods")
+    }
+}
+
+/// Settings to apply when writing a dynamic loader with `Bindings::write_loader`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct LoaderConfig {
+    /// The prefix of the names of the generated struct and functions. Defaults
+    /// to the file name of the header, without its extension.
+    pub prefix: Option<String>,
+}
+
+/// How diagnostics are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiagnosticFormat {
+    /// Like rustc, with the offending source line.
+    #[default]
+    Human,
+    /// As JSON objects, one per line.
+    Json,
+}
+
+impl FromStr for DiagnosticFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(DiagnosticFormat::Human),
+            "json" => Ok(DiagnosticFormat::Json),
+            _ => Err(format!("Unrecognized diagnostic format: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(DiagnosticFormat);
+
+/// Settings for the diagnostics reported while generating bindings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct DiagnosticsConfig {
+    /// How diagnostics are written.
+    pub format: DiagnosticFormat,
+    /// Whether warnings are turned into errors, failing generation.
+    pub deny_warnings: bool,
+    /// Whether the FFI lints are run.
+    pub lints: bool,
+    /// The lints which are never reported, by name, like `bool-or-char`.
+    pub allow: Vec<String>,
+}
+
 /// A collection of settings to customize the generated bindings.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -1018,6 +1162,12 @@ pub struct Config {
     pub only_target_dependencies: bool,
     /// Configuration options specific to Cython.
     pub cython: CythonConfig,
+    /// Configuration options specific to C#.
+    pub csharp: CSharpConfig,
+    /// Configuration options for the dynamic loader.
+    pub loader: LoaderConfig,
+    /// Configuration options for diagnostics.
+    pub diagnostics: DiagnosticsConfig,
     #[doc(hidden)]
     #[serde(skip)]
     /// Internal field for tracking from which file the config was loaded.
@@ -1070,6 +1220,9 @@ impl Default for Config {
             pointer: PtrConfig::default(),
             only_target_dependencies: false,
             cython: CythonConfig::default(),
+            csharp: CSharpConfig::default(),
+            loader: LoaderConfig::default(),
+            diagnostics: DiagnosticsConfig::default(),
             config_path: None,
         }
     }
@@ -1081,7 +1234,10 @@ impl Config {
     }
 
     pub(crate) fn include_guard(&self) -> Option<&str> {
-        if self.language == Language::Cython {
+        if matches!(
+            self.language,
+            Language::Cython | Language::Zig | Language::CSharp | Language::Python
+        ) {
             None
         } else {
             self.include_guard.as_deref()
@@ -1089,7 +1245,10 @@ impl Config {
     }
 
     pub(crate) fn includes(&self) -> &[String] {
-        if self.language == Language::Cython {
+        if matches!(
+            self.language,
+            Language::Cython | Language::Zig | Language::CSharp | Language::Python
+        ) {
             &[]
         } else {
             &self.includes
@@ -1097,7 +1256,10 @@ impl Config {
     }
 
     pub(crate) fn sys_includes(&self) -> &[String] {
-        if self.language == Language::Cython {
+        if matches!(
+            self.language,
+            Language::Cython | Language::Zig | Language::CSharp | Language::Python
+        ) {
             &[]
         } else {
             &self.sys_includes
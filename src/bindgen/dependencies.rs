@@ -3,8 +3,9 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
+use crate::bindgen::explain::{Requester, Root};
 use crate::bindgen::ir::{ItemContainer, Path};
 
 /// A dependency list is used for gathering what order to output the types.
@@ -12,6 +13,10 @@ use crate::bindgen::ir::{ItemContainer, Path};
 pub struct Dependencies {
     pub order: Vec<ItemContainer>,
     pub items: HashSet<Path>,
+    /// What pulled in each item, for `Bindings::explain`.
+    pub(crate) requested_by: HashMap<Path, Requester>,
+    /// What pulls in the items being added.
+    requester: Option<Requester>,
 }
 
 impl Dependencies {
@@ -19,9 +24,31 @@ impl Dependencies {
         Dependencies {
             order: Vec::new(),
             items: HashSet::new(),
+            requested_by: HashMap::new(),
+            requester: None,
         }
     }
 
+    /// Adds the dependencies `f` adds on behalf of `root`.
+    pub(crate) fn with_root(&mut self, root: Root, f: impl FnOnce(&mut Dependencies)) {
+        let previous = self.requester.replace(Requester::Root(root));
+        f(self);
+        self.requester = previous;
+    }
+
+    /// Records that `path` was pulled in by the item or root being added, and
+    /// adds the dependencies `f` adds on behalf of `path`.
+    pub(crate) fn with_item(&mut self, path: &Path, f: impl FnOnce(&mut Dependencies)) {
+        if let Some(ref requester) = self.requester {
+            self.requested_by
+                .entry(path.clone())
+                .or_insert_with(|| requester.clone());
+        }
+        let previous = self.requester.replace(Requester::Item(path.clone()));
+        f(self);
+        self.requester = previous;
+    }
+
     pub fn sort(&mut self) {
         // Sort untagged enums and opaque structs into their own layers because they don't
         // depend on each other or anything else.
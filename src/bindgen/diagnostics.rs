@@ -0,0 +1,346 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The diagnostics reported while generating bindings.
+//!
+//! Diagnostics are logged as they are reported, with the `log` target
+//! `cbindgen::diagnostics`, and collected into the `Bindings` they are
+//! reported for.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::Span;
+use serde::Serialize;
+
+use crate::bindgen::config::{DiagnosticFormat, DiagnosticsConfig};
+
+/// The `log` target diagnostics are logged with.
+pub const LOG_TARGET: &str = "cbindgen::diagnostics";
+
+macro_rules! diagnostic_codes {
+    ($($(#[$doc:meta])* $variant:ident = $code:literal, $name:literal;)*) => {
+        /// What a diagnostic is about. Each one has a stable code and name.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum DiagnosticCode {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl DiagnosticCode {
+            /// The stable code, like `CB0001`.
+            pub fn code(self) -> &'static str {
+                match self {
+                    $(DiagnosticCode::$variant => $code,)*
+                }
+            }
+
+            /// The stable name, like `unsupported-fn`.
+            #[allow(unused)]
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(DiagnosticCode::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+diagnostic_codes! {
+    /// A function can't be written, e.g. for an unsupported type.
+    UnsupportedFn = "CB0001", "unsupported-fn";
+    /// An `extern "C"` function is neither `no_mangle` nor `export_name`d.
+    UnexportedFn = "CB0002", "unexported-fn";
+    /// An exported function doesn't have a C calling convention.
+    NonCAbi = "CB0003", "non-c-abi";
+    /// A calling convention has no macro in `[fn] abi_macros`.
+    UnmappedAbi = "CB0004", "unmapped-abi";
+    /// A constant can't be written.
+    UnsupportedConstant = "CB0005", "unsupported-constant";
+    /// Several constants have the same name.
+    ConflictingConstant = "CB0006", "conflicting-constant";
+    /// A static can't be written.
+    UnsupportedStatic = "CB0007", "unsupported-static";
+    /// A module, crate or included file can't be found.
+    MissingSource = "CB0008", "missing-source";
+    /// A macro invocation can't be parsed.
+    InvalidMacro = "CB0009", "invalid-macro";
+    /// A cfg has no `[defines]` entry.
+    MissingDefine = "CB0010", "missing-define";
+    /// A type can't be found.
+    UnknownType = "CB0011", "unknown-type";
+    /// An instance of a generic type can't be named.
+    UnmangledGeneric = "CB0012", "unmangled-generic";
+    /// Annotations can't be transferred from a typedef.
+    AnnotationConflict = "CB0013", "annotation-conflict";
+    /// A `bool` or `char`, which C may set to an invalid value.
+    BoolOrChar = "CB0014", "bool-or-char";
+    /// A type which isn't `repr(C)` is used by value.
+    OpaqueByValue = "CB0015", "opaque-by-value";
+    /// A function returns a `Box` or a reference to an opaque type.
+    OpaquePointerReturn = "CB0016", "opaque-pointer-return";
+    /// An instance of a generic type which isn't `repr(C)` is used.
+    OpaqueGeneric = "CB0017", "opaque-generic";
+    /// A function pointer isn't `extern`.
+    RustAbiFnPointer = "CB0018", "rust-abi-fn-pointer";
+    /// A field has a zero-sized type.
+    ZeroSizedField = "CB0019", "zero-sized-field";
+    /// An enum without an integer `repr` is used in a function signature.
+    ImplicitEnumRepr = "CB0020", "implicit-enum-repr";
+    /// A calling convention can't be written in the output language.
+    UnsupportedAbi = "CB0021", "unsupported-abi";
+    /// Items with the same name for different cfgs can't be written in the
+    /// output language.
+    CfgVariant = "CB0022", "cfg-variant";
+}
+
+impl Serialize for DiagnosticCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// A position in a Rust source file, with 1-based lines and columns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Location {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    /// The number of columns the span covers on its first line.
+    #[serde(skip)]
+    pub width: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    pub code: DiagnosticCode,
+    pub severity: Severity,
+    pub message: String,
+    /// The path of the item the diagnostic is about.
+    pub item: Option<String>,
+    #[serde(flatten)]
+    pub location: Option<Location>,
+}
+
+impl Diagnostic {
+    pub fn warning(code: DiagnosticCode, message: impl Into<String>) -> Diagnostic {
+        Diagnostic {
+            code,
+            severity: Severity::Warning,
+            message: message.into(),
+            item: None,
+            location: None,
+        }
+    }
+
+    pub fn with_item(mut self, item: impl fmt::Display) -> Diagnostic {
+        self.item = Some(item.to_string());
+        self
+    }
+
+    /// Points the diagnostic at `span`, if it comes from a parsed file.
+    pub fn with_span(mut self, span: Span) -> Diagnostic {
+        self.location = location(span);
+        self
+    }
+
+    /// Renders the diagnostic as a JSON object, on one line.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Diagnostics can be serialized")
+    }
+}
+
+/// Renders the diagnostic like rustc, with the source line it points at.
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}]: {}",
+            self.severity,
+            self.code.code(),
+            self.message
+        )?;
+        let Some(ref location) = self.location else {
+            return Ok(());
+        };
+        let gutter = location.line.to_string().len();
+        write!(
+            f,
+            "\n{:gutter$}--> {}:{}:{}",
+            "",
+            location.file.display(),
+            location.line,
+            location.column,
+        )?;
+        let source = fs::read_to_string(&location.file).ok();
+        if let Some(line) = source
+            .as_deref()
+            .and_then(|source| source.lines().nth(location.line - 1))
+        {
+            write!(
+                f,
+                "\n{:gutter$} |\n{} | {}\n{:gutter$} | {:indent$}{}",
+                "",
+                location.line,
+                line,
+                "",
+                "",
+                "^".repeat(location.width.max(1)),
+                indent = location.column - 1,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A span of each parsed file, to find which one another span comes from.
+struct SourceFile {
+    path: PathBuf,
+    span: Span,
+}
+
+/// The diagnostics reported during a generation.
+struct Sink {
+    config: DiagnosticsConfig,
+    diagnostics: Vec<Diagnostic>,
+}
+
+thread_local! {
+    static SOURCE_FILES: RefCell<Vec<SourceFile>> = const { RefCell::new(Vec::new()) };
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Remembers that the tokens with `span` were parsed from `path`.
+pub(crate) fn register_file(path: &Path, span: Span) {
+    SOURCE_FILES.with(|files| {
+        files.borrow_mut().push(SourceFile {
+            path: path.to_owned(),
+            span,
+        })
+    });
+}
+
+fn location(span: Span) -> Option<Location> {
+    // Spans of different files can't be joined, and the files parsed last
+    // are the ones spans usually come from.
+    let file = SOURCE_FILES.with(|files| {
+        files
+            .borrow()
+            .iter()
+            .rev()
+            .find(|file| file.span.join(span).is_some())
+            .map(|file| file.path.clone())
+    })?;
+    let (start, end) = (span.start(), span.end());
+    let width = if end.line == start.line {
+        end.column - start.column
+    } else {
+        1
+    };
+    Some(Location {
+        file,
+        line: start.line,
+        column: start.column + 1,
+        width,
+    })
+}
+
+/// Reports `diagnostic`: it is logged, and collected by the generation in
+/// progress, if any. The same diagnostic is only reported once.
+pub fn report(mut diagnostic: Diagnostic) {
+    let config = SINK.with(|sink| {
+        let mut sink = sink.borrow_mut();
+        let sink = sink.as_mut()?;
+        if sink.config.deny_warnings {
+            diagnostic.severity = Severity::Error;
+        }
+        if sink.diagnostics.contains(&diagnostic) {
+            return Some(None);
+        }
+        sink.diagnostics.push(diagnostic.clone());
+        Some(Some(sink.config.clone()))
+    });
+    let config = match config {
+        Some(Some(config)) => config,
+        Some(None) => return,
+        None => DiagnosticsConfig::default(),
+    };
+
+    let rendered = match config.format {
+        DiagnosticFormat::Human => diagnostic.to_string(),
+        DiagnosticFormat::Json => diagnostic.to_json(),
+    };
+    match diagnostic.severity {
+        Severity::Warning => warn!(target: LOG_TARGET, "{}", rendered),
+        Severity::Error => error!(target: LOG_TARGET, "{}", rendered),
+    }
+}
+
+/// Runs `f`, collecting the diagnostics it reports. If a generation is
+/// already in progress, they are left to it instead. The source files
+/// registered for the generation are forgotten when it ends.
+pub(crate) fn collect<T>(
+    config: &DiagnosticsConfig,
+    f: impl FnOnce() -> T,
+) -> (T, Vec<Diagnostic>) {
+    let nested = SINK.with(|sink| sink.borrow().is_some());
+    if nested {
+        return (f(), Vec::new());
+    }
+
+    SOURCE_FILES.with(|files| files.borrow_mut().clear());
+    SINK.with(|sink| {
+        *sink.borrow_mut() = Some(Sink {
+            config: config.clone(),
+            diagnostics: Vec::new(),
+        })
+    });
+    // Leaves no sink behind if `f` panics.
+    struct Reset;
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            SINK.with(|sink| sink.borrow_mut().take());
+            SOURCE_FILES.with(|files| files.borrow_mut().clear());
+        }
+    }
+    let reset = Reset;
+    let result = f();
+    let diagnostics = SINK
+        .with(|sink| sink.borrow_mut().take())
+        .map_or_else(Vec::new, |sink| sink.diagnostics);
+    drop(reset);
+    (result, diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_files_are_forgotten() {
+        let source_files = || SOURCE_FILES.with(|files| files.borrow().len());
+        collect(&DiagnosticsConfig::default(), || {
+            register_file(Path::new("lib.rs"), Span::call_site());
+            assert_eq!(source_files(), 1);
+        });
+        assert_eq!(source_files(), 0);
+    }
+}
@@ -0,0 +1,176 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A minimal reader for the dynamic symbol table of ELF shared libraries.
+
+use std::convert::TryFrom;
+
+const SHT_DYNSYM: u32 = 11;
+const SHN_UNDEF: u16 = 0;
+const STB_GLOBAL: u8 = 1;
+const STB_WEAK: u8 = 2;
+const STT_OBJECT: u8 = 1;
+const STT_FUNC: u8 = 2;
+const STT_TLS: u8 = 6;
+const STV_DEFAULT: u8 = 0;
+const STV_PROTECTED: u8 = 3;
+
+/// Reads fields of either width and endianness.
+struct Reader<'a> {
+    data: &'a [u8],
+    is_64: bool,
+    little_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&self, offset: usize, len: usize) -> Result<&'a [u8], String> {
+        offset
+            .checked_add(len)
+            .and_then(|end| self.data.get(offset..end))
+            .ok_or_else(|| "truncated file".to_owned())
+    }
+
+    fn u8(&self, offset: usize) -> Result<u8, String> {
+        Ok(self.bytes(offset, 1)?[0])
+    }
+
+    fn u16(&self, offset: usize) -> Result<u16, String> {
+        let bytes = <[u8; 2]>::try_from(self.bytes(offset, 2)?).unwrap();
+        Ok(if self.little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    }
+
+    fn u32(&self, offset: usize) -> Result<u32, String> {
+        let bytes = <[u8; 4]>::try_from(self.bytes(offset, 4)?).unwrap();
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn u64(&self, offset: usize) -> Result<u64, String> {
+        let bytes = <[u8; 8]>::try_from(self.bytes(offset, 8)?).unwrap();
+        Ok(if self.little_endian {
+            u64::from_le_bytes(bytes)
+        } else {
+            u64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Reads an address or offset, which is 4 or 8 bytes wide.
+    fn word(&self, offset: usize) -> Result<usize, String> {
+        let word = if self.is_64 {
+            self.u64(offset)?
+        } else {
+            u64::from(self.u32(offset)?)
+        };
+        usize::try_from(word).map_err(|_| "offset out of range".to_owned())
+    }
+
+    fn c_str(&self, offset: usize) -> Result<&'a str, String> {
+        let tail = self.data.get(offset..).ok_or("string out of range")?;
+        let len = tail
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or("unterminated string")?;
+        std::str::from_utf8(&tail[..len]).map_err(|_| "symbol name isn't UTF-8".to_owned())
+    }
+}
+
+/// The offset of the field at `offset` in the structure at `base`.
+fn field(base: usize, offset: usize) -> Result<usize, String> {
+    base.checked_add(offset)
+        .ok_or_else(|| "truncated file".to_owned())
+}
+
+/// Returns the names of the functions and variables an ELF shared library
+/// exports, sorted.
+pub(crate) fn dynamic_symbols(data: &[u8]) -> Result<Vec<String>, String> {
+    if data.get(..4) != Some(b"\x7fELF") {
+        return Err("not an ELF file".to_owned());
+    }
+    let is_64 = match data.get(4) {
+        Some(1) => false,
+        Some(2) => true,
+        _ => return Err("unknown ELF class".to_owned()),
+    };
+    let little_endian = match data.get(5) {
+        Some(1) => true,
+        Some(2) => false,
+        _ => return Err("unknown ELF data encoding".to_owned()),
+    };
+    let reader = Reader {
+        data,
+        is_64,
+        little_endian,
+    };
+
+    // The location of the section header table in the file header, and the
+    // offsets of the fields of a section header.
+    let (shoff, shentsize, shnum) = if is_64 {
+        (reader.word(0x28)?, reader.u16(0x3a)?, reader.u16(0x3c)?)
+    } else {
+        (reader.word(0x20)?, reader.u16(0x2e)?, reader.u16(0x30)?)
+    };
+    let (sh_offset, sh_link, sh_size, sh_entsize) = if is_64 {
+        (0x18, 0x28, 0x20, 0x38)
+    } else {
+        (0x10, 0x18, 0x14, 0x24)
+    };
+    let section = |index: usize| {
+        index
+            .checked_mul(usize::from(shentsize))
+            .and_then(|offset| shoff.checked_add(offset))
+            .ok_or_else(|| "truncated file".to_owned())
+    };
+
+    let mut symbols = Vec::new();
+    for index in 0..usize::from(shnum) {
+        let header = section(index)?;
+        if reader.u32(field(header, 4)?)? != SHT_DYNSYM {
+            continue;
+        }
+        let offset = reader.word(field(header, sh_offset)?)?;
+        let size = reader.word(field(header, sh_size)?)?;
+        let entsize = reader.word(field(header, sh_entsize)?)?;
+        let link = reader.u32(field(header, sh_link)?)? as usize;
+        let strtab = reader.word(field(section(link)?, sh_offset)?)?;
+        if entsize == 0 {
+            return Err("invalid dynamic symbol table".to_owned());
+        }
+
+        for symbol in (offset..offset.saturating_add(size)).step_by(entsize) {
+            let (info, other, shndx) = if is_64 {
+                (
+                    reader.u8(field(symbol, 4)?)?,
+                    reader.u8(field(symbol, 5)?)?,
+                    reader.u16(field(symbol, 6)?)?,
+                )
+            } else {
+                (
+                    reader.u8(field(symbol, 12)?)?,
+                    reader.u8(field(symbol, 13)?)?,
+                    reader.u16(field(symbol, 14)?)?,
+                )
+            };
+            let (bind, ty, visibility) = (info >> 4, info & 0xf, other & 0x3);
+            if shndx == SHN_UNDEF
+                || !matches!(bind, STB_GLOBAL | STB_WEAK)
+                || !matches!(visibility, STV_DEFAULT | STV_PROTECTED)
+                || !matches!(ty, STT_OBJECT | STT_FUNC | STT_TLS)
+            {
+                continue;
+            }
+            let name = reader.c_str(field(strtab, reader.u32(symbol)? as usize)?)?;
+            symbols.push(name.to_owned());
+        }
+    }
+    symbols.sort();
+    symbols.dedup();
+    Ok(symbols)
+}
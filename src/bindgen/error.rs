@@ -4,6 +4,7 @@
 
 use std::error;
 use std::fmt;
+use std::io;
 
 pub use crate::bindgen::cargo::cargo_expand::Error as CargoExpandError;
 pub use crate::bindgen::cargo::cargo_metadata::Error as CargoMetadataError;
@@ -25,6 +26,39 @@ pub enum Error {
         crate_name: String,
         src_path: String,
     },
+    IrFile {
+        path: String,
+        message: String,
+    },
+    RustdocJson {
+        path: String,
+        message: String,
+    },
+    SymbolTable {
+        path: String,
+        message: String,
+    },
+    NameCollision {
+        name: String,
+        paths: Vec<String>,
+    },
+    UnknownTarget {
+        target: String,
+        message: String,
+    },
+    AmbiguousTargets {
+        targets: Vec<String>,
+    },
+    Diagnostics {
+        errors: usize,
+    },
+    Io {
+        path: String,
+        error: io::Error,
+    },
+    Output {
+        error: io::Error,
+    },
 }
 
 impl fmt::Display for Error {
@@ -71,6 +105,58 @@ impl fmt::Display for Error {
                 "Parsing crate `{}`: cannot open file `{}`.",
                 crate_name, src_path
             ),
+            Error::IrFile {
+                ref path,
+                ref message,
+            } => write!(f, "Couldn't load IR file `{}`: {}", path, message),
+            Error::RustdocJson {
+                ref path,
+                ref message,
+            } => write!(f, "Couldn't load rustdoc JSON `{}`: {}", path, message),
+            Error::SymbolTable {
+                ref path,
+                ref message,
+            } => write!(
+                f,
+                "Couldn't read the dynamic symbol table of `{}`: {}",
+                path, message
+            ),
+            Error::NameCollision {
+                ref name,
+                ref paths,
+            } => write!(
+                f,
+                "`{}` is defined in several modules: `{}`. Rename the types, or set \
+                 `name_collisions = \"prefix\"` in the `[parse]` section of the config.",
+                name,
+                paths.join("`, `")
+            ),
+            Error::UnknownTarget {
+                ref target,
+                ref message,
+            } => write!(
+                f,
+                "Couldn't get the cfgs of target `{}`: {}",
+                target, message
+            ),
+            Error::AmbiguousTargets { ref targets } => write!(
+                f,
+                "The targets `{}` can't be told apart in a merged header, as they only \
+                 differ in cfgs without a `[defines]` entry, or a predefined macro in C \
+                 and C++.",
+                targets.join("`, `")
+            ),
+            Error::Diagnostics { errors } => write!(
+                f,
+                "Generation failed with {} error{}.",
+                errors,
+                if errors == 1 { "" } else { "s" }
+            ),
+            Error::Io {
+                ref path,
+                ref error,
+            } => write!(f, "Couldn't access `{}`: {}", path, error),
+            Error::Output { ref error } => write!(f, "Couldn't write the output: {}", error),
         }
     }
 }
@@ -83,6 +169,15 @@ impl error::Error for Error {
             Error::CargoExpand(_, ref error) => Some(error),
             Error::ParseSyntaxError { ref error, .. } => Some(error),
             Error::ParseCannotOpenFile { .. } => None,
+            Error::IrFile { .. } => None,
+            Error::RustdocJson { .. } => None,
+            Error::SymbolTable { .. } => None,
+            Error::NameCollision { .. } => None,
+            Error::UnknownTarget { .. } => None,
+            Error::AmbiguousTargets { .. } => None,
+            Error::Diagnostics { .. } => None,
+            Error::Io { ref error, .. } => Some(error),
+            Error::Output { ref error } => Some(error),
         }
     }
 }
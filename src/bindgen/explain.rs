@@ -0,0 +1,352 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Why an item is, or isn't, in the bindings.
+//!
+//! The parser records what it leaves out, and the dependency walk of
+//! `Library::generate` records which item pulled in each one, so that
+//! `Bindings::explain` can tell the filter which dropped an item, or the chain
+//! of items through which an exported function, global, constant or
+//! `export.include` entry pulled it in.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::config::ItemType;
+use crate::bindgen::diagnostics::{Diagnostic, DiagnosticCode};
+use crate::bindgen::ir::{Item, ItemMap, Path};
+
+/// What pulls items in the bindings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+    Function(String),
+    Global(String),
+    Constant(String),
+    /// An entry of `export.include`.
+    Include(String),
+}
+
+impl fmt::Display for Root {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Root::Function(ref name) => write!(f, "fn `{}`", name),
+            Root::Global(ref name) => write!(f, "static `{}`", name),
+            Root::Constant(ref name) => write!(f, "const `{}`", name),
+            Root::Include(ref name) => write!(f, "`export.include` entry `{}`", name),
+        }
+    }
+}
+
+/// What pulled an item in: a root, or another item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Requester {
+    Root(Root),
+    Item(Path),
+}
+
+/// Why a dependency crate wasn't parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrateFilter {
+    /// `parse.parse_deps` is off.
+    NoDeps,
+    /// The crate isn't in `parse.include`.
+    NotIncluded,
+    /// The crate is in `parse.exclude`.
+    Excluded,
+}
+
+impl fmt::Display for CrateFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            CrateFilter::NoDeps => "`parse_deps` is off",
+            CrateFilter::NotIncluded => "not in `parse.include`",
+            CrateFilter::Excluded => "in `parse.exclude`",
+        })
+    }
+}
+
+/// Why a parsed item was left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Filter {
+    /// The item is in `export.exclude`.
+    ExportExclude,
+    /// The item is marked `cbindgen:ignore`.
+    Ignored,
+    /// The kind of the item isn't in `export.item_types`.
+    ItemTypes(ItemType),
+    /// The item was skipped while parsing, with this diagnostic.
+    Skipped(Diagnostic),
+    /// Nothing the bindings export depends on the item.
+    Unused,
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Filter::ExportExclude => write!(f, "it is listed in `export.exclude`"),
+            Filter::Ignored => write!(f, "it is marked `cbindgen:ignore`"),
+            Filter::ItemTypes(ref item_type) => write!(
+                f,
+                "its kind, `{}`, isn't listed in `export.item_types`",
+                match *item_type {
+                    ItemType::Constants => "constants",
+                    ItemType::Globals => "globals",
+                    ItemType::Enums => "enums",
+                    ItemType::Structs => "structs",
+                    ItemType::Unions => "unions",
+                    ItemType::Typedefs => "typedefs",
+                    ItemType::OpaqueItems => "opaque",
+                    ItemType::Functions => "functions",
+                }
+            ),
+            Filter::Skipped(ref diagnostic) => write!(
+                f,
+                "it was skipped ({}): {}",
+                diagnostic.code.code(),
+                diagnostic.message
+            ),
+            Filter::Unused => write!(
+                f,
+                "no exported function, global or constant uses it, and it isn't listed in \
+                 `export.include`"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The item is written, pulled in by `root` through `chain`, the items
+    /// from the one `root` uses to the item itself.
+    Included { root: Root, chain: Vec<String> },
+    /// The item was parsed, or skipped while parsing, but isn't written.
+    Dropped(Filter),
+    /// The item wasn't found in the parsed crates, maybe because it is in one
+    /// of `unparsed_crates`.
+    NotFound {
+        unparsed_crates: Vec<(String, CrateFilter)>,
+    },
+}
+
+/// Why an item is, or isn't, in the bindings. See `Bindings::explain`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub item: String,
+    pub status: Status,
+    /// Why the item is opaque, if it is a type cbindgen couldn't represent.
+    pub opaque: Option<String>,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.status {
+            Status::Included {
+                ref root,
+                ref chain,
+            } => {
+                write!(f, "`{}` is included", self.item)?;
+                if !chain.is_empty() {
+                    write!(f, ", because {} uses it:\n    {}", root, root)?;
+                    for item in chain {
+                        write!(f, "\n    -> `{}`", item)?;
+                    }
+                } else {
+                    write!(f, " as {}", root)?;
+                }
+            }
+            Status::Dropped(ref filter) => {
+                write!(f, "`{}` is not included: {}", self.item, filter)?;
+            }
+            Status::NotFound {
+                ref unparsed_crates,
+            } => {
+                write!(f, "`{}` wasn't found in the parsed crates", self.item)?;
+                if !unparsed_crates.is_empty() {
+                    write!(f, ". These crates weren't parsed:")?;
+                    for (name, filter) in unparsed_crates {
+                        write!(f, "\n    `{}`: {}", name, filter)?;
+                    }
+                }
+            }
+        }
+        if let Some(ref reason) = self.opaque {
+            write!(f, "\n`{}` is opaque: {}", self.item, reason)?;
+        }
+        writeln!(f)
+    }
+}
+
+/// What the parser and the dependency walk record to explain the bindings.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Provenance {
+    /// Why the types cbindgen couldn't represent are opaque, by name.
+    pub opaque: HashMap<String, String>,
+    /// The names of the enums which are opaque.
+    pub opaque_enums: HashSet<String>,
+    /// The names of the items marked `cbindgen:ignore`.
+    pub ignored: HashSet<String>,
+    /// The dependency crates which weren't parsed.
+    pub unparsed_crates: Vec<(String, CrateFilter)>,
+    /// What pulled in each item of the bindings.
+    pub requested_by: HashMap<Path, Requester>,
+    /// The instances of each generic item, which replace it in C and Cython.
+    pub instances: HashMap<Path, Vec<Path>>,
+}
+
+impl Provenance {
+    pub fn extend_with(&mut self, other: &Provenance) {
+        for (name, reason) in &other.opaque {
+            self.opaque
+                .entry(name.clone())
+                .or_insert_with(|| reason.clone());
+        }
+        self.opaque_enums.extend(other.opaque_enums.iter().cloned());
+        self.ignored.extend(other.ignored.iter().cloned());
+        for unparsed in &other.unparsed_crates {
+            if !self.unparsed_crates.contains(unparsed) {
+                self.unparsed_crates.push(unparsed.clone());
+            }
+        }
+        for (path, requester) in &other.requested_by {
+            self.requested_by
+                .entry(path.clone())
+                .or_insert_with(|| requester.clone());
+        }
+        for (generic, instances) in &other.instances {
+            let known = self.instances.entry(generic.clone()).or_default();
+            for instance in instances {
+                if !known.contains(instance) {
+                    known.push(instance.clone());
+                }
+            }
+        }
+    }
+
+    /// Returns the items from the one a root uses to `path`, and the root.
+    fn chain(&self, path: &Path) -> Option<(Root, Vec<String>)> {
+        let mut chain = vec![path.name().to_owned()];
+        let mut visited = HashSet::new();
+        let mut path = path;
+        while visited.insert(path) {
+            match self.requested_by.get(path)? {
+                Requester::Root(root) => {
+                    chain.reverse();
+                    return Some((root.clone(), chain));
+                }
+                Requester::Item(item) => {
+                    chain.push(item.name().to_owned());
+                    path = item;
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Returns whether `map` has an item named `name`.
+fn contains<T: Item + Clone>(map: &ItemMap<T>, name: &str) -> bool {
+    map.get_items(&Path::new(name)).is_some()
+}
+
+/// Returns the diagnostic of an item skipped while parsing, if `name` is one.
+fn skipped<'a>(bindings: &'a Bindings, name: &str) -> Option<&'a Diagnostic> {
+    bindings.diagnostics.iter().find(|diagnostic| {
+        matches!(
+            diagnostic.code,
+            DiagnosticCode::UnsupportedFn
+                | DiagnosticCode::UnexportedFn
+                | DiagnosticCode::NonCAbi
+                | DiagnosticCode::UnsupportedConstant
+                | DiagnosticCode::UnsupportedStatic
+        ) && diagnostic
+            .item
+            .as_deref()
+            .is_some_and(|item| item == name || item.ends_with(&format!("::{}", name)))
+    })
+}
+
+pub(crate) fn explain(bindings: &Bindings, name: &str) -> Explanation {
+    let provenance = &bindings.provenance;
+    let config = &bindings.config;
+    let path = Path::new(name);
+
+    let root = if bindings.functions.iter().any(|f| f.path == path) {
+        Some(Root::Function(name.to_owned()))
+    } else if bindings.globals.iter().any(|g| g.path == path) {
+        Some(Root::Global(name.to_owned()))
+    } else if bindings.constants.iter().any(|c| c.path == path) {
+        Some(Root::Constant(name.to_owned()))
+    } else {
+        None
+    };
+
+    let status = if let Some(root) = root {
+        Status::Included {
+            root,
+            chain: Vec::new(),
+        }
+    } else if let Some((root, chain)) = provenance
+        .instances
+        .get(&path)
+        .map_or(std::slice::from_ref(&path), |instances| &instances[..])
+        .iter()
+        .filter(|path| {
+            bindings
+                .items
+                .iter()
+                .any(|item| item.deref().path() == *path)
+        })
+        .find_map(|path| provenance.chain(path))
+    {
+        Status::Included { root, chain }
+    } else if config
+        .export
+        .exclude
+        .iter()
+        .any(|excluded| excluded == name)
+    {
+        Status::Dropped(Filter::ExportExclude)
+    } else if provenance.ignored.contains(name) {
+        Status::Dropped(Filter::Ignored)
+    } else if let Some(diagnostic) = skipped(bindings, name) {
+        Status::Dropped(Filter::Skipped(diagnostic.clone()))
+    } else {
+        let parsed = &bindings.parsed;
+        let item_type = if parsed.functions.iter().any(|f| f.path == path) {
+            Some(ItemType::Functions)
+        } else if contains(&parsed.globals, name) {
+            Some(ItemType::Globals)
+        } else if contains(&parsed.constants, name) {
+            Some(ItemType::Constants)
+        } else if contains(&parsed.enums, name) {
+            Some(ItemType::Enums)
+        } else if contains(&parsed.structs, name) {
+            Some(ItemType::Structs)
+        } else if contains(&parsed.unions, name) {
+            Some(ItemType::Unions)
+        } else if contains(&parsed.opaque_items, name) {
+            Some(ItemType::OpaqueItems)
+        } else if contains(&parsed.typedefs, name) {
+            Some(ItemType::Typedefs)
+        } else {
+            None
+        };
+        match item_type {
+            Some(item_type) if !config.export.should_generate(item_type.clone()) => {
+                Status::Dropped(Filter::ItemTypes(item_type))
+            }
+            Some(_) => Status::Dropped(Filter::Unused),
+            None => Status::NotFound {
+                unparsed_crates: provenance.unparsed_crates.clone(),
+            },
+        }
+    };
+
+    Explanation {
+        item: name.to_owned(),
+        status,
+        opaque: provenance.opaque.get(name).cloned(),
+    }
+}
@@ -24,7 +24,8 @@ use crate::bindgen::utilities::SynAttributeHelpers;
 //  * cbindgen:function-postfix=WR_DESTRUCTOR_SAFE
 
 /// A value specified by an annotation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum AnnotationValue {
     List(Vec<String>),
     Atom(Option<String>),
@@ -32,8 +33,9 @@ pub enum AnnotationValue {
 }
 
 /// A set of annotations specified by a document comment.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct AnnotationSet {
+    #[serde(serialize_with = "crate::bindgen::json::serialize_sorted")]
     annotations: HashMap<String, AnnotationValue>,
     pub must_use: bool,
     pub deprecated: Option<String>,
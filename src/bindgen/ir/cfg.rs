@@ -7,6 +7,7 @@ use std::io::Write;
 
 use crate::bindgen::cargo::cargo_metadata::Dependency;
 use crate::bindgen::config::{Config, Language};
+use crate::bindgen::diagnostics::{self, Diagnostic, DiagnosticCode};
 use crate::bindgen::writer::SourceWriter;
 
 #[derive(PartialEq, Eq)]
@@ -42,7 +43,8 @@ impl<'a> DefineKey<'a> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Cfg {
     Boolean(String),
     Named(String, String),
@@ -146,7 +148,7 @@ impl Cfg {
         }
     }
 
-    fn load_single(item: &syn::NestedMeta) -> Option<Cfg> {
+    pub(crate) fn load_single(item: &syn::NestedMeta) -> Option<Cfg> {
         Some(match *item {
             syn::NestedMeta::Meta(syn::Meta::Path(ref path)) => {
                 Cfg::Boolean(format!("{}", path.segments.first().unwrap().ident))
@@ -197,6 +199,128 @@ impl Cfg {
     }
 }
 
+impl Cfg {
+    /// The condition on the macros C compilers predefine which corresponds to
+    /// a cfg of the target, used when there's no `[defines]` entry for it.
+    fn predefined_condition(&self, config: &Config) -> Option<Condition> {
+        // Other languages don't get the macros of a C compiler.
+        if !matches!(config.language, Language::C | Language::Cxx) {
+            return None;
+        }
+
+        let defined = |name: &str| Condition::Define(name.to_owned());
+        let any = |names: &[&str]| Condition::Any(names.iter().map(|n| defined(n)).collect());
+        let unix = || any(&["__unix__", "__APPLE__"]);
+        let windows = || defined("_WIN32");
+
+        Some(match *self {
+            Cfg::Boolean(ref name) => match name.as_str() {
+                "unix" => unix(),
+                "windows" => windows(),
+                _ => return None,
+            },
+            Cfg::Named(ref name, ref value) => match (name.as_str(), value.as_str()) {
+                ("target_family", "unix") => unix(),
+                ("target_family", "windows") => windows(),
+                ("target_family", "wasm") => defined("__wasm__"),
+                ("target_os", "windows") => windows(),
+                ("target_os", "macos") => {
+                    Condition::All(vec![defined("__APPLE__"), defined("__MACH__")])
+                }
+                // Android defines `__linux__` too, but isn't `target_os = "linux"`.
+                ("target_os", "linux") => Condition::All(vec![
+                    defined("__linux__"),
+                    Condition::Not(Box::new(defined("__ANDROID__"))),
+                ]),
+                ("target_os", "android") => defined("__ANDROID__"),
+                ("target_os", "freebsd") => defined("__FreeBSD__"),
+                ("target_os", "netbsd") => defined("__NetBSD__"),
+                ("target_os", "openbsd") => defined("__OpenBSD__"),
+                ("target_os", "dragonfly") => defined("__DragonFly__"),
+                ("target_os", "illumos") => defined("__illumos__"),
+                ("target_os", "solaris") => defined("__sun"),
+                ("target_os", "haiku") => defined("__HAIKU__"),
+                ("target_os", "fuchsia") => defined("__Fuchsia__"),
+                ("target_os", "emscripten") => defined("__EMSCRIPTEN__"),
+                ("target_os", "wasi") => defined("__wasi__"),
+                ("target_vendor", "apple") => defined("__APPLE__"),
+                ("target_env", "msvc") => defined("_MSC_VER"),
+                ("target_arch", "x86_64") => any(&["__x86_64__", "_M_X64"]),
+                ("target_arch", "x86") => any(&["__i386__", "_M_IX86"]),
+                ("target_arch", "aarch64") => any(&["__aarch64__", "_M_ARM64"]),
+                ("target_arch", "arm") => any(&["__arm__", "_M_ARM"]),
+                ("target_arch", "wasm32") => defined("__wasm32__"),
+                ("target_arch", "wasm64") => defined("__wasm64__"),
+                ("target_arch", "powerpc") => defined("__powerpc__"),
+                ("target_arch", "powerpc64") => defined("__powerpc64__"),
+                ("target_arch", "s390x") => defined("__s390x__"),
+                ("target_arch", "loongarch64") => defined("__loongarch64"),
+                ("target_pointer_width", "64") => any(&["__LP64__", "_WIN64"]),
+                _ => return None,
+            },
+            _ => return None,
+        })
+    }
+}
+
+impl Cfg {
+    /// The condition of a single cfg, from `[defines]` or else predefined.
+    fn define_condition(&self, config: &Config) -> Option<Condition> {
+        let key = match *self {
+            Cfg::Boolean(ref cfg_name) => DefineKey::Boolean(cfg_name),
+            Cfg::Named(ref cfg_name, ref cfg_value) => DefineKey::Named(cfg_name, cfg_value),
+            _ => return None,
+        };
+        let define = config
+            .defines
+            .iter()
+            .find(|(define_key, ..)| DefineKey::load(define_key) == key);
+        match define {
+            Some((_, define)) => Some(Condition::Define(define.to_owned())),
+            None => self.predefined_condition(config),
+        }
+    }
+
+    /// Whether a condition is written for each of the cfgs this one is made
+    /// of, from `[defines]` or predefined.
+    pub(crate) fn has_condition(&self, config: &Config) -> bool {
+        match *self {
+            Cfg::Boolean(..) | Cfg::Named(..) => self.define_condition(config).is_some(),
+            Cfg::Any(ref cfgs) | Cfg::All(ref cfgs) => {
+                cfgs.iter().all(|cfg| cfg.has_condition(config))
+            }
+            Cfg::Not(ref cfg) => cfg.has_condition(config),
+        }
+    }
+
+    /// Reports the cfgs which have no condition, and are left out of the
+    /// conditions written for this one.
+    pub(crate) fn report_missing_defines(&self, config: &Config, item: &str) {
+        match *self {
+            Cfg::Boolean(..) | Cfg::Named(..) => {
+                if self.define_condition(config).is_none() {
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::MissingDefine,
+                            format!(
+                                "missing `[defines]` entry for `{}` in cbindgen config",
+                                self
+                            ),
+                        )
+                        .with_item(item),
+                    );
+                }
+            }
+            Cfg::Any(ref cfgs) | Cfg::All(ref cfgs) => {
+                for cfg in cfgs {
+                    cfg.report_missing_defines(config, item);
+                }
+            }
+            Cfg::Not(ref cfg) => cfg.report_missing_defines(config, item),
+        }
+    }
+}
+
 pub trait ToCondition: Sized {
     fn to_condition(&self, config: &Config) -> Option<Condition>;
 }
@@ -210,35 +334,7 @@ impl ToCondition for Option<Cfg> {
 impl ToCondition for Cfg {
     fn to_condition(&self, config: &Config) -> Option<Condition> {
         match *self {
-            Cfg::Boolean(ref cfg_name) => {
-                let define = config
-                    .defines
-                    .iter()
-                    .find(|(key, ..)| DefineKey::Boolean(cfg_name) == DefineKey::load(key));
-                if let Some((_, define)) = define {
-                    Some(Condition::Define(define.to_owned()))
-                } else {
-                    warn!(
-                        "Missing `[defines]` entry for `{}` in cbindgen config.",
-                        self,
-                    );
-                    None
-                }
-            }
-            Cfg::Named(ref cfg_name, ref cfg_value) => {
-                let define = config.defines.iter().find(|(key, ..)| {
-                    DefineKey::Named(cfg_name, cfg_value) == DefineKey::load(key)
-                });
-                if let Some((_, define)) = define {
-                    Some(Condition::Define(define.to_owned()))
-                } else {
-                    warn!(
-                        "Missing `[defines]` entry for `{}` in cbindgen config.",
-                        self,
-                    );
-                    None
-                }
-            }
+            Cfg::Boolean(..) | Cfg::Named(..) => self.define_condition(config),
             Cfg::Any(ref children) => {
                 let conditions: Vec<_> = children
                     .iter()
@@ -277,10 +373,21 @@ pub enum Condition {
 }
 
 impl Condition {
+    /// Whether the condition holds when exactly `defines` are defined.
+    pub(crate) fn holds_with(&self, defines: &[&str]) -> bool {
+        match *self {
+            Condition::Define(ref define) => defines.contains(&define.as_str()),
+            Condition::Any(ref conditions) => conditions.iter().any(|c| c.holds_with(defines)),
+            Condition::All(ref conditions) => conditions.iter().all(|c| c.holds_with(defines)),
+            Condition::Not(ref condition) => !condition.holds_with(defines),
+        }
+    }
+
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         match *self {
             Condition::Define(ref define) => {
-                if config.language == Language::Cython {
+                // C# preprocessor symbols are tested by name, without `defined()`.
+                if matches!(config.language, Language::Cython | Language::CSharp) {
                     write!(out, "{}", define);
                 } else {
                     out.write("defined(");
@@ -342,6 +449,12 @@ impl ConditionWrite for Option<Condition> {
                 out.open_brace();
             } else {
                 out.push_set_spaces(0);
+                // Zig and Python have no preprocessor, so the condition is kept as a comment.
+                match config.language {
+                    Language::Zig => out.write("// "),
+                    Language::Python => out.write("# "),
+                    _ => {}
+                }
                 out.write("#if ");
                 cfg.write(config, out);
                 out.pop_set_spaces();
@@ -357,6 +470,11 @@ impl ConditionWrite for Option<Condition> {
             } else {
                 out.new_line();
                 out.push_set_spaces(0);
+                match config.language {
+                    Language::Zig => out.write("// "),
+                    Language::Python => out.write("# "),
+                    _ => {}
+                }
                 out.write("#endif");
                 out.pop_set_spaces();
             }
@@ -6,6 +6,8 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::io::Write;
 
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
 use syn::ext::IdentExt;
 use syn::UnOp;
 
@@ -14,7 +16,7 @@ use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
     AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    Struct, ToCondition, Type,
+    PrimitiveType, Struct, ToCondition, Type,
 };
 use crate::bindgen::language_backend::LanguageBackend;
 use crate::bindgen::library::Library;
@@ -78,7 +80,8 @@ pub(crate) fn to_known_assoc_constant(associated_to: &Path, name: &str) -> Optio
     Some(format!("{}_{}", prefix, name))
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Literal {
     Expr(String),
     Path {
@@ -101,6 +104,7 @@ pub enum Literal {
     Struct {
         path: Path,
         export_name: String,
+        #[serde(serialize_with = "crate::bindgen::json::serialize_sorted")]
         fields: HashMap<String, Literal>,
     },
     Cast {
@@ -109,6 +113,87 @@ pub enum Literal {
     },
 }
 
+/// `Literal` as it is read from JSON. Serde can't derive `Deserialize` for
+/// the `&'static str` operators, so they are looked up after the fact.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum LiteralDocument {
+    Expr(String),
+    Path {
+        associated_to: Option<(Path, String)>,
+        name: String,
+    },
+    PostfixUnaryOp {
+        op: String,
+        value: Box<Literal>,
+    },
+    BinOp {
+        left: Box<Literal>,
+        op: String,
+        right: Box<Literal>,
+    },
+    FieldAccess {
+        base: Box<Literal>,
+        field: String,
+    },
+    Struct {
+        path: Path,
+        export_name: String,
+        fields: HashMap<String, Literal>,
+    },
+    Cast {
+        ty: Type,
+        value: Box<Literal>,
+    },
+}
+
+impl<'de> Deserialize<'de> for Literal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const OPERATORS: &[&str] = &[
+            "+", "-", "*", "/", "%", "&&", "||", "^", "&", "|", "<<", ">>", "==", "<", "<=", "!=",
+            ">=", ">", "+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "<<=", ">>=", "~",
+        ];
+        let operator = |op: String| {
+            OPERATORS
+                .iter()
+                .find(|known| **known == op)
+                .copied()
+                .ok_or_else(|| D::Error::custom(format!("unknown operator `{}`", op)))
+        };
+
+        Ok(match LiteralDocument::deserialize(deserializer)? {
+            LiteralDocument::Expr(expr) => Literal::Expr(expr),
+            LiteralDocument::Path {
+                associated_to,
+                name,
+            } => Literal::Path {
+                associated_to,
+                name,
+            },
+            LiteralDocument::PostfixUnaryOp { op, value } => Literal::PostfixUnaryOp {
+                op: operator(op)?,
+                value,
+            },
+            LiteralDocument::BinOp { left, op, right } => Literal::BinOp {
+                left,
+                op: operator(op)?,
+                right,
+            },
+            LiteralDocument::FieldAccess { base, field } => Literal::FieldAccess { base, field },
+            LiteralDocument::Struct {
+                path,
+                export_name,
+                fields,
+            } => Literal::Struct {
+                path,
+                export_name,
+                fields,
+            },
+            LiteralDocument::Cast { ty, value } => Literal::Cast { ty, value },
+        })
+    }
+}
+
 impl Literal {
     fn replace_self_with(&mut self, self_ty: &Path) {
         match *self {
@@ -475,7 +560,7 @@ impl Literal {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Constant {
     pub path: Path,
     pub export_name: String,
@@ -708,6 +793,34 @@ impl Constant {
                 write!(out, " {} # = ", name);
                 language_backend.write_literal(out, value);
             }
+            Language::Zig => {
+                write!(out, "pub const {}: ", name);
+                language_backend.write_type(out, &self.ty);
+                out.write(" = ");
+                language_backend.write_literal(out, value);
+                out.write(";");
+            }
+            Language::CSharp => {
+                // Only primitive values can be `const` in C#.
+                if self.value.uses_only_primitive_types() && matches!(self.ty, Type::Primitive(..))
+                {
+                    out.write("public const ");
+                } else {
+                    out.write("public static readonly ");
+                }
+                language_backend.write_type(out, &self.ty);
+                write!(out, " {} = ", name);
+                // Floating point literals are `double` unless told otherwise.
+                if let Type::Primitive(PrimitiveType::Float) = self.ty {
+                    out.write("(float)");
+                }
+                language_backend.write_literal(out, value);
+                out.write(";");
+            }
+            Language::Python => {
+                write!(out, "{} = ", name);
+                language_backend.write_literal(out, value);
+            }
         }
 
         condition.write_after(config, out);
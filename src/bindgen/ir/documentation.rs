@@ -4,7 +4,8 @@
 
 use crate::bindgen::utilities::SynAttributeHelpers;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Documentation {
     pub doc_comment: Vec<String>,
 }
@@ -23,7 +23,8 @@ use crate::bindgen::reserved;
 use crate::bindgen::writer::{ListType, SourceWriter};
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum VariantBody {
     Empty(AnnotationSet),
     Body {
@@ -84,7 +85,7 @@ impl VariantBody {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnumVariant {
     pub name: String,
     pub export_name: String,
@@ -293,7 +294,7 @@ impl EnumVariant {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Enum {
     pub path: Path,
     pub export_name: String,
@@ -317,6 +318,44 @@ impl Enum {
         repr.style != ReprStyle::C
     }
 
+    /// Redoes the choice `EnumVariant::load` makes about inlining variant
+    /// bodies, for an enum which may have been parsed for another language.
+    pub(crate) fn inline_variant_bodies_for(&mut self, language: Language) {
+        for variant in &mut self.variants {
+            if let VariantBody::Body {
+                ref name,
+                ref mut body,
+                ref mut inline,
+                inline_casts: true,
+            } = variant.body
+            {
+                let should_inline = language != Language::Cxx;
+                if *inline == should_inline {
+                    continue;
+                }
+                *inline = should_inline;
+
+                let mut fields = body.fields.iter_mut();
+                if body.has_tag_field {
+                    if let Some(tag) = fields.next() {
+                        tag.name = if should_inline {
+                            format!("{}_tag", name)
+                        } else {
+                            "tag".to_owned()
+                        };
+                    }
+                }
+                for field in fields {
+                    field.name = if should_inline {
+                        name.clone()
+                    } else {
+                        "0".to_owned()
+                    };
+                }
+            }
+        }
+    }
+
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
         if self.generic_params.len() > 0 {
             return;
@@ -721,6 +760,12 @@ impl Enum {
                     write!(out, "{}enum {}", config.style.cython_def(), tag_name);
                 }
             }
+            Language::Zig | Language::CSharp | Language::Python => {
+                unreachable!(
+                    "{:?} enums are written by their own backend",
+                    config.language
+                )
+            }
         }
         out.open_brace();
 
@@ -775,6 +820,12 @@ impl Enum {
             Language::C if config.style.generate_typedef() => out.write("typedef "),
             Language::C | Language::Cxx => {}
             Language::Cython => out.write(config.style.cython_def()),
+            Language::Zig | Language::CSharp | Language::Python => {
+                unreachable!(
+                    "{:?} enums are written by their own backend",
+                    config.language
+                )
+            }
         }
 
         out.write(if inline_tag_field { "union" } else { "struct" });
@@ -3,7 +3,7 @@ use syn::ext::IdentExt;
 use crate::bindgen::ir::{AnnotationSet, Cfg};
 use crate::bindgen::ir::{Documentation, Path, Type};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Field {
     pub name: String,
     pub ty: Type,
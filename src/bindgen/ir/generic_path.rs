@@ -11,13 +11,14 @@ use crate::bindgen::language_backend::LanguageBackend;
 use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::SourceWriter;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum GenericParamType {
     Type,
     Const(Type),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenericParam {
     name: Path,
     ty: GenericParamType,
@@ -60,7 +61,8 @@ impl GenericParam {
     }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct GenericParams(pub Vec<GenericParam>);
 
 impl GenericParams {
@@ -151,7 +153,8 @@ impl Deref for GenericParams {
 /// Note: Both arguments in a type like `Array<T, N>` are represented as
 /// `GenericArgument::Type`s, even if `N` is actually the name of a const. This
 /// is a consequence of `syn::GenericArgument` doing the same thing.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum GenericArgument {
     Type(Type),
     Const(ConstExpr),
@@ -186,7 +189,7 @@ impl GenericArgument {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct GenericPath {
     path: Path,
     export_name: String,
@@ -48,7 +48,8 @@ pub trait Item {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ItemContainer {
     Constant(Constant),
     Static(Static),
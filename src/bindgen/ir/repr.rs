@@ -2,11 +2,14 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::convert::TryFrom;
+
 use syn::ext::IdentExt;
 
 use crate::bindgen::ir::ty::{IntKind, PrimitiveType};
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ReprStyle {
     #[default]
     Rust,
@@ -14,7 +17,8 @@ pub enum ReprStyle {
     Transparent,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "PrimitiveType", try_from = "PrimitiveType")]
 pub struct ReprType {
     kind: IntKind,
     signed: bool,
@@ -30,13 +34,35 @@ impl ReprType {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+impl From<ReprType> for PrimitiveType {
+    fn from(ty: ReprType) -> Self {
+        ty.to_primitive()
+    }
+}
+
+impl TryFrom<PrimitiveType> for ReprType {
+    type Error = String;
+
+    fn try_from(ty: PrimitiveType) -> Result<Self, Self::Error> {
+        match ty {
+            PrimitiveType::Integer {
+                kind,
+                signed,
+                zeroable: true,
+            } => Ok(ReprType { kind, signed }),
+            _ => Err(format!("{} is not a valid repr type", ty.to_repr_rust())),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ReprAlign {
     Packed,
     Align(u64),
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct Repr {
     pub style: ReprStyle,
     pub ty: Option<ReprType>,
@@ -4,15 +4,18 @@
 
 use std::borrow::Cow;
 
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use syn::ext::IdentExt;
 
 use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::diagnostics::{self, Diagnostic, DiagnosticCode};
 use crate::bindgen::ir::{GenericArgument, GenericParams, GenericPath, Path};
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
-use crate::bindgen::utilities::IterHelpers;
+use crate::bindgen::utilities::{IterHelpers, SynAbiHelpers};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum PrimitiveType {
@@ -296,6 +299,239 @@ impl PrimitiveType {
         }
     }
 
+    pub fn to_repr_zig(&self) -> &'static str {
+        match *self {
+            PrimitiveType::Void => "void",
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "c_char",
+            PrimitiveType::SChar => "i8",
+            PrimitiveType::UChar => "u8",
+            PrimitiveType::Char32 => "u32",
+            PrimitiveType::Integer {
+                kind,
+                signed,
+                zeroable: _,
+            } => match kind {
+                IntKind::Short => {
+                    if signed {
+                        "c_short"
+                    } else {
+                        "c_ushort"
+                    }
+                }
+                IntKind::Int => {
+                    if signed {
+                        "c_int"
+                    } else {
+                        "c_uint"
+                    }
+                }
+                IntKind::Long => {
+                    if signed {
+                        "c_long"
+                    } else {
+                        "c_ulong"
+                    }
+                }
+                IntKind::LongLong => {
+                    if signed {
+                        "c_longlong"
+                    } else {
+                        "c_ulonglong"
+                    }
+                }
+                IntKind::SizeT | IntKind::Size => {
+                    if signed {
+                        "isize"
+                    } else {
+                        "usize"
+                    }
+                }
+                IntKind::B8 => {
+                    if signed {
+                        "i8"
+                    } else {
+                        "u8"
+                    }
+                }
+                IntKind::B16 => {
+                    if signed {
+                        "i16"
+                    } else {
+                        "u16"
+                    }
+                }
+                IntKind::B32 => {
+                    if signed {
+                        "i32"
+                    } else {
+                        "u32"
+                    }
+                }
+                IntKind::B64 => {
+                    if signed {
+                        "i64"
+                    } else {
+                        "u64"
+                    }
+                }
+            },
+            PrimitiveType::Float => "f32",
+            PrimitiveType::Double => "f64",
+            PrimitiveType::PtrDiffT => "isize",
+            PrimitiveType::VaList => "std.builtin.VaList",
+        }
+    }
+
+    pub fn to_repr_csharp(&self) -> &'static str {
+        match *self {
+            PrimitiveType::Void => "void",
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "byte",
+            PrimitiveType::SChar => "sbyte",
+            PrimitiveType::UChar => "byte",
+            PrimitiveType::Char32 => "uint",
+            PrimitiveType::Integer {
+                kind,
+                signed,
+                zeroable: _,
+            } => match kind {
+                IntKind::Short | IntKind::B16 => {
+                    if signed {
+                        "short"
+                    } else {
+                        "ushort"
+                    }
+                }
+                IntKind::Int | IntKind::B32 => {
+                    if signed {
+                        "int"
+                    } else {
+                        "uint"
+                    }
+                }
+                IntKind::Long => {
+                    if signed {
+                        "CLong"
+                    } else {
+                        "CULong"
+                    }
+                }
+                IntKind::LongLong | IntKind::B64 => {
+                    if signed {
+                        "long"
+                    } else {
+                        "ulong"
+                    }
+                }
+                IntKind::SizeT | IntKind::Size => {
+                    if signed {
+                        "nint"
+                    } else {
+                        "nuint"
+                    }
+                }
+                IntKind::B8 => {
+                    if signed {
+                        "sbyte"
+                    } else {
+                        "byte"
+                    }
+                }
+            },
+            PrimitiveType::Float => "float",
+            PrimitiveType::Double => "double",
+            PrimitiveType::PtrDiffT => "nint",
+            // `va_list` is an opaque, platform-specific handle as far as C# is concerned.
+            PrimitiveType::VaList => "nint",
+        }
+    }
+
+    pub fn to_repr_ctypes(&self) -> &'static str {
+        match *self {
+            PrimitiveType::Void => "None",
+            PrimitiveType::Bool => "ctypes.c_bool",
+            PrimitiveType::Char => "ctypes.c_char",
+            PrimitiveType::SChar => "ctypes.c_byte",
+            PrimitiveType::UChar => "ctypes.c_ubyte",
+            PrimitiveType::Char32 => "ctypes.c_uint32",
+            PrimitiveType::Integer {
+                kind,
+                signed,
+                zeroable: _,
+            } => match kind {
+                IntKind::Short => {
+                    if signed {
+                        "ctypes.c_short"
+                    } else {
+                        "ctypes.c_ushort"
+                    }
+                }
+                IntKind::Int => {
+                    if signed {
+                        "ctypes.c_int"
+                    } else {
+                        "ctypes.c_uint"
+                    }
+                }
+                IntKind::Long => {
+                    if signed {
+                        "ctypes.c_long"
+                    } else {
+                        "ctypes.c_ulong"
+                    }
+                }
+                IntKind::LongLong => {
+                    if signed {
+                        "ctypes.c_longlong"
+                    } else {
+                        "ctypes.c_ulonglong"
+                    }
+                }
+                IntKind::SizeT | IntKind::Size => {
+                    if signed {
+                        "ctypes.c_ssize_t"
+                    } else {
+                        "ctypes.c_size_t"
+                    }
+                }
+                IntKind::B8 => {
+                    if signed {
+                        "ctypes.c_int8"
+                    } else {
+                        "ctypes.c_uint8"
+                    }
+                }
+                IntKind::B16 => {
+                    if signed {
+                        "ctypes.c_int16"
+                    } else {
+                        "ctypes.c_uint16"
+                    }
+                }
+                IntKind::B32 => {
+                    if signed {
+                        "ctypes.c_int32"
+                    } else {
+                        "ctypes.c_uint32"
+                    }
+                }
+                IntKind::B64 => {
+                    if signed {
+                        "ctypes.c_int64"
+                    } else {
+                        "ctypes.c_uint64"
+                    }
+                }
+            },
+            PrimitiveType::Float => "ctypes.c_float",
+            PrimitiveType::Double => "ctypes.c_double",
+            PrimitiveType::PtrDiffT => "ctypes.c_ssize_t",
+            // `va_list` can't be built from Python, it's only passed around as a pointer.
+            PrimitiveType::VaList => "ctypes.c_void_p",
+        }
+    }
+
     fn can_cmp_order(&self) -> bool {
         !matches!(*self, PrimitiveType::Bool)
     }
@@ -305,11 +541,51 @@ impl PrimitiveType {
     }
 }
 
+/// Primitives are serialized with their Rust name, e.g. `"u32"` or `"NonZero<u32>"`.
+impl Serialize for PrimitiveType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            PrimitiveType::Integer {
+                zeroable: false, ..
+            } => serializer.collect_str(&format_args!("NonZero<{}>", self.to_repr_rust())),
+            _ => serializer.serialize_str(self.to_repr_rust()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PrimitiveType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        let (name, zeroable) = match name.strip_prefix("NonZero<") {
+            Some(inner) => (inner.strip_suffix('>').unwrap_or(inner), false),
+            None => (name.as_str(), true),
+        };
+        let ty = match name {
+            // `to_repr_rust` doesn't use the `VaList` spelling `maybe` expects.
+            "va_list" => Some(PrimitiveType::VaList),
+            _ => PrimitiveType::maybe(name),
+        };
+        match ty {
+            Some(PrimitiveType::Integer { kind, signed, .. }) => Ok(PrimitiveType::Integer {
+                kind,
+                signed,
+                zeroable,
+            }),
+            Some(ty) if zeroable => Ok(ty),
+            _ => Err(D::Error::custom(format!(
+                "unknown primitive type `{}`",
+                name
+            ))),
+        }
+    }
+}
+
 /// Constant expressions.
 ///
 /// Used for the `U` part of `[T; U]` and const generics. We support a very
 /// limited vocabulary here: only identifiers and literals.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ConstExpr {
     Name(String),
     Value(String),
@@ -377,7 +653,8 @@ impl ConstExpr {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Type {
     Ptr {
         ty: Box<Type>,
@@ -396,6 +673,13 @@ pub enum Type {
         args: Vec<(Option<String>, Type)>,
         is_nullable: bool,
         never_return: bool,
+        /// The calling convention, if it isn't the default one of `extern "C"`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        abi: Option<String>,
+        /// Whether the pointer isn't `extern`, so has the unstable Rust
+        /// calling convention.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        rust_abi: bool,
     },
 }
 
@@ -518,6 +802,8 @@ impl Type {
                     args,
                     is_nullable: false,
                     never_return,
+                    abi: function.abi.calling_convention(),
+                    rust_abi: function.abi.is_none(),
                 }
             }
             syn::Type::Tuple(ref tuple) => {
@@ -582,11 +868,15 @@ impl Type {
                 ref args,
                 is_nullable: false,
                 never_return,
+                ref abi,
+                rust_abi,
             } => Some(Type::FuncPtr {
                 ret: ret.clone(),
                 args: args.clone(),
                 is_nullable: true,
                 never_return,
+                abi: abi.clone(),
+                rust_abi,
             }),
             _ => None,
         }
@@ -783,6 +1073,8 @@ impl Type {
                 ref args,
                 is_nullable,
                 never_return,
+                ref abi,
+                rust_abi,
             } => Type::FuncPtr {
                 ret: Box::new(ret.specialize(mappings)),
                 args: args
@@ -792,6 +1084,8 @@ impl Type {
                     .collect(),
                 is_nullable,
                 never_return,
+                abi: abi.clone(),
+                rust_abi,
             },
         }
     }
@@ -818,18 +1112,26 @@ impl Type {
                         if !out.items.contains(path) {
                             out.items.insert(path.clone());
 
-                            for item in &items {
-                                item.deref().add_dependencies(library, out);
-                            }
+                            out.with_item(path, |out| {
+                                for item in &items {
+                                    item.deref().add_dependencies(library, out);
+                                }
+                            });
                             for item in items {
                                 out.order.push(item);
                             }
                         }
                     } else {
-                        warn!(
-                            "Can't find {}. This usually means that this type was incompatible or \
-                             not found.",
-                            path
+                        diagnostics::report(
+                            Diagnostic::warning(
+                                DiagnosticCode::UnknownType,
+                                format!(
+                                    "can't find `{}`. This usually means that this type was \
+                                     incompatible or not found",
+                                    path
+                                ),
+                            )
+                            .with_item(path),
                         );
                     }
                 }
@@ -949,10 +1251,17 @@ impl Type {
                 if let Some(mangled_path) = monomorphs.mangle_path(generic_path) {
                     *generic_path = GenericPath::new(mangled_path.clone(), vec![]);
                 } else {
-                    warn!(
-                        "Cannot find a mangling for generic path {:?}. This usually means that a \
-                         type referenced by this generic was incompatible or not found.",
-                        generic_path
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::UnmangledGeneric,
+                            format!(
+                                "cannot find a mangling for generic path {:?}. This usually means \
+                                 that a type referenced by this generic was incompatible or not \
+                                 found",
+                                generic_path
+                            ),
+                        )
+                        .with_item(generic_path.path()),
                     );
                 }
             }
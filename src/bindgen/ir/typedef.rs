@@ -18,7 +18,7 @@ use crate::bindgen::mangle;
 use crate::bindgen::monomorph::Monomorphs;
 
 /// A type alias that is represented as a C typedef
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Typedef {
     pub path: Path,
     pub export_name: String,
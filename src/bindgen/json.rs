@@ -0,0 +1,152 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The JSON representation of the IR, see `Bindings::write_json` and
+//! `Builder::with_ir_file`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::BufReader;
+use std::path;
+
+use serde::{Serialize, Serializer};
+
+use crate::bindgen::config::Config;
+use crate::bindgen::error::Error;
+use crate::bindgen::ir::{
+    Constant, Enum, Function, ItemContainer, ItemMap, OpaqueItem, Static, Struct, Typedef, Union,
+};
+use crate::bindgen::parser::Parse;
+
+/// The version of the JSON IR schema.
+///
+/// It is bumped whenever the document changes in a way which isn't purely
+/// additive, so consumers can bail out on documents they don't understand.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// The top-level JSON IR document.
+#[derive(Serialize)]
+pub(crate) struct JsonDocument<'a> {
+    pub schema_version: u32,
+    pub cbindgen_version: &'static str,
+    pub package_version: &'a str,
+    pub constants: &'a [Constant],
+    pub globals: &'a [Static],
+    pub items: &'a [ItemContainer],
+    pub functions: &'a [Function],
+    pub library: &'a ParsedLibrary,
+}
+
+/// The part of a JSON IR document `Builder::with_ir_file` reads back.
+#[derive(Deserialize)]
+struct JsonInput {
+    schema_version: u32,
+    package_version: String,
+    library: ParsedLibrary,
+}
+
+/// The items of a crate as they were parsed, before `Library::generate`
+/// renamed, monomorphized and filtered them.
+///
+/// Reading this back gives the same input as parsing the crate again, so
+/// bindings for other languages or configs can be generated from it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ParsedLibrary {
+    #[serde(with = "item_map")]
+    pub constants: ItemMap<Constant>,
+    #[serde(with = "item_map")]
+    pub globals: ItemMap<Static>,
+    #[serde(with = "item_map")]
+    pub enums: ItemMap<Enum>,
+    #[serde(with = "item_map")]
+    pub structs: ItemMap<Struct>,
+    #[serde(with = "item_map")]
+    pub unions: ItemMap<Union>,
+    #[serde(with = "item_map")]
+    pub opaque_items: ItemMap<OpaqueItem>,
+    #[serde(with = "item_map")]
+    pub typedefs: ItemMap<Typedef>,
+    pub functions: Vec<Function>,
+}
+
+/// Loads the parsed library of a JSON IR document written by
+/// `Bindings::write_json`. The document is the only source file of the result.
+///
+/// Parsing mostly doesn't depend on the language, except for the shape of
+/// enum variant bodies, which is adjusted to `config.language` here.
+pub(crate) fn read_ir_file(path: &path::Path, config: &Config) -> Result<Parse, Error> {
+    let ir_file_error = |message: String| Error::IrFile {
+        path: path.display().to_string(),
+        message,
+    };
+
+    let file = File::open(path).map_err(|e| ir_file_error(e.to_string()))?;
+    let input: JsonInput =
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| ir_file_error(e.to_string()))?;
+    if input.schema_version != JSON_SCHEMA_VERSION {
+        return Err(ir_file_error(format!(
+            "unsupported schema version {}, expected {}",
+            input.schema_version, JSON_SCHEMA_VERSION
+        )));
+    }
+
+    let mut library = input.library;
+    library
+        .enums
+        .for_all_items_mut(|x| x.inline_variant_bodies_for(config.language));
+
+    Ok(Parse {
+        constants: library.constants,
+        globals: library.globals,
+        enums: library.enums,
+        structs: library.structs,
+        unions: library.unions,
+        opaque_items: library.opaque_items,
+        typedefs: library.typedefs,
+        functions: library.functions,
+        source_files: vec![path.to_owned()],
+        package_version: input.package_version,
+        provenance: Default::default(),
+    })
+}
+
+/// Serializes a `HashMap` with its keys sorted, so that the output is stable.
+pub(crate) fn serialize_sorted<S, V>(
+    map: &HashMap<String, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: Serialize,
+{
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+/// An `ItemMap` is written as the list of its items, including every `cfg`
+/// variant of an item.
+mod item_map {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::bindgen::ir::{Item, ItemMap};
+
+    pub fn serialize<S, T>(map: &ItemMap<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Item + Clone + Serialize,
+    {
+        map.to_vec().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<ItemMap<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Item + Clone + Deserialize<'de>,
+    {
+        let mut map = ItemMap::default();
+        for item in Vec::<T>::deserialize(deserializer)? {
+            map.try_insert(item);
+        }
+        Ok(map)
+    }
+}
@@ -0,0 +1,881 @@
+use crate::bindgen::ir::{
+    to_known_assoc_constant, ConditionWrite, ConstExpr, Documentation, Enum, EnumVariant, Field,
+    Function, Item, ItemContainer, Literal, OpaqueItem, PrimitiveType, ReprAlign, Static, Struct,
+    ToCondition, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::writer::SourceWriter;
+use crate::bindgen::DocumentationLength;
+use crate::bindgen::{Bindings, Config, Layout};
+use std::borrow::Cow;
+use std::io::Write;
+
+const KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Element types which may be used in a `fixed` size buffer.
+const FIXED_BUFFER_TYPES: &[&str] = &[
+    "bool", "byte", "short", "int", "long", "sbyte", "ushort", "uint", "ulong", "float", "double",
+];
+
+/// Returns the identifier prefixed with `@` if it clashes with a C# keyword.
+fn escape_ident(name: &str) -> Cow<'_, str> {
+    if KEYWORDS.contains(&name) {
+        Cow::Owned(format!("@{}", name))
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// Splits nested arrays into the innermost element type and the list of lengths.
+fn flatten_array(ty: &Type) -> (&Type, Vec<&ConstExpr>) {
+    let mut lengths = vec![];
+    let mut ty = ty;
+    while let Type::Array(ref elem, ref len) = *ty {
+        lengths.push(len);
+        ty = elem;
+    }
+    (ty, lengths)
+}
+
+/// Returns the `CallingConvention` of `DllImport` and the one of an unmanaged
+/// function pointer for a C ABI, `None` being the one of `extern "C"`, if C#
+/// has them. The platform default of `system` is a function pointer without
+/// a calling convention.
+pub(crate) fn calling_convention(
+    abi: Option<&str>,
+) -> Option<(&'static str, Option<&'static str>)> {
+    Some(match abi {
+        None | Some("cdecl") => ("Cdecl", Some("Cdecl")),
+        Some("system") => ("Winapi", None),
+        Some("stdcall") => ("StdCall", Some("Stdcall")),
+        Some("thiscall") => ("ThisCall", Some("Thiscall")),
+        // `CallingConvention.FastCall` isn't supported by the runtime.
+        Some(_) => return None,
+    })
+}
+
+pub struct CSharpLanguageBackend<'a> {
+    config: &'a Config,
+}
+
+impl<'a> CSharpLanguageBackend<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self { config }
+    }
+
+    fn class_name(&self) -> &str {
+        self.config.csharp.class_name()
+    }
+
+    fn write_enum_variant<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &EnumVariant) {
+        let condition = u.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &u.documentation);
+        write!(out, "{}", escape_ident(&u.export_name));
+        if let Some(discriminant) = &u.discriminant {
+            out.write(" = ");
+            self.write_literal(out, discriminant);
+        }
+        out.write(",");
+
+        condition.write_after(self.config, out);
+    }
+
+    /// Writes a struct or union field, `explicit` selects `LayoutKind.Explicit`
+    /// where every field lives at offset zero.
+    fn write_field<W: Write>(&mut self, out: &mut SourceWriter<W>, f: &Field, explicit: bool) {
+        let condition = f.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &f.documentation);
+        if explicit {
+            out.write("[FieldOffset(0)]");
+            out.new_line();
+        }
+
+        let name = escape_ident(&f.name);
+        if let Type::Array(..) = f.ty {
+            let (elem, lengths) = flatten_array(&f.ty);
+            let elem_name = match elem {
+                Type::Primitive(p) => Some(p.to_repr_csharp()),
+                _ => None,
+            };
+            let literal_len = lengths
+                .iter()
+                .map(|len| match len {
+                    ConstExpr::Value(v) => v.parse::<usize>().ok(),
+                    ConstExpr::Name(..) => None,
+                })
+                .product::<Option<usize>>();
+
+            if let Some(elem_name) = elem_name.filter(|e| FIXED_BUFFER_TYPES.contains(e)) {
+                write!(out, "public fixed {} {}[", elem_name, name);
+                for (i, len) in lengths.iter().enumerate() {
+                    if i != 0 {
+                        out.write(" * ");
+                    }
+                    write!(out, "{}", len.as_str());
+                }
+                out.write("];");
+            } else if let (Some(len), false) = (literal_len, explicit) {
+                // Fixed buffers only support primitive element types, so spell out
+                // every element as its own field, which has the same layout.
+                for i in 0..len {
+                    if i != 0 {
+                        out.new_line();
+                    }
+                    self.write_field_decl(out, elem, &format!("{}_{}", f.name, i));
+                }
+            } else {
+                write!(
+                    out,
+                    "// `{}` is an array which can't be represented in C#.",
+                    f.name
+                );
+            }
+        } else {
+            self.write_field_decl(out, &f.ty, &name);
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_field_decl<W: Write>(&mut self, out: &mut SourceWriter<W>, ty: &Type, name: &str) {
+        if let Type::Primitive(PrimitiveType::Bool) = ty {
+            out.write("[MarshalAs(UnmanagedType.U1)] ");
+        }
+        out.write("public ");
+        self.write_type(out, ty);
+        write!(out, " {};", name);
+    }
+
+    fn write_fields<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        fields: &[Field],
+        explicit: bool,
+    ) {
+        for (i, field) in fields.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            self.write_field(out, field, explicit);
+        }
+    }
+
+    fn write_struct_layout<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        explicit: bool,
+        alignment: Option<ReprAlign>,
+    ) {
+        if let Some(ReprAlign::Align(n)) = alignment {
+            // `StructLayout` can only lower the alignment, never raise it.
+            write!(out, "// NOTE: align({}) can't be expressed in C#.", n);
+            out.new_line();
+        }
+        write!(
+            out,
+            "[StructLayout(LayoutKind.{}",
+            if explicit { "Explicit" } else { "Sequential" }
+        );
+        if let Some(ReprAlign::Packed) = alignment {
+            out.write(", Pack = 1");
+        }
+        out.write(")]");
+        out.new_line();
+    }
+
+    fn write_function_arg<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        index: usize,
+        name: Option<&str>,
+        ty: &Type,
+    ) {
+        if let Type::Primitive(PrimitiveType::Bool) = ty {
+            out.write("[MarshalAs(UnmanagedType.U1)] ");
+        }
+        self.write_type(out, ty);
+        match name {
+            Some(name) => write!(out, " {}", escape_ident(name)),
+            None => write!(out, " arg{}", index),
+        }
+    }
+
+    fn write_expr<W: Write>(&mut self, out: &mut SourceWriter<W>, v: &str) {
+        // Character literals outside of the latin-1 range are stored in C++ syntax,
+        // which don't fit in a C# `char`, so use their numeric value instead.
+        if let Some(code) = v.strip_prefix("U'\\U").and_then(|v| v.strip_suffix('\'')) {
+            return write!(out, "0x{}", code.trim_start_matches('0'));
+        }
+        if let Some(code) = v.strip_prefix("'\\u{").and_then(|v| v.strip_suffix("}'")) {
+            return write!(out, "'\\u{:0>4}'", code);
+        }
+        if v.starts_with(|c: char| c.is_ascii_digit()) {
+            let digits = v.trim_end_matches(['u', 'l']);
+            if digits.bytes().all(|c| c.is_ascii_digit()) {
+                let suffix = match &v[digits.len()..] {
+                    "ull" => "UL",
+                    "ll" => "L",
+                    "u" => "U",
+                    _ => "",
+                };
+                return write!(out, "{}{}", digits, suffix);
+            }
+        }
+        write!(out, "{}", v)
+    }
+}
+
+impl LanguageBackend for CSharpLanguageBackend<'_> {
+    fn write_headers<W: Write>(&self, out: &mut SourceWriter<W>, package_version: &str) {
+        if self.config.package_version {
+            write!(out, "// Package version: {}", package_version);
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if self.config.no_includes && self.config.after_includes.is_none() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !self.config.no_includes {
+            out.write("using System;");
+            out.new_line();
+            out.write("using System.Runtime.InteropServices;");
+            out.new_line();
+        }
+
+        if let Some(ref line) = self.config.after_includes {
+            write!(out, "{}", line);
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces<W: Write>(&mut self, out: &mut SourceWriter<W>) {
+        out.new_line_if_not_start();
+        if let Some(ref namespace) = self.config.csharp.namespace {
+            write!(out, "namespace {}", namespace);
+            out.open_brace();
+        }
+        write!(
+            out,
+            "public static unsafe partial class {}",
+            self.class_name()
+        );
+        out.open_brace();
+        write!(
+            out,
+            "private const string LibraryName = \"{}\";",
+            self.config.csharp.library.as_deref().unwrap_or("native")
+        );
+        out.new_line();
+    }
+
+    fn close_namespaces<W: Write>(&mut self, out: &mut SourceWriter<W>) {
+        // Every declaration ends with a new line already, so don't go through
+        // `close_brace`, which would leave an empty line behind.
+        let blocks = 1 + usize::from(self.config.csharp.namespace.is_some());
+        for _ in 0..blocks {
+            out.pop_tab();
+            out.write("}");
+            out.new_line();
+        }
+    }
+
+    fn write_footers<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        // Enums can only be based on fixed-size integer types.
+        let tag_type = e
+            .repr
+            .ty
+            .map_or("int", |ty| match ty.to_primitive().to_repr_csharp() {
+                "nint" => "long",
+                "nuint" => "ulong",
+                "CLong" => "int",
+                "CULong" => "uint",
+                ty => ty,
+            });
+        let has_data = e.tag.is_some();
+        let inline_tag_field = Enum::inline_tag_field(&e.repr);
+        let tag_name = e.tag_name();
+
+        let condition = e.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &e.documentation);
+
+        // Emit the tag enum.
+        write!(out, "public enum {} : {}", escape_ident(tag_name), tag_type);
+        out.open_brace();
+        for (i, variant) in e.variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            self.write_enum_variant(out, variant);
+        }
+        out.close_brace(false);
+
+        // If the enum has data, we need to emit structs for the variants and gather them together.
+        if has_data {
+            for variant in &e.variants {
+                if let VariantBody::Body {
+                    ref body,
+                    inline: false,
+                    ..
+                } = variant.body
+                {
+                    out.new_line();
+                    out.new_line();
+                    let condition = variant.cfg.to_condition(self.config);
+                    condition.write_before(self.config, out);
+                    self.write_struct(out, body);
+                    condition.write_after(self.config, out);
+                }
+            }
+            out.new_line();
+            out.new_line();
+
+            // With an inline tag every variant starts with its own copy of the tag,
+            // so the whole enum is a union. Otherwise the tag is followed by a union
+            // of the variants, which C# can't keep anonymous.
+            self.write_struct_layout(out, inline_tag_field, None);
+            write!(out, "public struct {}", escape_ident(e.export_name()));
+            out.open_brace();
+
+            if let Some(body) = &self.config.export.pre_body(&e.path) {
+                out.write_raw_block(body);
+                out.new_line();
+            }
+
+            // Inline variant bodies are declared as nested types.
+            for variant in &e.variants {
+                if let VariantBody::Body {
+                    ref body,
+                    inline: true,
+                    ..
+                } = variant.body
+                {
+                    let condition = variant.cfg.to_condition(self.config);
+                    condition.write_before(self.config, out);
+                    self.write_struct(out, body);
+                    condition.write_after(self.config, out);
+                    out.new_line();
+                    out.new_line();
+                }
+            }
+
+            if !inline_tag_field {
+                self.write_struct_layout(out, true, None);
+                out.write("public struct Data");
+                out.open_brace();
+            }
+
+            let mut first = true;
+            for variant in &e.variants {
+                if let VariantBody::Body {
+                    ref name, ref body, ..
+                } = variant.body
+                {
+                    if !first {
+                        out.new_line();
+                    }
+                    first = false;
+                    let condition = variant.cfg.to_condition(self.config);
+                    condition.write_before(self.config, out);
+                    out.write("[FieldOffset(0)]");
+                    out.new_line();
+                    write!(
+                        out,
+                        "public {} {};",
+                        escape_ident(body.export_name()),
+                        escape_ident(name)
+                    );
+                    condition.write_after(self.config, out);
+                }
+            }
+
+            if !inline_tag_field {
+                out.close_brace(false);
+                out.new_line();
+                out.new_line();
+            } else {
+                out.new_line();
+                out.write("[FieldOffset(0)]");
+                out.new_line();
+            }
+            write!(out, "public {} tag;", escape_ident(tag_name));
+            if !inline_tag_field {
+                out.new_line();
+                out.write("public Data data;");
+            }
+
+            if let Some(body) = &self.config.export.post_body(&e.path) {
+                out.new_line();
+                out.write_raw_block(body);
+            }
+
+            out.close_brace(false);
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_struct<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Struct) {
+        // Transparent structs are replaced by their field type, like typedefs.
+        if !s.is_transparent {
+            let condition = s.cfg.to_condition(self.config);
+            condition.write_before(self.config, out);
+
+            self.write_documentation(out, &s.documentation);
+            self.write_struct_layout(out, false, s.alignment);
+            write!(out, "public struct {}", escape_ident(s.export_name()));
+            let pre_body = self.config.export.pre_body(&s.path);
+            let post_body = self.config.export.post_body(&s.path);
+            if s.fields.is_empty() && pre_body.is_none() && post_body.is_none() {
+                out.write(" { }");
+            } else {
+                out.open_brace();
+
+                // Emit the pre_body section, if relevant
+                if let Some(body) = pre_body {
+                    out.write_raw_block(body);
+                    out.new_line();
+                }
+
+                self.write_fields(out, &s.fields, false);
+
+                // Emit the post_body section, if relevant
+                if let Some(body) = post_body {
+                    out.new_line();
+                    out.write_raw_block(body);
+                }
+                out.close_brace(false);
+            }
+
+            condition.write_after(self.config, out);
+        }
+
+        for (i, constant) in s.associated_constants.iter().enumerate() {
+            if i != 0 || !s.is_transparent {
+                out.new_line();
+            }
+            constant.write(self.config, self, out, Some(s));
+        }
+    }
+
+    fn write_union<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &Union) {
+        let condition = u.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &u.documentation);
+        self.write_struct_layout(out, true, u.alignment);
+        write!(out, "public struct {}", escape_ident(&u.export_name));
+        out.open_brace();
+
+        // Emit the pre_body section, if relevant
+        if let Some(body) = &self.config.export.pre_body(&u.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        self.write_fields(out, &u.fields, true);
+
+        // Emit the post_body section, if relevant
+        if let Some(body) = &self.config.export.post_body(&u.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+
+        out.close_brace(false);
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_opaque_item<W: Write>(&mut self, out: &mut SourceWriter<W>, o: &OpaqueItem) {
+        let condition = o.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &o.documentation);
+        write!(out, "public struct {} {{ }}", escape_ident(o.export_name()));
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_type_def<W: Write>(&mut self, _out: &mut SourceWriter<W>, _t: &Typedef) {
+        // C# has no type aliases which could be declared inside a class, so
+        // typedefs are replaced by the aliased type wherever they're used.
+    }
+
+    fn write_static<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Static) {
+        let condition = s.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &s.documentation);
+
+        // Data symbols can't be imported with `DllImport`, so they're looked up at runtime.
+        let name = escape_ident(&s.export_name);
+        if let Type::Array(ref elem, _) = s.ty {
+            out.write("public static ");
+            self.write_type(out, elem);
+            write!(out, "* {} => (", name);
+            self.write_type(out, elem);
+        } else {
+            out.write(if s.mutable {
+                "public static ref "
+            } else {
+                "public static ref readonly "
+            });
+            self.write_type(out, &s.ty);
+            write!(out, " {} => ref *(", name);
+            self.write_type(out, &s.ty);
+        }
+        write!(
+            out,
+            "*)NativeLibrary.GetExport(LibraryHandle, \"{}\");",
+            s.export_name
+        );
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_function_with_layout<W: Write>(
+        &mut self,
+        config: &Config,
+        out: &mut SourceWriter<W>,
+        func: &Function,
+        layout: Layout,
+    ) {
+        let condition = func.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        self.write_documentation(out, &func.documentation);
+
+        let (convention, _) = calling_convention(func.abi.as_deref()).unwrap_or(("Cdecl", None));
+        write!(
+            out,
+            "[DllImport(LibraryName, CallingConvention = CallingConvention.{})]",
+            convention
+        );
+        out.new_line();
+        if let Type::Primitive(PrimitiveType::Bool) = func.ret {
+            out.write("[return: MarshalAs(UnmanagedType.U1)]");
+            out.new_line();
+        }
+        out.write("public static extern ");
+        self.write_type(out, &func.ret);
+        write!(out, " {}(", escape_ident(func.path().name()));
+        match layout {
+            Layout::Vertical if !func.args.is_empty() => {
+                out.push_tab();
+                for (i, arg) in func.args.iter().enumerate() {
+                    out.new_line();
+                    self.write_function_arg(out, i, arg.name.as_deref(), &arg.ty);
+                    if i != func.args.len() - 1 {
+                        out.write(",");
+                    }
+                }
+                out.pop_tab();
+            }
+            _ => {
+                for (i, arg) in func.args.iter().enumerate() {
+                    if i != 0 {
+                        out.write(", ");
+                    }
+                    self.write_function_arg(out, i, arg.name.as_deref(), &arg.ty);
+                }
+            }
+        }
+        out.write(");");
+
+        condition.write_after(config, out);
+    }
+
+    fn write_type<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Type) {
+        match t {
+            Type::Ptr { ty, .. } => {
+                self.write_type(out, ty);
+                out.write("*");
+            }
+            Type::Path(generic) => match out.bindings().aliased_type(generic.path()) {
+                Some(aliased) => self.write_type(out, &aliased),
+                None => write!(out, "{}", escape_ident(generic.export_name())),
+            },
+            Type::Primitive(primitive) => {
+                write!(out, "{}", primitive.to_repr_csharp());
+            }
+            Type::Array(ty, _) => {
+                // Arrays outside of structs decay to pointers, like in C.
+                self.write_type(out, ty);
+                out.write("*");
+            }
+            Type::FuncPtr { ret, args, abi, .. } => {
+                match calling_convention(abi.as_deref()) {
+                    Some((_, None)) => out.write("delegate* unmanaged<"),
+                    Some((_, Some(convention))) => {
+                        write!(out, "delegate* unmanaged[{}]<", convention)
+                    }
+                    None => out.write("delegate* unmanaged[Cdecl]<"),
+                }
+                for (_, ty) in args {
+                    self.write_type(out, ty);
+                    out.write(", ");
+                }
+                self.write_type(out, ret);
+                out.write(">");
+            }
+        }
+    }
+
+    fn write_documentation<W: Write>(&mut self, out: &mut SourceWriter<W>, d: &Documentation) {
+        if d.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        let end = match self.config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        // C# doc comments are XML, so `documentation_style` is not relevant.
+        out.write("/// <summary>");
+        out.new_line();
+        for line in &d.doc_comment[..end] {
+            let line = line
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            write!(out, "///{}", line);
+            out.new_line();
+        }
+        out.write("/// </summary>");
+        out.new_line();
+    }
+
+    fn write_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, l: &Literal) {
+        match l {
+            Literal::Expr(v) => self.write_expr(out, v),
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if to_known_assoc_constant(path, name).is_some() {
+                        let prim = PrimitiveType::maybe(path.name()).unwrap();
+                        let value = if name == "MAX" {
+                            "MaxValue"
+                        } else {
+                            "MinValue"
+                        };
+                        return write!(out, "{}.{}", prim.to_repr_csharp(), value);
+                    }
+                    write!(out, "{}_", export_name)
+                }
+                write!(out, "{}", name)
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                write!(out, "(");
+                self.write_literal(out, base);
+                write!(out, ").{}", escape_ident(field));
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{}", op);
+                self.write_literal(out, value);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                write!(out, "(");
+                self.write_literal(out, left);
+                write!(out, " {} ", op);
+                self.write_literal(out, right);
+                write!(out, ")");
+            }
+            Literal::Cast { ref ty, ref value } => {
+                out.write("(");
+                self.write_type(out, ty);
+                out.write(")(");
+                self.write_literal(out, value);
+                out.write(")");
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                write!(out, "new {} {{ ", escape_ident(export_name));
+                let mut is_first_field = true;
+                let ordered_fields = out.bindings().struct_field_names(path);
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            write!(out, ", ");
+                        }
+                        is_first_field = false;
+                        write!(out, "{} = ", escape_ident(ordered_key));
+                        self.write_literal(out, lit);
+                    }
+                }
+                write!(out, " }}");
+            }
+        }
+    }
+
+    fn write_items<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for item in &b.items {
+            if item
+                .deref()
+                .annotations()
+                .bool("no-export")
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
+            // Aliases don't produce any declaration, see `write_type_def`.
+            match *item {
+                ItemContainer::Typedef(..) => continue,
+                ItemContainer::Struct(ref s)
+                    if s.is_transparent && s.associated_constants.is_empty() =>
+                {
+                    continue
+                }
+                _ => {}
+            }
+
+            out.new_line_if_not_start();
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.write_enum(out, x),
+                ItemContainer::Struct(ref x) => self.write_struct(out, x),
+                ItemContainer::Union(ref x) => self.write_union(out, x),
+                ItemContainer::OpaqueItem(ref x) => self.write_opaque_item(out, x),
+                ItemContainer::Typedef(ref x) => self.write_type_def(out, x),
+            }
+            out.new_line();
+        }
+    }
+
+    fn write_globals<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        if b.globals.is_empty() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+        write!(
+            out,
+            "private static readonly nint LibraryHandle = NativeLibrary.Load(LibraryName, typeof({}).Assembly, null);",
+            self.class_name()
+        );
+        out.new_line();
+
+        self.write_globals_default(out, b);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::bindgen::config::Language;
 use crate::bindgen::ir::{
     cfg::ConditionWrite, DeprecatedNoteKind, Documentation, Enum, Function, ItemContainer, Literal,
     OpaqueItem, Static, Struct, ToCondition, Type, Typedef, Union,
@@ -9,10 +10,27 @@ use crate::Config;
 use std::io::Write;
 
 mod clike;
+mod csharp;
 mod cython;
+mod python;
+mod zig;
 
 pub use clike::CLikeLanguageBackend;
+pub use csharp::CSharpLanguageBackend;
 pub use cython::CythonLanguageBackend;
+pub use python::PythonLanguageBackend;
+pub use zig::ZigLanguageBackend;
+
+/// Whether functions and function pointers with the calling convention `abi`
+/// can be written in `language`. C-like languages use `[fn] abi_macros`.
+pub(crate) fn supports_abi(language: Language, abi: &str) -> bool {
+    match language {
+        Language::Cxx | Language::C | Language::Cython => true,
+        Language::Zig => zig::calling_convention(Some(abi)).is_some(),
+        Language::CSharp => csharp::calling_convention(Some(abi)).is_some(),
+        Language::Python => python::calling_convention(Some(abi)).is_some(),
+    }
+}
 
 pub trait LanguageBackend: Sized {
     fn open_namespaces<W: Write>(&mut self, out: &mut SourceWriter<W>);
@@ -0,0 +1,914 @@
+use crate::bindgen::ir::{
+    to_known_assoc_constant, ConditionWrite, Documentation, Enum, EnumVariant, Field, Function,
+    GenericPath, Item, ItemContainer, Literal, OpaqueItem, Path, PrimitiveType, ReprAlign, Static,
+    Struct, ToCondition, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::writer::SourceWriter;
+use crate::bindgen::{Bindings, Config, DocumentationLength, Layout};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+/// Python keywords, plus the modules the generated code relies on.
+const RESERVED: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "ctypes", "def", "del", "elif", "else", "enum", "except", "finally", "for", "from", "global",
+    "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return",
+    "try", "while", "with", "yield",
+];
+
+/// Returns the identifier with a trailing underscore if it clashes with a Python keyword.
+fn escape_ident(name: &str) -> Cow<'_, str> {
+    if RESERVED.contains(&name) {
+        Cow::Owned(format!("{}_", name))
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// Collects the names which `ty` refers to through a pointer. Those only need
+/// the class to exist, rather than to be complete, so they can be forward
+/// declared.
+fn pointer_references<'a>(ty: &'a Type, behind_pointer: bool, names: &mut Vec<&'a str>) {
+    match ty {
+        Type::Ptr { ty, .. } => pointer_references(ty, true, names),
+        Type::Path(generic) => {
+            if behind_pointer {
+                names.push(generic.export_name());
+            }
+        }
+        Type::Primitive(..) => {}
+        Type::Array(ty, _) => pointer_references(ty, behind_pointer, names),
+        Type::FuncPtr { ret, args, .. } => {
+            pointer_references(ret, true, names);
+            for (_, ty) in args {
+                pointer_references(ty, true, names);
+            }
+        }
+    }
+}
+
+/// Returns the ctypes function pointer factory of a C ABI, `None` being the one
+/// of `extern "C"`, if ctypes has it.
+pub(crate) fn calling_convention(abi: Option<&str>) -> Option<&'static str> {
+    Some(match abi {
+        None | Some("cdecl") => "ctypes.CFUNCTYPE",
+        // `WINFUNCTYPE` only exists on Windows, elsewhere `system` is the C
+        // calling convention.
+        Some("system" | "stdcall") => "getattr(ctypes, \"WINFUNCTYPE\", ctypes.CFUNCTYPE)",
+        Some(_) => return None,
+    })
+}
+
+/// Whether functions with the ABI are called through a `WinDLL` rather than
+/// the `CDLL` of the library.
+fn uses_windll(abi: Option<&str>) -> bool {
+    matches!(abi, Some("system" | "stdcall"))
+}
+
+pub struct PythonLanguageBackend<'a> {
+    config: &'a Config,
+    /// The ctypes representation of every C-like enum, since `IntEnum`
+    /// classes can't be used as field or argument types.
+    enum_types: HashMap<String, &'static str>,
+    /// The enum each C-like enum variant belongs to, since variants are not
+    /// global in Python.
+    enum_variants: HashMap<String, String>,
+    /// The ctypes base class of every structure and union which is going to
+    /// be written, for forward declarations.
+    classes: HashMap<String, &'static str>,
+    /// Classes which were declared ahead of their definition, and still need
+    /// their `_fields_`.
+    forward_declared: HashSet<String>,
+    /// Classes which were already written.
+    declared: HashSet<String>,
+}
+
+impl<'a> PythonLanguageBackend<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self {
+            config,
+            enum_types: HashMap::new(),
+            enum_variants: HashMap::new(),
+            classes: HashMap::new(),
+            forward_declared: HashSet::new(),
+            declared: HashSet::new(),
+        }
+    }
+
+    fn collect_classes(&mut self, items: &[ItemContainer]) {
+        for item in items {
+            match item {
+                ItemContainer::Struct(s) if !s.is_transparent => {
+                    self.classes
+                        .insert(s.export_name().to_owned(), "ctypes.Structure");
+                }
+                ItemContainer::Union(u) => {
+                    self.classes
+                        .insert(u.export_name().to_owned(), "ctypes.Union");
+                }
+                ItemContainer::OpaqueItem(o) => {
+                    self.classes
+                        .insert(o.export_name().to_owned(), "ctypes.Structure");
+                }
+                ItemContainer::Enum(e) => {
+                    let ctype = e
+                        .repr
+                        .ty
+                        .map_or("ctypes.c_int", |ty| ty.to_primitive().to_repr_ctypes());
+                    self.enum_types.insert(e.tag_name().to_owned(), ctype);
+                    if e.tag.is_none() {
+                        for variant in &e.variants {
+                            self.enum_variants
+                                .insert(variant.export_name.clone(), e.export_name().to_owned());
+                        }
+                        continue;
+                    }
+                    let base = if Enum::inline_tag_field(&e.repr) {
+                        "ctypes.Union"
+                    } else {
+                        "ctypes.Structure"
+                    };
+                    self.classes.insert(e.export_name().to_owned(), base);
+                    for variant in &e.variants {
+                        if let VariantBody::Body { ref body, .. } = variant.body {
+                            self.classes
+                                .insert(body.export_name().to_owned(), "ctypes.Structure");
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Declares the classes which `types` point to, but which haven't been
+    /// written yet, so that `ctypes.POINTER()` can refer to them.
+    fn write_forward_declarations<'t, W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        types: impl Iterator<Item = &'t Type>,
+        except: &str,
+    ) {
+        let mut names = vec![];
+        for ty in types {
+            pointer_references(ty, false, &mut names);
+        }
+        for name in names {
+            if name == except || self.declared.contains(name) {
+                continue;
+            }
+            let Some(base) = self.classes.get(name) else {
+                continue;
+            };
+            write!(out, "class {}({})", escape_ident(name), base);
+            out.open_brace();
+            out.write("pass");
+            out.close_brace(false);
+            out.new_line();
+            out.new_line();
+            self.declared.insert(name.to_owned());
+            self.forward_declared.insert(name.to_owned());
+        }
+    }
+
+    /// Writes a `ctypes.Structure` or `ctypes.Union` subclass.
+    ///
+    /// Classes referring to themselves, or which were forward declared, get
+    /// their `_fields_` assigned after the class statement, since the class
+    /// name isn't bound within its own body.
+    #[allow(clippy::too_many_arguments)]
+    fn write_class<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        name: &str,
+        path: &Path,
+        documentation: &Documentation,
+        fields: &[Field],
+        alignment: Option<ReprAlign>,
+    ) {
+        let base = self
+            .classes
+            .get(name)
+            .copied()
+            .unwrap_or("ctypes.Structure");
+        self.write_forward_declarations(out, fields.iter().map(|f| &f.ty), name);
+
+        let mut self_references = vec![];
+        for field in fields {
+            pointer_references(&field.ty, false, &mut self_references);
+        }
+        let forward_declared = self.forward_declared.remove(name);
+        let fields_after_class = forward_declared || self_references.contains(&name);
+
+        let mut attributes = vec![];
+        match alignment {
+            Some(ReprAlign::Packed) => attributes.push(("_pack_", 1)),
+            // Only honored by Python 3.13 and later.
+            Some(ReprAlign::Align(n)) => attributes.push(("_align_", n)),
+            None => {}
+        }
+
+        let pre_body = self.config.export.pre_body(path);
+        let post_body = self.config.export.post_body(path);
+
+        if fields_after_class {
+            if !forward_declared {
+                self.write_documentation(out, documentation);
+                write!(out, "class {}({})", escape_ident(name), base);
+                out.open_brace();
+                out.write("pass");
+                out.close_brace(false);
+                out.new_line();
+                out.new_line();
+            }
+            for (attribute, value) in attributes {
+                write!(out, "{}.{} = {}", escape_ident(name), attribute, value);
+                out.new_line();
+            }
+            write!(out, "{}.", escape_ident(name));
+            self.write_fields(out, fields);
+        } else {
+            self.write_documentation(out, documentation);
+            write!(out, "class {}({})", escape_ident(name), base);
+            out.open_brace();
+
+            if let Some(body) = &pre_body {
+                out.write_raw_block(body);
+                out.new_line();
+            }
+
+            for (attribute, value) in &attributes {
+                write!(out, "{} = {}", attribute, value);
+                out.new_line();
+            }
+
+            if !fields.is_empty() {
+                self.write_fields(out, fields);
+            } else if attributes.is_empty() && pre_body.is_none() && post_body.is_none() {
+                out.write("pass");
+            }
+
+            if let Some(body) = &post_body {
+                out.new_line();
+                out.write_raw_block(body);
+            }
+            out.close_brace(false);
+        }
+
+        self.declared.insert(name.to_owned());
+    }
+
+    fn write_fields<W: Write>(&mut self, out: &mut SourceWriter<W>, fields: &[Field]) {
+        out.write("_fields_ = [");
+        out.push_tab();
+        for field in fields {
+            out.new_line();
+            let condition = field.cfg.to_condition(self.config);
+            condition.write_before(self.config, out);
+
+            self.write_documentation(out, &field.documentation);
+            write!(out, "(\"{}\", ", escape_ident(&field.name));
+            self.write_type(out, &field.ty);
+            if let Some(bitfield) = field.annotations.atom("bitfield") {
+                write!(out, ", {}", bitfield.unwrap_or_default());
+            }
+            out.write("),");
+
+            condition.write_after(self.config, out);
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("]");
+    }
+
+    fn write_enum_variant<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        variant: &EnumVariant,
+        implicit_value: &mut String,
+    ) {
+        let condition = variant.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &variant.documentation);
+        let name = escape_ident(&variant.export_name);
+        write!(out, "{} = ", name);
+        // `IntEnum` members need an explicit value, so follow C's numbering.
+        let value = match variant.discriminant {
+            Some(Literal::Expr(ref v)) => v.trim_end_matches(['u', 'l']).parse::<i128>().ok(),
+            Some(_) => None,
+            None => implicit_value.parse::<i128>().ok(),
+        };
+        match (value, &variant.discriminant) {
+            (Some(value), _) => write!(out, "{}", value),
+            (None, Some(discriminant)) => self.write_literal(out, discriminant),
+            (None, None) => write!(out, "{}", implicit_value),
+        }
+        *implicit_value = match value {
+            Some(value) => (value + 1).to_string(),
+            None => format!("{} + 1", name),
+        };
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_data_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let inline_tag_field = Enum::inline_tag_field(&e.repr);
+        let tag = Type::Path(GenericPath::new(Path::new(e.tag_name()), vec![]));
+
+        let mut variants = vec![];
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                ref name, ref body, ..
+            } = variant.body
+            {
+                out.new_line();
+                out.new_line();
+                let condition = variant.cfg.to_condition(self.config);
+                condition.write_before(self.config, out);
+                self.write_class(
+                    out,
+                    body.export_name(),
+                    &body.path,
+                    &body.documentation,
+                    &body.fields,
+                    body.alignment,
+                );
+                condition.write_after(self.config, out);
+
+                let mut field = Field::from_name_and_type(
+                    name.clone(),
+                    Type::Path(GenericPath::new(Path::new(body.export_name()), vec![])),
+                );
+                field.cfg = variant.cfg.clone();
+                variants.push(field);
+            }
+        }
+        out.new_line();
+        out.new_line();
+
+        // With an inline tag every variant starts with its own copy of the tag,
+        // so the whole enum is a union. Otherwise the tag is followed by a union
+        // of the variants.
+        let mut fields = vec![Field::from_name_and_type("tag".to_owned(), tag)];
+        if inline_tag_field {
+            fields.extend(variants);
+        } else {
+            let data_name = format!("{}_Data", e.export_name());
+            self.classes.insert(data_name.clone(), "ctypes.Union");
+            self.write_class(
+                out,
+                &data_name,
+                &Path::new(data_name.clone()),
+                &Documentation::none(),
+                &variants,
+                None,
+            );
+            out.new_line();
+            out.new_line();
+            fields.push(Field::from_name_and_type(
+                "data".to_owned(),
+                Type::Path(GenericPath::new(Path::new(data_name), vec![])),
+            ));
+        }
+
+        self.write_class(
+            out,
+            e.export_name(),
+            &e.path,
+            &e.documentation,
+            &fields,
+            None,
+        );
+    }
+
+    /// Writes a type which may need parentheses before attribute access.
+    fn write_type_operand<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Type) {
+        if let Type::Array(..) = t {
+            out.write("(");
+            self.write_type(out, t);
+            out.write(")");
+        } else {
+            self.write_type(out, t);
+        }
+    }
+
+    /// Writes an attribute of the loaded library, which may not be a valid identifier.
+    fn write_symbol<W: Write>(&mut self, out: &mut SourceWriter<W>, lib: &str, name: &str) {
+        if RESERVED.contains(&name) {
+            write!(out, "getattr({}, \"{}\")", lib, name);
+        } else {
+            write!(out, "{}.{}", lib, name);
+        }
+    }
+
+    /// Opens a block which only declares the symbol `name` if the library
+    /// has it, as the cfg it is behind may not have been enabled.
+    fn open_symbol_guard<W: Write>(&mut self, out: &mut SourceWriter<W>, name: &str) {
+        write!(out, "if hasattr(lib, \"{}\")", name);
+        out.open_brace();
+    }
+
+    fn write_expr<W: Write>(&mut self, out: &mut SourceWriter<W>, v: &str) {
+        match v {
+            "true" => return out.write("True"),
+            "false" => return out.write("False"),
+            _ => {}
+        }
+        // Characters are plain integers as far as C is concerned.
+        if let Some(code) = v.strip_prefix("U'\\U").and_then(|v| v.strip_suffix('\'')) {
+            return write!(out, "0x{}", code.trim_start_matches('0'));
+        }
+        if let Some(code) = v.strip_prefix("'\\u{").and_then(|v| v.strip_suffix("}'")) {
+            return write!(out, "0x{}", code);
+        }
+        if v.starts_with('\'') {
+            return write!(out, "ord({})", v);
+        }
+        // Integer literals carry C suffixes, which Python doesn't need.
+        if v.starts_with(|c: char| c.is_ascii_digit()) {
+            let digits = v.trim_end_matches(['u', 'l']);
+            if digits.bytes().all(|c| c.is_ascii_digit()) {
+                return write!(out, "{}", digits);
+            }
+        }
+        write!(out, "{}", v)
+    }
+}
+
+impl LanguageBackend for PythonLanguageBackend<'_> {
+    fn write_headers<W: Write>(&self, out: &mut SourceWriter<W>, package_version: &str) {
+        if self.config.package_version {
+            write!(out, "# Package version: {}", package_version);
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "# Generated with cbindgen:{}",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if self.config.no_includes && self.config.after_includes.is_none() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !self.config.no_includes {
+            out.write("import ctypes");
+            out.new_line();
+            out.write("import enum");
+            out.new_line();
+        }
+
+        if let Some(ref line) = self.config.after_includes {
+            write!(out, "{}", line);
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn close_namespaces<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_footers<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let condition = e.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &e.documentation);
+
+        // Emit the tag enum.
+        write!(out, "class {}(enum.IntEnum)", escape_ident(e.tag_name()));
+        out.open_brace();
+        let mut implicit_value = "0".to_owned();
+        for (i, variant) in e.variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            self.write_enum_variant(out, variant, &mut implicit_value);
+        }
+        if e.variants.is_empty() {
+            out.write("pass");
+        }
+        out.close_brace(false);
+        self.declared.insert(e.tag_name().to_owned());
+
+        // If the enum has data, we need to emit structs for the variants and gather them together.
+        if e.tag.is_some() {
+            self.write_data_enum(out, e);
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_struct<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Struct) {
+        if s.is_transparent {
+            let typedef = Typedef {
+                path: s.path.clone(),
+                export_name: s.export_name.to_owned(),
+                generic_params: s.generic_params.clone(),
+                aliased: s.fields[0].ty.clone(),
+                cfg: s.cfg.clone(),
+                annotations: s.annotations.clone(),
+                documentation: s.documentation.clone(),
+            };
+            self.write_type_def(out, &typedef);
+            for constant in &s.associated_constants {
+                out.new_line();
+                constant.write(self.config, self, out, Some(s));
+            }
+            return;
+        }
+
+        let condition = s.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_class(
+            out,
+            s.export_name(),
+            &s.path,
+            &s.documentation,
+            &s.fields,
+            s.alignment,
+        );
+
+        for constant in &s.associated_constants {
+            out.new_line();
+            constant.write(self.config, self, out, Some(s));
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_union<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &Union) {
+        let condition = u.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_class(
+            out,
+            &u.export_name,
+            &u.path,
+            &u.documentation,
+            &u.fields,
+            u.alignment,
+        );
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_opaque_item<W: Write>(&mut self, out: &mut SourceWriter<W>, o: &OpaqueItem) {
+        let condition = o.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &o.documentation);
+
+        write!(
+            out,
+            "class {}(ctypes.Structure)",
+            escape_ident(o.export_name())
+        );
+        out.open_brace();
+        out.write("pass");
+        out.close_brace(false);
+        self.declared.insert(o.export_name().to_owned());
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_type_def<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Typedef) {
+        let condition = t.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_forward_declarations(out, std::iter::once(&t.aliased), t.export_name());
+        self.write_documentation(out, &t.documentation);
+
+        write!(out, "{} = ", escape_ident(t.export_name()));
+        self.write_type(out, &t.aliased);
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_static<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Static) {
+        let condition = s.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &s.documentation);
+        if s.cfg.is_some() {
+            self.open_symbol_guard(out, &s.export_name);
+        }
+        write!(out, "lib.{} = ", escape_ident(&s.export_name));
+        self.write_type_operand(out, &s.ty);
+        write!(out, ".in_dll(lib, \"{}\")", s.export_name);
+        if s.cfg.is_some() {
+            out.close_brace(false);
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_function_with_layout<W: Write>(
+        &mut self,
+        config: &Config,
+        out: &mut SourceWriter<W>,
+        func: &Function,
+        layout: Layout,
+    ) {
+        let condition = func.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        self.write_documentation(out, &func.documentation);
+
+        let name = func.path().name();
+        if func.cfg.is_some() {
+            self.open_symbol_guard(out, name);
+        }
+        if uses_windll(func.abi.as_deref()) {
+            if RESERVED.contains(&name) {
+                write!(out, "setattr(lib, \"{}\", ", name);
+                self.write_symbol(out, "windll", name);
+                out.write(")");
+            } else {
+                write!(out, "lib.{} = ", name);
+                self.write_symbol(out, "windll", name);
+            }
+            out.new_line();
+        }
+        self.write_symbol(out, "lib", name);
+        out.write(".argtypes = [");
+        match layout {
+            Layout::Vertical if !func.args.is_empty() => {
+                out.push_tab();
+                for arg in &func.args {
+                    out.new_line();
+                    self.write_type(out, &arg.ty);
+                    out.write(",");
+                }
+                out.pop_tab();
+                out.new_line();
+            }
+            _ => {
+                for (i, arg) in func.args.iter().enumerate() {
+                    if i != 0 {
+                        out.write(", ");
+                    }
+                    self.write_type(out, &arg.ty);
+                }
+            }
+        }
+        out.write("]");
+        out.new_line();
+        self.write_symbol(out, "lib", name);
+        out.write(".restype = ");
+        self.write_type(out, &func.ret);
+        if func.cfg.is_some() {
+            out.close_brace(false);
+        }
+
+        condition.write_after(config, out);
+    }
+
+    fn write_type<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Type) {
+        match t {
+            Type::Ptr { ty, .. } => match **ty {
+                Type::Primitive(PrimitiveType::Void) => out.write("ctypes.c_void_p"),
+                Type::Primitive(PrimitiveType::Char) => out.write("ctypes.c_char_p"),
+                _ => {
+                    out.write("ctypes.POINTER(");
+                    self.write_type(out, ty);
+                    out.write(")");
+                }
+            },
+            Type::Path(generic) => match self.enum_types.get(generic.export_name()) {
+                Some(ctype) => write!(out, "{}", ctype),
+                None => write!(out, "{}", escape_ident(generic.export_name())),
+            },
+            Type::Primitive(primitive) => {
+                write!(out, "{}", primitive.to_repr_ctypes());
+            }
+            Type::Array(ty, len) => {
+                self.write_type_operand(out, ty);
+                write!(out, " * {}", len.as_str());
+            }
+            Type::FuncPtr { ret, args, abi, .. } => {
+                let factory = calling_convention(abi.as_deref()).unwrap_or("ctypes.CFUNCTYPE");
+                write!(out, "{}(", factory);
+                self.write_type(out, ret);
+                for (_, ty) in args {
+                    out.write(", ");
+                    self.write_type(out, ty);
+                }
+                out.write(")");
+            }
+        }
+    }
+
+    fn write_documentation<W: Write>(&mut self, out: &mut SourceWriter<W>, d: &Documentation) {
+        if d.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        let end = match self.config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        // Python only has line comments, so `documentation_style` is not relevant.
+        for line in &d.doc_comment[..end] {
+            write!(out, "#{}", line);
+            out.new_line();
+        }
+    }
+
+    fn write_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, l: &Literal) {
+        match l {
+            Literal::Expr(v) => self.write_expr(out, v),
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if to_known_assoc_constant(path, name).is_some() {
+                        let prim = PrimitiveType::maybe(path.name()).unwrap();
+                        let bits = format!("(8 * ctypes.sizeof({}))", prim.to_repr_ctypes());
+                        let signed = path.name().starts_with('i');
+                        return match (name.as_str(), signed) {
+                            ("MAX", true) => write!(out, "((1 << ({} - 1)) - 1)", bits),
+                            ("MAX", false) => write!(out, "((1 << {}) - 1)", bits),
+                            (_, true) => write!(out, "(-(1 << ({} - 1)))", bits),
+                            (_, false) => out.write("0"),
+                        };
+                    }
+                    write!(out, "{}_", export_name)
+                } else if let Some(e) = self.enum_variants.get(name) {
+                    write!(out, "{}.", escape_ident(e))
+                }
+                write!(out, "{}", name)
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                write!(out, "(");
+                self.write_literal(out, base);
+                write!(out, ").{}", escape_ident(field));
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{}", op);
+                self.write_literal(out, value);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                let is_float = |l: &Literal| matches!(l, Literal::Expr(v) if v.contains('.'));
+                let op = match *op {
+                    "&&" => "and",
+                    "||" => "or",
+                    // C integer division truncates, `/` would produce a float.
+                    "/" if !is_float(left) && !is_float(right) => "//",
+                    op => op,
+                };
+                write!(out, "(");
+                self.write_literal(out, left);
+                write!(out, " {} ", op);
+                self.write_literal(out, right);
+                write!(out, ")");
+            }
+            Literal::Cast { ref ty, ref value } => {
+                match ty {
+                    Type::Primitive(PrimitiveType::Bool) => {
+                        out.write("bool(");
+                        self.write_literal(out, value);
+                        out.write(")");
+                    }
+                    Type::Primitive(PrimitiveType::Float | PrimitiveType::Double) => {
+                        out.write("float(");
+                        self.write_literal(out, value);
+                        out.write(")");
+                    }
+                    // Round-trip through the ctypes type to get C's wrapping semantics.
+                    Type::Primitive(..) => {
+                        self.write_type(out, ty);
+                        out.write("(int(");
+                        self.write_literal(out, value);
+                        out.write(")).value");
+                    }
+                    _ => self.write_literal(out, value),
+                }
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                write!(out, "{}(", escape_ident(export_name));
+                let mut is_first_field = true;
+                let ordered_fields = out.bindings().struct_field_names(path);
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            write!(out, ", ");
+                        }
+                        is_first_field = false;
+                        write!(out, "{}=", escape_ident(ordered_key));
+                        self.write_literal(out, lit);
+                    }
+                }
+                write!(out, ")");
+            }
+        }
+    }
+
+    fn write_items<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        self.collect_classes(&b.items);
+
+        for item in &b.items {
+            if item
+                .deref()
+                .annotations()
+                .bool("no-export")
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
+            // Opaque items are complete once declared, so forward declaring
+            // them was enough.
+            if let ItemContainer::OpaqueItem(ref o) = item {
+                if self.forward_declared.remove(o.export_name()) {
+                    continue;
+                }
+            }
+
+            out.new_line_if_not_start();
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.write_enum(out, x),
+                ItemContainer::Struct(ref x) => self.write_struct(out, x),
+                ItemContainer::Union(ref x) => self.write_union(out, x),
+                ItemContainer::OpaqueItem(ref x) => self.write_opaque_item(out, x),
+                ItemContainer::Typedef(ref x) => self.write_type_def(out, x),
+            }
+            out.new_line();
+        }
+    }
+
+    fn write_globals<W: Write>(&mut self, _out: &mut SourceWriter<W>, _b: &Bindings) {
+        // Globals can only be looked up once the library is loaded, see `write_functions`.
+    }
+
+    fn write_functions<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        out.new_line_if_not_start();
+        out.write("def load(path)");
+        out.open_brace();
+        out.write(
+            "\"\"\"Loads the shared library at `path` and declares its exported symbols.\"\"\"",
+        );
+        out.new_line();
+        out.write("lib = ctypes.CDLL(path)");
+        out.new_line();
+        if b.functions.iter().any(|f| uses_windll(f.abi.as_deref())) {
+            // `WinDLL` only exists on Windows, the functions are looked up in
+            // the `CDLL` elsewhere.
+            out.write("windll = getattr(ctypes, \"WinDLL\", ctypes.CDLL)(path)");
+            out.new_line();
+        }
+
+        for global in &b.globals {
+            out.new_line();
+            self.write_static(out, global);
+            out.new_line();
+        }
+
+        for function in &b.functions {
+            out.new_line();
+            self.write_function(&b.config, out, function);
+            out.new_line();
+        }
+
+        out.new_line();
+        out.write("return lib");
+        out.close_brace(false);
+        out.new_line();
+    }
+}
@@ -0,0 +1,748 @@
+use crate::bindgen::ir::{
+    to_known_assoc_constant, ConditionWrite, Documentation, Enum, EnumVariant, Field, Function,
+    Item, Literal, OpaqueItem, PrimitiveType, ReprAlign, Static, Struct, ToCondition, Type,
+    Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::writer::SourceWriter;
+use crate::bindgen::DocumentationLength;
+use crate::bindgen::{Config, Layout};
+use std::borrow::Cow;
+use std::io::Write;
+
+/// Keywords and primitive type names which can't be used as plain identifiers in Zig.
+const RESERVED: &[&str] = &[
+    "addrspace",
+    "align",
+    "allowzero",
+    "and",
+    "anyframe",
+    "anyopaque",
+    "anytype",
+    "asm",
+    "async",
+    "await",
+    "anyerror",
+    "bool",
+    "break",
+    "c_char",
+    "c_int",
+    "c_long",
+    "c_longdouble",
+    "c_longlong",
+    "c_short",
+    "c_uint",
+    "c_ulong",
+    "c_ulonglong",
+    "c_ushort",
+    "callconv",
+    "catch",
+    "comptime",
+    "comptime_float",
+    "comptime_int",
+    "const",
+    "continue",
+    "defer",
+    "else",
+    "enum",
+    "errdefer",
+    "error",
+    "export",
+    "extern",
+    "f128",
+    "f16",
+    "f32",
+    "f64",
+    "f80",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "inline",
+    "isize",
+    "linksection",
+    "noalias",
+    "noinline",
+    "noreturn",
+    "nosuspend",
+    "null",
+    "opaque",
+    "or",
+    "orelse",
+    "packed",
+    "pub",
+    "resume",
+    "return",
+    "struct",
+    "suspend",
+    "switch",
+    "test",
+    "threadlocal",
+    "true",
+    "try",
+    "type",
+    "undefined",
+    "union",
+    "unreachable",
+    "usingnamespace",
+    "usize",
+    "var",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Returns the identifier escaped with `@"..."` if it clashes with a Zig keyword or primitive.
+fn escape_ident(name: &str) -> Cow<'_, str> {
+    // Arbitrary bit-width integers (`u7`, `i128`, ...) are primitives as well.
+    let is_int_type = matches!(name.as_bytes(), [b'i' | b'u', rest @ ..]
+        if !rest.is_empty() && rest.iter().all(u8::is_ascii_digit));
+    if is_int_type || RESERVED.contains(&name) {
+        Cow::Owned(format!("@\"{}\"", name))
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// Returns the Zig calling convention of a C ABI, `None` being the one of
+/// `extern "C"`, if Zig has it.
+pub(crate) fn calling_convention(abi: Option<&str>) -> Option<&'static str> {
+    Some(match abi {
+        None | Some("cdecl") => ".C",
+        // `system` is only `stdcall` on Windows.
+        Some("system") => {
+            "if (@import(\"builtin\").os.tag == .windows) std.os.windows.WINAPI else .C"
+        }
+        Some("stdcall") => ".Stdcall",
+        Some("fastcall") => ".Fastcall",
+        Some("vectorcall") => ".Vectorcall",
+        Some("thiscall") => ".Thiscall",
+        Some("sysv64") => ".SysV",
+        Some("win64") => ".Win64",
+        Some("aapcs") => ".AAPCS",
+        Some(_) => return None,
+    })
+}
+
+pub struct ZigLanguageBackend<'a> {
+    config: &'a Config,
+}
+
+impl<'a> ZigLanguageBackend<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self { config }
+    }
+
+    fn write_enum_variant<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &EnumVariant) {
+        let condition = u.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &u.documentation);
+        write!(out, "{}", escape_ident(&u.export_name));
+        if let Some(discriminant) = &u.discriminant {
+            out.write(" = ");
+            self.write_literal(out, discriminant);
+        }
+        out.write(",");
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_field<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        f: &Field,
+        align: Option<ReprAlign>,
+    ) {
+        let condition = f.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &f.documentation);
+        write!(out, "{}: ", escape_ident(&f.name));
+        self.write_type(out, &f.ty);
+        match align {
+            Some(ReprAlign::Packed) => out.write(" align(1)"),
+            // `align` on a field is exact, so don't lower the natural alignment.
+            Some(ReprAlign::Align(n)) => {
+                write!(out, " align(@max({}, @alignOf(", n);
+                self.write_type(out, &f.ty);
+                out.write(")))");
+            }
+            None => {}
+        }
+        out.write(",");
+
+        // Zig has no C-compatible bitfields, so just keep the width as a hint.
+        if let Some(bitfield) = f.annotations.atom("bitfield") {
+            write!(out, " // bitfield: {}", bitfield.unwrap_or_default());
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    /// Writes the fields of an `extern struct` or `extern union`.
+    ///
+    /// Zig extern containers can't be packed or over-aligned as a whole, so
+    /// `#[repr(packed)]` lowers every field to `align(1)` and `#[repr(align(N))]`
+    /// raises the alignment of the first field.
+    fn write_fields<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        fields: &[Field],
+        alignment: Option<ReprAlign>,
+    ) {
+        for (i, field) in fields.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            let align = match alignment {
+                Some(ReprAlign::Align(_)) if i != 0 => None,
+                align => align,
+            };
+            self.write_field(out, field, align);
+        }
+    }
+
+    fn write_function_arg<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        name: Option<&str>,
+        ty: &Type,
+    ) {
+        if let Some(name) = name {
+            write!(out, "{}: ", escape_ident(name));
+        }
+        self.write_type(out, ty);
+    }
+
+    fn write_pointee<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Type) {
+        // `*void` is not a thing in Zig, pointers to unknown data use `anyopaque`.
+        if let Type::Primitive(PrimitiveType::Void) = t {
+            out.write("anyopaque");
+        } else {
+            self.write_type(out, t);
+        }
+    }
+
+    fn write_return_type<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        ret: &Type,
+        never_return: bool,
+    ) {
+        if never_return {
+            out.write("noreturn");
+        } else {
+            self.write_type(out, ret);
+        }
+    }
+
+    fn write_expr<W: Write>(&mut self, out: &mut SourceWriter<W>, v: &str) {
+        // Character literals outside of the latin-1 range are stored in C++ syntax.
+        if let Some(code) = v.strip_prefix("U'\\U").and_then(|v| v.strip_suffix('\'')) {
+            return write!(out, "'\\u{{{}}}'", code.trim_start_matches('0'));
+        }
+        // Integer literals carry C suffixes, which Zig doesn't need.
+        if v.starts_with(|c: char| c.is_ascii_digit()) {
+            let digits = v.trim_end_matches(['u', 'l']);
+            if digits.bytes().all(|c| c.is_ascii_digit()) {
+                return write!(out, "{}", digits);
+            }
+        }
+        write!(out, "{}", v)
+    }
+}
+
+impl LanguageBackend for ZigLanguageBackend<'_> {
+    fn write_headers<W: Write>(&self, out: &mut SourceWriter<W>, package_version: &str) {
+        if self.config.package_version {
+            write!(out, "// Package version: {}", package_version);
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if self.config.no_includes && self.config.after_includes.is_none() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !self.config.no_includes {
+            out.write("const std = @import(\"std\");");
+            out.new_line();
+        }
+
+        if let Some(ref line) = self.config.after_includes {
+            write!(out, "{}", line);
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn close_namespaces<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_footers<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let tag_type = e
+            .repr
+            .ty
+            .map_or("c_int", |ty| ty.to_primitive().to_repr_zig());
+        let has_data = e.tag.is_some();
+        let inline_tag_field = Enum::inline_tag_field(&e.repr);
+        let tag_name = e.tag_name();
+
+        let condition = e.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &e.documentation);
+
+        // Emit the tag enum.
+        write!(
+            out,
+            "pub const {} = enum({})",
+            escape_ident(tag_name),
+            tag_type
+        );
+        out.open_brace();
+        for (i, variant) in e.variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            self.write_enum_variant(out, variant);
+        }
+        out.close_brace(true);
+
+        // If the enum has data, we need to emit structs for the variants and gather them together.
+        if has_data {
+            for variant in &e.variants {
+                if let VariantBody::Body {
+                    ref body,
+                    inline: false,
+                    ..
+                } = variant.body
+                {
+                    out.new_line();
+                    out.new_line();
+                    let condition = variant.cfg.to_condition(self.config);
+                    condition.write_before(self.config, out);
+                    self.write_struct(out, body);
+                    condition.write_after(self.config, out);
+                }
+            }
+            out.new_line();
+            out.new_line();
+
+            // With an inline tag every variant starts with its own copy of the tag,
+            // so the whole enum is a union. Otherwise the tag is followed by a union
+            // of the variants, which Zig can't keep anonymous.
+            write!(
+                out,
+                "pub const {} = {}",
+                escape_ident(e.export_name()),
+                if inline_tag_field {
+                    "extern union"
+                } else {
+                    "extern struct"
+                }
+            );
+            out.open_brace();
+
+            if let Some(body) = &self.config.export.pre_body(&e.path) {
+                out.write_raw_block(body);
+                out.new_line();
+            }
+
+            write!(out, "tag: {},", escape_ident(tag_name));
+            out.new_line();
+            if !inline_tag_field {
+                out.write("data: extern union");
+                out.open_brace();
+            }
+
+            let mut first = true;
+            for variant in &e.variants {
+                if let VariantBody::Body {
+                    ref name,
+                    ref body,
+                    inline,
+                    ..
+                } = variant.body
+                {
+                    if !first {
+                        out.new_line();
+                    }
+                    first = false;
+                    let condition = variant.cfg.to_condition(self.config);
+                    condition.write_before(self.config, out);
+                    write!(out, "{}: ", escape_ident(name));
+                    if inline {
+                        out.write("extern struct");
+                        out.open_brace();
+                        self.write_fields(out, &body.fields, None);
+                        out.close_brace(false);
+                    } else {
+                        write!(out, "{}", escape_ident(body.export_name()));
+                    }
+                    out.write(",");
+                    condition.write_after(self.config, out);
+                }
+            }
+
+            if !inline_tag_field {
+                out.close_brace(false);
+                out.write(",");
+            }
+
+            if let Some(body) = &self.config.export.post_body(&e.path) {
+                out.new_line();
+                out.write_raw_block(body);
+            }
+
+            out.close_brace(true);
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_struct<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Struct) {
+        if s.is_transparent {
+            let typedef = Typedef {
+                path: s.path.clone(),
+                export_name: s.export_name.to_owned(),
+                generic_params: s.generic_params.clone(),
+                aliased: s.fields[0].ty.clone(),
+                cfg: s.cfg.clone(),
+                annotations: s.annotations.clone(),
+                documentation: s.documentation.clone(),
+            };
+            self.write_type_def(out, &typedef);
+            for constant in &s.associated_constants {
+                out.new_line();
+                constant.write(self.config, self, out, Some(s));
+            }
+            return;
+        }
+
+        let condition = s.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &s.documentation);
+
+        write!(
+            out,
+            "pub const {} = extern struct",
+            escape_ident(s.export_name())
+        );
+        if s.fields.is_empty() && self.config.export.pre_body(&s.path).is_none() {
+            out.write(" {};");
+        } else {
+            out.open_brace();
+
+            // Emit the pre_body section, if relevant
+            if let Some(body) = &self.config.export.pre_body(&s.path) {
+                out.write_raw_block(body);
+                out.new_line();
+            }
+
+            self.write_fields(out, &s.fields, s.alignment);
+
+            // Emit the post_body section, if relevant
+            if let Some(body) = &self.config.export.post_body(&s.path) {
+                out.new_line();
+                out.write_raw_block(body);
+            }
+            out.close_brace(true);
+        }
+
+        for constant in &s.associated_constants {
+            out.new_line();
+            constant.write(self.config, self, out, Some(s));
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_union<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &Union) {
+        let condition = u.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &u.documentation);
+
+        write!(
+            out,
+            "pub const {} = extern union",
+            escape_ident(&u.export_name)
+        );
+        out.open_brace();
+
+        // Emit the pre_body section, if relevant
+        if let Some(body) = &self.config.export.pre_body(&u.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        self.write_fields(out, &u.fields, u.alignment);
+
+        // Emit the post_body section, if relevant
+        if let Some(body) = &self.config.export.post_body(&u.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+
+        out.close_brace(true);
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_opaque_item<W: Write>(&mut self, out: &mut SourceWriter<W>, o: &OpaqueItem) {
+        let condition = o.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &o.documentation);
+
+        write!(
+            out,
+            "pub const {} = opaque {{}};",
+            escape_ident(o.export_name())
+        );
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_type_def<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Typedef) {
+        let condition = t.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &t.documentation);
+
+        write!(out, "pub const {} = ", escape_ident(t.export_name()));
+        self.write_type(out, &t.aliased);
+        out.write(";");
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_static<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Static) {
+        let condition = s.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &s.documentation);
+        write!(
+            out,
+            "pub extern {} {}: ",
+            if s.mutable { "var" } else { "const" },
+            escape_ident(&s.export_name)
+        );
+        self.write_type(out, &s.ty);
+        out.write(";");
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_function_with_layout<W: Write>(
+        &mut self,
+        config: &Config,
+        out: &mut SourceWriter<W>,
+        func: &Function,
+        layout: Layout,
+    ) {
+        let condition = func.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        self.write_documentation(out, &func.documentation);
+
+        write!(out, "pub extern fn {}(", escape_ident(func.path().name()));
+        match layout {
+            Layout::Vertical if !func.args.is_empty() => {
+                out.push_tab();
+                for arg in &func.args {
+                    out.new_line();
+                    self.write_function_arg(out, arg.name.as_deref(), &arg.ty);
+                    out.write(",");
+                }
+                out.pop_tab();
+                out.new_line();
+            }
+            _ => {
+                for (i, arg) in func.args.iter().enumerate() {
+                    if i != 0 {
+                        out.write(", ");
+                    }
+                    self.write_function_arg(out, arg.name.as_deref(), &arg.ty);
+                }
+            }
+        }
+        out.write(") ");
+        // `extern` functions use the C calling convention by default.
+        if func.abi.is_some() {
+            if let Some(convention) = calling_convention(func.abi.as_deref()) {
+                write!(out, "callconv({}) ", convention);
+            }
+        }
+        self.write_return_type(out, &func.ret, func.never_return);
+        out.write(";");
+
+        condition.write_after(config, out);
+    }
+
+    fn write_type<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Type) {
+        match t {
+            Type::Ptr {
+                ty,
+                is_const,
+                is_nullable,
+                ..
+            } => {
+                if *is_nullable {
+                    out.write("?");
+                }
+                out.write(if *is_const { "*const " } else { "*" });
+                self.write_pointee(out, ty);
+            }
+            Type::Path(generic) => {
+                write!(out, "{}", escape_ident(generic.export_name()));
+            }
+            Type::Primitive(primitive) => {
+                write!(out, "{}", primitive.to_repr_zig());
+            }
+            Type::Array(ty, len) => {
+                write!(out, "[{}]", len.as_str());
+                self.write_type(out, ty);
+            }
+            Type::FuncPtr {
+                ret,
+                args,
+                is_nullable,
+                never_return,
+                abi,
+                ..
+            } => {
+                if *is_nullable {
+                    out.write("?");
+                }
+                out.write("*const fn (");
+                for (i, (name, ty)) in args.iter().enumerate() {
+                    if i != 0 {
+                        out.write(", ");
+                    }
+                    self.write_function_arg(out, name.as_deref(), ty);
+                }
+                let convention = calling_convention(abi.as_deref()).unwrap_or(".C");
+                write!(out, ") callconv({}) ", convention);
+                self.write_return_type(out, ret, *never_return);
+            }
+        }
+    }
+
+    fn write_documentation<W: Write>(&mut self, out: &mut SourceWriter<W>, d: &Documentation) {
+        if d.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        let end = match self.config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        // Zig only has one kind of doc comment, so `documentation_style` is not relevant.
+        for line in &d.doc_comment[..end] {
+            write!(out, "///{}", line);
+            out.new_line();
+        }
+    }
+
+    fn write_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, l: &Literal) {
+        match l {
+            Literal::Expr(v) => self.write_expr(out, v),
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if to_known_assoc_constant(path, name).is_some() {
+                        let prim = PrimitiveType::maybe(path.name()).unwrap();
+                        let func = if name == "MAX" { "maxInt" } else { "minInt" };
+                        return write!(out, "std.math.{}({})", func, prim.to_repr_zig());
+                    }
+                    write!(out, "{}_", export_name)
+                }
+                write!(out, "{}", name)
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                write!(out, "(");
+                self.write_literal(out, base);
+                write!(out, ").{}", escape_ident(field));
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{}", op);
+                self.write_literal(out, value);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                let op = match *op {
+                    "&&" => "and",
+                    "||" => "or",
+                    op => op,
+                };
+                write!(out, "(");
+                self.write_literal(out, left);
+                write!(out, " {} ", op);
+                self.write_literal(out, right);
+                write!(out, ")");
+            }
+            Literal::Cast { ref ty, ref value } => {
+                out.write("@as(");
+                self.write_type(out, ty);
+                out.write(", ");
+                self.write_literal(out, value);
+                out.write(")");
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                write!(out, "{}{{ ", escape_ident(export_name));
+                let mut is_first_field = true;
+                let ordered_fields = out.bindings().struct_field_names(path);
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            write!(out, ", ");
+                        }
+                        is_first_field = false;
+                        write!(out, ".{} = ", escape_ident(ordered_key));
+                        self.write_literal(out, lit);
+                    }
+                }
+                write!(out, " }}");
+            }
+        }
+    }
+}
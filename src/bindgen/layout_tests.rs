@@ -0,0 +1,758 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Layout assertions for the exported types, see
+//! `Bindings::write_rust_layout_tests` and `Bindings::write_c_layout_tests`.
+//!
+//! The expected layouts are computed once from the IR with the C layout
+//! rules, and then checked on both sides of the FFI boundary. Only 64-bit
+//! targets are covered, and `long` is assumed to be 8 bytes, as on LP64
+//! targets. Types whose layout depends on `long` get separate expectations
+//! for Windows, which is LLP64. A type with `cfg`s on its fields is checked
+//! for each combination of them.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::slice;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::config::{Config, Language};
+use crate::bindgen::ir::{
+    Cfg, ConditionWrite, ConstExpr, Enum, Field, IntKind, ItemContainer, Path, PrimitiveType,
+    ReprAlign, ReprStyle, Struct, ToCondition, Type, Union, VariantBody,
+};
+use crate::bindgen::writer::SourceWriter;
+
+/// The size and alignment of a type, and the offsets of its fields if it's a
+/// struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Layout {
+    size: usize,
+    align: usize,
+    offsets: Vec<usize>,
+}
+
+impl Layout {
+    fn scalar(size: usize) -> Layout {
+        Layout {
+            size,
+            align: size,
+            offsets: Vec::new(),
+        }
+    }
+}
+
+fn round_up(value: usize, align: usize) -> usize {
+    (value + align - 1) / align * align
+}
+
+/// Computes layouts on a 64-bit target with the given size of `long`.
+struct LayoutCalculator<'a> {
+    /// The items which have a single definition, the others can't be laid out
+    /// without knowing which `cfg` applies.
+    items: &'a HashMap<&'a Path, &'a ItemContainer>,
+    long_size: usize,
+}
+
+impl<'a> LayoutCalculator<'a> {
+    fn primitive(&self, ty: &PrimitiveType) -> Option<Layout> {
+        let size = match *ty {
+            PrimitiveType::Void | PrimitiveType::VaList => return None,
+            PrimitiveType::Bool
+            | PrimitiveType::Char
+            | PrimitiveType::SChar
+            | PrimitiveType::UChar => 1,
+            PrimitiveType::Char32 | PrimitiveType::Float => 4,
+            PrimitiveType::Double | PrimitiveType::PtrDiffT => 8,
+            PrimitiveType::Integer { kind, .. } => match kind {
+                IntKind::B8 => 1,
+                IntKind::Short | IntKind::B16 => 2,
+                IntKind::Int | IntKind::B32 => 4,
+                IntKind::Long => self.long_size,
+                IntKind::LongLong | IntKind::SizeT | IntKind::Size | IntKind::B64 => 8,
+            },
+        };
+        Some(Layout::scalar(size))
+    }
+
+    fn ty(&self, ty: &Type) -> Option<Layout> {
+        match *ty {
+            Type::Ptr { .. } | Type::FuncPtr { .. } => Some(Layout::scalar(8)),
+            Type::Primitive(ref primitive) => self.primitive(primitive),
+            Type::Array(ref ty, ConstExpr::Value(ref len)) => {
+                let len: usize = len.parse().ok()?;
+                let element = self.ty(ty)?;
+                Some(Layout {
+                    size: element.size * len,
+                    align: element.align,
+                    offsets: Vec::new(),
+                })
+            }
+            Type::Array(_, ConstExpr::Name(_)) => None,
+            Type::Path(ref generic) => {
+                if !generic.generics().is_empty() {
+                    return None;
+                }
+                match **self.items.get(generic.path())? {
+                    ItemContainer::Struct(ref s) => self.structure(s),
+                    ItemContainer::Union(ref u) => self.union(u),
+                    ItemContainer::Enum(ref e) => self.enumeration(e),
+                    ItemContainer::Typedef(ref t) if t.generic_params.is_empty() => {
+                        self.ty(&t.aliased)
+                    }
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn structure(&self, s: &Struct) -> Option<Layout> {
+        if !s.generic_params.is_empty() || s.fields.iter().any(|f| f.cfg.is_some()) {
+            return None;
+        }
+        if s.is_transparent {
+            return self.ty(&s.fields.first()?.ty);
+        }
+        if s.fields
+            .iter()
+            .any(|f| f.annotations.atom("bitfield").is_some())
+        {
+            return None;
+        }
+        self.fields(s.fields.iter().map(|f| &f.ty), s.alignment)
+    }
+
+    /// Lays out fields one after the other, as C does for a struct.
+    fn fields<'t>(
+        &self,
+        types: impl IntoIterator<Item = &'t Type>,
+        alignment: Option<ReprAlign>,
+    ) -> Option<Layout> {
+        let fields = types
+            .into_iter()
+            .map(|ty| self.ty(ty))
+            .collect::<Option<Vec<_>>>()?;
+        Some(self.sequence(fields, alignment))
+    }
+
+    fn sequence(&self, fields: Vec<Layout>, alignment: Option<ReprAlign>) -> Layout {
+        let packed = alignment == Some(ReprAlign::Packed);
+        let mut size = 0;
+        let mut align = 1;
+        let mut offsets = Vec::with_capacity(fields.len());
+        for field in fields {
+            let field_align = if packed { 1 } else { field.align };
+            size = round_up(size, field_align);
+            offsets.push(size);
+            size += field.size;
+            align = align.max(field_align);
+        }
+        if let Some(ReprAlign::Align(n)) = alignment {
+            align = align.max(n as usize);
+        }
+        Layout {
+            size: round_up(size, align),
+            align,
+            offsets,
+        }
+    }
+
+    fn union(&self, u: &Union) -> Option<Layout> {
+        if !u.generic_params.is_empty() || u.fields.iter().any(|f| f.cfg.is_some()) {
+            return None;
+        }
+        let fields = u
+            .fields
+            .iter()
+            .map(|f| self.ty(&f.ty))
+            .collect::<Option<Vec<_>>>()?;
+        Some(self.overlap(fields, u.alignment))
+    }
+
+    /// Lays out fields on top of each other, as C does for a union.
+    fn overlap(&self, fields: Vec<Layout>, alignment: Option<ReprAlign>) -> Layout {
+        let packed = alignment == Some(ReprAlign::Packed);
+        let mut size = 0;
+        let mut align = 1;
+        for field in &fields {
+            size = size.max(field.size);
+            align = align.max(if packed { 1 } else { field.align });
+        }
+        if let Some(ReprAlign::Align(n)) = alignment {
+            align = align.max(n as usize);
+        }
+        Layout {
+            size: round_up(size, align),
+            align,
+            offsets: vec![0; fields.len()],
+        }
+    }
+
+    fn enumeration(&self, e: &Enum) -> Option<Layout> {
+        if !e.generic_params.is_empty() || e.variants.iter().any(|v| v.cfg.is_some()) {
+            return None;
+        }
+        let tag = match (e.repr.style, e.repr.ty) {
+            (ReprStyle::Transparent, _) | (ReprStyle::Rust, None) => return None,
+            (_, Some(ty)) => self.primitive(&ty.to_primitive())?,
+            // A C enum, which is `int` sized.
+            (ReprStyle::C, None) => Layout::scalar(4),
+        };
+        if e.tag.is_none() {
+            return Some(tag);
+        }
+
+        let inline_tag_field = Enum::inline_tag_field(&e.repr);
+        let mut bodies = Vec::with_capacity(e.variants.len());
+        for variant in &e.variants {
+            let fields: &[Field] = match variant.body {
+                VariantBody::Empty(..) if inline_tag_field => &[],
+                VariantBody::Empty(..) => continue,
+                VariantBody::Body { ref body, .. } => {
+                    if !body.generic_params.is_empty() {
+                        return None;
+                    }
+                    &body.fields[body.has_tag_field as usize..]
+                }
+            };
+            let mut layouts = Vec::with_capacity(fields.len() + 1);
+            if inline_tag_field {
+                layouts.push(tag.clone());
+            }
+            for field in fields {
+                layouts.push(self.ty(&field.ty)?);
+            }
+            bodies.push(self.sequence(layouts, None));
+        }
+
+        // With an inline tag the enum is a union of structs which all start
+        // with the tag, otherwise it's a tag followed by a union of structs.
+        let layout = if inline_tag_field {
+            self.overlap(bodies, e.repr.align)
+        } else {
+            let union = self.overlap(bodies, None);
+            self.sequence(vec![tag, union], e.repr.align)
+        };
+        Some(Layout {
+            offsets: Vec::new(),
+            ..layout
+        })
+    }
+}
+
+/// The most `cfg`s on the fields of a type the layout tests check each
+/// combination of.
+const MAX_FIELD_CFGS: usize = 4;
+
+/// What the layout tests check about a type on one data model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Expected {
+    /// The size and alignment, unless a `cfg` on a field makes them unknown.
+    size_and_align: Option<(usize, usize)>,
+    /// The offsets of the leading fields which have a known layout.
+    offsets: Vec<usize>,
+}
+
+/// The layout of a version of a type, for a combination of the `cfg`s of its
+/// fields.
+struct Variant {
+    /// The combination, if the fields have `cfg`s.
+    cfg: Option<Cfg>,
+    /// The Rust and C names of the fields whose offsets are checked.
+    fields: Vec<(String, String)>,
+    /// The layout on 64-bit targets where `long` is 8 bytes.
+    lp64: Expected,
+    /// The layout on 64-bit Windows, if it's not the same.
+    llp64: Option<Expected>,
+}
+
+impl Variant {
+    fn new(
+        cfg: Option<Cfg>,
+        mut fields: Vec<(String, String)>,
+        expected: impl Fn(usize) -> Option<Expected>,
+    ) -> Option<Variant> {
+        let lp64 = expected(8)?;
+        let llp64 = expected(4).filter(|llp64| *llp64 != lp64);
+        fields.truncate(lp64.offsets.len());
+        Some(Variant {
+            cfg,
+            fields,
+            lp64,
+            llp64,
+        })
+    }
+}
+
+/// The combinations of the `cfg`s of `fields`, each with the fields it keeps,
+/// unless there are none or too many of them.
+fn field_cfg_combinations(fields: &[Field]) -> Vec<(Cfg, Vec<bool>)> {
+    let mut cfgs: Vec<(String, &Cfg)> = Vec::new();
+    for cfg in fields.iter().filter_map(|f| f.cfg.as_ref()) {
+        let key = cfg.to_string();
+        if !cfgs.iter().any(|(other, _)| *other == key) {
+            cfgs.push((key, cfg));
+        }
+    }
+    if cfgs.is_empty() || cfgs.len() > MAX_FIELD_CFGS {
+        return Vec::new();
+    }
+
+    (0..1usize << cfgs.len())
+        .map(|combination| {
+            let holds = |i: usize| combination & (1 << i) != 0;
+            let conditions: Vec<_> = cfgs
+                .iter()
+                .enumerate()
+                .map(|(i, &(_, cfg))| match holds(i) {
+                    true => cfg.clone(),
+                    false => Cfg::Not(Box::new(cfg.clone())),
+                })
+                .collect();
+            let kept = fields
+                .iter()
+                .map(|f| match f.cfg {
+                    Some(ref cfg) => {
+                        let key = cfg.to_string();
+                        holds(cfgs.iter().position(|(other, _)| *other == key).unwrap())
+                    }
+                    None => true,
+                })
+                .collect();
+            let cfg = match conditions.len() {
+                1 => conditions.into_iter().next().unwrap(),
+                _ => Cfg::All(conditions),
+            };
+            (cfg, kept)
+        })
+        .collect()
+}
+
+/// The layout of an exported type, as it's checked by the layout tests.
+struct LayoutTest {
+    /// The name of the type in Rust.
+    rust_name: String,
+    /// The name of the type in C, including a `struct` or `union` keyword if
+    /// the type has no typedef.
+    c_name: String,
+    cfg: Option<Cfg>,
+    /// The versions of the type, for each combination of the `cfg`s of its
+    /// fields, or the type itself if they have none.
+    variants: Vec<Variant>,
+    /// The offsets of the fields before the first one with a `cfg`, checked
+    /// when the versions can't be.
+    fixed: Option<Variant>,
+}
+
+impl LayoutTest {
+    fn new(
+        bindings: &Bindings,
+        items: &HashMap<&Path, &ItemContainer>,
+        item: &ItemContainer,
+    ) -> Option<LayoutTest> {
+        // Monomorphs and other generated items have no Rust name to check.
+        let mut fields = Vec::new();
+        let keyword = match *item {
+            ItemContainer::Struct(ref s) => {
+                if s.is_transparent || s.is_enum_variant_body || s.fields.is_empty() {
+                    return None;
+                }
+                bindings.parsed.structs.for_items(&s.path, |parsed| {
+                    if parsed.fields.len() == s.fields.len() {
+                        fields = parsed
+                            .fields
+                            .iter()
+                            .zip(&s.fields)
+                            .map(|(rust, c)| (rust_field_name(&rust.name), c.name.clone()))
+                            .collect();
+                    }
+                });
+                if fields.is_empty() {
+                    return None;
+                }
+                "struct"
+            }
+            ItemContainer::Union(ref u) => {
+                bindings.parsed.unions.get_items(&u.path)?;
+                "union"
+            }
+            ItemContainer::Enum(ref e) => {
+                if e.tag.is_none() || bindings.parsed.enums.get_items(&e.path).is_none() {
+                    return None;
+                }
+                if Enum::inline_tag_field(&e.repr) {
+                    "union"
+                } else {
+                    "struct"
+                }
+            }
+            _ => return None,
+        };
+
+        let layout = |item: &ItemContainer, long_size| {
+            let calculator = LayoutCalculator { items, long_size };
+            let layout = match *item {
+                ItemContainer::Struct(ref s) => calculator.structure(s),
+                ItemContainer::Union(ref u) => calculator.union(u),
+                ItemContainer::Enum(ref e) => calculator.enumeration(e),
+                _ => None,
+            }?;
+            Some(Expected {
+                size_and_align: Some((layout.size, layout.align)),
+                offsets: layout.offsets,
+            })
+        };
+
+        let item_fields = match *item {
+            ItemContainer::Struct(ref s) => &s.fields[..],
+            ItemContainer::Union(ref u) => &u.fields[..],
+            _ => &[],
+        };
+        let mut variants = Vec::new();
+        for (cfg, kept) in field_cfg_combinations(item_fields) {
+            if !kept.contains(&true) {
+                continue;
+            }
+            let keep = |fields: &[Field]| {
+                fields
+                    .iter()
+                    .zip(&kept)
+                    .filter(|&(_, &kept)| kept)
+                    .map(|(f, _)| Field {
+                        cfg: None,
+                        ..f.clone()
+                    })
+                    .collect()
+            };
+            let version = match *item {
+                ItemContainer::Struct(ref s) => ItemContainer::Struct(Struct {
+                    fields: keep(&s.fields),
+                    ..s.clone()
+                }),
+                ItemContainer::Union(ref u) => ItemContainer::Union(Union {
+                    fields: keep(&u.fields),
+                    ..u.clone()
+                }),
+                _ => unreachable!(),
+            };
+            let names = fields
+                .iter()
+                .zip(&kept)
+                .filter(|&(_, &kept)| kept)
+                .map(|(names, _)| names.clone())
+                .collect();
+            // The other versions are still checked if one can't be laid out.
+            variants.extend(Variant::new(Some(cfg), names, |long_size| {
+                layout(&version, long_size)
+            }));
+        }
+
+        let mut fixed = None;
+        if item_fields.iter().any(|f| f.cfg.is_some()) {
+            // Fields behind a `cfg` make the size unknown, but the ones
+            // before them still have a fixed offset.
+            if let ItemContainer::Struct(ref s) = *item {
+                fixed = Variant::new(None, fields.clone(), |long_size| {
+                    let calculator = LayoutCalculator { items, long_size };
+                    let fixed = s.fields.iter().take_while(|f| f.cfg.is_none());
+                    let layout = calculator.fields(fixed.map(|f| &f.ty), s.alignment)?;
+                    (!layout.offsets.is_empty()).then_some(Expected {
+                        size_and_align: None,
+                        offsets: layout.offsets,
+                    })
+                });
+            }
+        } else {
+            variants.extend(Variant::new(None, fields, |long_size| {
+                layout(item, long_size)
+            }));
+        }
+        if variants.is_empty() && fixed.is_none() {
+            return None;
+        }
+
+        let config = &bindings.config;
+        let export_name = item.deref().export_name();
+        let c_name = if config.language == Language::Cxx {
+            let mut name = String::new();
+            for namespace in config
+                .namespace
+                .iter()
+                .chain(config.namespaces.iter().flatten())
+            {
+                name.push_str(namespace);
+                name.push_str("::");
+            }
+            name.push_str(export_name);
+            name
+        } else if config.style.generate_typedef() {
+            export_name.to_owned()
+        } else {
+            format!("{} {}", keyword, export_name)
+        };
+
+        Some(LayoutTest {
+            rust_name: item.deref().path().name().to_owned(),
+            c_name,
+            cfg: item.deref().cfg().cloned(),
+            variants,
+            fixed,
+        })
+    }
+
+    /// The versions checked in Rust, where any `cfg` can be written.
+    fn rust_variants(&self) -> &[Variant] {
+        if self.variants.is_empty() {
+            self.fixed.as_ref().map_or(&[], slice::from_ref)
+        } else {
+            &self.variants
+        }
+    }
+
+    /// The versions checked in C, where the `cfg`s without a condition can't
+    /// be told apart.
+    fn c_variants(&self, config: &Config) -> &[Variant] {
+        let written = |v: &Variant| v.cfg.as_ref().map_or(true, |cfg| cfg.has_condition(config));
+        if !self.variants.is_empty() && self.variants.iter().all(written) {
+            &self.variants
+        } else {
+            self.fixed.as_ref().map_or(&[], slice::from_ref)
+        }
+    }
+}
+
+/// Field names which are keywords need to be raw identifiers in Rust.
+fn rust_field_name(name: &str) -> String {
+    if syn::parse_str::<syn::Ident>(name).is_err() && name.parse::<usize>().is_err() {
+        format!("r#{}", name)
+    } else {
+        name.to_owned()
+    }
+}
+
+fn layout_tests(bindings: &Bindings) -> Vec<LayoutTest> {
+    let mut counts = HashMap::<&Path, usize>::new();
+    for item in &bindings.items {
+        *counts.entry(item.deref().path()).or_default() += 1;
+    }
+    let items: HashMap<&Path, &ItemContainer> = bindings
+        .items
+        .iter()
+        .filter(|item| counts[item.deref().path()] == 1)
+        .map(|item| (item.deref().path(), item))
+        .collect();
+
+    bindings
+        .items
+        .iter()
+        .filter(|item| counts[item.deref().path()] == 1)
+        .filter_map(|item| LayoutTest::new(bindings, &items, item))
+        .collect()
+}
+
+fn write_autogen_warning<F: Write>(out: &mut SourceWriter<F>, config: &Config) {
+    if let Some(ref warning) = config.autogen_warning {
+        write!(out, "{}", warning);
+        out.new_line();
+        out.new_line();
+    }
+}
+
+/// Writes a Rust module with a `#[test]` for every checked type. It is meant
+/// to be `include!`d where the exported types are in scope.
+pub(crate) fn write_rust<F: Write>(bindings: &Bindings, file: F) {
+    let mut out = SourceWriter::new(file, bindings);
+    write_autogen_warning(&mut out, &bindings.config);
+
+    let mut first = true;
+    for test in &layout_tests(bindings) {
+        for variant in test.rust_variants() {
+            if !first {
+                out.new_line();
+            }
+            first = false;
+            write_rust_test(&mut out, test, variant);
+        }
+    }
+}
+
+/// Writes the test of a version of a type. The versions have exclusive
+/// `cfg`s, so at most one of them is compiled.
+fn write_rust_test<F: Write>(out: &mut SourceWriter<F>, test: &LayoutTest, variant: &Variant) {
+    out.write("#[test]");
+    out.new_line();
+    out.write("#[allow(non_snake_case)]");
+    out.new_line();
+    out.write("#[cfg(");
+    let cfgs: Vec<_> = test.cfg.iter().chain(&variant.cfg).collect();
+    if !cfgs.is_empty() {
+        out.write("all(");
+    }
+    out.write("target_pointer_width = \"64\"");
+    for cfg in &cfgs {
+        write!(out, ", {}", cfg);
+    }
+    if !cfgs.is_empty() {
+        out.write(")");
+    }
+    out.write(")]");
+    out.new_line();
+    write!(out, "fn layout_{}() {{", test.rust_name);
+    out.push_tab();
+    out.new_line();
+    match variant.llp64 {
+        Some(ref llp64) => {
+            out.write("if cfg!(windows) {");
+            out.push_tab();
+            out.new_line();
+            write_rust_assertions(out, test, variant, llp64);
+            out.pop_tab();
+            out.new_line();
+            out.write("} else {");
+            out.push_tab();
+            out.new_line();
+            write_rust_assertions(out, test, variant, &variant.lp64);
+            out.pop_tab();
+            out.new_line();
+            out.write("}");
+        }
+        None => write_rust_assertions(out, test, variant, &variant.lp64),
+    }
+    out.pop_tab();
+    out.new_line();
+    out.write("}");
+    out.new_line();
+}
+
+fn write_rust_assertions<F: Write>(
+    out: &mut SourceWriter<F>,
+    test: &LayoutTest,
+    variant: &Variant,
+    expected: &Expected,
+) {
+    let name = &test.rust_name;
+    if let Some((size, align)) = expected.size_and_align {
+        write!(
+            out,
+            "assert_eq!(::std::mem::size_of::<{0}>(), {1}, \"size of {0}\");",
+            name, size
+        );
+        out.new_line();
+        write!(
+            out,
+            "assert_eq!(::std::mem::align_of::<{0}>(), {1}, \"alignment of {0}\");",
+            name, align
+        );
+    }
+    for (i, ((field, _), offset)) in variant.fields.iter().zip(&expected.offsets).enumerate() {
+        if i != 0 || expected.size_and_align.is_some() {
+            out.new_line();
+        }
+        write!(
+            out,
+            "assert_eq!(::std::mem::offset_of!({0}, {1}), {2}, \"offset of {0}::{1}\");",
+            name, field, offset
+        );
+    }
+}
+
+/// Writes a C or C++ file with static assertions for every checked type,
+/// which includes the generated `header`.
+pub(crate) fn write_c<F: Write>(bindings: &Bindings, file: F, header: &str) {
+    let config = &bindings.config;
+    let mut out = SourceWriter::new(file, bindings);
+    write_autogen_warning(&mut out, config);
+
+    if config.language == Language::Cxx {
+        out.write("#include <cstddef>");
+        out.new_line();
+        out.write("#include <cstdint>");
+    } else {
+        out.write("#include <stddef.h>");
+        out.new_line();
+        out.write("#include <stdint.h>");
+    }
+    out.new_line();
+    write!(out, "#include \"{}\"", header);
+    out.new_line();
+    out.new_line();
+    out.write("#if UINTPTR_MAX == UINT64_MAX");
+    out.new_line();
+
+    for test in layout_tests(bindings) {
+        let variants = test.c_variants(config);
+        if variants.is_empty() {
+            continue;
+        }
+        out.new_line();
+        let condition = test.cfg.to_condition(config);
+        condition.write_before(config, &mut out);
+        for (i, variant) in variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            let condition = variant.cfg.to_condition(config);
+            condition.write_before(config, &mut out);
+            match variant.llp64 {
+                Some(ref llp64) => {
+                    out.write("#if defined(_WIN32)");
+                    out.new_line();
+                    write_c_assertions(&mut out, config, &test, variant, llp64);
+                    out.new_line();
+                    out.write("#else");
+                    out.new_line();
+                    write_c_assertions(&mut out, config, &test, variant, &variant.lp64);
+                    out.new_line();
+                    out.write("#endif");
+                }
+                None => write_c_assertions(&mut out, config, &test, variant, &variant.lp64),
+            }
+            condition.write_after(config, &mut out);
+        }
+        condition.write_after(config, &mut out);
+        out.new_line();
+    }
+
+    out.new_line();
+    out.write("#endif");
+    out.new_line();
+}
+
+fn write_c_assertions<F: Write>(
+    out: &mut SourceWriter<F>,
+    config: &Config,
+    test: &LayoutTest,
+    variant: &Variant,
+    expected: &Expected,
+) {
+    let (static_assert, align_of) = if config.language == Language::Cxx {
+        ("static_assert", "alignof")
+    } else {
+        ("_Static_assert", "_Alignof")
+    };
+    let name = &test.c_name;
+    if let Some((size, align)) = expected.size_and_align {
+        write!(
+            out,
+            "{}(sizeof({}) == {}, \"size of {}\");",
+            static_assert, name, size, name
+        );
+        out.new_line();
+        write!(
+            out,
+            "{}({}({}) == {}, \"alignment of {}\");",
+            static_assert, align_of, name, align, name
+        );
+    }
+    for (i, ((_, field), offset)) in variant.fields.iter().zip(&expected.offsets).enumerate() {
+        if i != 0 || expected.size_and_align.is_some() {
+            out.new_line();
+        }
+        write!(
+            out,
+            "{}(offsetof({}, {}) == {}, \"offset of {}::{}\");",
+            static_assert, name, field, offset, name, field
+        );
+    }
+}
@@ -9,9 +9,13 @@ use crate::bindgen::bindings::Bindings;
 use crate::bindgen::config::{Config, Language, SortKey};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::diagnostics::{self, Diagnostic, DiagnosticCode};
 use crate::bindgen::error::Error;
+use crate::bindgen::explain::{Provenance, Root};
 use crate::bindgen::ir::{Constant, Enum, Function, Item, ItemContainer, ItemMap};
 use crate::bindgen::ir::{OpaqueItem, Path, Static, Struct, Typedef, Union};
+use crate::bindgen::json::ParsedLibrary;
+use crate::bindgen::lint;
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::ItemType;
 
@@ -28,11 +32,12 @@ pub struct Library {
     functions: Vec<Function>,
     source_files: Vec<PathBuf>,
     package_version: String,
+    provenance: Provenance,
 }
 
 impl Library {
     #[allow(clippy::too_many_arguments)]
-    pub fn new(
+    pub(crate) fn new(
         config: Config,
         constants: ItemMap<Constant>,
         globals: ItemMap<Static>,
@@ -44,6 +49,7 @@ impl Library {
         functions: Vec<Function>,
         source_files: Vec<PathBuf>,
         package_version: String,
+        provenance: Provenance,
     ) -> Library {
         Library {
             config,
@@ -57,10 +63,22 @@ impl Library {
             functions,
             source_files,
             package_version,
+            provenance,
         }
     }
 
     pub fn generate(mut self) -> Result<Bindings, Error> {
+        let parsed = ParsedLibrary {
+            constants: self.constants.clone(),
+            globals: self.globals.clone(),
+            enums: self.enums.clone(),
+            structs: self.structs.clone(),
+            unions: self.unions.clone(),
+            opaque_items: self.opaque_items.clone(),
+            typedefs: self.typedefs.clone(),
+            functions: self.functions.clone(),
+        };
+
         self.transfer_annotations();
         self.simplify_standard_types();
 
@@ -82,21 +100,34 @@ impl Library {
         let mut dependencies = Dependencies::new();
 
         for function in &self.functions {
-            function.add_dependencies(&self, &mut dependencies);
+            let root = Root::Function(function.path.name().to_owned());
+            dependencies.with_root(root, |dependencies| {
+                function.add_dependencies(&self, dependencies);
+            });
         }
         self.globals.for_all_items(|global| {
-            global.add_dependencies(&self, &mut dependencies);
+            let root = Root::Global(global.path.name().to_owned());
+            dependencies.with_root(root, |dependencies| {
+                global.add_dependencies(&self, dependencies);
+            });
         });
         self.constants.for_all_items(|constant| {
-            constant.add_dependencies(&self, &mut dependencies);
+            let root = Root::Constant(constant.path.name().to_owned());
+            dependencies.with_root(root, |dependencies| {
+                constant.add_dependencies(&self, dependencies);
+            });
         });
         for name in &self.config.export.include {
             let path = Path::new(name.clone());
             if let Some(items) = self.get_items(&path) {
-                if dependencies.items.insert(path) {
-                    for item in &items {
-                        item.deref().add_dependencies(&self, &mut dependencies);
-                    }
+                if dependencies.items.insert(path.clone()) {
+                    dependencies.with_root(Root::Include(name.clone()), |dependencies| {
+                        dependencies.with_item(&path, |dependencies| {
+                            for item in &items {
+                                item.deref().add_dependencies(&self, dependencies);
+                            }
+                        });
+                    });
                     for item in items {
                         dependencies.order.push(item);
                     }
@@ -105,6 +136,16 @@ impl Library {
         }
 
         dependencies.sort();
+        if self.config.diagnostics.lints {
+            lint::lint(
+                &self,
+                &self.functions,
+                &self.globals,
+                &dependencies.order,
+                &self.provenance,
+            );
+        }
+        self.provenance.requested_by = dependencies.requested_by;
 
         let items = dependencies.order;
         let constants = if self.config.export.should_generate(ItemType::Constants) {
@@ -134,7 +175,7 @@ impl Library {
             vec![]
         };
 
-        Ok(Bindings::new(
+        let mut bindings = Bindings::new(
             self.config,
             self.structs,
             self.typedefs,
@@ -143,9 +184,12 @@ impl Library {
             items,
             functions,
             self.source_files,
+            parsed,
             false,
             self.package_version,
-        ))
+        );
+        bindings.provenance = self.provenance;
+        Ok(bindings)
     }
 
     pub fn get_items(&self, p: &Path) -> Option<Vec<ItemContainer>> {
@@ -209,10 +253,16 @@ impl Library {
                     *x.annotations_mut() = annotations.clone();
                     transferred = true;
                 } else {
-                    warn!(
-                        "Can't transfer annotations from typedef to alias ({}) \
-                         that already has annotations.",
-                        alias_path
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::AnnotationConflict,
+                            format!(
+                                "can't transfer annotations from typedef to alias `{}` \
+                                 that already has annotations",
+                                alias_path
+                            ),
+                        )
+                        .with_item(&alias_path),
                     );
                 }
             });
@@ -224,10 +274,16 @@ impl Library {
                     *x.annotations_mut() = annotations.clone();
                     transferred = true;
                 } else {
-                    warn!(
-                        "Can't transfer annotations from typedef to alias ({}) \
-                         that already has annotations.",
-                        alias_path
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::AnnotationConflict,
+                            format!(
+                                "can't transfer annotations from typedef to alias `{}` \
+                                 that already has annotations",
+                                alias_path
+                            ),
+                        )
+                        .with_item(&alias_path),
                     );
                 }
             });
@@ -239,10 +295,16 @@ impl Library {
                     *x.annotations_mut() = annotations.clone();
                     transferred = true;
                 } else {
-                    warn!(
-                        "Can't transfer annotations from typedef to alias ({}) \
-                         that already has annotations.",
-                        alias_path
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::AnnotationConflict,
+                            format!(
+                                "can't transfer annotations from typedef to alias `{}` \
+                                 that already has annotations",
+                                alias_path
+                            ),
+                        )
+                        .with_item(&alias_path),
                     );
                 }
             });
@@ -254,10 +316,16 @@ impl Library {
                     *x.annotations_mut() = annotations.clone();
                     transferred = true;
                 } else {
-                    warn!(
-                        "Can't transfer annotations from typedef to alias ({}) \
-                         that already has annotations.",
-                        alias_path
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::AnnotationConflict,
+                            format!(
+                                "can't transfer annotations from typedef to alias `{}` \
+                                 that already has annotations",
+                                alias_path
+                            ),
+                        )
+                        .with_item(&alias_path),
                     );
                 }
             });
@@ -269,10 +337,16 @@ impl Library {
                     *x.annotations_mut() = annotations.clone();
                     transferred = true;
                 } else {
-                    warn!(
-                        "Can't transfer annotations from typedef to alias ({}) \
-                         that already has annotations.",
-                        alias_path
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::AnnotationConflict,
+                            format!(
+                                "can't transfer annotations from typedef to alias `{}` \
+                                 that already has annotations",
+                                alias_path
+                            ),
+                        )
+                        .with_item(&alias_path),
                     );
                 }
             });
@@ -410,6 +484,14 @@ impl Library {
             x.add_monomorphs(self, &mut monomorphs);
         }
 
+        for (generic, instance) in monomorphs.instances() {
+            self.provenance
+                .instances
+                .entry(generic.clone())
+                .or_default()
+                .push(instance.clone());
+        }
+
         // Insert the monomorphs into self
         for monomorph in monomorphs.drain_structs() {
             self.structs.try_insert(monomorph);
@@ -0,0 +1,351 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Lints for the FFI hazards of the items the bindings export.
+//!
+//! The lints only run when `lints` is set in the `[diagnostics]` section. Each
+//! lint is then reported as a diagnostic, unless it is listed in the `allow`
+//! list of the `[diagnostics]` section, or allowed with a
+//! `cbindgen:allow=<lint>` annotation on the item it is reported for, on the
+//! field, or on the type it is about.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::bindgen::diagnostics::{self, Diagnostic, DiagnosticCode};
+use crate::bindgen::explain::Provenance;
+use crate::bindgen::ir::{
+    AnnotationSet, ConstExpr, Field, Function, GenericArgument, GenericPath, ItemContainer,
+    ItemMap, OpaqueItem, PrimitiveType, Static, Struct, Type,
+};
+use crate::bindgen::library::Library;
+
+/// Where a type is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Argument,
+    Return,
+    Field,
+    Static,
+    Typedef,
+}
+
+/// The item a lint is reported for.
+struct Site<'a> {
+    /// The path of the item, or of the field, the lint is reported for.
+    item: String,
+    /// The annotations which may allow the lint, the innermost first.
+    annotations: Vec<&'a AnnotationSet>,
+}
+
+struct Linter<'a> {
+    library: &'a Library,
+    /// The names of the generic types, by the names of their instances.
+    instances: HashMap<&'a str, &'a str>,
+    opaque_enums: &'a HashSet<String>,
+    allowed: &'a [String],
+}
+
+/// Whether `annotations` allow the lint `code` with `cbindgen:allow`.
+fn allows(annotations: &AnnotationSet, code: DiagnosticCode) -> bool {
+    match annotations.list("allow") {
+        Some(lints) => lints.iter().any(|lint| lint == code.name()),
+        None => annotations.atom("allow") == Some(Some(code.name().to_owned())),
+    }
+}
+
+/// Whether `ty` is an array of no elements.
+fn is_empty_array(ty: &Type) -> bool {
+    matches!(*ty, Type::Array(_, ConstExpr::Value(ref len)) if len == "0")
+}
+
+impl<'a> Linter<'a> {
+    fn report(
+        &self,
+        site: &Site,
+        about: Option<&AnnotationSet>,
+        code: DiagnosticCode,
+        message: String,
+    ) {
+        if self.allowed.iter().any(|lint| lint == code.name())
+            || site
+                .annotations
+                .iter()
+                .copied()
+                .chain(about)
+                .any(|annotations| allows(annotations, code))
+        {
+            return;
+        }
+        diagnostics::report(Diagnostic::warning(code, message).with_item(&site.item));
+    }
+
+    /// Returns the item `path` names, if any.
+    fn resolve(&self, path: &GenericPath) -> Option<ItemContainer> {
+        self.library.get_items(path.path())?.into_iter().next()
+    }
+
+    fn check_type(&self, ty: &Type, position: Position, by_value: bool, site: &Site) {
+        match *ty {
+            // A `bool` behind a pointer is only read by Rust when it is dereferenced,
+            // which the code that does it can check.
+            Type::Primitive(PrimitiveType::Bool) if by_value => self.report(
+                site,
+                None,
+                DiagnosticCode::BoolOrChar,
+                format!(
+                    "`{}` uses `bool`, which C may set to a value other than 0 or 1, which \
+                     is undefined behavior in Rust; use an integer, like `u8`, and convert it",
+                    site.item
+                ),
+            ),
+            Type::Primitive(PrimitiveType::Char32) => self.report(
+                site,
+                None,
+                DiagnosticCode::BoolOrChar,
+                format!(
+                    "`{}` uses `char`, which C may set to a value which isn't a Unicode \
+                     scalar value, which is undefined behavior in Rust; use `u32`, and \
+                     convert it with `char::from_u32`",
+                    site.item
+                ),
+            ),
+            Type::Primitive(_) => {}
+            Type::Ptr {
+                ty: ref pointee, ..
+            } => {
+                self.check_type(pointee, position, false, site);
+            }
+            Type::Array(ref element, _) => self.check_type(element, position, by_value, site),
+            Type::FuncPtr {
+                ref ret,
+                ref args,
+                rust_abi,
+                ..
+            } => {
+                if rust_abi {
+                    self.report(
+                        site,
+                        None,
+                        DiagnosticCode::RustAbiFnPointer,
+                        format!(
+                            "`{}` uses a function pointer without `extern \"C\"`, which has \
+                             the unstable Rust calling convention; add `extern \"C\"` to it",
+                            site.item
+                        ),
+                    );
+                }
+                self.check_type(ret, position, true, site);
+                for (_, arg) in args {
+                    self.check_type(arg, position, true, site);
+                }
+            }
+            Type::Path(ref path) => {
+                if let Some(ItemContainer::OpaqueItem(ref opaque)) = self.resolve(path) {
+                    self.check_opaque(opaque, path, position, by_value, site);
+                }
+                for generic in path.generics() {
+                    if let GenericArgument::Type(ref generic) = *generic {
+                        self.check_type(generic, position, false, site);
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_opaque(
+        &self,
+        opaque: &OpaqueItem,
+        path: &GenericPath,
+        position: Position,
+        by_value: bool,
+        site: &Site,
+    ) {
+        let name = opaque.path.name();
+        let generic = if path.generics().is_empty() {
+            self.instances.get(name).copied()
+        } else {
+            Some(name)
+        };
+        if let Some(generic) = generic {
+            self.report(
+                site,
+                Some(&opaque.annotations),
+                DiagnosticCode::OpaqueGeneric,
+                format!(
+                    "`{}` uses `{}`, an instance of `{}`, which isn't `#[repr(C)]`, so it is \
+                     written as an opaque type C can't look into; mark `{}` `#[repr(C)]`, or \
+                     use a pointer to a non-generic type",
+                    site.item, opaque.export_name, generic, generic
+                ),
+            );
+        } else if by_value
+            && self.opaque_enums.contains(name)
+            && matches!(position, Position::Argument | Position::Return)
+        {
+            self.report(
+                site,
+                Some(&opaque.annotations),
+                DiagnosticCode::ImplicitEnumRepr,
+                format!(
+                    "`{}` uses the enum `{}` by value, but it has no `repr`, so it is \
+                     written as an opaque type; mark it `#[repr(u32)]`, or another integer \
+                     type",
+                    site.item, name
+                ),
+            );
+        } else if by_value {
+            self.report(
+                site,
+                Some(&opaque.annotations),
+                DiagnosticCode::OpaqueByValue,
+                format!(
+                    "`{}` uses `{}` by value, but it isn't `#[repr(C)]`, so it is written \
+                     as an incomplete type C can't hold; mark it `#[repr(C)]`, or use a \
+                     pointer",
+                    site.item, name
+                ),
+            );
+        }
+    }
+
+    /// Checks the type a function returns, which C gets ownership of.
+    fn check_return(&self, ty: &Type, site: &Site) {
+        let pointee = match *ty {
+            Type::Ptr {
+                ref ty,
+                is_nullable: false,
+                ..
+            } => &**ty,
+            Type::Path(ref path) if path.name() == "Box" => match path.generics().first() {
+                Some(GenericArgument::Type(ref ty)) => ty,
+                _ => return,
+            },
+            _ => return,
+        };
+        let Type::Path(ref path) = *pointee else {
+            return;
+        };
+        if let Some(ItemContainer::OpaqueItem(ref opaque)) = self.resolve(path) {
+            self.report(
+                site,
+                Some(&opaque.annotations),
+                DiagnosticCode::OpaquePointerReturn,
+                format!(
+                    "`{}` returns a `Box` or a reference to the opaque type `{}`, which C \
+                     can't free, or tell apart from a pointer it owns; return a raw pointer, \
+                     and export a function which frees it",
+                    site.item,
+                    opaque.path.name()
+                ),
+            );
+        }
+    }
+
+    fn check_function(&self, function: &Function) {
+        let site = Site {
+            item: function.path.name().to_owned(),
+            annotations: vec![&function.annotations],
+        };
+        for arg in &function.args {
+            self.check_type(&arg.ty, Position::Argument, true, &site);
+        }
+        self.check_return(&function.ret, &site);
+        self.check_type(&function.ret, Position::Return, true, &site);
+    }
+
+    fn check_static(&self, global: &Static) {
+        let site = Site {
+            item: global.path.name().to_owned(),
+            annotations: vec![&global.annotations],
+        };
+        self.check_type(&global.ty, Position::Static, true, &site);
+    }
+
+    fn check_fields(&self, item: &str, annotations: &AnnotationSet, fields: &[Field]) {
+        for (i, field) in fields.iter().enumerate() {
+            let site = Site {
+                item: format!("{}::{}", item, field.name),
+                annotations: vec![&field.annotations, annotations],
+            };
+            // An array of no elements at the end of a struct is the idiom for a
+            // flexible array member.
+            let flexible_array = i + 1 == fields.len();
+            let zero_sized = (is_empty_array(&field.ty) && !flexible_array)
+                || match field.ty {
+                    Type::Path(ref path) => matches!(
+                        self.resolve(path),
+                        Some(ItemContainer::Struct(Struct { ref fields, .. })) if fields.is_empty()
+                    ),
+                    _ => false,
+                };
+            if zero_sized {
+                self.report(
+                    &site,
+                    None,
+                    DiagnosticCode::ZeroSizedField,
+                    format!(
+                        "`{}` has a zero-sized type, which C doesn't allow, or gives a size \
+                         other than Rust's; remove the field",
+                        site.item
+                    ),
+                );
+            }
+            self.check_type(&field.ty, Position::Field, true, &site);
+        }
+    }
+
+    fn check_item(&self, item: &ItemContainer) {
+        match *item {
+            ItemContainer::Struct(ref s) => {
+                self.check_fields(s.path.name(), &s.annotations, &s.fields)
+            }
+            ItemContainer::Union(ref u) => {
+                self.check_fields(u.path.name(), &u.annotations, &u.fields)
+            }
+            ItemContainer::Typedef(ref t) => {
+                let site = Site {
+                    item: t.path.name().to_owned(),
+                    annotations: vec![&t.annotations],
+                };
+                self.check_type(&t.aliased, Position::Typedef, false, &site);
+            }
+            ItemContainer::Constant(..)
+            | ItemContainer::Static(..)
+            | ItemContainer::OpaqueItem(..)
+            | ItemContainer::Enum(..) => {}
+        }
+    }
+}
+
+/// Reports the FFI hazards of `functions`, `globals` and `items`, the items
+/// the bindings of `library` export.
+pub(crate) fn lint(
+    library: &Library,
+    functions: &[Function],
+    globals: &ItemMap<Static>,
+    items: &[ItemContainer],
+    provenance: &Provenance,
+) {
+    let linter = Linter {
+        library,
+        instances: provenance
+            .instances
+            .iter()
+            .flat_map(|(generic, instances)| {
+                instances
+                    .iter()
+                    .map(move |instance| (instance.name(), generic.name()))
+            })
+            .collect(),
+        opaque_enums: &provenance.opaque_enums,
+        allowed: &library.get_config().diagnostics.allow,
+    };
+    for function in functions {
+        linter.check_function(function);
+    }
+    globals.for_all_items(|global| linter.check_static(global));
+    for item in items {
+        linter.check_item(item);
+    }
+}
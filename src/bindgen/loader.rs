@@ -0,0 +1,186 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A C and C++ shim loading the exported functions at runtime with `dlopen`,
+//! see `Bindings::write_loader`.
+
+use std::io::Write;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::cdecl;
+use crate::bindgen::config::Language;
+use crate::bindgen::ir::{ConditionWrite, Function, ToCondition, Type};
+use crate::bindgen::language_backend::{CLikeLanguageBackend, LanguageBackend};
+use crate::bindgen::writer::SourceWriter;
+
+/// The prefix of the generated names, from the config or else from the file
+/// name of `header`.
+fn prefix(bindings: &Bindings, header: &str) -> String {
+    if let Some(ref prefix) = bindings.config.loader.prefix {
+        return prefix.clone();
+    }
+    let file_name = header.rsplit(['/', '\\']).next().unwrap_or(header);
+    let stem = file_name.split('.').next().unwrap_or(file_name);
+    stem.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// The type of the struct field holding a pointer to `function`.
+fn pointer_type(function: &Function) -> Type {
+    Type::FuncPtr {
+        ret: Box::new(function.ret.clone()),
+        args: function
+            .args
+            .iter()
+            .map(|arg| (arg.name.clone(), arg.ty.clone()))
+            .collect(),
+        is_nullable: true,
+        never_return: false,
+        abi: function.abi.clone(),
+        rust_abi: false,
+    }
+}
+
+pub(crate) fn write<F: Write>(bindings: &Bindings, file: F, header: &str) {
+    let config = &bindings.config;
+    let mut out = SourceWriter::new(file, bindings);
+    let mut backend = CLikeLanguageBackend::new(config);
+
+    let prefix = prefix(bindings, header);
+    let api = if config.language == Language::C && !config.style.generate_typedef() {
+        format!("struct {}_api", prefix)
+    } else {
+        format!("{}_api", prefix)
+    };
+    // Only the functions the crate defines can be loaded from it.
+    let functions: Vec<_> = bindings
+        .functions
+        .iter()
+        .filter(|f| !f.extern_decl)
+        .collect();
+
+    if let Some(ref warning) = config.autogen_warning {
+        write!(out, "{}", warning);
+        out.new_line();
+        out.new_line();
+    }
+    let guard = format!("{}_LOADER_H", prefix.to_uppercase());
+    write!(out, "#ifndef {}", guard);
+    out.new_line();
+    write!(out, "#define {}", guard);
+    out.new_line();
+    out.new_line();
+    out.write("#include <dlfcn.h>");
+    out.new_line();
+    if config.language == Language::Cxx {
+        out.write("#include <cstddef>");
+    } else {
+        out.write("#include <stddef.h>");
+    }
+    out.new_line();
+    write!(out, "#include \"{}\"", header);
+    out.new_line();
+    backend.open_namespaces(&mut out);
+    out.new_line();
+
+    // The struct with a pointer for each function.
+    if config.language == Language::C && config.style.generate_typedef() {
+        out.write("typedef ");
+    }
+    write!(out, "struct {}_api", prefix);
+    out.open_brace();
+    out.write("void *handle;");
+    for function in &functions {
+        let condition = function.cfg.to_condition(config);
+        out.new_line();
+        condition.write_before(config, &mut out);
+        cdecl::write_field(
+            &mut backend,
+            &mut out,
+            &pointer_type(function),
+            function.path.name(),
+            config,
+        );
+        out.write(";");
+        condition.write_after(config, &mut out);
+    }
+    if config.language == Language::C && config.style.generate_typedef() {
+        out.close_brace(false);
+        write!(out, " {}_api;", prefix);
+    } else {
+        out.close_brace(true);
+    }
+    out.new_line();
+    out.new_line();
+
+    // Closes the library and clears the pointers.
+    write!(out, "static inline void {}_unload({} *api)", prefix, api);
+    out.open_brace();
+    out.write("if (api->handle)");
+    out.open_brace();
+    out.write("dlclose(api->handle);");
+    out.close_brace(false);
+    out.new_line();
+    out.write("api->handle = NULL;");
+    for function in &functions {
+        let condition = function.cfg.to_condition(config);
+        out.new_line();
+        condition.write_before(config, &mut out);
+        write!(out, "api->{} = NULL;", function.path.name());
+        condition.write_after(config, &mut out);
+    }
+    out.close_brace(false);
+    out.new_line();
+    out.new_line();
+
+    // Opens the library and looks up every function, giving up on the first
+    // one which is missing.
+    out.write("/* Returns NULL on success. Otherwise returns the error of dlopen,");
+    out.new_line();
+    out.write(" * or the name of the first missing function, and leaves api unloaded. */");
+    out.new_line();
+    write!(
+        out,
+        "static inline const char *{}_load({} *api, const char *path)",
+        prefix, api
+    );
+    out.open_brace();
+    out.write("api->handle = dlopen(path, RTLD_NOW | RTLD_LOCAL);");
+    out.new_line();
+    out.write("if (!api->handle)");
+    out.open_brace();
+    out.write("return dlerror();");
+    out.close_brace(false);
+    for function in &functions {
+        let name = function.path.name();
+        let condition = function.cfg.to_condition(config);
+        out.new_line();
+        condition.write_before(config, &mut out);
+        // POSIX guarantees a function pointer can be converted from the
+        // `void *` dlsym returns, which neither C nor C++ allow implicitly.
+        write!(
+            out,
+            "*(void **)&api->{} = dlsym(api->handle, \"{}\");",
+            name, name
+        );
+        out.new_line();
+        write!(out, "if (!api->{})", name);
+        out.open_brace();
+        write!(out, "{}_unload(api);", prefix);
+        out.new_line();
+        write!(out, "return \"{}\";", name);
+        out.close_brace(false);
+        condition.write_after(config, &mut out);
+    }
+    out.new_line();
+    out.write("return NULL;");
+    out.close_brace(false);
+    out.new_line();
+
+    backend.close_namespaces(&mut out);
+    out.new_line();
+    write!(out, "#endif  /* {} */", guard);
+    out.new_line();
+}
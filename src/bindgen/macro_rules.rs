@@ -0,0 +1,536 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Expansion of the `macro_rules!` macros a crate defines, for the invocations
+//! in item position, so the items they generate can be loaded without
+//! `cargo rustc -Zunpretty=expanded`.
+//!
+//! This is macro-by-example without hygiene: the fragments are matched with
+//! the `syn` parsers and substituted as plain tokens.
+
+use std::collections::HashMap;
+
+use proc_macro2::{Delimiter, Group, Ident, Spacing, TokenStream, TokenTree};
+use syn::ext::IdentExt;
+use syn::parse::discouraged::Speculative;
+use syn::parse::{ParseStream, Parser};
+
+use crate::bindgen::modules::ParsedModule;
+use crate::bindgen::utilities::{desugar_2024, inherit_cfgs};
+
+/// Bounds the expansion of macros invoking other macros.
+const RECURSION_LIMIT: usize = 64;
+
+/// The kinds of fragments a macro can match.
+const FRAGMENT_KINDS: &[&str] = &[
+    "block",
+    "expr",
+    "ident",
+    "item",
+    "lifetime",
+    "literal",
+    "meta",
+    "pat",
+    "pat_param",
+    "path",
+    "stmt",
+    "tt",
+    "ty",
+    "vis",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Repetition {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    ZeroOrOne,
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    /// A token which must be there as is.
+    Token(TokenTree),
+    Group(Delimiter, Vec<Matcher>),
+    /// `$name:kind`
+    Fragment(String, String),
+    /// `$( ... ) sep op`
+    Repeat(Vec<Matcher>, Option<TokenTree>, Repetition),
+}
+
+/// What a metavariable matched, nested once for each repetition it is in.
+#[derive(Debug, Clone)]
+enum Binding {
+    Leaf(TokenStream),
+    Seq(Vec<Binding>),
+}
+
+type Bindings = HashMap<String, Binding>;
+
+#[derive(Debug, Clone)]
+struct Rule {
+    matcher: Vec<Matcher>,
+    transcriber: TokenStream,
+}
+
+/// A `macro_rules!` definition.
+#[derive(Debug, Clone)]
+pub struct MacroRules {
+    rules: Vec<Rule>,
+}
+
+/// Reads the repetition operator, and the separator before it, of a `$(...)`
+/// in a matcher or transcriber.
+fn repetition(
+    tokens: &mut impl Iterator<Item = TokenTree>,
+) -> Result<(Option<TokenTree>, Repetition), String> {
+    let op = |token: &TokenTree| match *token {
+        TokenTree::Punct(ref punct) => match punct.as_char() {
+            '*' => Some(Repetition::ZeroOrMore),
+            '+' => Some(Repetition::OneOrMore),
+            '?' => Some(Repetition::ZeroOrOne),
+            _ => None,
+        },
+        _ => None,
+    };
+    let first = tokens.next().ok_or("missing repetition operator")?;
+    if let Some(op) = op(&first) {
+        return Ok((None, op));
+    }
+    let second = tokens.next().ok_or("missing repetition operator")?;
+    match op(&second) {
+        // `?` doesn't take a separator.
+        Some(op) if op != Repetition::ZeroOrOne => Ok((Some(first), op)),
+        _ => Err(format!("invalid repetition operator `{}`", second)),
+    }
+}
+
+fn parse_matcher(tokens: TokenStream) -> Result<Vec<Matcher>, String> {
+    let mut matchers = Vec::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == '$' => match tokens.next() {
+                Some(TokenTree::Ident(name)) => {
+                    let name = name.unraw().to_string();
+                    match (tokens.next(), tokens.next()) {
+                        (Some(TokenTree::Punct(ref colon)), Some(TokenTree::Ident(ref kind)))
+                            if colon.as_char() == ':' =>
+                        {
+                            let kind = kind.to_string();
+                            if !FRAGMENT_KINDS.contains(&kind.as_str()) {
+                                return Err(format!("unknown fragment kind `{}`", kind));
+                            }
+                            matchers.push(Matcher::Fragment(name, kind));
+                        }
+                        _ => return Err(format!("missing fragment kind for `${}`", name)),
+                    }
+                }
+                Some(TokenTree::Group(ref group))
+                    if group.delimiter() == Delimiter::Parenthesis =>
+                {
+                    let inner = parse_matcher(group.stream())?;
+                    let (separator, op) = repetition(&mut tokens)?;
+                    matchers.push(Matcher::Repeat(inner, separator, op));
+                }
+                _ => return Err("invalid `$` in matcher".to_owned()),
+            },
+            TokenTree::Group(ref group) => matchers.push(Matcher::Group(
+                group.delimiter(),
+                parse_matcher(group.stream())?,
+            )),
+            token => matchers.push(Matcher::Token(token)),
+        }
+    }
+    Ok(matchers)
+}
+
+/// The names of the metavariables of `matchers`.
+fn variables(matchers: &[Matcher], names: &mut Vec<String>) {
+    for matcher in matchers {
+        match *matcher {
+            Matcher::Token(..) => {}
+            Matcher::Group(_, ref inner) | Matcher::Repeat(ref inner, ..) => {
+                variables(inner, names)
+            }
+            Matcher::Fragment(ref name, _) => names.push(name.clone()),
+        }
+    }
+}
+
+fn same_token(a: &TokenTree, b: &TokenTree) -> bool {
+    match (a, b) {
+        (TokenTree::Punct(a), TokenTree::Punct(b)) => a.as_char() == b.as_char(),
+        (TokenTree::Ident(a), TokenTree::Ident(b)) => a == b,
+        (TokenTree::Literal(a), TokenTree::Literal(b)) => a.to_string() == b.to_string(),
+        _ => false,
+    }
+}
+
+fn match_token(input: ParseStream, expected: &TokenTree) -> syn::Result<()> {
+    input.step(|cursor| match cursor.token_tree() {
+        Some((found, rest)) if same_token(&found, expected) => Ok(((), rest)),
+        _ => Err(cursor.error(format!("expected `{}`", expected))),
+    })
+}
+
+fn match_fragment(input: ParseStream, kind: &str) -> syn::Result<TokenStream> {
+    use quote::ToTokens;
+
+    Ok(match kind {
+        "block" => input.parse::<syn::Block>()?.into_token_stream(),
+        "expr" => input.parse::<syn::Expr>()?.into_token_stream(),
+        "ident" => input
+            .step(|cursor| {
+                cursor
+                    .ident()
+                    .ok_or_else(|| cursor.error("expected an identifier"))
+            })?
+            .into_token_stream(),
+        "item" => input.parse::<syn::Item>()?.into_token_stream(),
+        "lifetime" => input.parse::<syn::Lifetime>()?.into_token_stream(),
+        "literal" => {
+            let mut tokens = TokenStream::new();
+            if input.peek(Token![-]) {
+                tokens.extend(input.parse::<Token![-]>()?.into_token_stream());
+            }
+            tokens.extend(input.parse::<syn::Lit>()?.into_token_stream());
+            tokens
+        }
+        "meta" => input.parse::<syn::Meta>()?.into_token_stream(),
+        "pat" | "pat_param" => input.parse::<syn::Pat>()?.into_token_stream(),
+        "path" => input.parse::<syn::Path>()?.into_token_stream(),
+        "stmt" => input.parse::<syn::Stmt>()?.into_token_stream(),
+        "tt" => input.parse::<TokenTree>()?.into_token_stream(),
+        "ty" => input.parse::<syn::Type>()?.into_token_stream(),
+        "vis" => input.parse::<syn::Visibility>()?.into_token_stream(),
+        _ => unreachable!("unknown fragment kind `{}`", kind),
+    })
+}
+
+fn match_all(input: ParseStream, matchers: &[Matcher], bindings: &mut Bindings) -> syn::Result<()> {
+    for matcher in matchers {
+        match *matcher {
+            Matcher::Token(ref token) => match_token(input, token)?,
+            Matcher::Group(delimiter, ref inner) => {
+                let group: Group = match input.parse::<TokenTree>()? {
+                    TokenTree::Group(group) if group.delimiter() == delimiter => group,
+                    _ => return Err(input.error("expected a group")),
+                };
+                let inner_bindings = (|input: ParseStream| {
+                    let mut inner_bindings = Bindings::new();
+                    match_all(input, inner, &mut inner_bindings)?;
+                    Ok(inner_bindings)
+                })
+                .parse2(group.stream())?;
+                bindings.extend(inner_bindings);
+            }
+            Matcher::Fragment(ref name, ref kind) => {
+                let tokens = match_fragment(input, kind)?;
+                bindings.insert(name.clone(), Binding::Leaf(tokens));
+            }
+            Matcher::Repeat(ref inner, ref separator, op) => {
+                let mut iterations = Vec::new();
+                while op != Repetition::ZeroOrOne || iterations.is_empty() {
+                    let fork = input.fork();
+                    if let (Some(separator), false) = (separator, iterations.is_empty()) {
+                        if match_token(&fork, separator).is_err() {
+                            break;
+                        }
+                    }
+                    let mut iteration = Bindings::new();
+                    // A repetition which matches nothing would loop forever.
+                    if match_all(&fork, inner, &mut iteration).is_err()
+                        || fork.cursor() == input.cursor()
+                    {
+                        break;
+                    }
+                    input.advance_to(&fork);
+                    iterations.push(iteration);
+                }
+                if op == Repetition::OneOrMore && iterations.is_empty() {
+                    return Err(input.error("expected at least one repetition"));
+                }
+
+                let mut names = Vec::new();
+                variables(inner, &mut names);
+                for name in names {
+                    let seq = iterations
+                        .iter_mut()
+                        .filter_map(|iteration| iteration.remove(&name))
+                        .collect();
+                    bindings.insert(name, Binding::Seq(seq));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns what `name` is bound to inside the repetitions at `indices`.
+fn lookup<'a>(bindings: &'a Bindings, name: &str, indices: &[usize]) -> Option<&'a Binding> {
+    let mut binding = bindings.get(name)?;
+    for &index in indices {
+        match *binding {
+            Binding::Seq(ref seq) => binding = seq.get(index)?,
+            Binding::Leaf(..) => break,
+        }
+    }
+    Some(binding)
+}
+
+/// The number of repetitions of a `$(...)` in a transcriber, given by the
+/// metavariables repeating at this depth inside it.
+fn repetitions(
+    tokens: TokenStream,
+    bindings: &Bindings,
+    indices: &[usize],
+    count: &mut Option<usize>,
+) -> Result<(), String> {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == '$' => {
+                if let Some(TokenTree::Ident(name)) = tokens.peek() {
+                    let name = name.unraw().to_string();
+                    if let Some(Binding::Seq(seq)) = lookup(bindings, &name, indices) {
+                        match *count {
+                            Some(count) if count != seq.len() => {
+                                return Err(format!(
+                                    "`${}` repeats {} times instead of {}",
+                                    name,
+                                    seq.len(),
+                                    count
+                                ))
+                            }
+                            _ => *count = Some(seq.len()),
+                        }
+                    }
+                }
+            }
+            TokenTree::Group(ref group) => repetitions(group.stream(), bindings, indices, count)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn transcribe(
+    tokens: TokenStream,
+    bindings: &Bindings,
+    indices: &mut Vec<usize>,
+    out: &mut TokenStream,
+) -> Result<(), String> {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == '$' => match tokens.next() {
+                Some(TokenTree::Ident(ref name)) if name == "crate" => {
+                    out.extend(Some(TokenTree::Ident(Ident::new("crate", name.span()))));
+                }
+                Some(TokenTree::Ident(name)) => {
+                    match lookup(bindings, &name.unraw().to_string(), indices) {
+                        Some(Binding::Leaf(tokens)) => out.extend(tokens.clone()),
+                        Some(Binding::Seq(..)) => {
+                            return Err(format!("`${}` is still repeating at this depth", name))
+                        }
+                        None => return Err(format!("unknown metavariable `${}`", name)),
+                    }
+                }
+                Some(TokenTree::Group(ref group))
+                    if group.delimiter() == Delimiter::Parenthesis =>
+                {
+                    let (separator, op) = repetition(&mut tokens)?;
+                    let mut count = None;
+                    repetitions(group.stream(), bindings, indices, &mut count)?;
+                    let count = count.ok_or("a repetition doesn't contain repeating variables")?;
+                    if op == Repetition::ZeroOrOne && count > 1 {
+                        return Err("a `?` repetition repeats several times".to_owned());
+                    }
+                    for index in 0..count {
+                        if index > 0 {
+                            out.extend(separator.clone());
+                        }
+                        indices.push(index);
+                        transcribe(group.stream(), bindings, indices, out)?;
+                        indices.pop();
+                    }
+                }
+                // A `$` which isn't a metavariable, e.g. in a nested
+                // macro_rules! definition.
+                Some(token) => out.extend([TokenTree::Punct(punct.clone()), token]),
+                None => out.extend(Some(TokenTree::Punct(punct.clone()))),
+            },
+            TokenTree::Group(ref group) => {
+                let mut inner = TokenStream::new();
+                transcribe(group.stream(), bindings, indices, &mut inner)?;
+                let mut transcribed = Group::new(group.delimiter(), inner);
+                transcribed.set_span(group.span());
+                out.extend(Some(TokenTree::Group(transcribed)));
+            }
+            token => out.extend(Some(token)),
+        }
+    }
+    Ok(())
+}
+
+impl MacroRules {
+    /// Loads a `macro_rules! name { ... }` definition, returning its name.
+    pub fn load(item: &syn::ItemMacro) -> Option<Result<(String, MacroRules), String>> {
+        if !item.mac.path.is_ident("macro_rules") {
+            return None;
+        }
+        let name = item.ident.as_ref()?.unraw().to_string();
+
+        let mut rules = Vec::new();
+        let mut tokens = item.mac.tokens.clone().into_iter();
+        loop {
+            let matcher = match tokens.next() {
+                Some(TokenTree::Group(group)) => group,
+                None => break,
+                Some(_) => return Some(Err("expected a matcher".to_owned())),
+            };
+            match (tokens.next(), tokens.next()) {
+                (Some(TokenTree::Punct(ref eq)), Some(TokenTree::Punct(ref gt)))
+                    if eq.as_char() == '='
+                        && eq.spacing() == Spacing::Joint
+                        && gt.as_char() == '>' => {}
+                _ => return Some(Err("expected `=>`".to_owned())),
+            }
+            let transcriber = match tokens.next() {
+                Some(TokenTree::Group(group)) => group,
+                _ => return Some(Err("expected a transcriber".to_owned())),
+            };
+            let matcher = match parse_matcher(matcher.stream()) {
+                Ok(matcher) => matcher,
+                Err(e) => return Some(Err(e)),
+            };
+            rules.push(Rule {
+                matcher,
+                transcriber: transcriber.stream(),
+            });
+            match tokens.next() {
+                Some(TokenTree::Punct(ref semi)) if semi.as_char() == ';' => {}
+                None => break,
+                Some(_) => return Some(Err("expected `;`".to_owned())),
+            }
+        }
+        Some(Ok((name, MacroRules { rules })))
+    }
+
+    /// Expands an invocation with the arguments `tokens`, using the first
+    /// rule which matches them.
+    pub fn expand(&self, tokens: &TokenStream) -> Result<TokenStream, String> {
+        for rule in &self.rules {
+            let bindings = (|input: ParseStream| {
+                let mut bindings = Bindings::new();
+                match_all(input, &rule.matcher, &mut bindings)?;
+                Ok(bindings)
+            })
+            .parse2(tokens.clone());
+            if let Ok(bindings) = bindings {
+                let mut out = TokenStream::new();
+                transcribe(
+                    rule.transcriber.clone(),
+                    &bindings,
+                    &mut Vec::new(),
+                    &mut out,
+                )?;
+                return Ok(out);
+            }
+        }
+        Err("no rule matches the invocation".to_owned())
+    }
+}
+
+/// Replaces the invocations of `macros` in `items` with their expansion.
+fn expand_items(
+    items: &[syn::Item],
+    macros: &HashMap<String, MacroRules>,
+    depth: usize,
+    out: &mut Vec<syn::Item>,
+) {
+    for item in items {
+        let invocation = match *item {
+            syn::Item::Macro(ref invocation) if invocation.ident.is_none() => invocation,
+            _ => {
+                out.push(item.clone());
+                continue;
+            }
+        };
+        let name = match invocation.mac.path.segments.last() {
+            Some(segment) => segment.ident.unraw().to_string(),
+            None => continue,
+        };
+        let Some(rules) = macros.get(&name) else {
+            out.push(item.clone());
+            continue;
+        };
+        if depth >= RECURSION_LIMIT {
+            warn!("Failed to expand `{}!`: recursion limit reached.", name);
+            continue;
+        }
+
+        let expanded = rules.expand(&invocation.mac.tokens).and_then(|tokens| {
+            syn::parse2::<syn::File>(desugar_2024(tokens)).map_err(|e| e.to_string())
+        });
+        let mut expanded = match expanded {
+            Ok(file) => file.items,
+            Err(e) => {
+                warn!("Failed to expand `{}!`: {}.", name, e);
+                continue;
+            }
+        };
+        inherit_cfgs(&invocation.attrs, &mut expanded);
+        expand_items(&expanded, macros, depth + 1, out);
+    }
+}
+
+/// Expands the invocations in item position of the `macro_rules!` macros each
+/// crate defines.
+pub(crate) fn expand_modules(modules: &[ParsedModule]) -> Vec<ParsedModule> {
+    let mut macros: HashMap<&str, HashMap<String, MacroRules>> = HashMap::new();
+    for module in modules {
+        for item in &module.items {
+            let syn::Item::Macro(ref item) = *item else {
+                continue;
+            };
+            match MacroRules::load(item) {
+                Some(Ok((name, rules))) => {
+                    // Macros defined several times are usually the same
+                    // macro for different `cfg`s.
+                    macros
+                        .entry(&module.crate_name)
+                        .or_default()
+                        .entry(name)
+                        .or_insert(rules);
+                }
+                Some(Err(e)) => warn!(
+                    "Failed to load macro_rules! {} in {}: {}.",
+                    item.ident.as_ref().map_or(String::new(), |i| i.to_string()),
+                    module.path.join("::"),
+                    e
+                ),
+                None => {}
+            }
+        }
+    }
+
+    modules
+        .iter()
+        .map(|module| match macros.get(module.crate_name.as_str()) {
+            Some(macros) => {
+                let mut items = Vec::with_capacity(module.items.len());
+                expand_items(&module.items, macros, 0, &mut items);
+                ParsedModule {
+                    items,
+                    ..module.clone()
+                }
+            }
+            None => module.clone(),
+        })
+        .collect()
+}
@@ -0,0 +1,233 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Merging of the bindings generated for each of `[parse] targets` into a
+//! single header.
+//!
+//! Items are compared on their IR, so any difference between the targets,
+//! whether it comes from a `cfg` or not, makes them conditional on the
+//! targets they were generated for.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::ir::{
+    Cfg, Constant, Enum, Function, Item, ItemContainer, ItemMap, OpaqueItem, Static, Struct,
+    Typedef, Union,
+};
+use crate::bindgen::json::ParsedLibrary;
+
+/// An item of the bindings of a target.
+trait TargetItem: Clone + Serialize {
+    /// Identifies the same item in the bindings of different targets.
+    fn key(&self) -> String;
+    fn cfg_mut(&mut self) -> &mut Option<Cfg>;
+}
+
+macro_rules! impl_target_item {
+    ($t:ty) => {
+        impl TargetItem for $t {
+            fn key(&self) -> String {
+                match self.cfg {
+                    Some(ref cfg) => format!("{} {}", self.path.name(), cfg),
+                    None => self.path.name().to_owned(),
+                }
+            }
+
+            fn cfg_mut(&mut self) -> &mut Option<Cfg> {
+                &mut self.cfg
+            }
+        }
+    };
+}
+
+impl_target_item!(Constant);
+impl_target_item!(Static);
+impl_target_item!(Function);
+impl_target_item!(Struct);
+impl_target_item!(Union);
+impl_target_item!(Enum);
+impl_target_item!(Typedef);
+impl_target_item!(OpaqueItem);
+
+impl TargetItem for ItemContainer {
+    fn key(&self) -> String {
+        let (kind, key) = match *self {
+            ItemContainer::Constant(ref x) => ("constant", x.key()),
+            ItemContainer::Static(ref x) => ("static", x.key()),
+            ItemContainer::OpaqueItem(ref x) => ("opaque", x.key()),
+            ItemContainer::Struct(ref x) => ("struct", x.key()),
+            ItemContainer::Union(ref x) => ("union", x.key()),
+            ItemContainer::Enum(ref x) => ("enum", x.key()),
+            ItemContainer::Typedef(ref x) => ("typedef", x.key()),
+        };
+        format!("{} {}", kind, key)
+    }
+
+    fn cfg_mut(&mut self) -> &mut Option<Cfg> {
+        match *self {
+            ItemContainer::Constant(ref mut x) => x.cfg_mut(),
+            ItemContainer::Static(ref mut x) => x.cfg_mut(),
+            ItemContainer::OpaqueItem(ref mut x) => x.cfg_mut(),
+            ItemContainer::Struct(ref mut x) => x.cfg_mut(),
+            ItemContainer::Union(ref mut x) => x.cfg_mut(),
+            ItemContainer::Enum(ref mut x) => x.cfg_mut(),
+            ItemContainer::Typedef(ref mut x) => x.cfg_mut(),
+        }
+    }
+}
+
+/// Orders the keys of all `sequences` so that each sequence keeps its order.
+/// When the sequences disagree, the order of the first one wins.
+fn merge_orders(sequences: &[Vec<String>]) -> Vec<String> {
+    let mut keys: IndexMap<&str, ()> = IndexMap::new();
+    for key in sequences.iter().flatten() {
+        keys.insert(key, ());
+    }
+    let index = |key: &str| keys.get_index_of(key).unwrap();
+
+    let mut successors = vec![Vec::new(); keys.len()];
+    let mut predecessors = vec![0; keys.len()];
+    let mut edges = HashSet::new();
+    for sequence in sequences {
+        for pair in sequence.windows(2) {
+            let (from, to) = (index(&pair[0]), index(&pair[1]));
+            if from != to && edges.insert((from, to)) {
+                successors[from].push(to);
+                predecessors[to] += 1;
+            }
+        }
+    }
+
+    let mut ready: BTreeSet<_> = (0..keys.len()).filter(|&i| predecessors[i] == 0).collect();
+    let mut remaining: BTreeSet<_> = (0..keys.len()).collect();
+    let mut order = Vec::with_capacity(keys.len());
+    // Without ready keys, the cycles of sequences which disagree are broken in
+    // the first order.
+    while let Some(&next) = ready.iter().next().or_else(|| remaining.iter().next()) {
+        ready.remove(&next);
+        remaining.remove(&next);
+        order.push(keys.get_index(next).unwrap().0.to_string());
+        for &successor in &successors[next] {
+            predecessors[successor] -= 1;
+            if predecessors[successor] == 0 && remaining.contains(&successor) {
+                ready.insert(successor);
+            }
+        }
+    }
+    order
+}
+
+/// Merges the items of each target, making the ones which aren't the same on
+/// every target conditional on the targets they are generated for.
+fn merge_items<T: TargetItem>(cfgs: &[Cfg], lists: Vec<Vec<T>>) -> Vec<T> {
+    type Variants<T> = Vec<(serde_json::Value, T, Vec<usize>)>;
+
+    let mut variants: HashMap<String, Variants<T>> = HashMap::new();
+    let mut sequences = Vec::with_capacity(lists.len());
+    for (target, list) in lists.into_iter().enumerate() {
+        let mut sequence = Vec::with_capacity(list.len());
+        for item in list {
+            let key = item.key();
+            let ir = serde_json::to_value(&item).expect("Items can be serialized");
+            let key_variants = variants.entry(key.clone()).or_default();
+            match key_variants.iter_mut().find(|(other, ..)| *other == ir) {
+                Some((_, _, targets)) => targets.push(target),
+                None => key_variants.push((ir, item, vec![target])),
+            }
+            sequence.push(key);
+        }
+        sequences.push(sequence);
+    }
+
+    let mut merged = Vec::new();
+    for key in merge_orders(&sequences) {
+        for (_, mut item, targets) in variants.remove(&key).unwrap() {
+            if targets.len() < cfgs.len() {
+                let mut target_cfgs: Vec<_> = targets.iter().map(|&t| cfgs[t].clone()).collect();
+                let target_cfg = if target_cfgs.len() == 1 {
+                    target_cfgs.pop().unwrap()
+                } else {
+                    Cfg::Any(target_cfgs)
+                };
+                let cfg = item.cfg_mut();
+                *cfg = Cfg::append(Some(&target_cfg), cfg.take());
+            }
+            merged.push(item);
+        }
+    }
+    merged
+}
+
+fn merge_maps<T: TargetItem + Item>(cfgs: &[Cfg], maps: Vec<&ItemMap<T>>) -> ItemMap<T> {
+    let lists = maps.into_iter().map(|map| map.to_vec()).collect();
+    let mut merged = ItemMap::default();
+    for item in merge_items(cfgs, lists) {
+        merged.try_insert(item);
+    }
+    merged
+}
+
+fn merge_parsed(cfgs: &[Cfg], parsed: Vec<&ParsedLibrary>) -> ParsedLibrary {
+    macro_rules! merge {
+        ($field:ident) => {
+            merge_maps(cfgs, parsed.iter().map(|p| &p.$field).collect())
+        };
+    }
+
+    ParsedLibrary {
+        constants: merge!(constants),
+        globals: merge!(globals),
+        enums: merge!(enums),
+        structs: merge!(structs),
+        unions: merge!(unions),
+        opaque_items: merge!(opaque_items),
+        typedefs: merge!(typedefs),
+        functions: merge_items(cfgs, parsed.iter().map(|p| p.functions.clone()).collect()),
+    }
+}
+
+/// Merges the bindings generated for each target, with the cfg telling apart
+/// the target, into the bindings of all of them.
+pub(crate) fn merge(targets: Vec<(Cfg, Bindings)>) -> Bindings {
+    let (cfgs, bindings): (Vec<_>, Vec<_>) = targets.into_iter().unzip();
+    let first = &bindings[0];
+    if first.noop {
+        return bindings.into_iter().next().unwrap();
+    }
+
+    let mut source_files: Vec<_> = bindings
+        .iter()
+        .flat_map(|b| b.source_files.iter().cloned())
+        .collect();
+    source_files.sort();
+    source_files.dedup();
+
+    let mut merged = Bindings::new(
+        first.config.clone(),
+        merge_maps(&cfgs, bindings.iter().map(|b| &b.struct_map).collect()),
+        merge_maps(&cfgs, bindings.iter().map(|b| &b.typedef_map).collect()),
+        merge_items(
+            &cfgs,
+            bindings.iter().map(|b| b.constants.clone()).collect(),
+        ),
+        merge_items(&cfgs, bindings.iter().map(|b| b.globals.clone()).collect()),
+        merge_items(&cfgs, bindings.iter().map(|b| b.items.clone()).collect()),
+        merge_items(
+            &cfgs,
+            bindings.iter().map(|b| b.functions.clone()).collect(),
+        ),
+        source_files,
+        merge_parsed(&cfgs, bindings.iter().map(|b| &b.parsed).collect()),
+        false,
+        first.package_version.clone(),
+    );
+    for b in &bindings {
+        merged.provenance.extend_with(&b.provenance);
+    }
+    merged
+}
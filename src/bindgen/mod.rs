@@ -36,31 +36,61 @@ macro_rules! deserialize_enum_str {
     };
 }
 
+mod abi_diff;
 mod bindings;
 mod bitflags;
 mod builder;
 mod cargo;
 mod cdecl;
+mod cfg_attr;
 mod config;
 mod declarationtyperesolver;
 mod dependencies;
+mod diagnostics;
+mod elf;
 mod error;
+mod explain;
 mod ir;
+mod json;
 mod language_backend;
+mod layout_tests;
 mod library;
+mod lint;
+mod loader;
+mod macro_rules;
 mod mangle;
+mod merge;
+mod modules;
 mod monomorph;
 mod parser;
 mod rename;
 mod reserved;
+mod rustdoc;
+mod symbols;
+mod target;
+mod unreachable;
 mod utilities;
 mod writer;
 
 #[allow(unused)]
 pub(crate) use self::cargo::*;
 
+#[allow(unused)]
+pub use self::abi_diff::{AbiChange, AbiChangeKind, AbiDiff};
 pub use self::bindings::Bindings;
 pub use self::builder::Builder;
 pub use self::config::Profile; // disambiguate with cargo::Profile
 pub use self::config::*;
+#[allow(unused)]
+pub use self::diagnostics::{
+    Diagnostic, DiagnosticCode, Location as DiagnosticLocation, Severity,
+    LOG_TARGET as DIAGNOSTICS_LOG_TARGET,
+};
 pub use self::error::Error;
+#[allow(unused)]
+pub use self::explain::{CrateFilter, Explanation, Filter, Root, Status};
+#[allow(unused)]
+pub use self::json::JSON_SCHEMA_VERSION;
+pub use self::symbols::{SymbolCheck, SymbolFileFormat};
+#[allow(unused)]
+pub use self::unreachable::{UnreachableItem, UnreachableReport};
@@ -0,0 +1,651 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The module tree of the parsed crates, which resolves the type paths of the
+//! items through their modules and `use` declarations before they are loaded.
+//!
+//! The rest of cbindgen looks types up by their bare name, so each type path
+//! which resolves to a type of the parsed crates is replaced with the name of
+//! that type. Types with the same name in different modules are renamed so
+//! that these names are unique, see `NameCollisions`. Only the types the
+//! bindings can use are renamed: the ones the exported functions, statics and
+//! constants use, directly or through other types, and the ones in the
+//! `include` list of `[export]`.
+//!
+//! Functions, statics and constants aren't renamed. The exported functions and
+//! statics are named by their symbol, which the linker already requires to be
+//! unique, and constants with the same name in different modules still collide
+//! in the header.
+
+use std::collections::{HashMap, HashSet};
+
+use syn::ext::IdentExt;
+use syn::fold::{self, Fold};
+
+use crate::bindgen::config::{Config, NameCollisions};
+use crate::bindgen::error::Error;
+use crate::bindgen::ir::Cfg;
+use crate::bindgen::utilities::{SynAttributeHelpers, SynItemHelpers};
+
+/// Bounds the resolution of cyclic imports, e.g. modules glob importing each
+/// other.
+const MAX_DEPTH: usize = 32;
+
+/// A module of a parsed crate.
+#[derive(Debug, Clone)]
+pub(crate) struct ParsedModule {
+    /// The name of the crate, as in its manifest.
+    pub crate_name: String,
+    /// The path of the module, starting with the name of the crate as written
+    /// in Rust paths.
+    pub path: Vec<String>,
+    /// The `cfg` of the crate and the module.
+    pub cfg: Option<Cfg>,
+    pub items: Vec<syn::Item>,
+}
+
+/// A path as written in the source.
+#[derive(Debug, Clone)]
+struct UsePath {
+    leading_colon: bool,
+    segments: Vec<String>,
+}
+
+/// What a path resolves to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Target {
+    /// A type, with the path of the module defining it.
+    Type(Vec<String>, String),
+    Module(Vec<String>),
+}
+
+/// The names visible in a module.
+#[derive(Debug, Default)]
+struct Scope {
+    /// The structs, enums, unions and type aliases defined in the module.
+    types: HashSet<String>,
+    /// The modules declared in the module.
+    modules: HashSet<String>,
+    /// The names brought in scope by `use` and `extern crate`.
+    imports: HashMap<String, UsePath>,
+    /// The paths of `use path::*` declarations.
+    globs: Vec<UsePath>,
+}
+
+impl Scope {
+    fn add_use(&mut self, tree: &syn::UseTree, prefix: &mut Vec<String>, leading_colon: bool) {
+        let path = |prefix: &[String], name: &str| {
+            let mut segments = prefix.to_vec();
+            if name != "self" {
+                segments.push(name.to_owned());
+            }
+            UsePath {
+                leading_colon,
+                segments,
+            }
+        };
+
+        match *tree {
+            syn::UseTree::Path(ref use_path) => {
+                prefix.push(use_path.ident.unraw().to_string());
+                self.add_use(&use_path.tree, prefix, leading_colon);
+                prefix.pop();
+            }
+            syn::UseTree::Name(ref use_name) => {
+                let name = use_name.ident.unraw().to_string();
+                // `use module::{self}` imports `module`.
+                let alias = if name == "self" {
+                    match prefix.last() {
+                        Some(last) => last.clone(),
+                        None => return,
+                    }
+                } else {
+                    name.clone()
+                };
+                self.imports.insert(alias, path(prefix, &name));
+            }
+            syn::UseTree::Rename(ref rename) => {
+                let alias = rename.rename.unraw().to_string();
+                if alias != "_" {
+                    let name = rename.ident.unraw().to_string();
+                    self.imports.insert(alias, path(prefix, &name));
+                }
+            }
+            syn::UseTree::Glob(..) => {
+                self.globs.push(path(prefix, "self"));
+            }
+            syn::UseTree::Group(ref group) => {
+                for tree in &group.items {
+                    self.add_use(tree, prefix, leading_colon);
+                }
+            }
+        }
+    }
+}
+
+/// The types of a module, which need to be looked up by name.
+fn type_name(item: &syn::Item) -> Option<(String, &[syn::Attribute])> {
+    let (ident, attrs) = match *item {
+        syn::Item::Struct(ref item) => (&item.ident, &item.attrs),
+        syn::Item::Enum(ref item) => (&item.ident, &item.attrs),
+        syn::Item::Union(ref item) => (&item.ident, &item.attrs),
+        syn::Item::Type(ref item) => (&item.ident, &item.attrs),
+        _ => return None,
+    };
+    Some((ident.unraw().to_string(), attrs))
+}
+
+/// The parts of `item` the bindings are generated from, if any: the exported
+/// functions and statics, the constants and the foreign items, and the
+/// exported methods and the constants of impls.
+fn exported_part(item: &syn::Item) -> Option<syn::Item> {
+    match *item {
+        syn::Item::Fn(ref function) if function.exported_name().is_some() => Some(item.clone()),
+        syn::Item::Static(ref global) if global.exported_name().is_some() => Some(item.clone()),
+        syn::Item::Const(..) | syn::Item::ForeignMod(..) => Some(item.clone()),
+        syn::Item::Impl(ref item) => {
+            let mut item = item.clone();
+            item.items.retain(|item| match *item {
+                syn::ImplItem::Const(..) => true,
+                syn::ImplItem::Method(ref method) => method.exported_name().is_some(),
+                _ => false,
+            });
+            (!item.items.is_empty()).then_some(syn::Item::Impl(item))
+        }
+        _ => None,
+    }
+}
+
+/// The modules defining a type name, in parse order, and whether all the
+/// definitions in each module have a `cfg`.
+type Definitions = Vec<(Vec<String>, bool)>;
+
+/// A type of the parsed crates, by the path of its module and its name.
+type TypeKey = (Vec<String>, String);
+
+pub(crate) struct ModuleTree {
+    scopes: HashMap<Vec<String>, Scope>,
+    /// The names of the types renamed because of a collision, by module path
+    /// and original name.
+    renames: HashMap<(Vec<String>, String), String>,
+}
+
+impl ModuleTree {
+    /// Builds the scopes of `modules`, which are in parse order, and renames
+    /// the colliding types the bindings of `binding_crate_name` can use
+    /// according to `config`.
+    pub fn new(
+        modules: &[ParsedModule],
+        config: &Config,
+        binding_crate_name: &str,
+    ) -> Result<ModuleTree, Error> {
+        let mut scopes: HashMap<Vec<String>, Scope> = HashMap::new();
+        let mut definitions: Vec<(String, Definitions)> = Vec::new();
+        let mut definition_indices: HashMap<String, usize> = HashMap::new();
+        let mut types: HashMap<TypeKey, Vec<&syn::Item>> = HashMap::new();
+        let mut roots = Vec::new();
+
+        for module in modules {
+            if let Some((name, parent)) = module.path.split_last() {
+                if !parent.is_empty() {
+                    scopes
+                        .entry(parent.to_vec())
+                        .or_default()
+                        .modules
+                        .insert(name.clone());
+                }
+            }
+            let scope = scopes.entry(module.path.clone()).or_default();
+            let exports = config
+                .parse
+                .should_generate_top_level_item(&module.crate_name, binding_crate_name);
+
+            for item in &module.items {
+                if item.should_skip_parsing() {
+                    continue;
+                }
+                if exports {
+                    if let Some(part) = exported_part(item) {
+                        roots.push((&module.path[..], part));
+                    }
+                }
+                match *item {
+                    syn::Item::Use(ref item) => {
+                        scope.add_use(&item.tree, &mut Vec::new(), item.leading_colon.is_some())
+                    }
+                    syn::Item::ExternCrate(ref item) => {
+                        let name = item.ident.unraw().to_string();
+                        let alias = match item.rename {
+                            Some((_, ref alias)) => alias.unraw().to_string(),
+                            None => name.clone(),
+                        };
+                        if name != "self" && alias != "_" {
+                            scope.imports.insert(
+                                alias,
+                                UsePath {
+                                    leading_colon: true,
+                                    segments: vec![name],
+                                },
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+
+                let Some((name, attrs)) = type_name(item) else {
+                    continue;
+                };
+                scope.types.insert(name.clone());
+                types
+                    .entry((module.path.clone(), name.clone()))
+                    .or_default()
+                    .push(item);
+                let has_cfg = module.cfg.is_some() || Cfg::load(attrs).is_some();
+                let index = *definition_indices.entry(name.clone()).or_insert_with(|| {
+                    definitions.push((name, Vec::new()));
+                    definitions.len() - 1
+                });
+                let modules = &mut definitions[index].1;
+                match modules.iter_mut().find(|(path, _)| *path == module.path) {
+                    Some((_, all_have_cfg)) => *all_have_cfg &= has_cfg,
+                    None => modules.push((module.path.clone(), has_cfg)),
+                }
+            }
+        }
+
+        let mut tree = ModuleTree {
+            scopes,
+            renames: HashMap::new(),
+        };
+        let reachable = tree.reachable(roots, &types, &config.export.include);
+
+        let mut renames = HashMap::new();
+        for (name, modules) in &definitions {
+            let modules: Vec<_> = modules
+                .iter()
+                .filter(|(path, _)| reachable.contains(&(path.clone(), name.clone())))
+                .cloned()
+                .collect();
+            // Definitions which all have a `cfg` are alternatives of the same
+            // type, like in a single module.
+            if modules.len() < 2 || modules.iter().all(|&(_, has_cfg)| has_cfg) {
+                continue;
+            }
+            let paths = || {
+                modules
+                    .iter()
+                    .map(|(path, _)| format!("{}::{}", path.join("::"), name))
+                    .collect()
+            };
+            if config.parse.name_collisions == NameCollisions::Error {
+                return Err(Error::NameCollision {
+                    name: name.clone(),
+                    paths: paths(),
+                });
+            }
+
+            // The first type parsed keeps its name, the others are prefixed
+            // with the part of their module path which differs.
+            let common = (0..)
+                .take_while(|&i| {
+                    modules
+                        .iter()
+                        .all(|(path, _)| path.get(i).is_some() && path[i] == modules[0].0[i])
+                })
+                .count();
+            for (path, _) in &modules[1..] {
+                let prefix = match config.parse.module_prefixes.get(&path.join("::")) {
+                    Some(prefix) => prefix.clone(),
+                    None => {
+                        let distinct = &path[common.min(path.len() - 1)..];
+                        format!("{}_", distinct.join("_"))
+                    }
+                };
+                let new_name = format!("{}{}", prefix, name);
+                if definition_indices.contains_key(&new_name)
+                    || renames.values().any(|renamed| *renamed == new_name)
+                {
+                    return Err(Error::NameCollision {
+                        name: name.clone(),
+                        paths: paths(),
+                    });
+                }
+                info!(
+                    "Renaming {}::{} to {} to avoid a name collision.",
+                    path.join("::"),
+                    name,
+                    new_name
+                );
+                renames.insert((path.clone(), name.clone()), new_name);
+            }
+        }
+
+        tree.renames = renames;
+        Ok(tree)
+    }
+
+    /// The types the bindings can use: the ones `include` names, and the ones
+    /// the items of `roots` use, with the path of their module, and the types
+    /// these use in turn.
+    fn reachable(
+        &self,
+        roots: Vec<(&[String], syn::Item)>,
+        types: &HashMap<TypeKey, Vec<&syn::Item>>,
+        include: &[String],
+    ) -> HashSet<TypeKey> {
+        let mut pending: Vec<TypeKey> = types
+            .keys()
+            .filter(|(_, name)| include.contains(name))
+            .cloned()
+            .collect();
+        for (module, item) in roots {
+            pending.extend(self.used_types(module, item));
+        }
+
+        let mut reachable = HashSet::new();
+        while let Some(key) = pending.pop() {
+            if reachable.contains(&key) {
+                continue;
+            }
+            for item in types.get(&key).into_iter().flatten() {
+                pending.extend(self.used_types(&key.0, (*item).clone()));
+            }
+            reachable.insert(key);
+        }
+        reachable
+    }
+
+    /// The types of the parsed crates `item`, in `module`, uses.
+    fn used_types(&self, module: &[String], item: syn::Item) -> Vec<TypeKey> {
+        let mut rewriter = Rewriter {
+            tree: self,
+            module,
+            generics: Vec::new(),
+            used: Vec::new(),
+        };
+        rewriter.fold_item(item);
+        rewriter.used
+    }
+
+    /// The name of the type `name` defined in `module`.
+    fn final_name<'a>(&'a self, module: &[String], name: &'a str) -> &'a str {
+        self.renames
+            .get(&(module.to_vec(), name.to_owned()))
+            .map_or(name, |renamed| renamed)
+    }
+
+    /// Looks `name` up in the scope of `module`, preferring modules to types
+    /// if `want_module`.
+    fn lookup(
+        &self,
+        module: &[String],
+        name: &str,
+        want_module: bool,
+        depth: usize,
+    ) -> Option<Target> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let scope = self.scopes.get(module)?;
+
+        let ty = || {
+            scope
+                .types
+                .contains(name)
+                .then(|| Target::Type(module.to_vec(), name.to_owned()))
+        };
+        let submodule = || {
+            scope.modules.contains(name).then(|| {
+                let mut path = module.to_vec();
+                path.push(name.to_owned());
+                Target::Module(path)
+            })
+        };
+        let local = if want_module {
+            submodule().or_else(ty)
+        } else {
+            ty().or_else(submodule)
+        };
+        if local.is_some() {
+            return local;
+        }
+
+        // Explicit imports shadow glob imports, even when they refer to
+        // something outside of the parsed crates.
+        if let Some(path) = scope.imports.get(name) {
+            return self.resolve(module, path, want_module, depth + 1);
+        }
+        scope
+            .globs
+            .iter()
+            .find_map(|glob| match self.resolve(module, glob, true, depth + 1)? {
+                Target::Module(glob_module) => {
+                    self.lookup(&glob_module, name, want_module, depth + 1)
+                }
+                Target::Type(..) => None,
+            })
+    }
+
+    /// Resolves `path` as written in `module`.
+    fn resolve(
+        &self,
+        module: &[String],
+        path: &UsePath,
+        want_module: bool,
+        depth: usize,
+    ) -> Option<Target> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let crate_root = |name: &str| {
+            self.scopes
+                .contains_key(&[name.to_owned()][..])
+                .then(|| vec![name.to_owned()])
+        };
+
+        let (first, rest) = path.segments.split_first()?;
+        let mut current = if path.leading_colon {
+            crate_root(first)?
+        } else {
+            match first.as_str() {
+                "crate" => module[..1].to_vec(),
+                "self" => module.to_vec(),
+                "super" if module.len() > 1 => module[..module.len() - 1].to_vec(),
+                "super" => return None,
+                _ if rest.is_empty() => return self.lookup(module, first, want_module, depth + 1),
+                _ => match self.lookup(module, first, true, depth + 1) {
+                    Some(Target::Module(path)) => path,
+                    Some(Target::Type(..)) => return None,
+                    // Paths relative to the crate root, as in Rust 2015, or
+                    // starting with the name of another crate.
+                    None => match self.lookup(&module[..1], first, true, depth + 1) {
+                        Some(Target::Module(path)) => path,
+                        _ => crate_root(first)?,
+                    },
+                },
+            }
+        };
+
+        for (i, segment) in rest.iter().enumerate() {
+            let is_last = i + 1 == rest.len();
+            if segment == "super" {
+                if current.len() < 2 {
+                    return None;
+                }
+                current.pop();
+                continue;
+            }
+            match self.lookup(&current, segment, want_module || !is_last, depth + 1)? {
+                target if is_last => return Some(target),
+                Target::Module(path) => current = path,
+                // E.g. an associated item.
+                Target::Type(..) => return None,
+            }
+        }
+        Some(Target::Module(current))
+    }
+
+    /// Returns the items of `module` with each path to a type of the parsed
+    /// crates replaced by the name of the type, and the colliding types
+    /// renamed. Nested modules are left untouched.
+    pub fn rewrite(&self, module: &ParsedModule) -> Vec<syn::Item> {
+        let mut rewriter = Rewriter {
+            tree: self,
+            module: &module.path,
+            generics: Vec::new(),
+            used: Vec::new(),
+        };
+        module
+            .items
+            .iter()
+            .map(|item| rewriter.fold_item(item.clone()))
+            .collect()
+    }
+}
+
+struct Rewriter<'a> {
+    tree: &'a ModuleTree,
+    module: &'a [String],
+    /// The names of the generic parameters in scope, which shadow types.
+    generics: Vec<Vec<String>>,
+    /// The types the rewritten paths resolve to.
+    used: Vec<TypeKey>,
+}
+
+impl<'a> Rewriter<'a> {
+    fn push_generics(&mut self, generics: &syn::Generics) {
+        self.generics.push(
+            generics
+                .params
+                .iter()
+                .filter_map(|param| match *param {
+                    syn::GenericParam::Type(ref param) => Some(param.ident.unraw().to_string()),
+                    syn::GenericParam::Const(ref param) => Some(param.ident.unraw().to_string()),
+                    syn::GenericParam::Lifetime(..) => None,
+                })
+                .collect(),
+        );
+    }
+
+    fn rename(&self, ident: &mut syn::Ident) {
+        let name = ident.unraw().to_string();
+        let final_name = self.tree.final_name(self.module, &name);
+        if final_name != name {
+            *ident = syn::Ident::new(final_name, ident.span());
+        }
+    }
+
+    fn rewrite_path(&mut self, path: &mut syn::Path) {
+        let Some(last) = path.segments.last() else {
+            return;
+        };
+        let len = path.segments.len();
+        if path
+            .segments
+            .iter()
+            .take(len - 1)
+            .any(|segment| !segment.arguments.is_empty())
+        {
+            return;
+        }
+        let segments: Vec<_> = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.unraw().to_string())
+            .collect();
+        if let [ref name] = segments[..] {
+            if self.generics.iter().flatten().any(|param| param == name) {
+                return;
+            }
+        }
+
+        let use_path = UsePath {
+            leading_colon: path.leading_colon.is_some(),
+            segments,
+        };
+        let Some(Target::Type(module, name)) = self.tree.resolve(self.module, &use_path, false, 0)
+        else {
+            return;
+        };
+        let final_name = self.tree.final_name(&module, &name);
+        self.used.push((module.clone(), name.clone()));
+        if use_path.segments.len() == 1 && final_name == use_path.segments[0] {
+            return;
+        }
+
+        let mut segment = last.clone();
+        segment.ident = syn::Ident::new(final_name, segment.ident.span());
+        path.leading_colon = None;
+        path.segments.clear();
+        path.segments.push(segment);
+    }
+}
+
+impl<'a> Fold for Rewriter<'a> {
+    fn fold_item_mod(&mut self, item: syn::ItemMod) -> syn::ItemMod {
+        item
+    }
+
+    fn fold_block(&mut self, block: syn::Block) -> syn::Block {
+        block
+    }
+
+    fn fold_item_struct(&mut self, mut item: syn::ItemStruct) -> syn::ItemStruct {
+        self.rename(&mut item.ident);
+        self.push_generics(&item.generics);
+        let item = fold::fold_item_struct(self, item);
+        self.generics.pop();
+        item
+    }
+
+    fn fold_item_enum(&mut self, mut item: syn::ItemEnum) -> syn::ItemEnum {
+        self.rename(&mut item.ident);
+        self.push_generics(&item.generics);
+        let item = fold::fold_item_enum(self, item);
+        self.generics.pop();
+        item
+    }
+
+    fn fold_item_union(&mut self, mut item: syn::ItemUnion) -> syn::ItemUnion {
+        self.rename(&mut item.ident);
+        self.push_generics(&item.generics);
+        let item = fold::fold_item_union(self, item);
+        self.generics.pop();
+        item
+    }
+
+    fn fold_item_type(&mut self, mut item: syn::ItemType) -> syn::ItemType {
+        self.rename(&mut item.ident);
+        self.push_generics(&item.generics);
+        let item = fold::fold_item_type(self, item);
+        self.generics.pop();
+        item
+    }
+
+    fn fold_item_impl(&mut self, item: syn::ItemImpl) -> syn::ItemImpl {
+        self.push_generics(&item.generics);
+        let item = fold::fold_item_impl(self, item);
+        self.generics.pop();
+        item
+    }
+
+    fn fold_signature(&mut self, sig: syn::Signature) -> syn::Signature {
+        self.push_generics(&sig.generics);
+        let sig = fold::fold_signature(self, sig);
+        self.generics.pop();
+        sig
+    }
+
+    fn fold_type_path(&mut self, mut ty: syn::TypePath) -> syn::TypePath {
+        if ty.qself.is_none() {
+            self.rewrite_path(&mut ty.path);
+        }
+        fold::fold_type_path(self, ty)
+    }
+
+    fn fold_expr_struct(&mut self, mut expr: syn::ExprStruct) -> syn::ExprStruct {
+        self.rewrite_path(&mut expr.path);
+        fold::fold_expr_struct(self, expr)
+    }
+}
@@ -25,6 +25,14 @@ impl Monomorphs {
         self.replacements.contains_key(path)
     }
 
+    /// Returns the path of each generic item with an instance, and the path
+    /// of the instance.
+    pub fn instances(&self) -> impl Iterator<Item = (&Path, &Path)> {
+        self.replacements
+            .iter()
+            .map(|(generic, instance)| (generic.path(), instance))
+    }
+
     pub fn insert_struct(
         &mut self,
         library: &Library,
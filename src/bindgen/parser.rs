@@ -8,17 +8,30 @@ use std::fs::File;
 use std::io::Read;
 use std::path::{Path as FilePath, PathBuf as FilePathBuf};
 
+use proc_macro2::Span;
+use quote::ToTokens;
 use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 
 use crate::bindgen::bitflags;
 use crate::bindgen::cargo::{Cargo, PackageRef};
-use crate::bindgen::config::{Config, ParseConfig};
+use crate::bindgen::cfg_attr;
+use crate::bindgen::config::{Config, Language, ParseConfig};
+use crate::bindgen::diagnostics::{self, Diagnostic, DiagnosticCode};
 use crate::bindgen::error::Error;
+use crate::bindgen::explain::{CrateFilter, Provenance};
 use crate::bindgen::ir::{
     AnnotationSet, AnnotationValue, Cfg, Constant, Documentation, Enum, Function, GenericParam,
     GenericParams, ItemMap, OpaqueItem, Path, Static, Struct, Type, Typedef, Union,
 };
-use crate::bindgen::utilities::{SynAbiHelpers, SynAttributeHelpers, SynItemHelpers};
+use crate::bindgen::macro_rules;
+use crate::bindgen::modules::{ModuleTree, ParsedModule};
+use crate::bindgen::target::{self, TargetCfg};
+use crate::bindgen::utilities::{
+    inherit_cfgs, parse_source, parse_source_file, SynAbiHelpers, SynAttributeHelpers,
+    SynItemHelpers,
+};
 
 const STD_CRATES: &[&str] = &[
     "std",
@@ -29,6 +42,9 @@ const STD_CRATES: &[&str] = &[
     "proc_macro",
 ];
 
+/// Bounds `include!()`s of files including themselves.
+const MAX_INCLUDE_DEPTH: usize = 32;
+
 type ParseResult = Result<Parse, Error>;
 
 /// Parses a single rust source file, not following `mod` or `extern crate`.
@@ -37,6 +53,11 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
     let mut config = config.clone();
     config.parse = ParseConfig {
         parse_deps: true,
+        name_collisions: config.parse.name_collisions,
+        module_prefixes: config.parse.module_prefixes.clone(),
+        out_dir: config.parse.out_dir.clone(),
+        target: config.parse.target.clone(),
+        cfg: config.parse.cfg.clone(),
         ..ParseConfig::default()
     };
 
@@ -48,6 +69,9 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
         cache_src: HashMap::new(),
         cache_expanded_crate: HashMap::new(),
         cfg_stack: Vec::new(),
+        current_file: None,
+        mod_stack: Vec::new(),
+        modules: Vec::new(),
         out: Parse::new(),
     };
 
@@ -57,6 +81,7 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
     };
 
     context.parse_mod(&pkg_ref, src_file, 0)?;
+    context.load_modules()?;
     context.out.source_files = context.cache_src.keys().map(|k| k.to_owned()).collect();
     Ok(context.out)
 }
@@ -75,11 +100,15 @@ pub(crate) fn parse_lib(lib: Cargo, config: &Config) -> ParseResult {
         cache_src: HashMap::new(),
         cache_expanded_crate: HashMap::new(),
         cfg_stack: Vec::new(),
+        current_file: None,
+        mod_stack: Vec::new(),
+        modules: Vec::new(),
         out: Parse::new(),
     };
 
     let binding_crate = context.lib.as_ref().unwrap().binding_crate_ref();
     context.parse_crate(&binding_crate)?;
+    context.load_modules()?;
     context.out.source_files = context.cache_src.keys().map(|k| k.to_owned()).collect();
     context.out.package_version = context
         .lib
@@ -91,6 +120,44 @@ pub(crate) fn parse_lib(lib: Cargo, config: &Config) -> ParseResult {
     Ok(context.out)
 }
 
+/// Loads the items of `modules`, which are in parse order, into `out` once
+/// their type paths are resolved through the modules.
+pub(crate) fn load_modules(
+    out: &mut Parse,
+    config: &Config,
+    binding_crate_name: &str,
+    modules: &[ParsedModule],
+) -> Result<(), Error> {
+    let expanded;
+    let modules = if config.macro_expansion.macro_rules {
+        expanded = macro_rules::expand_modules(modules);
+        &expanded
+    } else {
+        modules
+    };
+    let evaluated;
+    let modules = match TargetCfg::load(&config.parse)? {
+        Some(target) => {
+            evaluated = target::evaluate_cfgs(modules, &target);
+            &evaluated
+        }
+        None => modules,
+    };
+    let modules = &cfg_attr::expand_modules(modules);
+    let tree = ModuleTree::new(modules, config, binding_crate_name)?;
+    for module in modules {
+        let items = tree.rewrite(module);
+        out.load_syn_crate_mod(
+            config,
+            binding_crate_name,
+            &module.crate_name,
+            module.cfg.as_ref(),
+            &items,
+        );
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 struct Parser<'a> {
     binding_crate_name: String,
@@ -102,23 +169,37 @@ struct Parser<'a> {
     cache_expanded_crate: HashMap<String, Vec<syn::Item>>,
 
     cfg_stack: Vec<Cfg>,
+    /// The file being parsed, if any.
+    current_file: Option<FilePathBuf>,
+    /// The path of the current module inside its crate.
+    mod_stack: Vec<String>,
+    /// The modules parsed so far, loaded once all the crates are parsed.
+    modules: Vec<ParsedModule>,
 
     out: Parse,
 }
 
 impl<'a> Parser<'a> {
-    fn should_parse_dependency(&self, pkg_name: &str) -> bool {
+    fn should_parse_dependency(&mut self, pkg_name: &str) -> bool {
         if self.parsed_crates.contains(pkg_name) {
             return false;
         }
 
-        if !self.config.parse.parse_deps {
-            return false;
+        let config = self.config;
+        let unparsed = &mut self.out.provenance.unparsed_crates;
+        let mut skip = |filter| {
+            if !unparsed.iter().any(|(name, _)| name == pkg_name) {
+                unparsed.push((pkg_name.to_owned(), filter));
+            }
+            false
+        };
+
+        if !config.parse.parse_deps {
+            return skip(CrateFilter::NoDeps);
         }
 
         // Skip any whitelist or blacklist for expand
-        if self
-            .config
+        if config
             .parse
             .expand
             .crates
@@ -129,21 +210,21 @@ impl<'a> Parser<'a> {
         }
 
         // If we have a whitelist, check it
-        if let Some(ref include) = self.config.parse.include {
+        if let Some(ref include) = config.parse.include {
             if !include.iter().any(|name| name == pkg_name) {
                 debug!("Excluding crate {}", pkg_name);
-                return false;
+                return skip(CrateFilter::NotIncluded);
             }
         }
 
         // Check the blacklist
-        !STD_CRATES.contains(&pkg_name)
-            && !self
-                .config
-                .parse
-                .exclude
-                .iter()
-                .any(|name| name == pkg_name)
+        if STD_CRATES.contains(&pkg_name) {
+            return false;
+        }
+        if config.parse.exclude.iter().any(|name| name == pkg_name) {
+            return skip(CrateFilter::Excluded);
+        }
+        true
     }
 
     fn parse_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
@@ -164,12 +245,16 @@ impl<'a> Parser<'a> {
                 Some(crate_src) => self.parse_mod(pkg, crate_src.as_path(), 0)?,
                 None => {
                     // This should be an error, but is common enough to just elicit a warning
-                    warn!(
-                        "Parsing crate `{}`: can't find lib.rs with `cargo metadata`. \
-                        The crate may be available only on a particular platform, \
-                        so consider setting `fetch_all_dependencies` in your cbindgen configuration.",
-                        pkg.name
-                    );
+                    diagnostics::report(Diagnostic::warning(
+                        DiagnosticCode::MissingSource,
+                        format!(
+                            "parsing crate `{}`: can't find lib.rs with `cargo metadata`. \
+                             The crate may be available only on a particular platform, \
+                             so consider setting `fetch_all_dependencies` in your cbindgen \
+                             configuration",
+                            pkg.name
+                        ),
+                    ));
                 }
             }
         }
@@ -193,6 +278,15 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
+    fn load_modules(&mut self) -> Result<(), Error> {
+        load_modules(
+            &mut self.out,
+            self.config,
+            &self.binding_crate_name,
+            &self.modules,
+        )
+    }
+
     fn parse_expand_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
         assert!(self.lib.is_some());
 
@@ -210,7 +304,7 @@ impl<'a> Parser<'a> {
                         self.config.parse.expand.profile,
                     )
                     .map_err(|x| Error::CargoExpand(pkg.name.clone(), x))?;
-                let i = syn::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
+                let i = parse_source(&s).map_err(|x| Error::ParseSyntaxError {
                     crate_name: pkg.name.clone(),
                     src_path: "".to_owned(),
                     error: x,
@@ -233,30 +327,7 @@ impl<'a> Parser<'a> {
         mod_path: &FilePath,
         depth: usize,
     ) -> Result<(), Error> {
-        let mod_items = match self.cache_src.entry(mod_path.to_path_buf()) {
-            Entry::Vacant(vacant_entry) => {
-                let mut s = String::new();
-                let mut f = File::open(mod_path).map_err(|_| Error::ParseCannotOpenFile {
-                    crate_name: pkg.name.clone(),
-                    src_path: mod_path.to_str().unwrap().to_owned(),
-                })?;
-
-                f.read_to_string(&mut s)
-                    .map_err(|_| Error::ParseCannotOpenFile {
-                        crate_name: pkg.name.clone(),
-                        src_path: mod_path.to_str().unwrap().to_owned(),
-                    })?;
-
-                let i = syn::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
-                    crate_name: pkg.name.clone(),
-                    src_path: mod_path.to_string_lossy().into(),
-                    error: x,
-                })?;
-
-                vacant_entry.insert(i.items).clone()
-            }
-            Entry::Occupied(occupied_entry) => occupied_entry.get().clone(),
-        };
+        let mod_items = self.load_file(pkg, mod_path)?;
 
         // Compute module directory according to Rust 2018 rules
         let submod_dir_2018;
@@ -274,7 +345,8 @@ impl<'a> Parser<'a> {
             &submod_dir_2018
         };
 
-        self.process_mod(
+        let parent_file = self.current_file.replace(mod_path.to_path_buf());
+        let result = self.process_mod(
             pkg,
             Some(mod_dir),
             Some(submod_dir),
@@ -282,7 +354,177 @@ impl<'a> Parser<'a> {
             depth,
             /* is_inline = */ false,
             is_mod_rs,
-        )
+        );
+        self.current_file = parent_file;
+        result
+    }
+
+    /// Parses a source file, once.
+    fn load_file(&mut self, pkg: &PackageRef, path: &FilePath) -> Result<Vec<syn::Item>, Error> {
+        Ok(match self.cache_src.entry(path.to_path_buf()) {
+            Entry::Vacant(vacant_entry) => {
+                let mut s = String::new();
+                let mut f = File::open(path).map_err(|_| Error::ParseCannotOpenFile {
+                    crate_name: pkg.name.clone(),
+                    src_path: path.to_str().unwrap().to_owned(),
+                })?;
+
+                f.read_to_string(&mut s)
+                    .map_err(|_| Error::ParseCannotOpenFile {
+                        crate_name: pkg.name.clone(),
+                        src_path: path.to_str().unwrap().to_owned(),
+                    })?;
+
+                let i = parse_source_file(path, &s).map_err(|x| Error::ParseSyntaxError {
+                    crate_name: pkg.name.clone(),
+                    src_path: path.to_string_lossy().into(),
+                    error: x,
+                })?;
+
+                vacant_entry.insert(i.items).clone()
+            }
+            Entry::Occupied(occupied_entry) => occupied_entry.get().clone(),
+        })
+    }
+
+    /// Evaluates the argument of an `include!()`: a string literal, or a
+    /// `concat!()` of literals and `env!()`s.
+    fn eval_include_arg(&self, pkg: &PackageRef, expr: &syn::Expr) -> Result<String, String> {
+        match *expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(ref lit),
+                ..
+            }) => Ok(lit.value()),
+            syn::Expr::Macro(ref expr) if expr.mac.path.is_ident("concat") => {
+                let args = expr
+                    .mac
+                    .parse_body_with(Punctuated::<syn::Expr, Token![,]>::parse_terminated)
+                    .map_err(|e| e.to_string())?;
+                let mut value = String::new();
+                for arg in &args {
+                    value += &self.eval_include_arg(pkg, arg)?;
+                }
+                Ok(value)
+            }
+            syn::Expr::Macro(ref expr) if expr.mac.path.is_ident("env") => {
+                let name = expr
+                    .mac
+                    .parse_body_with(Punctuated::<syn::LitStr, Token![,]>::parse_terminated)
+                    .map_err(|e| e.to_string())?
+                    .first()
+                    .ok_or("`env!` without a variable name")?
+                    .value();
+                if name == "OUT_DIR" {
+                    return self.out_dir(pkg);
+                }
+                std::env::var(&name).map_err(|_| format!("`{}` isn't set", name))
+            }
+            _ => Err(format!("can't evaluate `{}`", expr.to_token_stream())),
+        }
+    }
+
+    /// Finds the `OUT_DIR` of a crate's build script.
+    fn out_dir(&self, pkg: &PackageRef) -> Result<String, String> {
+        // A configured directory is relative to the config file.
+        let configured = self.config.parse.out_dir.as_ref().map(|dir| {
+            match self.config.config_path.as_ref().and_then(|p| p.parent()) {
+                Some(config_dir) => config_dir.join(dir),
+                None => dir.clone(),
+            }
+        });
+        let out_dir = configured
+            .or_else(|| std::env::var_os("OUT_DIR").map(FilePathBuf::from))
+            .or_else(|| self.lib.as_ref()?.find_out_dir(pkg))
+            .ok_or(
+                "`OUT_DIR` is unknown, set it in the environment or `out_dir` in the \
+                 `[parse]` section of the config, or build the crate first",
+            )?;
+        Ok(out_dir.to_string_lossy().into_owned())
+    }
+
+    /// Replaces the `include!()`s of `items` with the items of the files they
+    /// include, relative to the current file.
+    fn expand_includes(
+        &mut self,
+        pkg: &PackageRef,
+        items: &[syn::Item],
+        depth: usize,
+    ) -> Result<Vec<syn::Item>, Error> {
+        let mut expanded = Vec::with_capacity(items.len());
+        for item in items {
+            let mac = match *item {
+                syn::Item::Macro(ref mac)
+                    if mac.ident.is_none() && mac.mac.path.is_ident("include") =>
+                {
+                    mac
+                }
+                _ => {
+                    expanded.push(item.clone());
+                    continue;
+                }
+            };
+            if depth >= MAX_INCLUDE_DEPTH {
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::MissingSource,
+                        format!(
+                            "parsing crate `{}`: too many nested `include!()`s",
+                            pkg.name
+                        ),
+                    )
+                    .with_span(mac.mac.path.span()),
+                );
+                continue;
+            }
+
+            let path = match mac
+                .mac
+                .parse_body::<syn::Expr>()
+                .map_err(|e| e.to_string())
+                .and_then(|arg| self.eval_include_arg(pkg, &arg))
+            {
+                Ok(path) => FilePathBuf::from(path),
+                Err(e) => {
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::MissingSource,
+                            format!(
+                                "parsing crate `{}`: can't resolve an `include!()`: {}",
+                                pkg.name, e
+                            ),
+                        )
+                        .with_span(mac.mac.path.span()),
+                    );
+                    continue;
+                }
+            };
+            // Like `mod`s, relative paths are relative to the current file.
+            let path = match self.current_file {
+                Some(ref file) if path.is_relative() => file.parent().unwrap().join(path),
+                _ => path,
+            };
+            if !path.exists() {
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::MissingSource,
+                        format!(
+                            "parsing crate `{}`: can't find included file {:?}",
+                            pkg.name, path
+                        ),
+                    )
+                    .with_span(mac.mac.path.span()),
+                );
+                continue;
+            }
+
+            let mut included = self.load_file(pkg, &path)?;
+            inherit_cfgs(&mac.attrs, &mut included);
+            let parent_file = self.current_file.replace(path);
+            let included = self.expand_includes(pkg, &included, depth + 1);
+            self.current_file = parent_file;
+            expanded.extend(included?);
+        }
+        Ok(expanded)
     }
 
     /// `mod_dir` is the path to the current directory of the module. It may be
@@ -302,21 +544,28 @@ impl<'a> Parser<'a> {
         is_in_mod_rs: bool,
     ) -> Result<(), Error> {
         debug_assert_eq!(mod_dir.is_some(), submod_dir.is_some());
-        // We process the items first then the nested modules.
-        let nested_modules = self.out.load_syn_crate_mod(
-            self.config,
-            &self.binding_crate_name,
-            &pkg.name,
-            Cfg::join(&self.cfg_stack).as_ref(),
-            items,
-        );
+        let items = &self.expand_includes(pkg, items, 0)?;
+        // We record the items first then the nested modules.
+        let mut path = vec![pkg.name.replace('-', "_")];
+        path.extend(self.mod_stack.iter().cloned());
+        self.modules.push(ParsedModule {
+            crate_name: pkg.name.clone(),
+            path,
+            cfg: Cfg::join(&self.cfg_stack),
+            items: items.to_vec(),
+        });
 
+        let nested_modules = items.iter().filter_map(|item| match *item {
+            syn::Item::Mod(ref item) if !item.should_skip_parsing() => Some(item),
+            _ => None,
+        });
         for item in nested_modules {
             let next_mod_name = item.ident.unraw().to_string();
             let cfg = Cfg::load(&item.attrs);
             if let Some(ref cfg) = cfg {
                 self.cfg_stack.push(cfg.clone());
             }
+            self.mod_stack.push(next_mod_name.clone());
 
             if let Some((_, ref inline_items)) = item.content {
                 // TODO(emilio): This should use #[path] attribute if present,
@@ -383,19 +632,32 @@ impl<'a> Parser<'a> {
                     // This should be an error, but it's common enough to
                     // just elicit a warning
                     if !path_attr_found {
-                        warn!(
-                            "Parsing crate `{}`: can't find mod {}`.",
-                            pkg.name, next_mod_name
+                        diagnostics::report(
+                            Diagnostic::warning(
+                                DiagnosticCode::MissingSource,
+                                format!(
+                                    "parsing crate `{}`: can't find mod `{}`",
+                                    pkg.name, next_mod_name
+                                ),
+                            )
+                            .with_span(item.ident.span()),
                         );
                     }
                 }
             } else {
-                warn!(
-                    "Parsing expanded crate `{}`: can't find mod {}`.",
-                    pkg.name, next_mod_name
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::MissingSource,
+                        format!(
+                            "parsing expanded crate `{}`: can't find mod `{}`",
+                            pkg.name, next_mod_name
+                        ),
+                    )
+                    .with_span(item.ident.span()),
                 );
             }
 
+            self.mod_stack.pop();
             if cfg.is_some() {
                 self.cfg_stack.pop();
             }
@@ -417,6 +679,50 @@ pub struct Parse {
     pub functions: Vec<Function>,
     pub source_files: Vec<FilePathBuf>,
     pub package_version: String,
+    pub(crate) provenance: Provenance,
+}
+
+/// Warns about a function whose calling convention would be lost, as
+/// `[fn] abi_macros` doesn't have a macro for it.
+fn warn_unmapped_abi(config: &Config, func: &Function, span: Span) {
+    // The other languages write the calling conventions themselves.
+    if !matches!(
+        config.language,
+        Language::Cxx | Language::C | Language::Cython
+    ) {
+        return;
+    }
+    if let Some(ref abi) = func.abi {
+        if config.function.abi_macro(Some(abi)).is_none() {
+            diagnostics::report(
+                Diagnostic::warning(
+                    DiagnosticCode::UnmappedAbi,
+                    format!(
+                        "`{}` uses the \"{}\" calling convention, which has no macro in \
+                         `[fn] abi_macros`",
+                        func.path, abi
+                    ),
+                )
+                .with_item(&func.path)
+                .with_span(span),
+            );
+        }
+    }
+}
+
+/// Returns the name of `item`, if cbindgen loads items of its kind.
+fn item_name(item: &syn::Item) -> Option<String> {
+    let ident = match *item {
+        syn::Item::Fn(ref item) => &item.sig.ident,
+        syn::Item::Const(ref item) => &item.ident,
+        syn::Item::Static(ref item) => &item.ident,
+        syn::Item::Struct(ref item) => &item.ident,
+        syn::Item::Union(ref item) => &item.ident,
+        syn::Item::Enum(ref item) => &item.ident,
+        syn::Item::Type(ref item) => &item.ident,
+        _ => return None,
+    };
+    Some(ident.unraw().to_string())
 }
 
 impl Parse {
@@ -432,6 +738,7 @@ impl Parse {
             functions: Vec::new(),
             source_files: Vec::new(),
             package_version: String::new(),
+            provenance: Provenance::default(),
         }
     }
 
@@ -481,21 +788,26 @@ impl Parse {
         self.functions.extend_from_slice(&other.functions);
         self.source_files.extend_from_slice(&other.source_files);
         self.package_version = other.package_version.clone();
+        self.provenance.extend_with(&other.provenance);
     }
 
-    fn load_syn_crate_mod<'a>(
+    fn load_syn_crate_mod(
         &mut self,
         config: &Config,
         binding_crate_name: &str,
         crate_name: &str,
         mod_cfg: Option<&Cfg>,
-        items: &'a [syn::Item],
-    ) -> Vec<&'a syn::ItemMod> {
+        items: &[syn::Item],
+    ) {
         let mut impls_with_assoc_consts = Vec::new();
-        let mut nested_modules = Vec::new();
 
         for item in items {
             if item.should_skip_parsing() {
+                if item.is_ignored() {
+                    if let Some(name) = item_name(item) {
+                        self.provenance.ignored.insert(name);
+                    }
+                }
                 continue;
             }
             match item {
@@ -559,9 +871,6 @@ impl Parse {
                 syn::Item::Macro(ref item) => {
                     self.load_builtin_macro(config, crate_name, mod_cfg, item);
                 }
-                syn::Item::Mod(ref item) => {
-                    nested_modules.push(item);
-                }
                 _ => {}
             }
         }
@@ -569,8 +878,6 @@ impl Parse {
         for item_impl in impls_with_assoc_consts {
             self.load_syn_assoc_consts_from_impl(crate_name, mod_cfg, item_impl)
         }
-
-        nested_modules
     }
 
     fn load_syn_assoc_consts_from_impl(
@@ -601,7 +908,10 @@ impl Parse {
         item: &syn::ItemForeignMod,
     ) {
         if !item.abi.is_c() && !item.abi.is_omitted() {
-            info!("Skip {} - (extern block must be extern C).", crate_name);
+            info!(
+                "Skip {} - (extern block must have a C calling convention).",
+                crate_name
+            );
             return;
         }
 
@@ -623,19 +933,25 @@ impl Parse {
                     path,
                     None,
                     &function.sig,
+                    item.abi.calling_convention(),
                     true,
                     &function.attrs,
                     mod_cfg.as_ref(),
                 ) {
                     Ok(func) => {
                         info!("Take {}::{}.", crate_name, &function.sig.ident);
+                        warn_unmapped_abi(config, &func, function.sig.ident.span());
 
                         self.functions.push(func);
                     }
                     Err(msg) => {
-                        error!(
-                            "Cannot use fn {}::{} ({}).",
-                            crate_name, &function.sig.ident, msg
+                        diagnostics::report(
+                            Diagnostic::warning(
+                                DiagnosticCode::UnsupportedFn,
+                                format!("cannot use fn `{}`: {}", function.sig.ident, msg),
+                            )
+                            .with_item(format!("{}::{}", crate_name, function.sig.ident))
+                            .with_span(function.sig.ident.span()),
                         );
                     }
                 }
@@ -725,24 +1041,47 @@ impl Parse {
         match (is_extern_c, exported_name) {
             (true, Some(exported_name)) => {
                 let path = Path::new(exported_name);
-                match Function::load(path, self_type, sig, false, attrs, mod_cfg) {
+                let abi = sig.abi.calling_convention();
+                match Function::load(path, self_type, sig, abi, false, attrs, mod_cfg) {
                     Ok(func) => {
                         info!("Take {}.", loggable_item_name());
+                        warn_unmapped_abi(config, &func, sig.ident.span());
                         self.functions.push(func);
                     }
                     Err(msg) => {
-                        error!("Cannot use fn {} ({}).", loggable_item_name(), msg);
+                        diagnostics::report(
+                            Diagnostic::warning(
+                                DiagnosticCode::UnsupportedFn,
+                                format!("cannot use fn `{}`: {}", sig.ident, msg),
+                            )
+                            .with_item(loggable_item_name())
+                            .with_span(sig.ident.span()),
+                        );
                     }
                 }
             }
             (true, None) => {
-                warn!(
-                    "Skipping {} - (not `no_mangle`, and has no `export_name` attribute)",
-                    loggable_item_name()
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::UnexportedFn,
+                        format!(
+                            "skipping `{}`: not `no_mangle`, and has no `export_name` attribute",
+                            sig.ident
+                        ),
+                    )
+                    .with_item(loggable_item_name())
+                    .with_span(sig.ident.span()),
                 );
             }
             (false, Some(_exported_name)) => {
-                warn!("Skipping {} - (not `extern \"C\"`", loggable_item_name());
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::NonCAbi,
+                        format!("skipping `{}`: not `extern \"C\"`", sig.ident),
+                    )
+                    .with_item(loggable_item_name())
+                    .with_span(sig.ident.span()),
+                );
             }
             (false, None) => {}
         }
@@ -761,7 +1100,14 @@ impl Parse {
         let ty = match Type::load(impl_ty) {
             Ok(ty) => ty,
             Err(e) => {
-                warn!("Skipping associated constants for {:?}: {:?}", impl_ty, e);
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::UnsupportedConstant,
+                        format!("skipping associated constants: {}", e),
+                    )
+                    .with_item(impl_ty.to_token_stream())
+                    .with_span(impl_ty.span()),
+                );
                 return;
             }
         };
@@ -774,9 +1120,13 @@ impl Parse {
         let impl_path = match ty.get_root_path() {
             Some(p) => p,
             None => {
-                warn!(
-                    "Couldn't find path for {:?}, skipping associated constants",
-                    ty
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::UnsupportedConstant,
+                        "couldn't find the path of the type, skipping associated constants",
+                    )
+                    .with_item(impl_ty.to_token_stream())
+                    .with_span(impl_ty.span()),
                 );
                 return;
             }
@@ -785,7 +1135,14 @@ impl Parse {
         for item in items.into_iter() {
             if let syn::Visibility::Public(_) = item.vis {
             } else {
-                warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::UnsupportedConstant,
+                        format!("skipping `{}`: not `pub`", item.ident),
+                    )
+                    .with_item(format!("{}::{}", crate_name, item.ident))
+                    .with_span(item.ident.span()),
+                );
                 return;
             }
 
@@ -808,14 +1165,25 @@ impl Parse {
                     // Handle associated constants to other item types that are
                     // not structs like enums or such as regular constants.
                     if !any && !self.constants.try_insert(constant) {
-                        error!(
-                            "Conflicting name for constant {}::{}::{}.",
-                            crate_name, impl_path, &item.ident,
+                        diagnostics::report(
+                            Diagnostic::warning(
+                                DiagnosticCode::ConflictingConstant,
+                                format!("conflicting name for constant `{}`", item.ident),
+                            )
+                            .with_item(format!("{}::{}::{}", crate_name, impl_path, item.ident))
+                            .with_span(item.ident.span()),
                         );
                     }
                 }
                 Err(msg) => {
-                    warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::UnsupportedConstant,
+                            format!("skipping `{}`: {}", item.ident, msg),
+                        )
+                        .with_item(format!("{}::{}", crate_name, item.ident))
+                        .with_span(item.ident.span()),
+                    );
                 }
             }
         }
@@ -843,7 +1211,14 @@ impl Parse {
 
         if let syn::Visibility::Public(_) = item.vis {
         } else {
-            warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+            diagnostics::report(
+                Diagnostic::warning(
+                    DiagnosticCode::UnsupportedConstant,
+                    format!("skipping `{}`: not `pub`", item.ident),
+                )
+                .with_item(format!("{}::{}", crate_name, item.ident))
+                .with_span(item.ident.span()),
+            );
             return;
         }
 
@@ -854,11 +1229,25 @@ impl Parse {
 
                 let full_name = constant.path.clone();
                 if !self.constants.try_insert(constant) {
-                    error!("Conflicting name for constant {}", full_name);
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::ConflictingConstant,
+                            format!("conflicting name for constant `{}`", full_name),
+                        )
+                        .with_item(full_name)
+                        .with_span(item.ident.span()),
+                    );
                 }
             }
             Err(msg) => {
-                warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::UnsupportedConstant,
+                        format!("skipping `{}`: {}", item.ident, msg),
+                    )
+                    .with_item(format!("{}::{}", crate_name, item.ident))
+                    .with_span(item.ident.span()),
+                );
             }
         }
     }
@@ -891,11 +1280,25 @@ impl Parse {
                     self.globals.try_insert(constant);
                 }
                 Err(msg) => {
-                    warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                    diagnostics::report(
+                        Diagnostic::warning(
+                            DiagnosticCode::UnsupportedStatic,
+                            format!("skipping `{}`: {}", item.ident, msg),
+                        )
+                        .with_item(format!("{}::{}", crate_name, item.ident))
+                        .with_span(item.ident.span()),
+                    );
                 }
             }
         } else {
-            warn!("Skip {}::{} - (not `no_mangle`).", crate_name, &item.ident);
+            diagnostics::report(
+                Diagnostic::warning(
+                    DiagnosticCode::UnsupportedStatic,
+                    format!("skipping `{}`: not `no_mangle`", item.ident),
+                )
+                .with_item(format!("{}::{}", crate_name, item.ident))
+                .with_span(item.ident.span()),
+            );
         }
     }
 
@@ -915,6 +1318,7 @@ impl Parse {
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
                 let path = Path::new(item.ident.unraw().to_string());
+                self.provenance.opaque.insert(path.name().to_owned(), msg);
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
                 );
@@ -939,6 +1343,7 @@ impl Parse {
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
                 let path = Path::new(item.ident.unraw().to_string());
+                self.provenance.opaque.insert(path.name().to_owned(), msg);
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
                 );
@@ -962,6 +1367,8 @@ impl Parse {
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
                 let path = Path::new(item.ident.unraw().to_string());
+                self.provenance.opaque.insert(path.name().to_owned(), msg);
+                self.provenance.opaque_enums.insert(path.name().to_owned());
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
                 );
@@ -980,6 +1387,7 @@ impl Parse {
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
                 let path = Path::new(item.ident.unraw().to_string());
+                self.provenance.opaque.insert(path.name().to_owned(), msg);
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
                 );
@@ -1006,7 +1414,13 @@ impl Parse {
         let bitflags = match bitflags::parse(item.mac.tokens.clone()) {
             Ok(bf) => bf,
             Err(e) => {
-                warn!("Failed to parse bitflags invocation: {:?}", e);
+                diagnostics::report(
+                    Diagnostic::warning(
+                        DiagnosticCode::InvalidMacro,
+                        format!("failed to parse `bitflags!` invocation: {}", e),
+                    )
+                    .with_span(item.mac.path.span()),
+                );
                 return;
             }
         };
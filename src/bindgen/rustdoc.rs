@@ -0,0 +1,959 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A front-end reading the crates from rustdoc JSON documents
+//! (`rustdoc --output-format json`) instead of their source, see
+//! `Builder::with_rustdoc_json`.
+//!
+//! rustdoc already expanded the macros and evaluated the `cfg`s. Each item of
+//! a document is turned back into a Rust declaration, with its type paths
+//! written out in full, and loaded like a parsed one.
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::BufReader;
+use std::path;
+
+use serde_json::{Map, Value};
+
+use crate::bindgen::config::Config;
+use crate::bindgen::error::Error;
+use crate::bindgen::modules::ParsedModule;
+use crate::bindgen::parser::{self, Parse};
+use crate::bindgen::utilities::parse_source;
+
+/// The newest version of the rustdoc JSON format this was tested with. Newer
+/// documents are read anyway, as the format rarely changes in the parts used
+/// here.
+const MAX_FORMAT_VERSION: u64 = 57;
+
+/// Loads the crates of the rustdoc JSON documents at `paths`, the first one
+/// being the crate to generate bindings for.
+pub(crate) fn parse_rustdoc_json(paths: &[path::PathBuf], config: &Config) -> Result<Parse, Error> {
+    let mut documents = Vec::with_capacity(paths.len());
+    for path in paths {
+        let rustdoc_json_error = |message: String| Error::RustdocJson {
+            path: path.display().to_string(),
+            message,
+        };
+        let file = File::open(path).map_err(|e| rustdoc_json_error(e.to_string()))?;
+        let document: Value = serde_json::from_reader(BufReader::new(file))
+            .map_err(|e| rustdoc_json_error(e.to_string()))?;
+        let document = Document::new(path, &document)
+            .map_err(rustdoc_json_error)?
+            .load();
+        documents.push(document);
+    }
+
+    let mut out = Parse::new();
+    let Some((binding_crate_name, package_version, _)) = documents.first() else {
+        return Ok(out);
+    };
+    let modules: Vec<_> = documents
+        .iter()
+        .flat_map(|(_, _, modules)| modules.iter().cloned())
+        .collect();
+    parser::load_modules(&mut out, config, binding_crate_name, &modules)?;
+    out.source_files = paths.to_vec();
+    out.package_version = package_version.clone();
+    Ok(out)
+}
+
+/// Returns the key of an item of the index. Ids are numbers in recent
+/// versions of the format and strings in older ones.
+fn id_key(id: &Value) -> Option<String> {
+    match *id {
+        Value::Number(ref id) => Some(id.to_string()),
+        Value::String(ref id) => Some(id.clone()),
+        _ => None,
+    }
+}
+
+/// Returns the single key of an externally tagged enum, and its value.
+fn variant(value: &Value) -> Option<(&str, &Value)> {
+    match *value {
+        Value::String(ref name) => Some((name, &Value::Null)),
+        Value::Object(ref object) if object.len() == 1 => {
+            object.iter().next().map(|(k, v)| (k.as_str(), v))
+        }
+        _ => None,
+    }
+}
+
+/// Returns `name` as an identifier, raw if it is a keyword.
+fn ident(name: &str) -> String {
+    if name == "_" || syn::parse_str::<syn::Ident>(name).is_ok() {
+        name.to_owned()
+    } else {
+        format!("r#{}", name)
+    }
+}
+
+fn str_field<'a>(value: &'a Value, field: &str) -> Option<&'a str> {
+    value.get(field).and_then(Value::as_str)
+}
+
+fn bool_field(value: &Value, field: &str) -> bool {
+    value.get(field).and_then(Value::as_bool).unwrap_or(false)
+}
+
+fn array_field<'a>(value: &'a Value, field: &str) -> &'a [Value] {
+    value
+        .get(field)
+        .and_then(Value::as_array)
+        .map_or(&[], |array| &array[..])
+}
+
+/// Returns the Rust string for the name of an ABI.
+fn abi(abi: &Value) -> Option<String> {
+    let (name, options) = variant(abi)?;
+    let unwind = if bool_field(options, "unwind") {
+        "-unwind"
+    } else {
+        ""
+    };
+    let name = match name {
+        "Rust" => return None,
+        "C" => "C",
+        "Cdecl" => "cdecl",
+        "Stdcall" => "stdcall",
+        "Fastcall" => "fastcall",
+        "Aapcs" => "aapcs",
+        "Win64" => "win64",
+        "SysV64" => "sysv64",
+        "System" => "system",
+        "Other" => return Some(options.as_str()?.trim_matches('"').to_owned()),
+        _ => return Some(name.to_owned()),
+    };
+    Some(format!("{}{}", name, unwind))
+}
+
+struct Document<'a> {
+    path: &'a path::Path,
+    /// The name of the crate as written in Rust paths.
+    crate_name: String,
+    root: &'a Value,
+    index: &'a Map<String, Value>,
+    paths: Option<&'a Map<String, Value>>,
+    crate_version: String,
+}
+
+impl<'a> Document<'a> {
+    fn new(path: &'a path::Path, document: &'a Value) -> Result<Document<'a>, String> {
+        let format_version = document
+            .get("format_version")
+            .and_then(Value::as_u64)
+            .ok_or("not a rustdoc JSON document")?;
+        if format_version > MAX_FORMAT_VERSION {
+            warn!(
+                "{} uses version {} of the rustdoc JSON format, which is newer than the \
+                 version {} cbindgen was tested with.",
+                path.display(),
+                format_version,
+                MAX_FORMAT_VERSION
+            );
+        }
+        if !bool_field(document, "includes_private") {
+            warn!(
+                "{} doesn't include private items. Types with private fields will be \
+                 opaque; generate it with `--document-private-items` to avoid this.",
+                path.display()
+            );
+        }
+
+        let index = document
+            .get("index")
+            .and_then(Value::as_object)
+            .ok_or("missing index")?;
+        let root = document
+            .get("root")
+            .and_then(id_key)
+            .and_then(|root| index.get(&root))
+            .ok_or("missing root module")?;
+        let crate_name = str_field(root, "name")
+            .ok_or("the root module has no name")?
+            .to_owned();
+        Ok(Document {
+            path,
+            crate_name,
+            root,
+            index,
+            paths: document.get("paths").and_then(Value::as_object),
+            crate_version: str_field(document, "crate_version")
+                .unwrap_or_default()
+                .to_owned(),
+        })
+    }
+
+    fn item(&self, id: &Value) -> Option<&'a Value> {
+        self.index.get(&id_key(id)?)
+    }
+
+    /// Returns the kind of `item` and its details.
+    fn inner(item: &Value) -> Option<(&str, &Value)> {
+        variant(item.get("inner")?)
+    }
+
+    /// Returns the name of the crate, its version and its modules in parse
+    /// order.
+    fn load(&self) -> (String, String, Vec<ParsedModule>) {
+        let mut reachable = HashSet::new();
+        self.reachable_items(self.root, &mut reachable);
+        let mut modules = Vec::new();
+        self.load_module(
+            self.root,
+            vec![self.crate_name.clone()],
+            &mut reachable,
+            &mut modules,
+        );
+        (self.crate_name.clone(), self.crate_version.clone(), modules)
+    }
+
+    /// Collects the ids of the items of `module` and its submodules.
+    fn reachable_items(&self, module: &Value, reachable: &mut HashSet<String>) {
+        let Some(("module", details)) = Self::inner(module) else {
+            return;
+        };
+        for id in array_field(details, "items") {
+            let Some(key) = id_key(id) else {
+                continue;
+            };
+            if !reachable.insert(key) {
+                continue;
+            }
+            if let Some(item) = self.item(id) {
+                self.reachable_items(item, reachable);
+            }
+        }
+    }
+
+    fn load_module(
+        &self,
+        module: &Value,
+        path: Vec<String>,
+        reachable: &mut HashSet<String>,
+        modules: &mut Vec<ParsedModule>,
+    ) {
+        let Some(("module", details)) = Self::inner(module) else {
+            return;
+        };
+
+        let mut items = Vec::new();
+        let mut submodules = Vec::new();
+        for id in array_field(details, "items") {
+            let Some(item) = self.item(id) else {
+                continue;
+            };
+            match Self::inner(item) {
+                Some(("module", _)) => submodules.push(item),
+                Some(("use", import)) | Some(("import", import)) => {
+                    // rustdoc may document an item only where it is
+                    // re-exported, e.g. when its module is private.
+                    let target = import.get("id").filter(|_| !bool_field(import, "is_glob"));
+                    if let Some((key, target)) =
+                        target.and_then(|id| Some((id_key(id)?, self.item(id)?)))
+                    {
+                        if !matches!(Self::inner(target), Some(("module", _)))
+                            && reachable.insert(key)
+                        {
+                            items.extend(self.items(target));
+                        }
+                    }
+                    items.extend(self.items(item));
+                }
+                _ => items.extend(self.items(item)),
+            }
+        }
+        modules.push(ParsedModule {
+            crate_name: self.crate_name.clone(),
+            path: path.clone(),
+            cfg: None,
+            items,
+        });
+
+        for submodule in submodules {
+            let Some(name) = str_field(submodule, "name") else {
+                continue;
+            };
+            let mut path = path.clone();
+            path.push(name.to_owned());
+            self.load_module(submodule, path, reachable, modules);
+        }
+    }
+
+    /// Returns the declarations for `item`, or none if it is of a kind
+    /// cbindgen doesn't use or can't be represented.
+    fn items(&self, item: &Value) -> Vec<syn::Item> {
+        let name = str_field(item, "name").unwrap_or("_");
+        let source = match self.source(item) {
+            Ok(Some(source)) => source,
+            Ok(None) => return Vec::new(),
+            Err(message) => {
+                warn!(
+                    "Skip {}::{} from {} - ({}).",
+                    self.crate_name,
+                    name,
+                    self.path.display(),
+                    message
+                );
+                return Vec::new();
+            }
+        };
+        match parse_source(&source) {
+            Ok(file) => file.items,
+            Err(e) => {
+                warn!(
+                    "Skip {}::{} from {} - (can't parse `{}`: {}).",
+                    self.crate_name,
+                    name,
+                    self.path.display(),
+                    source,
+                    e
+                );
+                Vec::new()
+            }
+        }
+    }
+
+    /// Returns the Rust source of the declarations for `item`.
+    fn source(&self, item: &Value) -> Result<Option<String>, String> {
+        let Some((kind, details)) = Self::inner(item) else {
+            return Ok(None);
+        };
+        let name = || str_field(item, "name").map(ident).ok_or("missing name");
+        let source = match kind {
+            "struct" => self.structure(item, &name()?, details)?,
+            "enum" => self.enumeration(item, &name()?, details)?,
+            "union" => {
+                let (fields, complete) = self.fields(details, "fields")?;
+                let generics = self.generics(details.get("generics"))?;
+                let mut source = self.attributes(item, complete);
+                source.push_str(&format!("pub union {}{} {}", name()?, generics.0, fields));
+                source.push_str(&self.impls(&name()?, &generics, details)?);
+                source
+            }
+            "type_alias" | "typedef" => {
+                let (params, _) = self.generics(details.get("generics"))?;
+                format!(
+                    "{}pub type {}{} = {};",
+                    self.attributes(item, true),
+                    name()?,
+                    params,
+                    self.ty(details.get("type"))?
+                )
+            }
+            "function" => self.function(item, &name()?, details, true)?,
+            "constant" => {
+                // The expression is `_` when it isn't a literal, but the value
+                // is still known.
+                let constant = details.get("const").unwrap_or(details);
+                let expr = str_field(constant, "expr")
+                    .filter(|expr| *expr != "_" && syn::parse_str::<syn::Expr>(expr).is_ok())
+                    .or_else(|| str_field(constant, "value"))
+                    .ok_or("the value isn't known")?;
+                format!(
+                    "{}pub const {}: {} = {};",
+                    self.attributes(item, true),
+                    name()?,
+                    self.ty(details.get("type"))?,
+                    expr
+                )
+            }
+            "static" => {
+                let mutable = bool_field(details, "is_mutable") || bool_field(details, "mutable");
+                let expr = str_field(details, "expr")
+                    .filter(|expr| syn::parse_str::<syn::Expr>(expr).is_ok())
+                    .unwrap_or("unimplemented!()");
+                format!(
+                    "{}pub static {}{}: {} = {};",
+                    self.attributes(item, true),
+                    if mutable { "mut " } else { "" },
+                    name()?,
+                    self.ty(details.get("type"))?,
+                    expr
+                )
+            }
+            "use" | "import" => {
+                let Some(source) = str_field(details, "source") else {
+                    return Ok(None);
+                };
+                if bool_field(details, "is_glob") {
+                    format!("pub use {}::*;", source)
+                } else {
+                    let alias = str_field(details, "name").ok_or("missing name")?;
+                    format!("pub use {} as {};", source, ident(alias))
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(source))
+    }
+
+    /// Returns the outer attributes of `item`. The `repr` is left out of
+    /// types whose fields aren't all `complete`, which makes them opaque.
+    fn attributes(&self, item: &Value, complete: bool) -> String {
+        let mut source = String::new();
+        if let Some(docs) = str_field(item, "docs") {
+            for line in docs.lines() {
+                source.push_str(&format!("#[doc = {:?}]\n", format!(" {}", line)));
+            }
+        }
+        if let Some(deprecation) = item.get("deprecation").filter(|d| !d.is_null()) {
+            match str_field(deprecation, "note") {
+                Some(note) => source.push_str(&format!("#[deprecated(note = {:?})]\n", note)),
+                None => source.push_str("#[deprecated]\n"),
+            }
+        }
+
+        for attr in array_field(item, "attrs") {
+            let attr = match variant(attr) {
+                // Older versions of the format have the source of the
+                // attributes.
+                Some((attr, Value::Null)) if attr.starts_with("#[") => attr.to_owned(),
+                Some(("other", Value::String(attr))) => attr.clone(),
+                Some(("repr", repr)) => {
+                    if !complete {
+                        continue;
+                    }
+                    let mut args = Vec::new();
+                    match str_field(repr, "kind") {
+                        Some("c") => args.push("C".to_owned()),
+                        Some("transparent") => args.push("transparent".to_owned()),
+                        _ => {}
+                    }
+                    if let Some(int) = str_field(repr, "int") {
+                        args.push(int.to_owned());
+                    }
+                    if let Some(align) = repr.get("align").and_then(Value::as_u64) {
+                        args.push(format!("align({})", align));
+                    }
+                    if let Some(packed) = repr.get("packed").and_then(Value::as_u64) {
+                        args.push(format!("packed({})", packed));
+                    }
+                    if args.is_empty() {
+                        continue;
+                    }
+                    format!("#[repr({})]", args.join(", "))
+                }
+                Some(("export_name", Value::String(name))) => {
+                    format!("#[export_name = {:?}]", name)
+                }
+                Some(("must_use", _)) => "#[must_use]".to_owned(),
+                Some((attr, Value::Null)) => format!("#[{}]", attr),
+                _ => continue,
+            };
+            if attr.starts_with("#[repr(") && !complete {
+                continue;
+            }
+            // Attributes rustdoc prints in its own syntax don't parse, and
+            // are of no use here.
+            if parse_source(&format!("{}\nstruct S;", attr)).is_ok() {
+                source.push_str(&attr);
+                source.push('\n');
+            }
+        }
+        source
+    }
+
+    /// Returns the generic parameters of a declaration, and the arguments
+    /// naming them.
+    fn generics(&self, generics: Option<&Value>) -> Result<(String, String), String> {
+        let mut params = Vec::new();
+        let mut args = Vec::new();
+        for param in generics.map_or(&[][..], |g| array_field(g, "params")) {
+            let name = str_field(param, "name").ok_or("missing generic parameter name")?;
+            match param.get("kind").and_then(variant) {
+                Some(("lifetime", _)) => params.push(name.to_owned()),
+                Some(("type", kind)) => {
+                    // Parameters of `impl Trait` arguments.
+                    if bool_field(kind, "is_synthetic") || bool_field(kind, "synthetic") {
+                        continue;
+                    }
+                    params.push(ident(name));
+                }
+                Some(("const", kind)) => params.push(format!(
+                    "const {}: {}",
+                    ident(name),
+                    self.ty(kind.get("type"))?
+                )),
+                _ => return Err(format!("unsupported generic parameter `{}`", name)),
+            }
+            args.push(if name.starts_with('\'') {
+                name.to_owned()
+            } else {
+                ident(name)
+            });
+        }
+        if params.is_empty() {
+            return Ok((String::new(), String::new()));
+        }
+        Ok((
+            format!("<{}>", params.join(", ")),
+            format!("<{}>", args.join(", ")),
+        ))
+    }
+
+    /// Returns the braced fields listed in `details[field]`, and whether none
+    /// of them was stripped.
+    fn fields(&self, details: &Value, field: &str) -> Result<(String, bool), String> {
+        let mut source = String::from("{\n");
+        for id in array_field(details, field) {
+            let field = self.item(id).ok_or("missing field")?;
+            let (_, ty) = Self::inner(field).ok_or("missing field type")?;
+            source.push_str(&format!(
+                "{}pub {}: {},\n",
+                self.attributes(field, true),
+                ident(str_field(field, "name").ok_or("missing field name")?),
+                self.ty(Some(ty))?
+            ));
+        }
+        source.push('}');
+        let complete =
+            !bool_field(details, "has_stripped_fields") && !bool_field(details, "fields_stripped");
+        Ok((source, complete))
+    }
+
+    /// Returns the parenthesized fields of a tuple struct or variant, and
+    /// whether none of them was stripped.
+    fn tuple_fields(&self, ids: &[Value]) -> Result<(String, bool), String> {
+        let mut fields = Vec::new();
+        let mut complete = true;
+        for id in ids {
+            // Stripped fields are null.
+            let Some(field) = self.item(id) else {
+                complete = false;
+                continue;
+            };
+            let (_, ty) = Self::inner(field).ok_or("missing field type")?;
+            fields.push(format!(
+                "{}pub {}",
+                self.attributes(field, true),
+                self.ty(Some(ty))?
+            ));
+        }
+        Ok((format!("({})", fields.join(", ")), complete))
+    }
+
+    fn structure(&self, item: &Value, name: &str, details: &Value) -> Result<String, String> {
+        let generics = self.generics(details.get("generics"))?;
+        let kind = details.get("kind").ok_or("missing struct kind")?;
+        let (body, complete) = match variant(kind) {
+            Some(("unit", _)) => (";".to_owned(), true),
+            Some(("tuple", Value::Array(ids))) => {
+                let (fields, complete) = self.tuple_fields(ids)?;
+                (format!("{};", fields), complete)
+            }
+            Some(("plain", plain)) => self.fields(plain, "fields")?,
+            _ => return Err("unsupported struct kind".to_owned()),
+        };
+        if !complete && item_has_repr(item) {
+            warn!(
+                "{}::{} has private fields which aren't in {}, so it will be opaque.",
+                self.crate_name,
+                name,
+                self.path.display()
+            );
+        }
+        // An opaque struct doesn't need fields, which might not parse without
+        // the stripped ones.
+        let body = if complete { body } else { "{}".to_owned() };
+        let mut source = self.attributes(item, complete);
+        source.push_str(&format!("pub struct {}{} {}", name, generics.0, body));
+        source.push_str(&self.impls(name, &generics, details)?);
+        Ok(source)
+    }
+
+    fn enumeration(&self, item: &Value, name: &str, details: &Value) -> Result<String, String> {
+        let generics = self.generics(details.get("generics"))?;
+        let mut complete = !bool_field(details, "has_stripped_variants")
+            && !bool_field(details, "variants_stripped");
+        let mut variants = String::new();
+        for id in array_field(details, "variants") {
+            let variant_item = self.item(id).ok_or("missing variant")?;
+            let (_, variant_details) = Self::inner(variant_item).ok_or("missing variant kind")?;
+            let variant_name = ident(str_field(variant_item, "name").ok_or("missing name")?);
+            let body = match variant_details.get("kind").and_then(variant) {
+                Some(("plain", _)) | None => String::new(),
+                Some(("tuple", Value::Array(ids))) => {
+                    let (fields, variant_complete) = self.tuple_fields(ids)?;
+                    complete &= variant_complete;
+                    fields
+                }
+                Some(("struct", fields)) => {
+                    let (fields, variant_complete) = self.fields(fields, "fields")?;
+                    complete &= variant_complete;
+                    format!(" {}", fields)
+                }
+                _ => return Err("unsupported variant kind".to_owned()),
+            };
+            let discriminant = match variant_details.get("discriminant") {
+                Some(discriminant) if !discriminant.is_null() => {
+                    let expr = str_field(discriminant, "expr")
+                        .filter(|expr| *expr != "_" && syn::parse_str::<syn::Expr>(expr).is_ok())
+                        .or_else(|| str_field(discriminant, "value"))
+                        .ok_or("unknown discriminant")?;
+                    format!(" = {}", expr)
+                }
+                _ => String::new(),
+            };
+            variants.push_str(&format!(
+                "{}{}{}{},\n",
+                self.attributes(variant_item, true),
+                variant_name,
+                body,
+                discriminant
+            ));
+        }
+        if !complete && item_has_repr(item) {
+            warn!(
+                "{}::{} has private fields which aren't in {}, so it will be opaque.",
+                self.crate_name,
+                name,
+                self.path.display()
+            );
+        }
+        let mut source = self.attributes(item, complete);
+        source.push_str(&format!(
+            "pub enum {}{} {{\n{}}}",
+            name,
+            generics.0,
+            if complete { &variants[..] } else { "" }
+        ));
+        source.push_str(&self.impls(name, &generics, details)?);
+        Ok(source)
+    }
+
+    /// Returns the inherent impls of the type `name`, for their associated
+    /// constants and `extern` methods.
+    fn impls(
+        &self,
+        name: &str,
+        generics: &(String, String),
+        details: &Value,
+    ) -> Result<String, String> {
+        let mut source = String::new();
+        for id in array_field(details, "impls") {
+            let Some(("impl", imp)) = self.item(id).and_then(Self::inner) else {
+                continue;
+            };
+            let is_inherent = imp.get("trait").map_or(true, Value::is_null)
+                && !bool_field(imp, "is_synthetic")
+                && !bool_field(imp, "synthetic")
+                && imp.get("blanket_impl").map_or(true, Value::is_null);
+            if !is_inherent {
+                continue;
+            }
+
+            let mut body = String::new();
+            for id in array_field(imp, "items") {
+                let Some(item) = self.item(id) else {
+                    continue;
+                };
+                let Some(item_name) = str_field(item, "name") else {
+                    continue;
+                };
+                match Self::inner(item) {
+                    Some(("assoc_const", constant)) | Some(("associated_const", constant)) => {
+                        let Some(value) =
+                            str_field(constant, "value").or_else(|| str_field(constant, "default"))
+                        else {
+                            continue;
+                        };
+                        body.push_str(&format!(
+                            "{}pub const {}: {} = {};\n",
+                            self.attributes(item, true),
+                            ident(item_name),
+                            self.ty(constant.get("type"))?,
+                            value
+                        ));
+                    }
+                    // Only `extern` methods can be in the bindings.
+                    Some(("function", function))
+                        if function
+                            .get("header")
+                            .and_then(|header| header.get("abi"))
+                            .and_then(abi)
+                            .is_some() =>
+                    {
+                        body.push_str(&self.function(item, &ident(item_name), function, false)?);
+                        body.push('\n');
+                    }
+                    _ => {}
+                }
+            }
+            if !body.is_empty() {
+                source.push_str(&format!(
+                    "\nimpl{} {}{} {{\n{}}}",
+                    generics.0, name, generics.1, body
+                ));
+            }
+        }
+        Ok(source)
+    }
+
+    /// Returns a function. Functions without a body at the top level are
+    /// declarations of an `extern` block.
+    fn function(
+        &self,
+        item: &Value,
+        name: &str,
+        details: &Value,
+        top_level: bool,
+    ) -> Result<String, String> {
+        let sig = details
+            .get("sig")
+            .or_else(|| details.get("decl"))
+            .ok_or("missing signature")?;
+        let header = details.get("header").ok_or("missing header")?;
+        let abi = header.get("abi").and_then(abi);
+        let (params, _) = self.generics(details.get("generics"))?;
+        let has_body = details
+            .get("has_body")
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+
+        let mut inputs = Vec::new();
+        for input in array_field(sig, "inputs") {
+            let (input_name, ty) = match *input {
+                Value::Array(ref pair) if pair.len() == 2 => (pair[0].as_str(), &pair[1]),
+                _ => return Err("invalid argument".to_owned()),
+            };
+            let input_name = match input_name.ok_or("missing argument name")? {
+                "self" => "self".to_owned(),
+                input_name => ident(input_name),
+            };
+            inputs.push(format!("{}: {}", input_name, self.ty(Some(ty))?));
+        }
+        if bool_field(sig, "is_c_variadic") || bool_field(sig, "c_variadic") {
+            inputs.push("...".to_owned());
+        }
+        let output = match sig.get("output") {
+            Some(output) if !output.is_null() => format!(" -> {}", self.ty(Some(output))?),
+            _ => String::new(),
+        };
+        let signature = format!("fn {}{}({}){}", name, params, inputs.join(", "), output);
+
+        let attributes = self.attributes(item, true);
+        if top_level && !has_body {
+            return Ok(format!(
+                "extern {:?} {{\n{}pub {};\n}}",
+                abi.unwrap_or_else(|| "C".to_owned()),
+                attributes,
+                signature
+            ));
+        }
+        let is_unsafe = bool_field(header, "is_unsafe") || bool_field(header, "unsafe");
+        Ok(format!(
+            "{}pub {}{}{} {{}}",
+            attributes,
+            if is_unsafe { "unsafe " } else { "" },
+            abi.map_or_else(String::new, |abi| format!("extern {:?} ", abi)),
+            signature
+        ))
+    }
+
+    /// Returns the path of the item `id`, in full if it is known.
+    fn path(&self, id: Option<&Value>, written: &str) -> String {
+        let segments = id
+            .and_then(id_key)
+            .and_then(|key| self.paths?.get(&key))
+            .and_then(|summary| summary.get("path"))
+            .and_then(Value::as_array)
+            .and_then(|segments| {
+                segments
+                    .iter()
+                    .map(|segment| segment.as_str().map(ident))
+                    .collect::<Option<Vec<_>>>()
+            });
+        match segments {
+            Some(segments) if !segments.is_empty() => format!("::{}", segments.join("::")),
+            _ => written.to_owned(),
+        }
+    }
+
+    fn generic_args(&self, args: Option<&Value>) -> Result<String, String> {
+        let Some(args) = args.filter(|args| !args.is_null()) else {
+            return Ok(String::new());
+        };
+        match variant(args) {
+            Some(("angle_bracketed", args)) => {
+                let mut list = Vec::new();
+                for arg in array_field(args, "args") {
+                    list.push(match variant(arg) {
+                        Some(("type", ty)) => self.ty(Some(ty))?,
+                        Some(("lifetime", Value::String(lifetime))) => lifetime.clone(),
+                        Some(("const", constant)) => str_field(constant, "expr")
+                            .filter(|expr| *expr != "_")
+                            .or_else(|| str_field(constant, "value"))
+                            .map(|expr| format!("{{ {} }}", expr))
+                            .ok_or("unknown const argument")?,
+                        Some(("infer", _)) => "_".to_owned(),
+                        _ => return Err("unsupported generic argument".to_owned()),
+                    });
+                }
+                if list.is_empty() {
+                    Ok(String::new())
+                } else {
+                    Ok(format!("<{}>", list.join(", ")))
+                }
+            }
+            Some(("parenthesized", args)) => {
+                let inputs = array_field(args, "inputs")
+                    .iter()
+                    .map(|input| self.ty(Some(input)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let output = match args.get("output") {
+                    Some(output) if !output.is_null() => {
+                        format!(" -> {}", self.ty(Some(output))?)
+                    }
+                    _ => String::new(),
+                };
+                Ok(format!("({}){}", inputs.join(", "), output))
+            }
+            _ => Err("unsupported generic arguments".to_owned()),
+        }
+    }
+
+    /// Returns the Rust source of a type.
+    fn ty(&self, ty: Option<&Value>) -> Result<String, String> {
+        let (kind, details) = ty.and_then(variant).ok_or("missing type")?;
+        Ok(match kind {
+            "primitive" => match details.as_str().ok_or("invalid primitive")? {
+                "never" => "!".to_owned(),
+                primitive => primitive.to_owned(),
+            },
+            "generic" => details.as_str().ok_or("invalid generic")?.to_owned(),
+            "resolved_path" => {
+                let written = str_field(details, "path")
+                    .or_else(|| str_field(details, "name"))
+                    .ok_or("missing path")?;
+                format!(
+                    "{}{}",
+                    self.path(details.get("id"), written),
+                    self.generic_args(details.get("args"))?
+                )
+            }
+            "borrowed_ref" => format!(
+                "&{}{}{}",
+                str_field(details, "lifetime").map_or_else(String::new, |l| format!("{} ", l)),
+                if bool_field(details, "is_mutable") || bool_field(details, "mutable") {
+                    "mut "
+                } else {
+                    ""
+                },
+                self.ty(details.get("type"))?
+            ),
+            "raw_pointer" => format!(
+                "*{} {}",
+                if bool_field(details, "is_mutable") || bool_field(details, "mutable") {
+                    "mut"
+                } else {
+                    "const"
+                },
+                self.ty(details.get("type"))?
+            ),
+            "array" => format!(
+                "[{}; {}]",
+                self.ty(details.get("type"))?,
+                str_field(details, "len").ok_or("missing array length")?
+            ),
+            "slice" => format!("[{}]", self.ty(Some(details))?),
+            "tuple" => {
+                let types = details
+                    .as_array()
+                    .ok_or("invalid tuple")?
+                    .iter()
+                    .map(|ty| self.ty(Some(ty)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if types.len() == 1 {
+                    format!("({},)", types[0])
+                } else {
+                    format!("({})", types.join(", "))
+                }
+            }
+            "function_pointer" => {
+                let sig = details
+                    .get("sig")
+                    .or_else(|| details.get("decl"))
+                    .ok_or("missing signature")?;
+                let header = details.get("header").ok_or("missing header")?;
+                let mut inputs = Vec::new();
+                for input in array_field(sig, "inputs") {
+                    match *input {
+                        Value::Array(ref pair) if pair.len() == 2 => {
+                            let ty = self.ty(Some(&pair[1]))?;
+                            inputs.push(match pair[0].as_str() {
+                                Some(name) if name != "_" => format!("{}: {}", ident(name), ty),
+                                _ => ty,
+                            });
+                        }
+                        _ => return Err("invalid argument".to_owned()),
+                    }
+                }
+                if bool_field(sig, "is_c_variadic") || bool_field(sig, "c_variadic") {
+                    inputs.push("...".to_owned());
+                }
+                let output = match sig.get("output") {
+                    Some(output) if !output.is_null() => {
+                        format!(" -> {}", self.ty(Some(output))?)
+                    }
+                    _ => String::new(),
+                };
+                format!(
+                    "{}{}fn({}){}",
+                    if bool_field(header, "is_unsafe") || bool_field(header, "unsafe") {
+                        "unsafe "
+                    } else {
+                        ""
+                    },
+                    header
+                        .get("abi")
+                        .and_then(abi)
+                        .map_or_else(String::new, |abi| format!("extern {:?} ", abi)),
+                    inputs.join(", "),
+                    output
+                )
+            }
+            "qualified_path" => {
+                let name = str_field(details, "name").ok_or("missing name")?;
+                let self_type = self.ty(details.get("self_type"))?;
+                match details.get("trait").filter(|t| !t.is_null()) {
+                    Some(tr) => format!(
+                        "<{} as {}{}>::{}",
+                        self_type,
+                        self.path(tr.get("id"), str_field(tr, "path").unwrap_or_default()),
+                        self.generic_args(tr.get("args"))?,
+                        ident(name)
+                    ),
+                    None => format!("<{}>::{}", self_type, ident(name)),
+                }
+            }
+            "dyn_trait" => {
+                let mut traits = Vec::new();
+                for bound in array_field(details, "traits") {
+                    let tr = bound.get("trait").ok_or("missing trait")?;
+                    traits.push(format!(
+                        "{}{}",
+                        self.path(tr.get("id"), str_field(tr, "path").unwrap_or_default()),
+                        self.generic_args(tr.get("args"))?
+                    ));
+                }
+                format!("dyn {}", traits.join(" + "))
+            }
+            "pat" => self.ty(details.get("type"))?,
+            "infer" => "_".to_owned(),
+            kind => return Err(format!("unsupported type `{}`", kind)),
+        })
+    }
+}
+
+fn item_has_repr(item: &Value) -> bool {
+    array_field(item, "attrs")
+        .iter()
+        .any(|attr| match variant(attr) {
+            Some(("repr", _)) => true,
+            Some((attr, _)) => attr.starts_with("#[repr("),
+            None => false,
+        })
+}
@@ -0,0 +1,159 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Lists of the symbols the bindings declare, for the linker. See
+//! `Bindings::write_symbol_file`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::elf;
+use crate::bindgen::error::Error;
+use crate::bindgen::ir::{Cfg, ToCondition};
+
+/// A linker input listing the symbols a shared library exports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolFileFormat {
+    /// A GNU ld version script, for `-Wl,--version-script`.
+    VersionScript,
+    /// A list for the `-exported_symbols_list` option of Apple's and LLVM's
+    /// Mach-O linkers.
+    ExportedSymbolsList,
+    /// A Windows module-definition (`.def`) file.
+    Def,
+}
+
+/// The symbols defined by the crate, by name, and whether each is data rather
+/// than a function.
+///
+/// Only the symbols whose `cfg` holds when exactly `defines` are defined are
+/// included, where the `cfg` is mapped to defines by the `[defines]` section
+/// of the config like in the header. A symbol with a `cfg` which has no
+/// mapping is always included, as its declaration is in the header.
+fn exported_symbols(bindings: &Bindings, defines: &[&str]) -> BTreeMap<String, bool> {
+    let included = |cfg: &Option<Cfg>| {
+        cfg.to_condition(&bindings.config)
+            .map_or(true, |condition| condition.holds_with(defines))
+    };
+
+    let mut symbols = BTreeMap::new();
+    for function in &bindings.functions {
+        // Functions from `extern` blocks are declared by the crate, not
+        // defined.
+        if !function.extern_decl && included(&function.cfg) {
+            symbols.insert(function.path.name().to_owned(), false);
+        }
+    }
+    for global in &bindings.globals {
+        if included(&global.cfg) {
+            symbols.insert(global.path.name().to_owned(), true);
+        }
+    }
+    symbols
+}
+
+pub(crate) fn write<F: Write>(
+    bindings: &Bindings,
+    mut out: F,
+    format: SymbolFileFormat,
+    defines: &[&str],
+) {
+    let symbols = exported_symbols(bindings, defines);
+    match format {
+        SymbolFileFormat::VersionScript => {
+            writeln!(out, "{{").unwrap();
+            if !symbols.is_empty() {
+                writeln!(out, "  global:").unwrap();
+                for name in symbols.keys() {
+                    writeln!(out, "    {};", name).unwrap();
+                }
+            }
+            writeln!(out, "  local:").unwrap();
+            writeln!(out, "    *;").unwrap();
+            writeln!(out, "}};").unwrap();
+        }
+        SymbolFileFormat::ExportedSymbolsList => {
+            // Mach-O symbol names have a leading underscore.
+            for name in symbols.keys() {
+                writeln!(out, "_{}", name).unwrap();
+            }
+        }
+        SymbolFileFormat::Def => {
+            writeln!(out, "EXPORTS").unwrap();
+            for (name, is_data) in &symbols {
+                if *is_data {
+                    writeln!(out, "    {} DATA", name).unwrap();
+                } else {
+                    writeln!(out, "    {}", name).unwrap();
+                }
+            }
+        }
+    }
+}
+
+/// The differences between the symbols the bindings declare and the ones a
+/// built ELF shared library exports.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolCheck {
+    /// Symbols the bindings declare which the library doesn't export, e.g.
+    /// because of a `cfg` mismatch or because they were optimized out.
+    pub not_exported: Vec<String>,
+    /// Symbols the library exports which the bindings don't declare, e.g. a
+    /// function cbindgen skipped or one missing from `export.include`.
+    pub undeclared: Vec<String>,
+}
+
+impl SymbolCheck {
+    /// Compares the dynamic symbol table of the shared library at `library`
+    /// with the functions and globals of `bindings`, selected with `defines`
+    /// as in `Bindings::write_symbol_file`.
+    pub fn new<P: AsRef<path::Path>>(
+        bindings: &Bindings,
+        library: P,
+        defines: &[&str],
+    ) -> Result<SymbolCheck, Error> {
+        let library = library.as_ref();
+        let symbol_table_error = |message: String| Error::SymbolTable {
+            path: library.display().to_string(),
+            message,
+        };
+
+        let data = fs::read(library).map_err(|e| symbol_table_error(e.to_string()))?;
+        let exported = elf::dynamic_symbols(&data).map_err(symbol_table_error)?;
+        let declared = exported_symbols(bindings, defines);
+
+        Ok(SymbolCheck {
+            not_exported: declared
+                .keys()
+                .filter(|name| exported.binary_search(name).is_err())
+                .cloned()
+                .collect(),
+            undeclared: exported
+                .into_iter()
+                .filter(|name| !declared.contains_key(name))
+                .collect(),
+        })
+    }
+
+    /// Whether the library exports exactly the symbols the bindings declare.
+    pub fn is_ok(&self) -> bool {
+        self.not_exported.is_empty() && self.undeclared.is_empty()
+    }
+}
+
+impl fmt::Display for SymbolCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for name in &self.not_exported {
+            writeln!(f, "declared but not exported: `{}`", name)?;
+        }
+        for name in &self.undeclared {
+            writeln!(f, "exported but not declared: `{}`", name)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,351 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Evaluation of `#[cfg]`s for a concrete target, for `[parse] target`.
+
+use std::collections::HashSet;
+use std::env;
+use std::mem;
+use std::process::Command;
+
+use syn::fold::{self, Fold};
+use syn::punctuated::Punctuated;
+
+use crate::bindgen::cfg_attr;
+use crate::bindgen::config::{Config, ParseConfig};
+use crate::bindgen::error::Error;
+use crate::bindgen::ir::Cfg;
+use crate::bindgen::modules::ParsedModule;
+
+/// The cfgs which are set when building for a target.
+#[derive(Debug, Clone, Default)]
+pub struct TargetCfg {
+    names: HashSet<String>,
+    values: HashSet<(String, String)>,
+}
+
+impl TargetCfg {
+    /// Gets the cfgs of `[parse] target` with `rustc --print cfg`, or from
+    /// the target triple when rustc can't be run, plus the `[parse] cfg`s.
+    /// Returns `None` when no target is set.
+    pub fn load(config: &ParseConfig) -> Result<Option<TargetCfg>, Error> {
+        let Some(ref target) = config.target else {
+            return Ok(None);
+        };
+
+        let mut cfg = match TargetCfg::from_rustc(target) {
+            Ok(cfg) => cfg,
+            Err(message) => match TargetCfg::from_triple(target) {
+                Some(cfg) => {
+                    warn!(
+                        "Couldn't get the cfgs of `{}` from rustc ({}), guessing them from the \
+                         target triple.",
+                        target, message
+                    );
+                    cfg
+                }
+                None => {
+                    return Err(Error::UnknownTarget {
+                        target: target.clone(),
+                        message,
+                    })
+                }
+            },
+        };
+        for spec in &config.cfg {
+            cfg.add(spec);
+        }
+        Ok(Some(cfg))
+    }
+
+    fn from_rustc(target: &str) -> Result<TargetCfg, String> {
+        let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+        let output = Command::new(rustc)
+            .args(["--print", "cfg", "--target", target])
+            .output()
+            .map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_owned());
+        }
+
+        let mut cfg = TargetCfg::default();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            cfg.add(line);
+        }
+        // rustc prints the cfgs of a debug build, but the headers shouldn't
+        // depend on the profile.
+        cfg.names.remove("debug_assertions");
+        Ok(cfg)
+    }
+
+    /// Guesses the cfgs of the common `<arch>-<vendor>-<os>[-<env>]` triples.
+    fn from_triple(target: &str) -> Option<TargetCfg> {
+        let mut parts = target.split('-');
+        let arch = parts.next()?;
+        let rest: Vec<_> = parts.collect();
+
+        let (arch, pointer_width, endian) = match arch {
+            "x86_64" => ("x86_64", "64", "little"),
+            "i386" | "i586" | "i686" => ("x86", "32", "little"),
+            "aarch64" | "arm64" | "arm64e" => ("aarch64", "64", "little"),
+            arch if arch.starts_with("thumb") || arch.starts_with("arm") => ("arm", "32", "little"),
+            "riscv64gc" | "riscv64imac" => ("riscv64", "64", "little"),
+            "riscv32i" | "riscv32imc" | "riscv32imac" => ("riscv32", "32", "little"),
+            "powerpc64le" => ("powerpc64", "64", "little"),
+            "powerpc64" => ("powerpc64", "64", "big"),
+            "s390x" => ("s390x", "64", "big"),
+            "wasm32" => ("wasm32", "32", "little"),
+            "wasm64" => ("wasm64", "64", "little"),
+            _ => return None,
+        };
+
+        let (vendor, os, target_env) = match rest[..] {
+            ["apple", "darwin"] => ("apple", "macos", ""),
+            ["apple", "ios"] => ("apple", "ios", ""),
+            ["apple", "ios", "sim"] => ("apple", "ios", "sim"),
+            ["pc", "windows", env] | ["uwp", "windows", env] => ("pc", "windows", env),
+            ["unknown", "linux", "gnu"] | ["unknown", "linux", "gnueabihf"] => {
+                ("unknown", "linux", "gnu")
+            }
+            ["unknown", "linux", "musl"] | ["unknown", "linux", "musleabihf"] => {
+                ("unknown", "linux", "musl")
+            }
+            ["linux", "android"] | ["linux", "androideabi"] => ("unknown", "android", ""),
+            ["unknown", "freebsd"] => ("unknown", "freebsd", ""),
+            ["unknown", "netbsd"] => ("unknown", "netbsd", ""),
+            ["unknown", "openbsd"] => ("unknown", "openbsd", ""),
+            ["unknown", "unknown"] => ("unknown", "unknown", ""),
+            ["wasi"] | ["wasip1"] | ["unknown", "wasi"] => ("unknown", "wasi", "p1"),
+            ["unknown", "none"] | ["none", "eabi"] | ["none", "eabihf"] => ("unknown", "none", ""),
+            _ => return None,
+        };
+
+        let mut cfg = TargetCfg::default();
+        cfg.insert("target_arch", arch);
+        cfg.insert("target_pointer_width", pointer_width);
+        cfg.insert("target_endian", endian);
+        cfg.insert("target_vendor", vendor);
+        cfg.insert("target_os", os);
+        cfg.insert("target_env", target_env);
+        let family = match os {
+            "windows" => Some("windows"),
+            "unknown" | "wasi" if arch.starts_with("wasm") => Some("wasm"),
+            "none" | "unknown" => None,
+            _ => Some("unix"),
+        };
+        if let Some(family) = family {
+            cfg.insert("target_family", family);
+            cfg.names.insert(family.to_owned());
+        }
+        Some(cfg)
+    }
+
+    /// The value of the cfg `name`, if it has a single one.
+    fn value(&self, name: &str) -> Option<&str> {
+        let mut values = self.values.iter().filter(|(n, _)| n == name);
+        match (values.next(), values.next()) {
+            (Some((_, value)), None) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn insert(&mut self, name: &str, value: &str) {
+        self.values.insert((name.to_owned(), value.to_owned()));
+    }
+
+    /// Adds a cfg written like with `--cfg`: `name` or `name = "value"`.
+    pub fn add(&mut self, spec: &str) {
+        match spec.split_once('=') {
+            Some((name, value)) => self.insert(name.trim(), value.trim().trim_matches('"')),
+            None if !spec.trim().is_empty() => {
+                self.names.insert(spec.trim().to_owned());
+            }
+            None => {}
+        }
+    }
+
+    /// Whether `cfg` holds for this target.
+    pub fn matches(&self, cfg: &Cfg) -> bool {
+        match *cfg {
+            Cfg::Boolean(ref name) => self.names.contains(name),
+            Cfg::Named(ref name, ref value) => self.values.contains(&(name.clone(), value.clone())),
+            Cfg::Any(ref cfgs) => cfgs.iter().any(|cfg| self.matches(cfg)),
+            Cfg::All(ref cfgs) => cfgs.iter().all(|cfg| self.matches(cfg)),
+            Cfg::Not(ref cfg) => !self.matches(cfg),
+        }
+    }
+
+    /// Whether the item with `attrs` is built for this target. The `#[cfg]`s
+    /// of built items are removed so no condition is written for them.
+    fn keep(&self, attrs: &mut Vec<syn::Attribute>) -> bool {
+        cfg_attr::expand_for_target(attrs, self);
+        if !Cfg::load(attrs).map_or(true, |cfg| self.matches(&cfg)) {
+            return false;
+        }
+        attrs.retain(|attr| !attr.path.is_ident("cfg"));
+        true
+    }
+
+    fn filter_items(&mut self, items: Vec<syn::Item>) -> Vec<syn::Item> {
+        items
+            .into_iter()
+            .filter_map(|mut item| {
+                let keep = match item {
+                    syn::Item::Const(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Enum(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::ExternCrate(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Fn(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::ForeignMod(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Impl(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Macro(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Mod(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Static(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Struct(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Trait(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Type(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Union(ref mut item) => self.keep(&mut item.attrs),
+                    syn::Item::Use(ref mut item) => self.keep(&mut item.attrs),
+                    _ => true,
+                };
+                keep.then(|| self.fold_item(item))
+            })
+            .collect()
+    }
+}
+
+impl Fold for TargetCfg {
+    fn fold_item_mod(&mut self, mut item: syn::ItemMod) -> syn::ItemMod {
+        if let Some((brace, items)) = item.content.take() {
+            item.content = Some((brace, self.filter_items(items)));
+        }
+        item
+    }
+
+    fn fold_item_enum(&mut self, mut item: syn::ItemEnum) -> syn::ItemEnum {
+        item.variants = mem::take(&mut item.variants)
+            .into_iter()
+            .filter_map(|mut variant| self.keep(&mut variant.attrs).then_some(variant))
+            .collect();
+        fold::fold_item_enum(self, item)
+    }
+
+    fn fold_fields_named(&mut self, mut fields: syn::FieldsNamed) -> syn::FieldsNamed {
+        fields.named = mem::take(&mut fields.named)
+            .into_iter()
+            .filter_map(|mut field| self.keep(&mut field.attrs).then_some(field))
+            .collect::<Punctuated<_, _>>();
+        fields
+    }
+
+    fn fold_fields_unnamed(&mut self, mut fields: syn::FieldsUnnamed) -> syn::FieldsUnnamed {
+        fields.unnamed = mem::take(&mut fields.unnamed)
+            .into_iter()
+            .filter_map(|mut field| self.keep(&mut field.attrs).then_some(field))
+            .collect::<Punctuated<_, _>>();
+        fields
+    }
+
+    fn fold_item_foreign_mod(&mut self, mut item: syn::ItemForeignMod) -> syn::ItemForeignMod {
+        item.items.retain_mut(|item| match *item {
+            syn::ForeignItem::Fn(ref mut item) => self.keep(&mut item.attrs),
+            syn::ForeignItem::Static(ref mut item) => self.keep(&mut item.attrs),
+            syn::ForeignItem::Type(ref mut item) => self.keep(&mut item.attrs),
+            _ => true,
+        });
+        item
+    }
+
+    fn fold_item_impl(&mut self, mut item: syn::ItemImpl) -> syn::ItemImpl {
+        item.items.retain_mut(|item| match *item {
+            syn::ImplItem::Const(ref mut item) => self.keep(&mut item.attrs),
+            syn::ImplItem::Method(ref mut item) => self.keep(&mut item.attrs),
+            syn::ImplItem::Type(ref mut item) => self.keep(&mut item.attrs),
+            _ => true,
+        });
+        item
+    }
+
+    // Statements don't contain items cbindgen loads.
+    fn fold_block(&mut self, block: syn::Block) -> syn::Block {
+        block
+    }
+}
+
+/// Drops the modules and items of `modules` which aren't built for `target`,
+/// and the `#[cfg]`s of the others.
+pub(crate) fn evaluate_cfgs(modules: &[ParsedModule], target: &TargetCfg) -> Vec<ParsedModule> {
+    let mut target = target.clone();
+    let mut evaluated = Vec::with_capacity(modules.len());
+    for module in modules {
+        if module.cfg.as_ref().map_or(true, |cfg| target.matches(cfg)) {
+            evaluated.push(ParsedModule {
+                cfg: None,
+                items: target.filter_items(module.items.clone()),
+                ..module.clone()
+            });
+        }
+    }
+    evaluated
+}
+
+/// The cfg which tells apart each of `[parse] targets` in a merged header, on
+/// their `target_os` and `target_arch`, and `target_env` when needed.
+///
+/// The cfgs without a condition, from `[defines]` or predefined, are left out
+/// of the conditions written, so each target has to be told apart from the
+/// others by the cfgs which have one.
+pub(crate) fn target_cfgs(config: &Config) -> Result<Vec<(String, Cfg)>, Error> {
+    const KEYS: &[&str] = &["target_os", "target_arch", "target_env"];
+
+    let mut targets = Vec::with_capacity(config.parse.targets.len());
+    for triple in &config.parse.targets {
+        let parse = ParseConfig {
+            target: Some(triple.clone()),
+            ..config.parse.clone()
+        };
+        let target = TargetCfg::load(&parse)?.expect("A target was given");
+        let values: Vec<_> = KEYS
+            .iter()
+            .map(|&key| target.value(key).unwrap_or_default().to_owned())
+            .collect();
+        targets.push((triple.clone(), values));
+    }
+
+    let mut cfgs = Vec::with_capacity(targets.len());
+    for (triple, values) in &targets {
+        let same_os_and_arch = targets
+            .iter()
+            .filter(|(_, other)| other[..2] == values[..2])
+            .count();
+        let keys = if same_os_and_arch == 1 { 2 } else { 3 };
+        let cfg: Vec<_> = KEYS[..keys]
+            .iter()
+            .zip(values)
+            .map(|(key, value)| Cfg::Named(key.to_string(), value.clone()))
+            .collect();
+        cfgs.push((triple.clone(), cfg));
+    }
+
+    // The condition of a target excludes another one if a cfg it is written
+    // with has another value on it.
+    let excludes = |cfg: &[Cfg], (_, other): &(String, Vec<String>)| {
+        cfg.iter().zip(other).any(|(cfg, other)| {
+            matches!(*cfg, Cfg::Named(_, ref value) if value != other) && cfg.has_condition(config)
+        })
+    };
+    for (i, (triple, cfg)) in cfgs.iter().enumerate() {
+        for (j, other) in targets.iter().enumerate().skip(i + 1) {
+            if !excludes(cfg, other) || !excludes(&cfgs[j].1, &targets[i]) {
+                return Err(Error::AmbiguousTargets {
+                    targets: vec![triple.clone(), other.0.clone()],
+                });
+            }
+        }
+    }
+
+    Ok(cfgs
+        .into_iter()
+        .map(|(triple, cfg)| (triple, Cfg::All(cfg)))
+        .collect())
+}
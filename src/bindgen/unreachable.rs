@@ -0,0 +1,150 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The FFI items which were parsed, but left out of the bindings.
+//!
+//! Every `#[repr(C)]`, `#[repr(transparent)]` or `#[repr(<int>)]` type, and
+//! every `#[no_mangle]` static, that cbindgen parsed is meant to be used from
+//! C, so one which no exported function, global, constant or `export.include`
+//! entry reaches is likely dead, or missing from `export.include`.
+
+use std::fmt;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::config::ItemType;
+use crate::bindgen::diagnostics::{Diagnostic, DiagnosticCode};
+use crate::bindgen::explain::{self, Filter, Status};
+use crate::bindgen::ir::{Item, ItemMap, Path};
+
+/// A parsed item which isn't in the bindings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnreachableItem {
+    pub name: String,
+    /// `Structs`, `Unions`, `Enums` or `Globals`.
+    pub kind: ItemType,
+    /// What dropped the item.
+    pub filter: Filter,
+}
+
+/// The FFI items which were parsed, but left out of the bindings. See
+/// `Bindings::unreachable`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnreachableReport {
+    /// The types and statics which weren't written, by name.
+    pub items: Vec<UnreachableItem>,
+    /// The `extern` functions skipped for their ABI or mangling, or because
+    /// they can't be written.
+    pub skipped_functions: Vec<Diagnostic>,
+    /// The entries of `export.include` which match no parsed type.
+    pub missing_includes: Vec<String>,
+}
+
+impl UnreachableReport {
+    /// Whether nothing was left out.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+            && self.skipped_functions.is_empty()
+            && self.missing_includes.is_empty()
+    }
+}
+
+impl fmt::Display for UnreachableReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "Every parsed FFI item is in the bindings.");
+        }
+        if !self.items.is_empty() {
+            writeln!(f, "Unreachable items:")?;
+            for item in &self.items {
+                let kind = match item.kind {
+                    ItemType::Structs => "struct",
+                    ItemType::Unions => "union",
+                    ItemType::Enums => "enum",
+                    ItemType::Globals => "static",
+                    _ => "item",
+                };
+                writeln!(f, "    {} `{}`: {}", kind, item.name, item.filter)?;
+            }
+        }
+        if !self.skipped_functions.is_empty() {
+            writeln!(f, "Skipped functions:")?;
+            for diagnostic in &self.skipped_functions {
+                writeln!(f, "    {}: {}", diagnostic.code.code(), diagnostic.message)?;
+            }
+        }
+        if !self.missing_includes.is_empty() {
+            writeln!(f, "`export.include` entries which match no type:")?;
+            for name in &self.missing_includes {
+                writeln!(f, "    `{}`", name)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Appends the items of `map` which aren't in the bindings to `items`.
+fn add_unreachable<T: Item + Clone>(
+    bindings: &Bindings,
+    map: &ItemMap<T>,
+    kind: ItemType,
+    items: &mut Vec<UnreachableItem>,
+) {
+    map.for_all_items(|item| {
+        let name = item.path().name();
+        if let Status::Dropped(filter) = explain::explain(bindings, name).status {
+            items.push(UnreachableItem {
+                name: name.to_owned(),
+                kind: kind.clone(),
+                filter,
+            });
+        }
+    });
+}
+
+pub(crate) fn unreachable(bindings: &Bindings) -> UnreachableReport {
+    let parsed = &bindings.parsed;
+
+    let mut items = Vec::new();
+    add_unreachable(bindings, &parsed.structs, ItemType::Structs, &mut items);
+    add_unreachable(bindings, &parsed.unions, ItemType::Unions, &mut items);
+    add_unreachable(bindings, &parsed.enums, ItemType::Enums, &mut items);
+    add_unreachable(bindings, &parsed.globals, ItemType::Globals, &mut items);
+    items.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let skipped_functions = bindings
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| {
+            matches!(
+                diagnostic.code,
+                DiagnosticCode::UnsupportedFn
+                    | DiagnosticCode::UnexportedFn
+                    | DiagnosticCode::NonCAbi
+            )
+        })
+        .cloned()
+        .collect();
+
+    let missing_includes = bindings
+        .config
+        .export
+        .include
+        .iter()
+        .filter(|name| {
+            let path = Path::new(name.as_str());
+            parsed.enums.get_items(&path).is_none()
+                && parsed.structs.get_items(&path).is_none()
+                && parsed.unions.get_items(&path).is_none()
+                && parsed.opaque_items.get_items(&path).is_none()
+                && parsed.typedefs.get_items(&path).is_none()
+        })
+        .cloned()
+        .collect();
+
+    UnreachableReport {
+        items,
+        skipped_functions,
+        missing_includes,
+    }
+}
@@ -4,8 +4,11 @@
 
 #![allow(clippy::redundant_closure_call)]
 
+use proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
 use syn::ext::IdentExt;
 
+use crate::bindgen::diagnostics;
+
 pub trait IterHelpers: Iterator {
     fn try_skip_map<F, T, E>(&mut self, f: F) -> Result<Vec<T>, E>
     where
@@ -30,6 +33,147 @@ where
     }
 }
 
+/// Parses Rust source, with the Rust 2024 forms of `desugar_2024`.
+pub fn parse_source(source: &str) -> syn::Result<syn::File> {
+    syn::parse2(desugar_2024(lex_source(source)?))
+}
+
+/// Parses a Rust source file read from `path`, which diagnostics can then
+/// point into.
+pub fn parse_source_file(path: &std::path::Path, source: &str) -> syn::Result<syn::File> {
+    let tokens = lex_source(source)?;
+    if let Some(token) = tokens.clone().into_iter().next() {
+        diagnostics::register_file(path, token.span());
+    }
+    syn::parse2(desugar_2024(tokens))
+}
+
+fn lex_source(source: &str) -> Result<TokenStream, proc_macro2::LexError> {
+    let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+    // A shebang line, which isn't an inner attribute.
+    let source = match source.strip_prefix("#!") {
+        Some(rest) if !rest.trim_start().starts_with('[') => {
+            &source[source.find('\n').unwrap_or(source.len())..]
+        }
+        _ => source,
+    };
+    source.parse()
+}
+
+/// Rewrites the Rust 2024 forms syn can't parse into ones it can:
+///
+/// - `#[unsafe(no_mangle)]` becomes `#[r#unsafe(no_mangle)]`, which
+///   `SynAttributeHelpers` looks into.
+/// - `unsafe extern "C" { safe fn f(); }` becomes `extern "C" { fn f(); }`.
+pub fn desugar_2024(tokens: TokenStream) -> TokenStream {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    let mut out = Vec::with_capacity(tokens.len());
+    let mut i = 0;
+    while i < tokens.len() {
+        match tokens[i] {
+            TokenTree::Group(ref group) if group.delimiter() == Delimiter::Bracket => {
+                let is_attr = match i.checked_sub(1).map(|j| &tokens[j]) {
+                    Some(TokenTree::Punct(ref punct)) => {
+                        punct.as_char() == '#' || punct.as_char() == '!'
+                    }
+                    _ => false,
+                };
+                let stream = desugar_2024(group.stream());
+                let stream = if is_attr {
+                    unsafe_attrs_to_raw(stream)
+                } else {
+                    stream
+                };
+                out.push(regroup(group, stream));
+            }
+            TokenTree::Group(ref group) => {
+                out.push(regroup(group, desugar_2024(group.stream())));
+            }
+            TokenTree::Ident(ref ident) if ident == "unsafe" => {
+                // `unsafe extern "abi" { ... }`, as opposed to an
+                // `unsafe extern "abi" fn`.
+                let mut block = i + 1;
+                let is_extern =
+                    matches!(tokens.get(block), Some(TokenTree::Ident(ref e)) if e == "extern");
+                block += 1;
+                if let Some(TokenTree::Literal(..)) = tokens.get(block) {
+                    block += 1;
+                }
+                match tokens.get(block) {
+                    Some(TokenTree::Group(ref group))
+                        if is_extern && group.delimiter() == Delimiter::Brace =>
+                    {
+                        out.extend(tokens[i + 1..block].iter().cloned());
+                        let items = strip_item_safety(desugar_2024(group.stream()));
+                        out.push(regroup(group, items));
+                        i = block;
+                    }
+                    _ => out.push(tokens[i].clone()),
+                }
+            }
+            _ => out.push(tokens[i].clone()),
+        }
+        i += 1;
+    }
+    out.into_iter().collect()
+}
+
+fn regroup(group: &Group, stream: TokenStream) -> TokenTree {
+    let mut regrouped = Group::new(group.delimiter(), stream);
+    regrouped.set_span(group.span());
+    TokenTree::Group(regrouped)
+}
+
+/// Turns the `unsafe(...)` of an attribute, possibly nested in a `cfg_attr`,
+/// into `r#unsafe(...)`.
+fn unsafe_attrs_to_raw(stream: TokenStream) -> TokenStream {
+    let tokens: Vec<_> = stream.into_iter().collect();
+    let mut out = Vec::with_capacity(tokens.len());
+    for (i, token) in tokens.iter().enumerate() {
+        match *token {
+            TokenTree::Ident(ref ident)
+                if ident == "unsafe"
+                    && matches!(tokens.get(i + 1), Some(TokenTree::Group(ref g)) if g.delimiter() == Delimiter::Parenthesis) =>
+            {
+                out.push(TokenTree::Ident(Ident::new_raw("unsafe", ident.span())));
+            }
+            TokenTree::Group(ref group) if group.delimiter() == Delimiter::Parenthesis => {
+                out.push(regroup(group, unsafe_attrs_to_raw(group.stream())));
+            }
+            _ => out.push(token.clone()),
+        }
+    }
+    out.into_iter().collect()
+}
+
+/// Removes the `safe` and `unsafe` of the items of an `unsafe extern` block.
+fn strip_item_safety(stream: TokenStream) -> TokenStream {
+    let tokens: Vec<_> = stream.into_iter().collect();
+    let mut out = Vec::with_capacity(tokens.len());
+    for (i, token) in tokens.iter().enumerate() {
+        if let TokenTree::Ident(ref ident) = *token {
+            let is_item = matches!(tokens.get(i + 1), Some(TokenTree::Ident(ref next)) if next == "fn" || next == "static");
+            if is_item && (ident == "safe" || ident == "unsafe") {
+                continue;
+            }
+        }
+        out.push(token.clone());
+    }
+    out.into_iter().collect()
+}
+
+/// Returns the attribute an `#[unsafe(...)]` wraps.
+pub(crate) fn unwrap_unsafe_attr(meta: syn::Meta) -> syn::Meta {
+    if let syn::Meta::List(ref list) = meta {
+        if list.path.get_ident().is_some_and(|i| i.unraw() == "unsafe") && list.nested.len() == 1 {
+            if let Some(syn::NestedMeta::Meta(ref inner)) = list.nested.first() {
+                return inner.clone();
+            }
+        }
+    }
+    meta
+}
+
 pub trait SynItemHelpers: SynAttributeHelpers {
     fn exported_name(&self) -> Option<String>;
 }
@@ -121,6 +265,7 @@ pub trait SynAttributeHelpers {
         self.attrs()
             .iter()
             .filter_map(|x| x.parse_meta().ok())
+            .map(unwrap_unsafe_attr)
             .any(|attr| {
                 if let syn::Meta::Path(ref path) = attr {
                     path.is_ident(name)
@@ -188,11 +333,18 @@ pub trait SynAttributeHelpers {
         false
     }
 
+    /// Whether the item is marked `cbindgen:ignore`.
+    fn is_ignored(&self) -> bool {
+        self.get_comment_lines()
+            .iter()
+            .any(|line| line.trim() == "cbindgen:ignore")
+    }
+
     fn attr_name_value_lookup(&self, name: &str) -> Option<String> {
         self.attrs()
             .iter()
             .filter_map(|attr| {
-                let attr = attr.parse_meta().ok()?;
+                let attr = unwrap_unsafe_attr(attr.parse_meta().ok()?);
                 if let syn::Meta::NameValue(syn::MetaNameValue {
                     path,
                     lit: syn::Lit::Str(lit),
@@ -292,20 +444,76 @@ impl_syn_item_helper!(syn::ItemMacro);
 impl_syn_item_helper!(syn::ItemMacro2);
 impl_syn_item_helper!(syn::ItemTraitAlias);
 
+/// Copies the `cfg`s of a macro invocation, given its attributes, to the
+/// items it expands to.
+pub fn inherit_cfgs(attrs: &[syn::Attribute], items: &mut [syn::Item]) {
+    let cfgs: Vec<_> = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .collect();
+    if cfgs.is_empty() {
+        return;
+    }
+    for item in items {
+        let item_attrs = match *item {
+            syn::Item::Const(ref mut item) => &mut item.attrs,
+            syn::Item::Enum(ref mut item) => &mut item.attrs,
+            syn::Item::Fn(ref mut item) => &mut item.attrs,
+            syn::Item::ForeignMod(ref mut item) => &mut item.attrs,
+            syn::Item::Impl(ref mut item) => &mut item.attrs,
+            syn::Item::Macro(ref mut item) => &mut item.attrs,
+            syn::Item::Mod(ref mut item) => &mut item.attrs,
+            syn::Item::Static(ref mut item) => &mut item.attrs,
+            syn::Item::Struct(ref mut item) => &mut item.attrs,
+            syn::Item::Type(ref mut item) => &mut item.attrs,
+            syn::Item::Union(ref mut item) => &mut item.attrs,
+            syn::Item::Use(ref mut item) => &mut item.attrs,
+            _ => continue,
+        };
+        item_attrs.splice(0..0, cfgs.iter().map(|&attr| attr.clone()));
+    }
+}
+
+/// The ABIs of C functions with another calling convention than the default
+/// one, which can be given a macro with `[fn] abi_macros`.
+const CALLING_CONVENTIONS: &[&str] = &[
+    "system",
+    "cdecl",
+    "stdcall",
+    "fastcall",
+    "vectorcall",
+    "thiscall",
+    "sysv64",
+    "win64",
+    "efiapi",
+    "aapcs",
+];
+
+/// Returns the calling convention of a C ABI: `None` for the default one, and
+/// `Some` for one of `CALLING_CONVENTIONS`. The `-unwind` variants are the
+/// same as far as C is concerned.
+fn c_calling_convention(abi: &str) -> Option<Option<&str>> {
+    let abi = abi.strip_suffix("-unwind").unwrap_or(abi);
+    if abi == "C" {
+        return Some(None);
+    }
+    CALLING_CONVENTIONS
+        .iter()
+        .find(|&&convention| convention == abi)
+        .map(|&convention| Some(convention))
+}
+
 /// Helper function for accessing Abi information
 pub trait SynAbiHelpers {
     fn is_c(&self) -> bool;
     fn is_omitted(&self) -> bool;
+    /// The calling convention of a C ABI other than `extern "C"`.
+    fn calling_convention(&self) -> Option<String>;
 }
 
 impl SynAbiHelpers for Option<syn::Abi> {
     fn is_c(&self) -> bool {
-        if let Some(ref abi) = *self {
-            if let Some(ref lit_string) = abi.name {
-                return matches!(lit_string.value().as_str(), "C" | "C-unwind");
-            }
-        }
-        false
+        self.as_ref().is_some_and(|abi| abi.is_c())
     }
     fn is_omitted(&self) -> bool {
         if let Some(ref abi) = *self {
@@ -314,12 +522,15 @@ impl SynAbiHelpers for Option<syn::Abi> {
             false
         }
     }
+    fn calling_convention(&self) -> Option<String> {
+        self.as_ref().and_then(|abi| abi.calling_convention())
+    }
 }
 
 impl SynAbiHelpers for syn::Abi {
     fn is_c(&self) -> bool {
         if let Some(ref lit_string) = self.name {
-            matches!(lit_string.value().as_str(), "C" | "C-unwind")
+            c_calling_convention(&lit_string.value()).is_some()
         } else {
             false
         }
@@ -327,6 +538,10 @@ impl SynAbiHelpers for syn::Abi {
     fn is_omitted(&self) -> bool {
         self.name.is_none()
     }
+    fn calling_convention(&self) -> Option<String> {
+        let name = self.name.as_ref()?.value();
+        c_calling_convention(&name)?.map(str::to_owned)
+    }
 }
 
 impl SynAttributeHelpers for [syn::Attribute] {
@@ -172,18 +172,29 @@ impl<'a, F: Write> SourceWriter<'a, F> {
                     self.new_line();
                 }
             },
-            Language::Cython => {
+            Language::Cython | Language::Python => {
                 self.write(":");
                 self.new_line();
                 self.push_tab();
             }
+            Language::Zig => {
+                self.write(" {");
+                self.push_tab();
+                self.new_line();
+            }
+            Language::CSharp => {
+                self.new_line();
+                self.write("{");
+                self.push_tab();
+                self.new_line();
+            }
         }
     }
 
     pub fn close_brace(&mut self, semicolon: bool) {
         self.pop_tab();
         match self.bindings.config.language {
-            Language::Cxx | Language::C => {
+            Language::Cxx | Language::C | Language::Zig | Language::CSharp => {
                 self.new_line();
                 if semicolon {
                     self.write("};");
@@ -191,7 +202,7 @@ impl<'a, F: Write> SourceWriter<'a, F> {
                     self.write("}");
                 }
             }
-            Language::Cython => {}
+            Language::Cython | Language::Python => {}
         }
     }
 
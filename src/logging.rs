@@ -7,6 +7,8 @@ use std::io::Write;
 
 use log::*;
 
+use crate::bindgen::DIAGNOSTICS_LOG_TARGET;
+
 pub struct TraceLogger;
 pub struct WarnLogger;
 pub struct InfoLogger;
@@ -26,7 +28,7 @@ impl log::Log for TraceLogger {
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            eprintln!("{}: {}", record.level(), record.args());
+            print(record);
         }
     }
 
@@ -49,7 +51,7 @@ impl log::Log for WarnLogger {
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            eprintln!("{}: {}", record.level(), record.args());
+            print(record);
         }
     }
 
@@ -72,7 +74,7 @@ impl log::Log for ErrorLogger {
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            eprintln!("{}: {}", record.level(), record.args());
+            print(record);
         }
     }
 
@@ -95,7 +97,7 @@ impl log::Log for InfoLogger {
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            eprintln!("{}: {}", record.level(), record.args());
+            print(record);
         }
     }
 
@@ -103,3 +105,13 @@ impl log::Log for InfoLogger {
         io::stderr().flush().unwrap();
     }
 }
+
+/// Prints `record`, prefixed with its level unless it is a diagnostic, which
+/// is already rendered with its severity.
+fn print(record: &Record) {
+    if record.target() == DIAGNOSTICS_LOG_TARGET {
+        eprintln!("{}", record.args());
+    } else {
+        eprintln!("{}: {}", record.level(), record.args());
+    }
+}
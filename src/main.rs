@@ -22,10 +22,13 @@ extern crate toml;
 
 use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
 
+// The binary only uses part of the library API, which the library build
+// checks for dead code.
+#[allow(dead_code)]
 mod bindgen;
 mod logging;
 
-use bindgen::{Bindings, Builder, Cargo, Config, Error};
+use bindgen::{AbiDiff, Bindings, Builder, Cargo, Config, Error, SymbolCheck, SymbolFileFormat};
 
 fn apply_config_overrides(config: &mut Config, matches: &ArgMatches) {
     // We allow specifying a language to override the config default. This is
@@ -78,9 +81,73 @@ fn apply_config_overrides(config: &mut Config, matches: &ArgMatches) {
     if matches.get_flag("d") {
         config.parse.parse_deps = true;
     }
+
+    if let Some(targets) = matches.get_many::<String>("target") {
+        let mut targets: Vec<_> = targets.cloned().collect();
+        if targets.len() == 1 {
+            config.parse.target = targets.pop();
+            config.parse.targets.clear();
+        } else {
+            config.parse.targets = targets;
+        }
+    }
+
+    if let Some(cfgs) = matches.get_many::<String>("cfg") {
+        config.parse.cfg.extend(cfgs.cloned());
+    }
+
+    if let Some(format) = matches.get_one::<String>("message-format") {
+        config.diagnostics.format = bindgen::DiagnosticFormat::from_str(format).unwrap();
+    }
+
+    if matches.get_flag("deny-warnings") {
+        config.diagnostics.deny_warnings = true;
+    }
 }
 
 fn load_bindings(input: &Path, matches: &ArgMatches) -> Result<Bindings, Error> {
+    // If an IR file is specified then the crate has already been parsed
+    if let Some(ir_file) = matches.get_one::<PathBuf>("ir-file") {
+        // Load any config specified or search next to the IR file
+        let mut config = match matches.get_one::<PathBuf>("config") {
+            Some(c) => Config::from_file(c).unwrap(),
+            None => Config::from_root_or_default(
+                ir_file
+                    .parent()
+                    .expect("All files should have a parent directory"),
+            ),
+        };
+
+        apply_config_overrides(&mut config, matches);
+
+        return Builder::new()
+            .with_config(config)
+            .with_ir_file(ir_file)
+            .generate();
+    }
+
+    // If rustdoc JSON documents are specified then rustdoc parsed the crates
+    if let Some(documents) = matches.get_many::<PathBuf>("rustdoc-json") {
+        let documents: Vec<_> = documents.collect();
+        // Load any config specified or search next to the first document
+        let mut config = match matches.get_one::<PathBuf>("config") {
+            Some(c) => Config::from_file(c).unwrap(),
+            None => Config::from_root_or_default(
+                documents[0]
+                    .parent()
+                    .expect("All files should have a parent directory"),
+            ),
+        };
+
+        apply_config_overrides(&mut config, matches);
+
+        let mut builder = Builder::new().with_config(config);
+        for document in documents {
+            builder = builder.with_rustdoc_json(document);
+        }
+        return builder.generate();
+    }
+
     // If a file is specified then we load it as a single source
     if !input.is_dir() {
         // Load any config specified or search in the input directory
@@ -135,6 +202,126 @@ fn load_bindings(input: &Path, matches: &ArgMatches) -> Result<Bindings, Error>
         .generate()
 }
 
+/// Loads a library for `abi-diff` or `check-symbols`. It is always generated as
+/// C so that the comparison sees monomorphized types and C names.
+fn load_abi_bindings(input: &Path, matches: &ArgMatches) -> Result<Bindings, Error> {
+    load_subcommand_bindings(input, matches, Some(bindgen::Language::C))
+}
+
+/// Loads the bindings of the INPUT of a subcommand, with its config and
+/// `language` if given.
+fn load_subcommand_bindings(
+    input: &Path,
+    matches: &ArgMatches,
+    language: Option<bindgen::Language>,
+) -> Result<Bindings, Error> {
+    let mut config = match matches.get_one::<PathBuf>("config") {
+        Some(c) => Config::from_file(c).unwrap(),
+        None if input.is_dir() => Config::from_root_or_default(input),
+        None => Config::from_root_or_default(
+            input
+                .parent()
+                .expect("All files should have a parent directory"),
+        ),
+    };
+    if let Some(language) = language {
+        config.language = language;
+    }
+
+    let builder = Builder::new().with_config(config);
+    let builder = if input.extension().is_some_and(|ext| ext == "json") {
+        builder.with_ir_file(input)
+    } else if input.is_dir() {
+        builder.with_crate(input)
+    } else {
+        builder.with_src(input)
+    };
+    builder.generate()
+}
+
+fn abi_diff(matches: &ArgMatches) {
+    let load = |id: &str| {
+        let input = matches.get_one::<PathBuf>(id).unwrap();
+        load_abi_bindings(input, matches).unwrap_or_else(|msg| {
+            error!("{}", msg);
+            error!("Couldn't generate bindings for {}.", input.display());
+            std::process::exit(1);
+        })
+    };
+    let old = load("OLD");
+    let new = load("NEW");
+
+    let diff = AbiDiff::new(&old, &new);
+    print!("{}", diff);
+    if diff.is_breaking() {
+        error!("The ABI has breaking changes.");
+        std::process::exit(2);
+    }
+}
+
+fn explain(matches: &ArgMatches) {
+    let input: PathBuf = matches
+        .get_one("INPUT")
+        .cloned()
+        .unwrap_or_else(|| env::current_dir().unwrap());
+    let bindings = load_subcommand_bindings(&input, matches, None).unwrap_or_else(|msg| {
+        error!("{}", msg);
+        error!("Couldn't generate bindings for {}.", input.display());
+        std::process::exit(1);
+    });
+
+    let item = matches.get_one::<String>("ITEM").unwrap();
+    print!("{}", bindings.explain(item));
+}
+
+fn unreachable(matches: &ArgMatches) {
+    let input: PathBuf = matches
+        .get_one("INPUT")
+        .cloned()
+        .unwrap_or_else(|| env::current_dir().unwrap());
+    let bindings = load_subcommand_bindings(&input, matches, None).unwrap_or_else(|msg| {
+        error!("{}", msg);
+        error!("Couldn't generate bindings for {}.", input.display());
+        std::process::exit(1);
+    });
+
+    let report = bindings.unreachable();
+    print!("{}", report);
+    if matches.get_flag("deny") && !report.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+fn check_symbols(matches: &ArgMatches) {
+    let input: PathBuf = matches
+        .get_one("INPUT")
+        .cloned()
+        .unwrap_or_else(|| env::current_dir().unwrap());
+    let bindings = load_abi_bindings(&input, matches).unwrap_or_else(|msg| {
+        error!("{}", msg);
+        error!("Couldn't generate bindings for {}.", input.display());
+        std::process::exit(1);
+    });
+
+    let defines: Vec<&str> = matches
+        .get_many::<String>("symbols-define")
+        .map(|defines| defines.map(String::as_str).collect())
+        .unwrap_or_default();
+    let lib = matches.get_one::<PathBuf>("lib").unwrap();
+    let check = SymbolCheck::new(&bindings, lib, &defines).unwrap_or_else(|msg| {
+        error!("{}", msg);
+        std::process::exit(1);
+    });
+    print!("{}", check);
+    if !check.is_ok() {
+        error!(
+            "{} doesn't export the symbols the bindings declare.",
+            lib.display()
+        );
+        std::process::exit(2);
+    }
+}
+
 fn main() {
     let matches = Command::new("cbindgen")
         .version(bindgen::VERSION)
@@ -165,7 +352,18 @@ fn main() {
                 .long("lang")
                 .value_name("LANGUAGE")
                 .help("Specify the language to output bindings in")
-                .value_parser(["c++", "C++", "c", "C", "cython", "Cython"]),
+                .value_parser([
+                    "c++", "C++", "c", "C", "cython", "Cython", "zig", "Zig", "csharp", "CSharp",
+                    "python", "Python",
+                ]),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .help("Specify whether to output a header or cbindgen's resolved IR as JSON")
+                .value_parser(["header", "json"])
+                .default_value("header"),
         )
         .arg(
             Arg::new("package-version")
@@ -267,6 +465,37 @@ fn main() {
                 .value_parser(value_parser!(PathBuf))
                 .required(false),
         )
+        .arg(
+            Arg::new("ir-file")
+                .long("ir-file")
+                .value_name("PATH")
+                .help(
+                    "Generate the bindings from a JSON IR document written \
+                     with `--format json` instead of parsing a crate. This \
+                     lets the crate be parsed once and several flavours of \
+                     bindings be generated from it. INPUT is ignored."
+                )
+                .value_parser(value_parser!(PathBuf))
+                .conflicts_with_all(["crate", "lockfile", "metadata"])
+                .required(false),
+        )
+        .arg(
+            Arg::new("rustdoc-json")
+                .long("rustdoc-json")
+                .value_name("PATH")
+                .help(
+                    "Generate the bindings from a rustdoc JSON document \
+                     (`rustdoc --output-format json --document-private-items`) \
+                     instead of parsing the source of a crate. Can be given \
+                     several times to read the documents of dependencies, \
+                     the first one being the crate to generate bindings for. \
+                     INPUT is ignored."
+                )
+                .value_parser(value_parser!(PathBuf))
+                .action(ArgAction::Append)
+                .conflicts_with_all(["crate", "lockfile", "metadata", "ir-file"])
+                .required(false),
+        )
         .arg(
             Arg::new("profile")
                 .long("profile")
@@ -277,6 +506,32 @@ fn main() {
                 )
                 .value_parser(["Debug", "debug", "Release", "release"]),
         )
+        .arg(
+            Arg::new("target")
+                .long("target")
+                .value_name("TRIPLE")
+                .help(
+                    "Generate the bindings of a target: the `#[cfg]`s are evaluated \
+                     for it instead of being written as preprocessor conditions. \
+                     When given several times, the bindings of each target are \
+                     merged into one header, where the items which differ are \
+                     conditional on the target. Overrides `target` and `targets` \
+                     in the `[parse]` section of the config."
+                )
+                .action(ArgAction::Append)
+                .required(false),
+        )
+        .arg(
+            Arg::new("cfg")
+                .long("cfg")
+                .value_name("SPEC")
+                .help(
+                    "Set a cfg for the targets, like rustc's `--cfg`, e.g. \
+                     `--cfg 'feature=\"foo\"'`. Can be given several times."
+                )
+                .action(ArgAction::Append)
+                .required(false),
+        )
         .arg(
             Arg::new("quiet")
                 .short('q')
@@ -285,6 +540,30 @@ fn main() {
                 .help("Report errors only (overrides verbosity options).")
                 .required(false),
         )
+        .arg(
+            Arg::new("message-format")
+                .long("message-format")
+                .value_name("FORMAT")
+                .help(
+                    "How to print the diagnostics: `human` (the default) renders \
+                     them with the source they point at, `json` prints one JSON \
+                     object per line. Overrides `format` in the `[diagnostics]` \
+                     section of the config."
+                )
+                .value_parser(["human", "json"])
+                .required(false),
+        )
+        .arg(
+            Arg::new("deny-warnings")
+                .long("deny-warnings")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Turn the diagnostics into errors, and fail if any is reported. \
+                     Overrides `deny_warnings` in the `[diagnostics]` section of \
+                     the config."
+                )
+                .required(false),
+        )
         .arg(
             Arg::new("depfile")
                 .value_name("PATH")
@@ -298,6 +577,221 @@ fn main() {
                     This option is ignored if `--out` is missing."
                 )
         )
+        .arg(
+            Arg::new("layout-tests-rust")
+                .value_name("PATH")
+                .long("layout-tests-rust")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+                .help("Generate Rust tests at the given Path checking the size, alignment \
+                    and field offsets of the exported types. The file is meant to be \
+                    `include!`d where the exported types are in scope."
+                )
+        )
+        .arg(
+            Arg::new("layout-tests-c")
+                .value_name("PATH")
+                .long("layout-tests-c")
+                .num_args(1)
+                .required(false)
+                .requires("out")
+                .value_parser(value_parser!(PathBuf))
+                .help("Generate a C or C++ file at the given Path which includes the \
+                    bindings and statically asserts the same layouts as \
+                    `--layout-tests-rust`."
+                )
+        )
+        .arg(
+            Arg::new("loader")
+                .value_name("PATH")
+                .long("loader")
+                .num_args(1)
+                .required(false)
+                .requires("out")
+                .value_parser(value_parser!(PathBuf))
+                .help("Generate a C or C++ header at the given Path which loads the \
+                    library at runtime with `dlopen`, into a struct with a pointer \
+                    for each function."
+                )
+        )
+        .arg(
+            Arg::new("version-script")
+                .value_name("PATH")
+                .long("version-script")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+                .help("Generate a GNU ld version script at the given Path which \
+                    exports the functions and globals of the bindings."
+                )
+        )
+        .arg(
+            Arg::new("exported-symbols-list")
+                .value_name("PATH")
+                .long("exported-symbols-list")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+                .help("Generate a list of the functions and globals of the bindings \
+                    at the given Path, for the `-exported_symbols_list` linker option \
+                    on Apple platforms."
+                )
+        )
+        .arg(
+            Arg::new("def-file")
+                .value_name("PATH")
+                .long("def-file")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+                .help("Generate a Windows module-definition file at the given Path \
+                    which exports the functions and globals of the bindings."
+                )
+        )
+        .arg(
+            Arg::new("symbols-define")
+                .value_name("DEFINE")
+                .long("symbols-define")
+                .action(ArgAction::Append)
+                .required(false)
+                .help("Consider DEFINE defined when deciding whether a function or \
+                    global behind a `cfg` is exported. Symbols whose `cfg` maps to \
+                    defines in `[defines]` are only listed if their condition holds."
+                )
+        )
+        .subcommand(
+            Command::new("abi-diff")
+                .about(
+                    "Compare the C ABI of two versions of a library, \
+                     and exit with an error if it has breaking changes",
+                )
+                .arg(
+                    Arg::new("config")
+                        .short('c')
+                        .long("config")
+                        .value_name("PATH")
+                        .value_parser(value_parser!(PathBuf))
+                        .help("Specify path to a `cbindgen.toml` config to use for both versions"),
+                )
+                .arg(
+                    Arg::new("OLD")
+                        .help(
+                            "The old version, as a crate directory, a source file or \
+                             a JSON IR document written with `--format json`",
+                        )
+                        .required(true)
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("NEW")
+                        .help("The new version, in any of the forms OLD can take")
+                        .required(true)
+                        .value_parser(value_parser!(PathBuf)),
+                ),
+        )
+        .subcommand(
+            Command::new("check-symbols")
+                .about(
+                    "Check that a built ELF shared library exports exactly \
+                     the functions and globals of the bindings",
+                )
+                .arg(
+                    Arg::new("config")
+                        .short('c')
+                        .long("config")
+                        .value_name("PATH")
+                        .value_parser(value_parser!(PathBuf))
+                        .help("Specify path to a `cbindgen.toml` config to use"),
+                )
+                .arg(
+                    Arg::new("lib")
+                        .long("lib")
+                        .value_name("PATH")
+                        .required(true)
+                        .value_parser(value_parser!(PathBuf))
+                        .help("The built shared library, e.g. `target/release/libfoo.so`"),
+                )
+                .arg(
+                    Arg::new("symbols-define")
+                        .value_name("DEFINE")
+                        .long("symbols-define")
+                        .action(ArgAction::Append)
+                        .help(
+                            "Consider DEFINE defined when deciding whether a function \
+                             or global behind a `cfg` should be exported",
+                        ),
+                )
+                .arg(
+                    Arg::new("INPUT")
+                        .help(
+                            "The crate directory, a source file or a JSON IR document \
+                             written with `--format json`, defaults to the current directory",
+                        )
+                        .required(false)
+                        .value_parser(value_parser!(PathBuf)),
+                ),
+        )
+        .subcommand(
+            Command::new("explain")
+                .about(
+                    "Explain why an item is in the bindings, or why it isn't: the \
+                     exported items which pull it in, or the filter which dropped it",
+                )
+                .arg(
+                    Arg::new("config")
+                        .short('c')
+                        .long("config")
+                        .value_name("PATH")
+                        .value_parser(value_parser!(PathBuf))
+                        .help("Specify path to a `cbindgen.toml` config to use"),
+                )
+                .arg(
+                    Arg::new("ITEM")
+                        .help("The Rust name of the item, e.g. `MyStruct`")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("INPUT")
+                        .help(
+                            "The crate directory, a source file or a JSON IR document \
+                             written with `--format json`, defaults to the current directory",
+                        )
+                        .required(false)
+                        .value_parser(value_parser!(PathBuf)),
+                ),
+        )
+        .subcommand(
+            Command::new("unreachable")
+                .about(
+                    "Report the repr(C) types and no_mangle statics which are left out of \
+                     the bindings, the skipped extern functions, and the `export.include` \
+                     entries which match nothing",
+                )
+                .arg(
+                    Arg::new("config")
+                        .short('c')
+                        .long("config")
+                        .value_name("PATH")
+                        .value_parser(value_parser!(PathBuf))
+                        .help("Specify path to a `cbindgen.toml` config to use"),
+                )
+                .arg(
+                    Arg::new("deny")
+                        .long("deny")
+                        .action(ArgAction::SetTrue)
+                        .help("Exit with an error if anything is reported"),
+                )
+                .arg(
+                    Arg::new("INPUT")
+                        .help(
+                            "The crate directory, a source file or a JSON IR document \
+                             written with `--format json`, defaults to the current directory",
+                        )
+                        .required(false)
+                        .value_parser(value_parser!(PathBuf)),
+                ),
+        )
         .get_matches();
 
     if matches.get_flag("verify") && !matches.contains_id("out") {
@@ -318,6 +812,26 @@ fn main() {
         }
     }
 
+    match matches.subcommand() {
+        Some(("abi-diff", matches)) => {
+            abi_diff(matches);
+            return;
+        }
+        Some(("check-symbols", matches)) => {
+            check_symbols(matches);
+            return;
+        }
+        Some(("explain", matches)) => {
+            explain(matches);
+            return;
+        }
+        Some(("unreachable", matches)) => {
+            unreachable(matches);
+            return;
+        }
+        _ => {}
+    }
+
     // Find the input directory
     let input: PathBuf = matches
         .get_one("INPUT")
@@ -333,21 +847,91 @@ fn main() {
         }
     };
 
+    let json = matches.get_one::<String>("format").map(String::as_str) == Some("json");
+
     // Write the bindings file
-    match matches.get_one::<PathBuf>("out") {
+    let result = match matches.get_one::<PathBuf>("out") {
         Some(file) => {
-            let changed = bindings.write_to_file(file);
+            let changed = if json {
+                bindings.try_write_json_to_file(file)
+            } else {
+                bindings.try_write_to_file(file)
+            };
 
-            if matches.get_flag("verify") && changed {
-                error!("Bindings changed: {}", file.display());
-                std::process::exit(2);
-            }
-            if let Some(depfile) = matches.get_one("depfile") {
-                bindings.generate_depfile(file, depfile)
-            }
+            changed.and_then(|changed| {
+                if matches.get_flag("verify") && changed {
+                    error!("Bindings changed: {}", file.display());
+                    std::process::exit(2);
+                }
+                match matches.get_one("depfile") {
+                    Some(depfile) => bindings.try_generate_depfile(file, depfile),
+                    None => Ok(()),
+                }
+            })
         }
         _ => {
-            bindings.write(io::stdout());
+            if json {
+                bindings.try_write_json(io::stdout())
+            } else {
+                bindings.try_write(io::stdout())
+            }
+        }
+    };
+    if let Err(msg) = result {
+        error!("{}", msg);
+        error!("Couldn't write bindings for {}.", input.display());
+        std::process::exit(1);
+    }
+
+    let check = |result: Result<bool, Error>, file: &Path| {
+        if let Err(msg) = result {
+            error!("{}", msg);
+            error!("Couldn't write {}.", file.display());
+            std::process::exit(1);
         }
+    };
+
+    // Write the loader
+    if let Some(file) = matches.get_one::<PathBuf>("loader") {
+        let header = matches.get_one::<PathBuf>("out").unwrap();
+        let header = header.file_name().unwrap_or(header.as_os_str());
+        check(
+            bindings.try_write_loader_to_file(file, &header.to_string_lossy()),
+            file,
+        );
+    }
+
+    // Write the symbol files
+    let defines: Vec<&str> = matches
+        .get_many::<String>("symbols-define")
+        .map(|defines| defines.map(String::as_str).collect())
+        .unwrap_or_default();
+    for (arg, format) in [
+        ("version-script", SymbolFileFormat::VersionScript),
+        (
+            "exported-symbols-list",
+            SymbolFileFormat::ExportedSymbolsList,
+        ),
+        ("def-file", SymbolFileFormat::Def),
+    ] {
+        if let Some(file) = matches.get_one::<PathBuf>(arg) {
+            check(
+                bindings.try_write_symbol_file_to_file(file, format, &defines),
+                file,
+            );
+        }
+    }
+
+    // Write the layout tests
+    if let Some(file) = matches.get_one::<PathBuf>("layout-tests-rust") {
+        check(bindings.try_write_rust_layout_tests_to_file(file), file);
+    }
+    if let Some(file) = matches.get_one::<PathBuf>("layout-tests-c") {
+        let header = matches.get_one::<PathBuf>("out").unwrap();
+        let header = header.file_name().unwrap_or(header.as_os_str());
+        check(
+            bindings.try_write_c_layout_tests_to_file(file, &header.to_string_lossy()),
+            file,
+        );
     }
 }
@@ -0,0 +1,111 @@
+mod common;
+
+use cbindgen::*;
+
+use std::path::PathBuf;
+use std::process::Command;
+
+static CBINDGEN_PATH: &str = env!("CARGO_BIN_EXE_cbindgen");
+
+fn test_file(name: &str) -> PathBuf {
+    common::test_file("abi_diff", name)
+}
+
+fn generate(name: &str) -> Bindings {
+    common::generate(
+        Builder::new()
+            .with_language(Language::C)
+            .with_src(test_file(name)),
+    )
+}
+
+#[test]
+fn reports_breaking_and_additive_changes() {
+    let diff = AbiDiff::new(&generate("old.rs"), &generate("new.rs"));
+    assert!(diff.is_breaking());
+    assert_eq!(
+        diff.to_string(),
+        "\
+breaking: `f` return type changed from `int32_t` to `int64_t`
+breaking: `f` now takes 3 arguments instead of 2
+breaking: `g` type of argument `a` changed from `struct Gen_i32` to `struct Gen_u32`
+breaking: `removed` function was removed
+breaking: `LIMIT` changed value
+breaking: `GONE` constant was removed
+breaking: `COUNTER` is no longer mutable
+breaking: `Mode` changed repr
+breaking: `Mode` variant `B` changed discriminant from 1 to 2
+breaking: `Point` field `x` moved from position 0 to 1
+breaking: `Point` type of field `x` changed from `int32_t` to `int64_t`
+breaking: `Point` field `y` moved from position 1 to 0
+breaking: `Point` field `z` was added
+breaking: `Gen_i32` type was removed
+breaking: `Shape` variant `Rect` was added
+additive: `added` function was added
+additive: `NEW` constant was added
+additive: `Mode` variant `X` was added
+additive: `Mode` variant `D` was added
+additive: `Gen_u32` type was added
+"
+    );
+}
+
+#[test]
+fn same_bindings_have_no_changes() {
+    let diff = AbiDiff::new(&generate("old.rs"), &generate("old.rs"));
+    assert!(diff.changes.is_empty());
+}
+
+#[test]
+fn abi_neutral_changes_are_ignored() {
+    let diff = AbiDiff::new(
+        &generate("conventions.rs"),
+        &generate("conventions_neutral.rs"),
+    );
+    assert!(diff.changes.is_empty(), "{}", diff);
+}
+
+#[test]
+fn reports_calling_convention_and_typedef_changes() {
+    let diff = AbiDiff::new(
+        &generate("conventions.rs"),
+        &generate("conventions_breaking.rs"),
+    );
+    assert_eq!(
+        diff.to_string(),
+        "\
+breaking: `set` type of argument `key` changed from `Key` (`uint32_t`) to `Key` (`uint64_t`)
+breaking: `callback` type of argument `f` (`int32_t(*)(int32_t)`) changed its ABI
+breaking: `win` calling convention changed from \"system\" to \"C\"
+breaking: `Key` aliased type changed from `uint32_t` to `uint64_t`
+"
+    );
+}
+
+#[test]
+fn cli_exit_status() {
+    let tmp_dir = tempfile::Builder::new()
+        .prefix("cbindgen-test-output-")
+        .tempdir()
+        .expect("Creating tmp dir failed");
+    let ir_file = tmp_dir.path().join("old.json");
+    generate("old.rs").write_json_to_file(&ir_file);
+
+    let run = |old: &std::path::Path, new: &std::path::Path| {
+        Command::new(CBINDGEN_PATH)
+            .arg("abi-diff")
+            .arg(old)
+            .arg(new)
+            .output()
+            .expect("failed to execute process")
+    };
+
+    let output = run(&ir_file, &test_file("old.rs"));
+    assert!(output.status.success(), "{:?}", output);
+    assert!(output.stdout.is_empty());
+
+    let output = run(&ir_file, &test_file("new.rs"));
+    assert_eq!(output.status.code(), Some(2), "{:?}", output);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("breaking: `removed` function was removed"));
+}
@@ -0,0 +1,17 @@
+pub type Id = u32;
+pub type Key = u32;
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+#[no_mangle]
+pub extern "C" fn get(p: *mut Point, id: Id) -> *const Point {
+    p
+}
+#[no_mangle]
+pub extern "C" fn set(p: &mut Point, key: Key) {}
+#[no_mangle]
+pub extern "C" fn callback(f: extern "C" fn(i32) -> i32) {}
+#[no_mangle]
+pub extern "system" fn win() {}
@@ -0,0 +1,17 @@
+pub type Id = u32;
+pub type Key = u32;
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+#[no_mangle]
+pub extern "C" fn get(p: *const Point, id: Key) -> *mut Point {
+    p as *mut Point
+}
+#[no_mangle]
+pub extern "C" fn set(p: Option<&mut Point>, key: Id) {}
+#[no_mangle]
+pub extern "C" fn callback(f: Option<extern "C" fn(i32) -> i32>) {}
+#[no_mangle]
+pub extern "system" fn win() {}
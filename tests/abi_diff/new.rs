@@ -0,0 +1,39 @@
+#[repr(C)]
+pub struct Point {
+    y: i32,
+    x: i64,
+    z: i32,
+}
+#[repr(C)]
+pub struct Gen<T> {
+    v: T,
+}
+#[repr(u16)]
+pub enum Mode {
+    A,
+    X,
+    B,
+    C = 5,
+    D,
+}
+pub const LIMIT: u32 = 11;
+pub const NEW: u32 = 1;
+#[no_mangle]
+pub extern "C" fn f(p: Point, m: Mode, extra: u8) -> i64 {
+    0
+}
+#[no_mangle]
+pub extern "C" fn g(a: Gen<u32>) {}
+#[no_mangle]
+pub extern "C" fn added() {}
+#[no_mangle]
+pub static COUNTER: u32 = 0;
+
+#[repr(C)]
+pub enum Shape {
+    Dot,
+    Circle(f32),
+    Rect(f32, f32),
+}
+#[no_mangle]
+pub extern "C" fn draw(shape: Shape) {}
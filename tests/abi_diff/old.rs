@@ -0,0 +1,35 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+#[repr(C)]
+pub struct Gen<T> {
+    v: T,
+}
+#[repr(u8)]
+pub enum Mode {
+    A,
+    B,
+    C = 5,
+}
+pub const LIMIT: u32 = 10;
+pub const GONE: u32 = 1;
+#[no_mangle]
+pub extern "C" fn f(p: Point, m: Mode) -> i32 {
+    0
+}
+#[no_mangle]
+pub extern "C" fn g(a: Gen<i32>) {}
+#[no_mangle]
+pub extern "C" fn removed() {}
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+#[repr(C)]
+pub enum Shape {
+    Dot,
+    Circle(f32),
+}
+#[no_mangle]
+pub extern "C" fn draw(shape: Shape) {}
@@ -0,0 +1,33 @@
+//! Helpers shared by the integration tests. Each test crate only uses some of
+//! them.
+#![allow(dead_code)]
+
+use cbindgen::{Bindings, Builder};
+
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// The path of the fixture `name` in `tests/<dir>`.
+pub fn test_file(dir: &str, name: &str) -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.extend(["tests", dir, name]);
+    path
+}
+
+/// Generates the bindings of `builder`, failing the test if it can't.
+pub fn generate(builder: Builder) -> Bindings {
+    builder.generate().expect("build should succeed")
+}
+
+/// Runs `command`, failing the test with its output if it doesn't succeed.
+pub fn run(command: &mut Command) -> Output {
+    let output = command.output().expect("failed to run the command");
+    assert!(
+        output.status.success(),
+        "{:?} failed:\n{}{}",
+        command,
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use cbindgen::*;
+
+use std::path::PathBuf;
+
+fn lib_path() -> PathBuf {
+    common::test_file("diagnostics", "lib.rs")
+}
+
+fn builder(deny_warnings: bool) -> Builder {
+    let mut config = Config::default();
+    config.diagnostics.deny_warnings = deny_warnings;
+    Builder::new()
+        .with_config(config)
+        .with_language(Language::C)
+        .with_src(lib_path())
+}
+
+#[test]
+fn diagnostics() {
+    let bindings = common::generate(builder(false));
+    let diagnostics = bindings.diagnostics();
+    assert_eq!(
+        diagnostics
+            .iter()
+            .map(|d| (d.code, d.item.as_deref()))
+            .collect::<Vec<_>>(),
+        [
+            (DiagnosticCode::UnexportedFn, Some("lib::not_exported")),
+            (DiagnosticCode::MissingDefine, Some("render")),
+        ]
+    );
+    assert!(diagnostics.iter().all(|d| d.severity == Severity::Warning));
+
+    let location = diagnostics[0].location.as_ref().unwrap();
+    assert_eq!(location.file, lib_path());
+    assert_eq!((location.line, location.column), (12, 19));
+    assert_eq!(
+        diagnostics[0].to_json(),
+        format!(
+            "{{\"code\":\"CB0002\",\"severity\":\"warning\",\"message\":\"skipping \
+             `not_exported`: not `no_mangle`, and has no `export_name` attribute\",\
+             \"item\":\"lib::not_exported\",\"file\":{:?},\"line\":12,\"column\":19}}",
+            lib_path().display().to_string()
+        )
+    );
+}
+
+#[test]
+fn deny_warnings() {
+    match builder(true).generate() {
+        Err(Error::Diagnostics { errors }) => assert_eq!(errors, 2),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("build should fail"),
+    }
+}
+
+#[test]
+fn unsupported_abis() {
+    let bindings = common::generate(
+        Builder::new()
+            .with_language(Language::CSharp)
+            .with_src(common::test_file("rust", "calling_conventions.rs")),
+    );
+    assert_eq!(
+        bindings
+            .diagnostics()
+            .iter()
+            .filter(|d| d.code != DiagnosticCode::NonCAbi)
+            .map(|d| (d.code, d.item.as_deref()))
+            .collect::<Vec<_>>(),
+        [
+            (DiagnosticCode::UnsupportedAbi, Some("Handlers::on_unwind")),
+            (DiagnosticCode::UnsupportedAbi, Some("unmapped")),
+            (DiagnosticCode::UnsupportedAbi, Some("firmware_call")),
+        ]
+    );
+}
+
+#[test]
+fn cfg_variants() {
+    for language in [Language::Zig, Language::Python] {
+        let bindings = common::generate(
+            Builder::new()
+                .with_language(language)
+                .with_src(common::test_file("rust", "cfg.rs")),
+        );
+        assert_eq!(
+            bindings
+                .diagnostics()
+                .iter()
+                .filter(|d| d.code == DiagnosticCode::CfgVariant)
+                .map(|d| d.item.as_deref())
+                .collect::<Vec<_>>(),
+            [Some("root")]
+        );
+        assert_eq!(bindings.functions.len(), 4);
+    }
+}
@@ -0,0 +1,16 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn origin() -> Point {
+    Point { x: 0, y: 0 }
+}
+
+pub extern "C" fn not_exported(p: Point) {}
+
+#[cfg(feature = "gpu")]
+#[no_mangle]
+pub extern "C" fn render() {}
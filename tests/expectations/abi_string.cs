@@ -0,0 +1,13 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void c();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void c_unwind();
+}
@@ -0,0 +1,14 @@
+import ctypes
+import enum
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.c.argtypes = []
+  lib.c.restype = None
+
+  lib.c_unwind.argtypes = []
+  lib.c_unwind.restype = None
+
+  return lib
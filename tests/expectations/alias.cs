@@ -0,0 +1,39 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum Status : uint
+  {
+    Ok,
+    Err,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Dep
+  {
+    public int a;
+    public float b;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_i32
+  {
+    public int a;
+    public int b;
+    public Dep c;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_f64
+  {
+    public double a;
+    public double b;
+    public Dep c;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo_i32 x, Foo_f64 y, int z, Status w);
+}
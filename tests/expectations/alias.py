@@ -0,0 +1,43 @@
+import ctypes
+import enum
+
+class Status(enum.IntEnum):
+  Ok = 0
+  Err = 1
+
+class Dep(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_int32),
+    ("b", ctypes.c_float),
+  ]
+
+class Foo_i32(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_int32),
+    ("b", ctypes.c_int32),
+    ("c", Dep),
+  ]
+
+IntFoo = Foo_i32
+
+class Foo_f64(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_double),
+    ("b", ctypes.c_double),
+    ("c", Dep),
+  ]
+
+DoubleFoo = Foo_f64
+
+Unit = ctypes.c_int32
+
+SpecialStatus = ctypes.c_uint32
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [IntFoo, DoubleFoo, Unit, SpecialStatus]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,35 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum Foo_Tag : int
+  {
+    A,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct A_Body
+    {
+      public fixed float a[20];
+    }
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Data
+    {
+      [FieldOffset(0)]
+      public A_Body a;
+    }
+
+    public Foo_Tag tag;
+    public Data data;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo a);
+}
@@ -0,0 +1,30 @@
+import ctypes
+import enum
+
+class Foo_Tag(enum.IntEnum):
+  A = 0
+
+class A_Body(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_float * 20),
+  ]
+
+class Foo_Data(ctypes.Union):
+  _fields_ = [
+    ("a", A_Body),
+  ]
+
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_int),
+    ("data", Foo_Data),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,99 @@
+#define MY_ASSERT(...) do { } while (0)
+#define MY_ATTRS __attribute((noinline))
+
+
+import ctypes
+import enum
+
+class I(ctypes.Structure):
+  pass
+
+class H_Tag(enum.IntEnum):
+  H_Foo = 0
+  H_Bar = 1
+  H_Baz = 2
+
+class H_Foo_Body(ctypes.Structure):
+  _fields_ = [
+    ("foo", ctypes.c_int16),
+  ]
+
+class H_Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class H_Data(ctypes.Union):
+  _fields_ = [
+    ("foo", H_Foo_Body),
+    ("bar", H_Bar_Body),
+  ]
+
+class H(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", H_Data),
+  ]
+
+class J_Tag(enum.IntEnum):
+  J_Foo = 0
+  J_Bar = 1
+  J_Baz = 2
+
+class J_Foo_Body(ctypes.Structure):
+  _fields_ = [
+    ("foo", ctypes.c_int16),
+  ]
+
+class J_Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class J_Data(ctypes.Union):
+  _fields_ = [
+    ("foo", J_Foo_Body),
+    ("bar", J_Bar_Body),
+  ]
+
+class J(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", J_Data),
+  ]
+
+class K_Tag(enum.IntEnum):
+  K_Foo = 0
+  K_Bar = 1
+  K_Baz = 2
+
+class K_Foo_Body(ctypes.Structure):
+  _fields_ = [
+    ("foo_tag", ctypes.c_uint8),
+    ("foo", ctypes.c_int16),
+  ]
+
+class K_Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class K(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("foo", K_Foo_Body),
+    ("bar", K_Bar_Body),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.foo.argtypes = [H, I, J, K]
+  lib.foo.restype = None
+
+  return lib
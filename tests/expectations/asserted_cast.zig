@@ -0,0 +1,72 @@
+#define MY_ASSERT(...) do { } while (0)
+#define MY_ATTRS __attribute((noinline))
+
+
+const std = @import("std");
+
+pub const I = opaque {};
+
+pub const H_Tag = enum(u8) {
+  H_Foo,
+  H_Bar,
+  H_Baz,
+};
+
+pub const H_Bar_Body = extern struct {
+  x: u8,
+  y: i16,
+};
+
+pub const H = extern struct {
+  tag: H_Tag,
+  data: extern union {
+    foo: extern struct {
+      foo: i16,
+    },
+    bar: H_Bar_Body,
+  },
+};
+
+pub const J_Tag = enum(u8) {
+  J_Foo,
+  J_Bar,
+  J_Baz,
+};
+
+pub const J_Bar_Body = extern struct {
+  x: u8,
+  y: i16,
+};
+
+pub const J = extern struct {
+  tag: J_Tag,
+  data: extern union {
+    foo: extern struct {
+      foo: i16,
+    },
+    bar: J_Bar_Body,
+  },
+};
+
+pub const K_Tag = enum(u8) {
+  K_Foo,
+  K_Bar,
+  K_Baz,
+};
+
+pub const K_Bar_Body = extern struct {
+  tag: K_Tag,
+  x: u8,
+  y: i16,
+};
+
+pub const K = extern union {
+  tag: K_Tag,
+  foo: extern struct {
+    foo_tag: K_Tag,
+    foo: i16,
+  },
+  bar: K_Bar_Body,
+};
+
+pub extern fn foo(h: H, i: I, j: J, k: K) void;
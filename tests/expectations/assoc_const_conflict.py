@@ -0,0 +1,10 @@
+import ctypes
+import enum
+
+Foo_FOO = 42
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  return lib
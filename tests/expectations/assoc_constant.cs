@@ -0,0 +1,15 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo { }
+  public const int Foo_GA = 10;
+  public const float Foo_ZO = (float)3.14;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo x);
+}
@@ -0,0 +1,49 @@
+import ctypes
+import enum
+
+# Constants shared by multiple CSS Box Alignment properties
+#
+# These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+class StyleAlignFlags(ctypes.Structure):
+  _fields_ = [
+    ("bits", ctypes.c_uint8),
+  ]
+# 'auto'
+StyleAlignFlags_AUTO = StyleAlignFlags(bits=ctypes.c_uint8(int(0)).value)
+# 'normal'
+StyleAlignFlags_NORMAL = StyleAlignFlags(bits=ctypes.c_uint8(int(1)).value)
+# 'start'
+StyleAlignFlags_START = StyleAlignFlags(bits=ctypes.c_uint8(int((1 << 1))).value)
+# 'end'
+StyleAlignFlags_END = StyleAlignFlags(bits=ctypes.c_uint8(int((1 << 2))).value)
+StyleAlignFlags_ALIAS = StyleAlignFlags(bits=ctypes.c_uint8(int((StyleAlignFlags_END).bits)).value)
+# 'flex-start'
+StyleAlignFlags_FLEX_START = StyleAlignFlags(bits=ctypes.c_uint8(int((1 << 3))).value)
+StyleAlignFlags_MIXED = StyleAlignFlags(bits=ctypes.c_uint8(int((((1 << 4) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits))).value)
+StyleAlignFlags_MIXED_SELF = StyleAlignFlags(bits=ctypes.c_uint8(int((((1 << 5) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits))).value)
+
+# An arbitrary identifier for a native (OS compositor) surface
+class StyleNativeSurfaceId(ctypes.Structure):
+  _fields_ = [
+    ("_0", ctypes.c_uint64),
+  ]
+# A special id for the native surface that is used for debug / profiler overlays.
+StyleNativeSurfaceId_DEBUG_OVERLAY = StyleNativeSurfaceId(_0=((1 << (8 * ctypes.sizeof(ctypes.c_uint64))) - 1))
+
+class StyleNativeTileId(ctypes.Structure):
+  _fields_ = [
+    ("surface_id", StyleNativeSurfaceId),
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_int32),
+  ]
+# A special id for the native surface that is used for debug / profiler overlays.
+StyleNativeTileId_DEBUG_OVERLAY = StyleNativeTileId(surface_id=StyleNativeSurfaceId_DEBUG_OVERLAY, x=0, y=0)
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [StyleAlignFlags, StyleNativeTileId]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct HasBitfields
+  {
+    public ulong foo;
+    public ulong bar;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(HasBitfields* arg0);
+}
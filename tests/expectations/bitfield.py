@@ -0,0 +1,17 @@
+import ctypes
+import enum
+
+class HasBitfields(ctypes.Structure):
+  _fields_ = [
+    ("foo", ctypes.c_uint64, 8),
+    ("bar", ctypes.c_uint64, 56),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(HasBitfields)]
+  lib.root.restype = None
+
+  return lib
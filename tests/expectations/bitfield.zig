@@ -0,0 +1,8 @@
+const std = @import("std");
+
+pub const HasBitfields = extern struct {
+  foo: u64, // bitfield: 8
+  bar: u64, // bitfield: 56
+};
+
+pub extern fn root(*const HasBitfields) void;
@@ -0,0 +1,78 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  /// <summary>
+  /// Constants shared by multiple CSS Box Alignment properties
+  ///
+  /// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+  /// </summary>
+  [StructLayout(LayoutKind.Sequential)]
+  public struct AlignFlags
+  {
+    public byte bits;
+  }
+  /// <summary>
+  /// 'auto'
+  /// </summary>
+  public static readonly AlignFlags AlignFlags_AUTO = new AlignFlags { bits = (byte)(0) };
+  /// <summary>
+  /// 'normal'
+  /// </summary>
+  public static readonly AlignFlags AlignFlags_NORMAL = new AlignFlags { bits = (byte)(1) };
+  /// <summary>
+  /// 'start'
+  /// </summary>
+  public static readonly AlignFlags AlignFlags_START = new AlignFlags { bits = (byte)((1 << 1)) };
+  /// <summary>
+  /// 'end'
+  /// </summary>
+  public static readonly AlignFlags AlignFlags_END = new AlignFlags { bits = (byte)((1 << 2)) };
+  public static readonly AlignFlags AlignFlags_ALIAS = new AlignFlags { bits = (byte)((AlignFlags_END).bits) };
+  /// <summary>
+  /// 'flex-start'
+  /// </summary>
+  public static readonly AlignFlags AlignFlags_FLEX_START = new AlignFlags { bits = (byte)((1 << 3)) };
+  public static readonly AlignFlags AlignFlags_MIXED = new AlignFlags { bits = (byte)((((1 << 4) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits)) };
+  public static readonly AlignFlags AlignFlags_MIXED_SELF = new AlignFlags { bits = (byte)((((1 << 5) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits)) };
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct DebugFlags
+  {
+    public uint bits;
+  }
+  /// <summary>
+  /// Flag with the topmost bit set of the u32
+  /// </summary>
+  public static readonly DebugFlags DebugFlags_BIGGEST_ALLOWED = new DebugFlags { bits = (uint)((1 << 31)) };
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct LargeFlags
+  {
+    public ulong bits;
+  }
+  /// <summary>
+  /// Flag with a very large shift that usually would be narrowed.
+  /// </summary>
+  public static readonly LargeFlags LargeFlags_LARGE_SHIFT = new LargeFlags { bits = (ulong)((1UL << 44)) };
+  public static readonly LargeFlags LargeFlags_INVERTED = new LargeFlags { bits = (ulong)(~(LargeFlags_LARGE_SHIFT).bits) };
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct OutOfLine
+  {
+    public uint _0;
+  }
+  public static readonly OutOfLine OutOfLine_A = new OutOfLine { _0 = (uint)(1) };
+  public static readonly OutOfLine OutOfLine_B = new OutOfLine { _0 = (uint)(2) };
+  public static readonly OutOfLine OutOfLine_AB = new OutOfLine { _0 = (uint)(((OutOfLine_A)._0 | (OutOfLine_B)._0)) };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(
+    AlignFlags flags,
+    DebugFlags bigger_flags,
+    LargeFlags largest_flags,
+    OutOfLine out_of_line);
+}
@@ -0,0 +1,48 @@
+const std = @import("std");
+
+/// Constants shared by multiple CSS Box Alignment properties
+///
+/// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+pub const AlignFlags = extern struct {
+  bits: u8,
+};
+/// 'auto'
+pub const AlignFlags_AUTO: AlignFlags = AlignFlags{ .bits = @as(u8, 0) };
+/// 'normal'
+pub const AlignFlags_NORMAL: AlignFlags = AlignFlags{ .bits = @as(u8, 1) };
+/// 'start'
+pub const AlignFlags_START: AlignFlags = AlignFlags{ .bits = @as(u8, (1 << 1)) };
+/// 'end'
+pub const AlignFlags_END: AlignFlags = AlignFlags{ .bits = @as(u8, (1 << 2)) };
+pub const AlignFlags_ALIAS: AlignFlags = AlignFlags{ .bits = @as(u8, (AlignFlags_END).bits) };
+/// 'flex-start'
+pub const AlignFlags_FLEX_START: AlignFlags = AlignFlags{ .bits = @as(u8, (1 << 3)) };
+pub const AlignFlags_MIXED: AlignFlags = AlignFlags{ .bits = @as(u8, (((1 << 4) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits)) };
+pub const AlignFlags_MIXED_SELF: AlignFlags = AlignFlags{ .bits = @as(u8, (((1 << 5) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits)) };
+
+pub const DebugFlags = extern struct {
+  bits: u32,
+};
+/// Flag with the topmost bit set of the u32
+pub const DebugFlags_BIGGEST_ALLOWED: DebugFlags = DebugFlags{ .bits = @as(u32, (1 << 31)) };
+
+pub const LargeFlags = extern struct {
+  bits: u64,
+};
+/// Flag with a very large shift that usually would be narrowed.
+pub const LargeFlags_LARGE_SHIFT: LargeFlags = LargeFlags{ .bits = @as(u64, (1 << 44)) };
+pub const LargeFlags_INVERTED: LargeFlags = LargeFlags{ .bits = @as(u64, ~(LargeFlags_LARGE_SHIFT).bits) };
+
+pub const OutOfLine = extern struct {
+  _0: u32,
+};
+pub const OutOfLine_A: OutOfLine = OutOfLine{ ._0 = @as(u32, 1) };
+pub const OutOfLine_B: OutOfLine = OutOfLine{ ._0 = @as(u32, 2) };
+pub const OutOfLine_AB: OutOfLine = OutOfLine{ ._0 = @as(u32, ((OutOfLine_A)._0 | (OutOfLine_B)._0)) };
+
+pub extern fn root(
+  flags: AlignFlags,
+  bigger_flags: DebugFlags,
+  largest_flags: LargeFlags,
+  out_of_line: OutOfLine,
+) void;
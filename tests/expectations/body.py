@@ -0,0 +1,120 @@
+import ctypes
+import enum
+
+class MyCLikeEnum(enum.IntEnum):
+  Foo1 = 0
+  Bar1 = 1
+  Baz1 = 2
+
+class MyCLikeEnum_Prepended(enum.IntEnum):
+  Foo1_Prepended = 0
+  Bar1_Prepended = 1
+  Baz1_Prepended = 2
+
+class MyFancyStruct(ctypes.Structure):
+  _fields_ = [
+    ("i", ctypes.c_int32),
+  ]
+#ifdef __cplusplus
+    inline void foo();
+#endif
+
+class MyFancyEnum_Tag(enum.IntEnum):
+  Foo = 0
+  Bar = 1
+  Baz = 2
+
+class Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("bar", ctypes.c_int32),
+  ]
+
+class Baz_Body(ctypes.Structure):
+  _fields_ = [
+    ("baz", ctypes.c_int32),
+  ]
+
+class MyFancyEnum_Data(ctypes.Union):
+  _fields_ = [
+    ("bar", Bar_Body),
+    ("baz", Baz_Body),
+  ]
+
+class MyFancyEnum(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_int),
+    ("data", MyFancyEnum_Data),
+  ]
+#ifdef __cplusplus
+    inline void wohoo();
+#endif
+
+class MyUnion(ctypes.Union):
+  _fields_ = [
+    ("f", ctypes.c_float),
+    ("u", ctypes.c_uint32),
+  ]
+  int32_t extra_member;
+
+class MyFancyStruct_Prepended(ctypes.Structure):
+#ifdef __cplusplus
+    inline void prepended_wohoo();
+#endif
+  _fields_ = [
+    ("i", ctypes.c_int32),
+  ]
+
+class MyFancyEnum_Prepended_Tag(enum.IntEnum):
+  Foo_Prepended = 0
+  Bar_Prepended = 1
+  Baz_Prepended = 2
+
+class Bar_Prepended_Body(ctypes.Structure):
+  _fields_ = [
+    ("bar_prepended", ctypes.c_int32),
+  ]
+
+class Baz_Prepended_Body(ctypes.Structure):
+  _fields_ = [
+    ("baz_prepended", ctypes.c_int32),
+  ]
+
+class MyFancyEnum_Prepended_Data(ctypes.Union):
+  _fields_ = [
+    ("bar_prepended", Bar_Prepended_Body),
+    ("baz_prepended", Baz_Prepended_Body),
+  ]
+
+class MyFancyEnum_Prepended(ctypes.Structure):
+#ifdef __cplusplus
+    inline void wohoo();
+#endif
+  _fields_ = [
+    ("tag", ctypes.c_int),
+    ("data", MyFancyEnum_Prepended_Data),
+  ]
+
+class MyUnion_Prepended(ctypes.Union):
+    int32_t extra_member;
+  _fields_ = [
+    ("f", ctypes.c_float),
+    ("u", ctypes.c_uint32),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    MyFancyStruct,
+    MyFancyEnum,
+    ctypes.c_int,
+    MyUnion,
+    MyFancyStruct_Prepended,
+    MyFancyEnum_Prepended,
+    ctypes.c_int,
+    MyUnion_Prepended,
+  ]
+  lib.root.restype = None
+
+  return lib
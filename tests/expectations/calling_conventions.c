@@ -0,0 +1,48 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#define WINAPI
+#define CALLBACK
+#define FASTCALL
+#define EFIAPI
+
+
+typedef int32_t (WINAPI *Callback)(int32_t code);
+
+typedef struct {
+  void (CALLBACK *on_event)(Handlers*);
+  void (*on_error)(int32_t);
+  const uint8_t *(FASTCALL *on_unwind)(void);
+} Handlers;
+
+int32_t WINAPI register_handlers(Callback callback, Handlers *handlers);
+
+Handlers *CALLBACK window_proc(uint32_t message);
+
+float unmapped(float x);
+
+void default_abi(void);
+
+extern uint64_t EFIAPI firmware_call(uint64_t arg);
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
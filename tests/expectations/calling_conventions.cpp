@@ -0,0 +1,53 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#define WINAPI
+#define CALLBACK
+#define FASTCALL
+#define EFIAPI
+
+
+using Callback = int32_t(WINAPI *)(int32_t code);
+
+struct Handlers {
+  void (CALLBACK *on_event)(Handlers*);
+  void (*on_error)(int32_t);
+  const uint8_t *(FASTCALL *on_unwind)();
+};
+
+extern "C" {
+
+int32_t WINAPI register_handlers(Callback callback, Handlers *handlers);
+
+Handlers *CALLBACK window_proc(uint32_t message);
+
+float unmapped(float x);
+
+void default_abi();
+
+extern uint64_t EFIAPI firmware_call(uint64_t arg);
+
+}  // extern "C"
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
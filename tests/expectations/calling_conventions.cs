@@ -0,0 +1,58 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+#define WINAPI
+#define CALLBACK
+#define FASTCALL
+#define EFIAPI
+
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Handlers
+  {
+    public delegate* unmanaged[Stdcall]<Handlers*, void> on_event;
+    public delegate* unmanaged[Cdecl]<int, void> on_error;
+    public delegate* unmanaged[Cdecl]<byte*> on_unwind;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Winapi)]
+  public static extern int register_handlers(
+    delegate* unmanaged<int, int> callback,
+    Handlers* handlers);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.StdCall)]
+  public static extern Handlers* window_proc(uint message);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern float unmapped(float x);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void default_abi();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern ulong firmware_call(ulong arg);
+}
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
@@ -0,0 +1,63 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+#define WINAPI
+#define CALLBACK
+#define FASTCALL
+#define EFIAPI
+
+
+Callback = getattr(ctypes, "WINFUNCTYPE", ctypes.CFUNCTYPE)(ctypes.c_int32, ctypes.c_int32)
+
+class Handlers(ctypes.Structure):
+  pass
+
+Handlers._fields_ = [
+  ("on_event", getattr(ctypes, "WINFUNCTYPE", ctypes.CFUNCTYPE)(None, ctypes.POINTER(Handlers))),
+  ("on_error", ctypes.CFUNCTYPE(None, ctypes.c_int32)),
+  ("on_unwind", ctypes.CFUNCTYPE(ctypes.POINTER(ctypes.c_uint8))),
+]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+  windll = getattr(ctypes, "WinDLL", ctypes.CDLL)(path)
+
+  lib.register_handlers = windll.register_handlers
+  lib.register_handlers.argtypes = [Callback, ctypes.POINTER(Handlers)]
+  lib.register_handlers.restype = ctypes.c_int32
+
+  lib.window_proc = windll.window_proc
+  lib.window_proc.argtypes = [ctypes.c_uint32]
+  lib.window_proc.restype = ctypes.POINTER(Handlers)
+
+  lib.unmapped.argtypes = [ctypes.c_float]
+  lib.unmapped.restype = ctypes.c_float
+
+  lib.default_abi.argtypes = []
+  lib.default_abi.restype = None
+
+  lib.firmware_call.argtypes = [ctypes.c_uint64]
+  lib.firmware_call.restype = ctypes.c_uint64
+
+  return lib
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
@@ -0,0 +1,48 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+#define WINAPI
+#define CALLBACK
+#define FASTCALL
+#define EFIAPI
+
+
+pub const Callback = *const fn (code: i32) callconv(if (@import("builtin").os.tag == .windows) std.os.windows.WINAPI else .C) i32;
+
+pub const Handlers = extern struct {
+  on_event: ?*const fn (?*Handlers) callconv(.Stdcall) void,
+  on_error: *const fn (i32) callconv(.C) void,
+  on_unwind: *const fn () callconv(.Fastcall) ?*const u8,
+};
+
+pub extern fn register_handlers(
+  callback: Callback,
+  handlers: ?*Handlers,
+) callconv(if (@import("builtin").os.tag == .windows) std.os.windows.WINAPI else .C) i32;
+
+pub extern fn window_proc(message: u32) callconv(.Stdcall) ?*Handlers;
+
+pub extern fn unmapped(x: f32) callconv(.Vectorcall) f32;
+
+pub extern fn default_abi() void;
+
+pub extern fn firmware_call(arg: u64) u64;
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
@@ -0,0 +1,50 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+#define WINAPI
+#define CALLBACK
+#define FASTCALL
+#define EFIAPI
+
+
+cdef extern from *:
+
+  ctypedef int32_t (*Callback)(int32_t code);
+
+  cdef struct Handlers:
+    void (*on_event)(Handlers*);
+    void (*on_error)(int32_t);
+    const uint8_t *(*on_unwind)();
+
+  int32_t register_handlers(Callback callback, Handlers *handlers);
+
+  Handlers *window_proc(uint32_t message);
+
+  float unmapped(float x);
+
+  void default_abi();
+
+  extern uint64_t firmware_call(uint64_t arg);
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
@@ -0,0 +1,28 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern delegate* unmanaged[Cdecl]<void> O();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(
+    delegate* unmanaged[Cdecl]<void> a,
+    delegate* unmanaged[Cdecl]<void> b,
+    delegate* unmanaged[Cdecl]<int, int, bool> c,
+    delegate* unmanaged[Cdecl]<int, delegate* unmanaged[Cdecl]<float, bool>> d,
+    delegate* unmanaged[Cdecl]<int**> e,
+    int* f,
+    int** g,
+    int** h,
+    int** i,
+    delegate* unmanaged[Cdecl]<float, double>* j,
+    int* k,
+    int** l,
+    delegate* unmanaged[Cdecl]<int, int, bool>* m,
+    delegate* unmanaged[Cdecl]<int, int, void>* n,
+    delegate* unmanaged[Cdecl]<int, bool, bool, int, void> p);
+}
@@ -0,0 +1,44 @@
+import ctypes
+import enum
+
+A = ctypes.CFUNCTYPE(None)
+
+B = ctypes.CFUNCTYPE(None)
+
+C = ctypes.CFUNCTYPE(ctypes.c_bool, ctypes.c_int32, ctypes.c_int32)
+
+D = ctypes.CFUNCTYPE(ctypes.CFUNCTYPE(ctypes.c_bool, ctypes.c_float), ctypes.c_int32)
+
+E = ctypes.CFUNCTYPE(ctypes.POINTER(ctypes.c_int32 * 16))
+
+F = ctypes.POINTER(ctypes.c_int32)
+
+G = ctypes.POINTER(ctypes.POINTER(ctypes.c_int32))
+
+H = ctypes.POINTER(ctypes.POINTER(ctypes.c_int32))
+
+I = ctypes.POINTER(ctypes.c_int32 * 16)
+
+J = ctypes.POINTER(ctypes.CFUNCTYPE(ctypes.c_double, ctypes.c_float))
+
+K = ctypes.c_int32 * 16
+
+L = ctypes.POINTER(ctypes.c_int32) * 16
+
+M = ctypes.CFUNCTYPE(ctypes.c_bool, ctypes.c_int32, ctypes.c_int32) * 16
+
+N = ctypes.CFUNCTYPE(None, ctypes.c_int32, ctypes.c_int32) * 16
+
+P = ctypes.CFUNCTYPE(None, ctypes.c_int32, ctypes.c_bool, ctypes.c_bool, ctypes.c_int32)
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.O.argtypes = []
+  lib.O.restype = ctypes.CFUNCTYPE(None)
+
+  lib.root.argtypes = [A, B, C, D, E, F, G, H, I, J, K, L, M, N, P]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,18 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct NotReprC_RefCell_i32 { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct MyStruct
+  {
+    public int number;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(NotReprC_RefCell_i32* a, MyStruct* with_cell);
+}
@@ -0,0 +1,21 @@
+import ctypes
+import enum
+
+class NotReprC_RefCell_i32(ctypes.Structure):
+  pass
+
+Foo = NotReprC_RefCell_i32
+
+class MyStruct(ctypes.Structure):
+  _fields_ = [
+    ("number", ctypes.c_int32),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Foo), ctypes.POINTER(MyStruct)]
+  lib.root.restype = None
+
+  return lib
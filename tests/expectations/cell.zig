@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub const NotReprC_RefCell_i32 = opaque {};
+
+pub const Foo = NotReprC_RefCell_i32;
+
+pub const MyStruct = extern struct {
+  number: i32,
+};
+
+pub extern fn root(a: *const Foo, with_cell: *const MyStruct) void;
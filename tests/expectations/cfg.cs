@@ -0,0 +1,123 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+DEF PLATFORM_WIN = 0
+DEF X11 = 0
+DEF M_32 = 0
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+#if (PLATFORM_WIN || M_32)
+  public enum BarType : uint
+  {
+    A,
+    B,
+    C,
+  }
+#endif
+
+#if (PLATFORM_UNIX && X11)
+  public enum FooType : uint
+  {
+    A,
+    B,
+    C,
+  }
+#endif
+
+#if (PLATFORM_UNIX && X11)
+  [StructLayout(LayoutKind.Sequential)]
+  public struct FooHandle
+  {
+    public FooType ty;
+    public int x;
+    public float y;
+  }
+#endif
+
+  public enum C_Tag : byte
+  {
+    C1,
+    C2,
+#if PLATFORM_WIN
+    C3,
+#endif
+#if PLATFORM_UNIX
+    C5,
+#endif
+  }
+
+#if PLATFORM_UNIX
+  [StructLayout(LayoutKind.Sequential)]
+  public struct C5_Body
+  {
+    public C_Tag tag;
+    public int int_;
+  }
+#endif
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct C
+  {
+#if PLATFORM_UNIX
+    [FieldOffset(0)]
+    public C5_Body c5;
+#endif
+    [FieldOffset(0)]
+    public C_Tag tag;
+  }
+
+#if (PLATFORM_WIN || M_32)
+  [StructLayout(LayoutKind.Sequential)]
+  public struct BarHandle
+  {
+    public BarType ty;
+    public int x;
+    public float y;
+  }
+#endif
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ConditionalField
+  {
+#if X11
+    public int field;
+#endif
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Normal
+  {
+    public int x;
+    public float y;
+  }
+
+#if (PLATFORM_UNIX && X11)
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(FooHandle a, C c);
+#endif
+
+#if (PLATFORM_WIN || M_32)
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(BarHandle a, C c);
+#endif
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void cond(ConditionalField a);
+
+#if PLATFORM_WIN
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern int foo();
+#endif
+
+#if PLATFORM_WIN
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void bar(Normal a);
+#endif
+}
@@ -0,0 +1,108 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+DEF PLATFORM_WIN = 0
+DEF X11 = 0
+DEF M_32 = 0
+#endif
+
+
+import ctypes
+import enum
+
+# #if (defined(PLATFORM_WIN) || defined(M_32))
+class BarType(enum.IntEnum):
+  A = 0
+  B = 1
+  C = 2
+# #endif
+
+# #if (defined(PLATFORM_UNIX) && defined(X11))
+class FooType(enum.IntEnum):
+  A = 0
+  B = 1
+  C = 2
+# #endif
+
+# #if (defined(PLATFORM_UNIX) && defined(X11))
+class FooHandle(ctypes.Structure):
+  _fields_ = [
+    ("ty", ctypes.c_uint32),
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+# #endif
+
+class C_Tag(enum.IntEnum):
+  C1 = 0
+  C2 = 1
+# #if defined(PLATFORM_WIN)
+  C3 = 2
+# #endif
+# #if defined(PLATFORM_UNIX)
+  C5 = 3
+# #endif
+
+# #if defined(PLATFORM_UNIX)
+class C5_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("int_", ctypes.c_int32),
+  ]
+# #endif
+
+class C(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+# #if defined(PLATFORM_UNIX)
+    ("c5", C5_Body),
+# #endif
+  ]
+
+# #if (defined(PLATFORM_WIN) || defined(M_32))
+class BarHandle(ctypes.Structure):
+  _fields_ = [
+    ("ty", ctypes.c_uint32),
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+# #endif
+
+class ConditionalField(ctypes.Structure):
+  _fields_ = [
+# #if defined(X11)
+    ("field", ctypes.c_int32),
+# #endif
+  ]
+
+class Normal(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+# #if (defined(PLATFORM_UNIX) && defined(X11))
+  if hasattr(lib, "root"):
+    lib.root.argtypes = [FooHandle, C]
+    lib.root.restype = None
+# #endif
+
+  lib.cond.argtypes = [ConditionalField]
+  lib.cond.restype = None
+
+# #if defined(PLATFORM_WIN)
+  if hasattr(lib, "foo"):
+    lib.foo.argtypes = []
+    lib.foo.restype = ctypes.c_int32
+# #endif
+
+# #if defined(PLATFORM_WIN)
+  if hasattr(lib, "bar"):
+    lib.bar.argtypes = [Normal]
+    lib.bar.restype = None
+# #endif
+
+  return lib
@@ -0,0 +1,54 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+#if NOT_DEFINED
+  public const int DEFAULT_X = 8;
+#endif
+
+#if DEFINED
+  public const int DEFAULT_X = 42;
+#endif
+
+#if (NOT_DEFINED || DEFINED)
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo
+  {
+    public int x;
+  }
+#endif
+
+#if NOT_DEFINED
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar
+  {
+    public Foo y;
+  }
+#endif
+
+#if DEFINED
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar
+  {
+    public Foo z;
+  }
+#endif
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Root
+  {
+    public Bar w;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Root a);
+}
@@ -0,0 +1,40 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+import ctypes
+import enum
+
+# #if defined(NOT_DEFINED)
+DEFAULT_X = 8
+# #endif
+
+# #if (defined(NOT_DEFINED) || defined(DEFINED))
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+  ]
+# #endif
+
+# #if defined(NOT_DEFINED)
+class Bar(ctypes.Structure):
+  _fields_ = [
+    ("y", Foo),
+  ]
+# #endif
+
+class Root(ctypes.Structure):
+  _fields_ = [
+    ("w", Bar),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Root]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,29 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+const std = @import("std");
+
+// #if defined(NOT_DEFINED)
+pub const DEFAULT_X: i32 = 8;
+// #endif
+
+// #if (defined(NOT_DEFINED) || defined(DEFINED))
+pub const Foo = extern struct {
+  x: i32,
+};
+// #endif
+
+// #if defined(NOT_DEFINED)
+pub const Bar = extern struct {
+  y: Foo,
+};
+// #endif
+
+pub const Root = extern struct {
+  w: Bar,
+};
+
+pub extern fn root(a: Root) void;
@@ -0,0 +1,107 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if (defined(DEFINE_UNIX) && defined(DEFINE_FFI))
+#define LIMIT 8
+#endif
+
+#if !defined(DEFINE_FFI)
+#define LIMIT 8
+#endif
+
+#if defined(DEFINE_FFI)
+enum Mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  /**
+   *Read only.
+   */
+  READ_ONLY,
+#if defined(DEFINE_DEPRECATIONS)
+  READ_WRITE DEPRECATED_VARIANT,
+#endif
+#if !defined(DEFINE_DEPRECATIONS)
+  READ_WRITE,
+#endif
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+#endif
+
+#if defined(DEFINE_FFI)
+/**
+ * Always documented.
+ *Only with `ffi` in Rust, but always in C.
+ */
+typedef struct {
+  /**
+   *The raw id.
+   */
+  uint64_t id;
+} Handle;
+#endif
+
+#if defined(DEFINE_WINDOWS)
+typedef struct PACKED {
+  uint8_t tag;
+  uint32_t len;
+} Packet;
+#endif
+
+#if !defined(DEFINE_WINDOWS)
+typedef struct {
+  uint8_t tag;
+  uint32_t len;
+} Packet;
+#endif
+
+typedef struct {
+  uint32_t count;
+#if (defined(DEFINE_UNIX) && defined(DEFINE_FFI))
+  int32_t fd
+#endif
+  ;
+#if !defined(DEFINE_FFI)
+  int32_t fd
+#endif
+  ;
+} Stats;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#if defined(DEFINE_FFI)
+Handle *open(const Packet *packet);
+#endif
+
+#if defined(DEFINE_FFI)
+MUST_USE_FUNC bool handle_close(Handle *handle);
+#endif
+
+#if defined(DEFINE_DEPRECATIONS)
+DEPRECATED_FUNC void legacy(void);
+#endif
+
+#if !defined(DEFINE_DEPRECATIONS)
+void legacy(void);
+#endif
+
+#if (defined(DEFINE_FFI) && defined(DEFINE_UNIX))
+void unix_only(void);
+#endif
+
+#if defined(DEFINE_FFI)
+void mode(Mode m);
+#endif
+
+void stats(const Stats *stats);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
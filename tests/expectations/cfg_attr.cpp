@@ -0,0 +1,91 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#if (defined(DEFINE_UNIX) && defined(DEFINE_FFI))
+constexpr static const uint32_t LIMIT = 8;
+#endif
+
+#if !defined(DEFINE_FFI)
+constexpr static const uint32_t LIMIT = 8;
+#endif
+
+#if defined(DEFINE_FFI)
+enum class Mode : uint8_t {
+  ///Read only.
+  READ_ONLY,
+#if defined(DEFINE_DEPRECATIONS)
+  READ_WRITE DEPRECATED_VARIANT,
+#endif
+#if !defined(DEFINE_DEPRECATIONS)
+  READ_WRITE,
+#endif
+};
+#endif
+
+#if defined(DEFINE_FFI)
+/// Always documented.
+///Only with `ffi` in Rust, but always in C.
+struct Handle {
+  ///The raw id.
+  uint64_t id;
+};
+#endif
+
+#if defined(DEFINE_WINDOWS)
+struct PACKED Packet {
+  uint8_t tag;
+  uint32_t len;
+};
+#endif
+
+#if !defined(DEFINE_WINDOWS)
+struct Packet {
+  uint8_t tag;
+  uint32_t len;
+};
+#endif
+
+struct Stats {
+  uint32_t count;
+#if (defined(DEFINE_UNIX) && defined(DEFINE_FFI))
+  int32_t fd
+#endif
+  ;
+#if !defined(DEFINE_FFI)
+  int32_t fd
+#endif
+  ;
+};
+
+extern "C" {
+
+#if defined(DEFINE_FFI)
+Handle *open(const Packet *packet);
+#endif
+
+#if defined(DEFINE_FFI)
+MUST_USE_FUNC bool handle_close(Handle *handle);
+#endif
+
+#if defined(DEFINE_DEPRECATIONS)
+DEPRECATED_FUNC void legacy();
+#endif
+
+#if !defined(DEFINE_DEPRECATIONS)
+void legacy();
+#endif
+
+#if (defined(DEFINE_FFI) && defined(DEFINE_UNIX))
+void unix_only();
+#endif
+
+#if defined(DEFINE_FFI)
+void mode(Mode m);
+#endif
+
+void stats(const Stats *stats);
+
+}  // extern "C"
@@ -0,0 +1,110 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+#if (DEFINE_UNIX && DEFINE_FFI)
+  public const uint LIMIT = 8;
+#endif
+
+#if !DEFINE_FFI
+  public const uint LIMIT = 8;
+#endif
+
+#if DEFINE_FFI
+  public enum Mode : byte
+  {
+    /// <summary>
+    ///Read only.
+    /// </summary>
+    READ_ONLY,
+#if DEFINE_DEPRECATIONS
+    READ_WRITE,
+#endif
+#if !DEFINE_DEPRECATIONS
+    READ_WRITE,
+#endif
+  }
+#endif
+
+#if DEFINE_FFI
+  /// <summary>
+  /// Always documented.
+  ///Only with `ffi` in Rust, but always in C.
+  /// </summary>
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Handle
+  {
+    /// <summary>
+    ///The raw id.
+    /// </summary>
+    public ulong id;
+  }
+#endif
+
+#if DEFINE_WINDOWS
+  [StructLayout(LayoutKind.Sequential, Pack = 1)]
+  public struct Packet
+  {
+    public byte tag;
+    public uint len;
+  }
+#endif
+
+#if !DEFINE_WINDOWS
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Packet
+  {
+    public byte tag;
+    public uint len;
+  }
+#endif
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Stats
+  {
+    public uint count;
+#if (DEFINE_UNIX && DEFINE_FFI)
+    public int fd;
+#endif
+#if !DEFINE_FFI
+    public int fd;
+#endif
+  }
+
+#if DEFINE_FFI
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Handle* open(Packet* packet);
+#endif
+
+#if DEFINE_FFI
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  [return: MarshalAs(UnmanagedType.U1)]
+  public static extern bool handle_close(Handle* handle);
+#endif
+
+#if DEFINE_DEPRECATIONS
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void legacy();
+#endif
+
+#if !DEFINE_DEPRECATIONS
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void legacy();
+#endif
+
+#if (DEFINE_FFI && DEFINE_UNIX)
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void unix_only();
+#endif
+
+#if DEFINE_FFI
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void mode(Mode m);
+#endif
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void stats(Stats* stats);
+}
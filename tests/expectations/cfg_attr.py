@@ -0,0 +1,81 @@
+import ctypes
+import enum
+
+# #if (defined(DEFINE_UNIX) && defined(DEFINE_FFI))
+LIMIT = 8
+# #endif
+
+# #if defined(DEFINE_FFI)
+class Mode(enum.IntEnum):
+  #Read only.
+  READ_ONLY = 0
+# #if defined(DEFINE_DEPRECATIONS)
+  READ_WRITE = 1
+# #endif
+# #endif
+
+# #if defined(DEFINE_FFI)
+# Always documented.
+#Only with `ffi` in Rust, but always in C.
+class Handle(ctypes.Structure):
+  _fields_ = [
+    #The raw id.
+    ("id", ctypes.c_uint64),
+  ]
+# #endif
+
+# #if defined(DEFINE_WINDOWS)
+class Packet(ctypes.Structure):
+  _pack_ = 1
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("len", ctypes.c_uint32),
+  ]
+# #endif
+
+class Stats(ctypes.Structure):
+  _fields_ = [
+    ("count", ctypes.c_uint32),
+# #if (defined(DEFINE_UNIX) && defined(DEFINE_FFI))
+    ("fd", ctypes.c_int32),
+# #endif
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+# #if defined(DEFINE_FFI)
+  if hasattr(lib, "open"):
+    lib.open.argtypes = [ctypes.POINTER(Packet)]
+    lib.open.restype = ctypes.POINTER(Handle)
+# #endif
+
+# #if defined(DEFINE_FFI)
+  if hasattr(lib, "handle_close"):
+    lib.handle_close.argtypes = [ctypes.POINTER(Handle)]
+    lib.handle_close.restype = ctypes.c_bool
+# #endif
+
+# #if defined(DEFINE_DEPRECATIONS)
+  if hasattr(lib, "legacy"):
+    lib.legacy.argtypes = []
+    lib.legacy.restype = None
+# #endif
+
+# #if (defined(DEFINE_FFI) && defined(DEFINE_UNIX))
+  if hasattr(lib, "unix_only"):
+    lib.unix_only.argtypes = []
+    lib.unix_only.restype = None
+# #endif
+
+# #if defined(DEFINE_FFI)
+  if hasattr(lib, "mode"):
+    lib.mode.argtypes = [ctypes.c_uint8]
+    lib.mode.restype = None
+# #endif
+
+  lib.stats.argtypes = [ctypes.POINTER(Stats)]
+  lib.stats.restype = None
+
+  return lib
@@ -0,0 +1,63 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  IF (DEFINE_UNIX and DEFINE_FFI):
+    const uint32_t LIMIT # = 8
+
+  IF not DEFINE_FFI:
+    const uint32_t LIMIT # = 8
+
+  IF DEFINE_FFI:
+    cdef enum:
+      #Read only.
+      READ_ONLY,
+      READ_WRITE,
+      READ_WRITE,
+    ctypedef uint8_t Mode;
+
+  IF DEFINE_FFI:
+    # Always documented.
+    #Only with `ffi` in Rust, but always in C.
+    ctypedef struct Handle:
+      #The raw id.
+      uint64_t id;
+
+  IF DEFINE_WINDOWS:
+    ctypedef packed struct Packet:
+      uint8_t tag;
+      uint32_t len;
+
+  IF not DEFINE_WINDOWS:
+    ctypedef struct Packet:
+      uint8_t tag;
+      uint32_t len;
+
+  ctypedef struct Stats:
+    uint32_t count;
+    int32_t fd;
+    int32_t fd;
+
+  IF DEFINE_FFI:
+    Handle *open(const Packet *packet);
+
+  IF DEFINE_FFI:
+    bool handle_close(Handle *handle);
+
+  IF DEFINE_DEPRECATIONS:
+    void legacy();
+
+  IF not DEFINE_DEPRECATIONS:
+    void legacy();
+
+  IF (DEFINE_FFI and DEFINE_UNIX):
+    void unix_only();
+
+  IF DEFINE_FFI:
+    void mode(Mode m);
+
+  void stats(const Stats *stats);
@@ -0,0 +1,60 @@
+const std = @import("std");
+
+// #if (defined(DEFINE_UNIX) && defined(DEFINE_FFI))
+pub const LIMIT: u32 = 8;
+// #endif
+
+// #if defined(DEFINE_FFI)
+pub const Mode = enum(u8) {
+  ///Read only.
+  READ_ONLY,
+// #if defined(DEFINE_DEPRECATIONS)
+  READ_WRITE,
+// #endif
+};
+// #endif
+
+// #if defined(DEFINE_FFI)
+/// Always documented.
+///Only with `ffi` in Rust, but always in C.
+pub const Handle = extern struct {
+  ///The raw id.
+  id: u64,
+};
+// #endif
+
+// #if defined(DEFINE_WINDOWS)
+pub const Packet = extern struct {
+  tag: u8 align(1),
+  len: u32 align(1),
+};
+// #endif
+
+pub const Stats = extern struct {
+  count: u32,
+// #if (defined(DEFINE_UNIX) && defined(DEFINE_FFI))
+  fd: i32,
+// #endif
+};
+
+// #if defined(DEFINE_FFI)
+pub extern fn open(packet: ?*const Packet) ?*Handle;
+// #endif
+
+// #if defined(DEFINE_FFI)
+pub extern fn handle_close(handle: ?*Handle) bool;
+// #endif
+
+// #if defined(DEFINE_DEPRECATIONS)
+pub extern fn legacy() void;
+// #endif
+
+// #if (defined(DEFINE_FFI) && defined(DEFINE_UNIX))
+pub extern fn unix_only() void;
+// #endif
+
+// #if defined(DEFINE_FFI)
+pub extern fn mode(m: Mode) void;
+// #endif
+
+pub extern fn stats(stats: ?*const Stats) void;
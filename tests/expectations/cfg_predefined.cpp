@@ -0,0 +1,49 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#if ((defined(__unix__) || defined(__APPLE__)) && (defined(__LP64__) || defined(_WIN64)))
+using Handle = uint64_t;
+#endif
+
+#if ((defined(__unix__) || defined(__APPLE__)) && defined(TARGET_32_BIT))
+using Handle = uint32_t;
+#endif
+
+#if defined(_WIN32)
+using Handle = void*;
+#endif
+
+struct Options {
+  Handle handle;
+#if (defined(__linux__) && !defined(__ANDROID__))
+  int32_t epoll_fd
+#endif
+  ;
+#if (defined(__APPLE__) && defined(__MACH__))
+  int32_t kqueue_fd
+#endif
+  ;
+#if ((defined(__x86_64__) || defined(_M_X64)) || (defined(__aarch64__) || defined(_M_ARM64)))
+  bool simd
+#endif
+  ;
+#if defined(WITH_TRACING)
+  uint8_t trace_level
+#endif
+  ;
+};
+
+extern "C" {
+
+#if defined(MY_WINDOWS)
+void win_only(const Options *options);
+#endif
+
+#if defined(DEBUG)
+void check_invariants(const Options *options);
+#endif
+
+}  // extern "C"
@@ -0,0 +1,29 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Options
+  {
+    public void* handle;
+    public int epoll_fd;
+    public int kqueue_fd;
+    [MarshalAs(UnmanagedType.U1)] public bool simd;
+#if WITH_TRACING
+    public byte trace_level;
+#endif
+  }
+
+#if MY_WINDOWS
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void win_only(Options* options);
+#endif
+
+#if DEBUG
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void check_invariants(Options* options);
+#endif
+}
@@ -0,0 +1,33 @@
+import ctypes
+import enum
+
+Handle = ctypes.c_uint64
+
+class Options(ctypes.Structure):
+  _fields_ = [
+    ("handle", Handle),
+    ("epoll_fd", ctypes.c_int32),
+    ("kqueue_fd", ctypes.c_int32),
+    ("simd", ctypes.c_bool),
+# #if defined(WITH_TRACING)
+    ("trace_level", ctypes.c_uint8),
+# #endif
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+# #if defined(MY_WINDOWS)
+  if hasattr(lib, "win_only"):
+    lib.win_only.argtypes = [ctypes.POINTER(Options)]
+    lib.win_only.restype = None
+# #endif
+
+# #if defined(DEBUG)
+  if hasattr(lib, "check_invariants"):
+    lib.check_invariants.argtypes = [ctypes.POINTER(Options)]
+    lib.check_invariants.restype = None
+# #endif
+
+  return lib
@@ -0,0 +1,21 @@
+const std = @import("std");
+
+pub const Handle = u64;
+
+pub const Options = extern struct {
+  handle: Handle,
+  epoll_fd: i32,
+  kqueue_fd: i32,
+  simd: bool,
+// #if defined(WITH_TRACING)
+  trace_level: u8,
+// #endif
+};
+
+// #if defined(MY_WINDOWS)
+pub extern fn win_only(options: ?*const Options) void;
+// #endif
+
+// #if defined(DEBUG)
+pub extern fn check_invariants(options: ?*const Options) void;
+// #endif
@@ -0,0 +1,44 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if ((defined(__unix__) || defined(__APPLE__)) && (defined(__LP64__) || defined(_WIN64)))
+typedef uint64_t Handle;
+#endif
+
+#if ((defined(__unix__) || defined(__APPLE__)) && defined(TARGET_32_BIT))
+typedef uint32_t Handle;
+#endif
+
+#if defined(_WIN32)
+typedef void *Handle;
+#endif
+
+typedef struct Options {
+  Handle handle;
+#if (defined(__linux__) && !defined(__ANDROID__))
+  int32_t epoll_fd
+#endif
+  ;
+#if (defined(__APPLE__) && defined(__MACH__))
+  int32_t kqueue_fd
+#endif
+  ;
+#if ((defined(__x86_64__) || defined(_M_X64)) || (defined(__aarch64__) || defined(_M_ARM64)))
+  bool simd
+#endif
+  ;
+#if defined(WITH_TRACING)
+  uint8_t trace_level
+#endif
+  ;
+} Options;
+
+#if defined(MY_WINDOWS)
+void win_only(const struct Options *options);
+#endif
+
+#if defined(DEBUG)
+void check_invariants(const struct Options *options);
+#endif
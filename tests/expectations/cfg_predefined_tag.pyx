@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef uint64_t Handle;
+
+  IF TARGET_32_BIT:
+    ctypedef uint32_t Handle;
+
+  ctypedef void *Handle;
+
+  cdef struct Options:
+    Handle handle;
+    int32_t epoll_fd;
+    int32_t kqueue_fd;
+    bool simd;
+    uint8_t trace_level;
+
+  IF MY_WINDOWS:
+    void win_only(const Options *options);
+
+  IF DEBUG:
+    void check_invariants(const Options *options);
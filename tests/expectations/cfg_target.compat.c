@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define VECTOR_WIDTH 256
+
+enum Backend
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Epoll,
+  Poll,
+};
+#ifndef __cplusplus
+typedef uint8_t Backend;
+#endif // __cplusplus
+
+typedef uint64_t Handle;
+
+typedef struct {
+  Handle handle;
+  int32_t epoll_fd;
+  uint8_t trace_level;
+  uint32_t flags;
+} Options;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void open_backend(const Options *options, Backend backend);
+
+void set_trace_level(uint8_t level);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
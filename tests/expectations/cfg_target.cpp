@@ -0,0 +1,29 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint32_t VECTOR_WIDTH = 256;
+
+enum class Backend : uint8_t {
+  Epoll,
+  Poll,
+};
+
+using Handle = uint64_t;
+
+struct Options {
+  Handle handle;
+  int32_t epoll_fd;
+  uint8_t trace_level;
+  uint32_t flags;
+};
+
+extern "C" {
+
+void open_backend(const Options *options, Backend backend);
+
+void set_trace_level(uint8_t level);
+
+}  // extern "C"
@@ -0,0 +1,30 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const uint VECTOR_WIDTH = 256;
+
+  public enum Backend : byte
+  {
+    Epoll,
+    Poll,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Options
+  {
+    public ulong handle;
+    public int epoll_fd;
+    public byte trace_level;
+    public uint flags;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void open_backend(Options* options, Backend backend);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void set_trace_level(byte level);
+}
@@ -0,0 +1,30 @@
+import ctypes
+import enum
+
+VECTOR_WIDTH = 256
+
+class Backend(enum.IntEnum):
+  Epoll = 0
+  Poll = 1
+
+Handle = ctypes.c_uint64
+
+class Options(ctypes.Structure):
+  _fields_ = [
+    ("handle", Handle),
+    ("epoll_fd", ctypes.c_int32),
+    ("trace_level", ctypes.c_uint8),
+    ("flags", ctypes.c_uint32),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.open_backend.argtypes = [ctypes.POINTER(Options), ctypes.c_uint8]
+  lib.open_backend.restype = None
+
+  lib.set_trace_level.argtypes = [ctypes.c_uint8]
+  lib.set_trace_level.restype = None
+
+  return lib
@@ -0,0 +1,26 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t VECTOR_WIDTH # = 256
+
+  cdef enum:
+    Epoll,
+    Poll,
+  ctypedef uint8_t Backend;
+
+  ctypedef uint64_t Handle;
+
+  ctypedef struct Options:
+    Handle handle;
+    int32_t epoll_fd;
+    uint8_t trace_level;
+    uint32_t flags;
+
+  void open_backend(const Options *options, Backend backend);
+
+  void set_trace_level(uint8_t level);
@@ -0,0 +1,21 @@
+const std = @import("std");
+
+pub const VECTOR_WIDTH: u32 = 256;
+
+pub const Backend = enum(u8) {
+  Epoll,
+  Poll,
+};
+
+pub const Handle = u64;
+
+pub const Options = extern struct {
+  handle: Handle,
+  epoll_fd: i32,
+  trace_level: u8,
+  flags: u32,
+};
+
+pub extern fn open_backend(options: ?*const Options, backend: Backend) void;
+
+pub extern fn set_trace_level(level: u8) void;
@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define VECTOR_WIDTH 256
+
+enum Backend {
+  Epoll,
+  Poll,
+};
+typedef uint8_t Backend;
+
+typedef uint64_t Handle;
+
+typedef struct Options {
+  Handle handle;
+  int32_t epoll_fd;
+  uint8_t trace_level;
+  uint32_t flags;
+} Options;
+
+void open_backend(const struct Options *options, Backend backend);
+
+void set_trace_level(uint8_t level);
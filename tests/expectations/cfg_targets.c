@@ -0,0 +1,64 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if (((defined(__linux__) && !defined(__ANDROID__)) && (defined(__x86_64__) || defined(_M_X64))) || ((defined(__APPLE__) && defined(__MACH__)) && (defined(__aarch64__) || defined(_M_ARM64))) || (defined(_WIN32) && (defined(__x86_64__) || defined(_M_X64))))
+#define WORD_SIZE 8
+#endif
+
+#if ((defined(__linux__) && !defined(__ANDROID__)) && (defined(__i386__) || defined(_M_IX86)))
+#define WORD_SIZE 4
+#endif
+
+#define VERSION 3
+
+#if (((defined(__linux__) && !defined(__ANDROID__)) && (defined(__x86_64__) || defined(_M_X64))) || ((defined(__APPLE__) && defined(__MACH__)) && (defined(__aarch64__) || defined(_M_ARM64))) || ((defined(__linux__) && !defined(__ANDROID__)) && (defined(__i386__) || defined(_M_IX86))))
+enum Mode {
+  Read,
+  Write,
+};
+typedef uint8_t Mode;
+#endif
+
+#if (defined(_WIN32) && (defined(__x86_64__) || defined(_M_X64)))
+enum Mode {
+  Read,
+  Write,
+};
+typedef uint32_t Mode;
+#endif
+
+#if (((defined(__linux__) && !defined(__ANDROID__)) && (defined(__x86_64__) || defined(_M_X64))) || ((defined(__APPLE__) && defined(__MACH__)) && (defined(__aarch64__) || defined(_M_ARM64))) || ((defined(__linux__) && !defined(__ANDROID__)) && (defined(__i386__) || defined(_M_IX86))))
+typedef int32_t Handle;
+#endif
+
+#if (defined(_WIN32) && (defined(__x86_64__) || defined(_M_X64)))
+typedef void *Handle;
+#endif
+
+#if (((defined(__linux__) && !defined(__ANDROID__)) && (defined(__x86_64__) || defined(_M_X64))) || ((defined(__linux__) && !defined(__ANDROID__)) && (defined(__i386__) || defined(_M_IX86))))
+typedef struct {
+  Handle handle;
+  Mode mode;
+  int32_t epoll_fd;
+} Options;
+#endif
+
+#if (((defined(__APPLE__) && defined(__MACH__)) && (defined(__aarch64__) || defined(_M_ARM64))) || (defined(_WIN32) && (defined(__x86_64__) || defined(_M_X64))))
+typedef struct {
+  Handle handle;
+  Mode mode;
+} Options;
+#endif
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+#if ((defined(__APPLE__) && defined(__MACH__)) && (defined(__aarch64__) || defined(_M_ARM64)))
+void use_kqueue(Options *options);
+#endif
+
+Handle open(const Options *options, Point origin);
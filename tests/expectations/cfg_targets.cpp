@@ -0,0 +1,67 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#if (((defined(__linux__) && !defined(__ANDROID__)) && (defined(__x86_64__) || defined(_M_X64))) || ((defined(__APPLE__) && defined(__MACH__)) && (defined(__aarch64__) || defined(_M_ARM64))) || (defined(_WIN32) && (defined(__x86_64__) || defined(_M_X64))))
+constexpr static const uint32_t WORD_SIZE = 8;
+#endif
+
+#if ((defined(__linux__) && !defined(__ANDROID__)) && (defined(__i386__) || defined(_M_IX86)))
+constexpr static const uint32_t WORD_SIZE = 4;
+#endif
+
+constexpr static const uint32_t VERSION = 3;
+
+#if (((defined(__linux__) && !defined(__ANDROID__)) && (defined(__x86_64__) || defined(_M_X64))) || ((defined(__APPLE__) && defined(__MACH__)) && (defined(__aarch64__) || defined(_M_ARM64))) || ((defined(__linux__) && !defined(__ANDROID__)) && (defined(__i386__) || defined(_M_IX86))))
+enum class Mode : uint8_t {
+  Read,
+  Write,
+};
+#endif
+
+#if (defined(_WIN32) && (defined(__x86_64__) || defined(_M_X64)))
+enum class Mode : uint32_t {
+  Read,
+  Write,
+};
+#endif
+
+#if (((defined(__linux__) && !defined(__ANDROID__)) && (defined(__x86_64__) || defined(_M_X64))) || ((defined(__APPLE__) && defined(__MACH__)) && (defined(__aarch64__) || defined(_M_ARM64))) || ((defined(__linux__) && !defined(__ANDROID__)) && (defined(__i386__) || defined(_M_IX86))))
+using Handle = int32_t;
+#endif
+
+#if (defined(_WIN32) && (defined(__x86_64__) || defined(_M_X64)))
+using Handle = void*;
+#endif
+
+#if (((defined(__linux__) && !defined(__ANDROID__)) && (defined(__x86_64__) || defined(_M_X64))) || ((defined(__linux__) && !defined(__ANDROID__)) && (defined(__i386__) || defined(_M_IX86))))
+struct Options {
+  Handle handle;
+  Mode mode;
+  int32_t epoll_fd;
+};
+#endif
+
+#if (((defined(__APPLE__) && defined(__MACH__)) && (defined(__aarch64__) || defined(_M_ARM64))) || (defined(_WIN32) && (defined(__x86_64__) || defined(_M_X64))))
+struct Options {
+  Handle handle;
+  Mode mode;
+};
+#endif
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+#if ((defined(__APPLE__) && defined(__MACH__)) && (defined(__aarch64__) || defined(_M_ARM64)))
+void use_kqueue(Options *options);
+#endif
+
+Handle open(const Options *options, Point origin);
+
+}  // extern "C"
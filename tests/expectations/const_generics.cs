@@ -0,0 +1,19 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const nuint TITLE_SIZE = 80;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Book
+  {
+    public sbyte* title;
+    public sbyte* author;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Book* a);
+}
@@ -0,0 +1,23 @@
+import ctypes
+import enum
+
+TITLE_SIZE = 80
+
+CArrayString_TITLE_SIZE = ctypes.c_int8 * TITLE_SIZE
+
+CArrayString_40 = ctypes.c_int8 * 40
+
+class Book(ctypes.Structure):
+  _fields_ = [
+    ("title", CArrayString_TITLE_SIZE),
+    ("author", CArrayString_40),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Book)]
+  lib.root.restype = None
+
+  return lib
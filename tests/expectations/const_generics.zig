@@ -0,0 +1,14 @@
+const std = @import("std");
+
+pub const TITLE_SIZE: usize = 80;
+
+pub const CArrayString_TITLE_SIZE = [TITLE_SIZE]i8;
+
+pub const CArrayString_40 = [40]i8;
+
+pub const Book = extern struct {
+  title: CArrayString_TITLE_SIZE,
+  author: CArrayString_40,
+};
+
+pub extern fn root(a: ?*Book) void;
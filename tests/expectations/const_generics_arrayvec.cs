@@ -0,0 +1,116 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ArrayVec_____u8__100
+  {
+    public byte* xs_0;
+    public byte* xs_1;
+    public byte* xs_2;
+    public byte* xs_3;
+    public byte* xs_4;
+    public byte* xs_5;
+    public byte* xs_6;
+    public byte* xs_7;
+    public byte* xs_8;
+    public byte* xs_9;
+    public byte* xs_10;
+    public byte* xs_11;
+    public byte* xs_12;
+    public byte* xs_13;
+    public byte* xs_14;
+    public byte* xs_15;
+    public byte* xs_16;
+    public byte* xs_17;
+    public byte* xs_18;
+    public byte* xs_19;
+    public byte* xs_20;
+    public byte* xs_21;
+    public byte* xs_22;
+    public byte* xs_23;
+    public byte* xs_24;
+    public byte* xs_25;
+    public byte* xs_26;
+    public byte* xs_27;
+    public byte* xs_28;
+    public byte* xs_29;
+    public byte* xs_30;
+    public byte* xs_31;
+    public byte* xs_32;
+    public byte* xs_33;
+    public byte* xs_34;
+    public byte* xs_35;
+    public byte* xs_36;
+    public byte* xs_37;
+    public byte* xs_38;
+    public byte* xs_39;
+    public byte* xs_40;
+    public byte* xs_41;
+    public byte* xs_42;
+    public byte* xs_43;
+    public byte* xs_44;
+    public byte* xs_45;
+    public byte* xs_46;
+    public byte* xs_47;
+    public byte* xs_48;
+    public byte* xs_49;
+    public byte* xs_50;
+    public byte* xs_51;
+    public byte* xs_52;
+    public byte* xs_53;
+    public byte* xs_54;
+    public byte* xs_55;
+    public byte* xs_56;
+    public byte* xs_57;
+    public byte* xs_58;
+    public byte* xs_59;
+    public byte* xs_60;
+    public byte* xs_61;
+    public byte* xs_62;
+    public byte* xs_63;
+    public byte* xs_64;
+    public byte* xs_65;
+    public byte* xs_66;
+    public byte* xs_67;
+    public byte* xs_68;
+    public byte* xs_69;
+    public byte* xs_70;
+    public byte* xs_71;
+    public byte* xs_72;
+    public byte* xs_73;
+    public byte* xs_74;
+    public byte* xs_75;
+    public byte* xs_76;
+    public byte* xs_77;
+    public byte* xs_78;
+    public byte* xs_79;
+    public byte* xs_80;
+    public byte* xs_81;
+    public byte* xs_82;
+    public byte* xs_83;
+    public byte* xs_84;
+    public byte* xs_85;
+    public byte* xs_86;
+    public byte* xs_87;
+    public byte* xs_88;
+    public byte* xs_89;
+    public byte* xs_90;
+    public byte* xs_91;
+    public byte* xs_92;
+    public byte* xs_93;
+    public byte* xs_94;
+    public byte* xs_95;
+    public byte* xs_96;
+    public byte* xs_97;
+    public byte* xs_98;
+    public byte* xs_99;
+    public uint len;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern int push(ArrayVec_____u8__100* v, byte* elem);
+}
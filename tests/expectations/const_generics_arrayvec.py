@@ -0,0 +1,17 @@
+import ctypes
+import enum
+
+class ArrayVec_____u8__100(ctypes.Structure):
+  _fields_ = [
+    ("xs", ctypes.POINTER(ctypes.c_uint8) * 100),
+    ("len", ctypes.c_uint32),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.push.argtypes = [ctypes.POINTER(ArrayVec_____u8__100), ctypes.POINTER(ctypes.c_uint8)]
+  lib.push.restype = ctypes.c_int32
+
+  return lib
@@ -0,0 +1,43 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct HashTable_Str__c_char__false
+  {
+    public nuint num_buckets;
+    public nuint capacity;
+    public byte* occupied;
+    public byte** keys;
+    public byte* vals;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct HashTable_Str__u64__true
+  {
+    public nuint num_buckets;
+    public nuint capacity;
+    public byte* occupied;
+    public byte** keys;
+    public ulong* vals;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern HashTable_Str__c_char__false* new_set();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void set_for_each(
+    HashTable_Str__c_char__false* set,
+    delegate* unmanaged[Cdecl]<byte*, void> callback);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern HashTable_Str__u64__true* new_map();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void map_for_each(
+    HashTable_Str__u64__true* map,
+    delegate* unmanaged[Cdecl]<byte*, ulong, void> callback);
+}
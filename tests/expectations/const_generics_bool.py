@@ -0,0 +1,46 @@
+import ctypes
+import enum
+
+Str = ctypes.c_char_p
+
+class HashTable_Str__c_char__false(ctypes.Structure):
+  _fields_ = [
+    ("num_buckets", ctypes.c_size_t),
+    ("capacity", ctypes.c_size_t),
+    ("occupied", ctypes.POINTER(ctypes.c_uint8)),
+    ("keys", ctypes.POINTER(Str)),
+    ("vals", ctypes.c_char_p),
+  ]
+
+MySet = HashTable_Str__c_char__false
+
+SetCallback = ctypes.CFUNCTYPE(None, Str)
+
+class HashTable_Str__u64__true(ctypes.Structure):
+  _fields_ = [
+    ("num_buckets", ctypes.c_size_t),
+    ("capacity", ctypes.c_size_t),
+    ("occupied", ctypes.POINTER(ctypes.c_uint8)),
+    ("keys", ctypes.POINTER(Str)),
+    ("vals", ctypes.POINTER(ctypes.c_uint64)),
+  ]
+
+MapCallback = ctypes.CFUNCTYPE(None, Str, ctypes.c_uint64)
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.new_set.argtypes = []
+  lib.new_set.restype = ctypes.POINTER(MySet)
+
+  lib.set_for_each.argtypes = [ctypes.POINTER(MySet), SetCallback]
+  lib.set_for_each.restype = None
+
+  lib.new_map.argtypes = []
+  lib.new_map.restype = ctypes.POINTER(HashTable_Str__u64__true)
+
+  lib.map_for_each.argtypes = [ctypes.POINTER(HashTable_Str__u64__true), MapCallback]
+  lib.map_for_each.restype = None
+
+  return lib
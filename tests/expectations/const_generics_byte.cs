@@ -0,0 +1,30 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Parser_40__41
+  {
+    public byte* buf;
+    public nuint len;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Parser_123__125
+  {
+    public byte* buf;
+    public nuint len;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void init_parens_parser(Parser_40__41* p, byte* buf, nuint len);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void destroy_parens_parser(Parser_40__41* p);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void init_braces_parser(Parser_123__125* p, byte* buf, nuint len);
+}
@@ -0,0 +1,18 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TakeUntil_0
+  {
+    public byte* start;
+    public nuint len;
+    public nuint point;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern TakeUntil_0 until_nul(byte* start, nuint len);
+}
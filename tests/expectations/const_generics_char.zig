@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const TakeUntil_0 = extern struct {
+  start: ?*const u8,
+  len: usize,
+  point: usize,
+};
+
+pub extern fn until_nul(start: ?*const u8, len: usize) TakeUntil_0;
@@ -0,0 +1,25 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const ushort FONT_WEIGHT_FRACTION_BITS = 6;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct FixedPoint_FONT_WEIGHT_FRACTION_BITS
+  {
+    public ushort value;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct FontWeight
+  {
+    public FixedPoint_FONT_WEIGHT_FRACTION_BITS _0;
+  }
+  public static readonly FontWeight FontWeight_NORMAL = new FontWeight { _0 = new FontWeightFixedPoint { value = (400 << FONT_WEIGHT_FRACTION_BITS) } };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(FontWeight w);
+}
@@ -0,0 +1,16 @@
+const std = @import("std");
+
+pub const FONT_WEIGHT_FRACTION_BITS: u16 = 6;
+
+pub const FixedPoint_FONT_WEIGHT_FRACTION_BITS = extern struct {
+  value: u16,
+};
+
+pub const FontWeightFixedPoint = FixedPoint_FONT_WEIGHT_FRACTION_BITS;
+
+pub const FontWeight = extern struct {
+  _0: FontWeightFixedPoint,
+};
+pub const FontWeight_NORMAL: FontWeight = FontWeight{ ._0 = FontWeightFixedPoint{ .value = (400 << FONT_WEIGHT_FRACTION_BITS) } };
+
+pub extern fn root(w: FontWeight) void;
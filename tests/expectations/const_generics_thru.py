@@ -0,0 +1,34 @@
+import ctypes
+import enum
+
+class Inner_1(ctypes.Structure):
+  _fields_ = [
+    ("bytes", ctypes.c_uint8 * 1),
+  ]
+
+class Outer_1(ctypes.Structure):
+  _fields_ = [
+    ("inner", Inner_1),
+  ]
+
+class Inner_2(ctypes.Structure):
+  _fields_ = [
+    ("bytes", ctypes.c_uint8 * 2),
+  ]
+
+class Outer_2(ctypes.Structure):
+  _fields_ = [
+    ("inner", Inner_2),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.one.argtypes = []
+  lib.one.restype = Outer_1
+
+  lib.two.argtypes = []
+  lib.two.restype = Outer_2
+
+  return lib
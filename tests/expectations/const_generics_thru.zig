@@ -0,0 +1,21 @@
+const std = @import("std");
+
+pub const Inner_1 = extern struct {
+  bytes: [1]u8,
+};
+
+pub const Outer_1 = extern struct {
+  inner: Inner_1,
+};
+
+pub const Inner_2 = extern struct {
+  bytes: [2]u8,
+};
+
+pub const Outer_2 = extern struct {
+  inner: Inner_2,
+};
+
+pub extern fn one() Outer_1;
+
+pub extern fn two() Outer_2;
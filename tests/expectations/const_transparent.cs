@@ -0,0 +1,9 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public static readonly byte FOO = 0;
+}
@@ -0,0 +1,12 @@
+import ctypes
+import enum
+
+Transparent = ctypes.c_uint8
+
+FOO = 0
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  return lib
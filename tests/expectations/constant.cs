@@ -0,0 +1,59 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const int FOO = 10;
+
+  public const uint DELIMITER = ':';
+
+  public const uint LEFTCURLY = '{';
+
+  public const uint QUOTE = '\'';
+
+  public const uint TAB = '\t';
+
+  public const uint NEWLINE = '\n';
+
+  public const uint HEART = 0x2764;
+
+  public const uint EQUID = 0x10083;
+
+  public const float ZOM = (float)3.14;
+
+  /// <summary>
+  /// A single-line doc comment.
+  /// </summary>
+  public const sbyte POS_ONE = 1;
+
+  /// <summary>
+  /// A
+  /// multi-line
+  /// doc
+  /// comment.
+  /// </summary>
+  public const sbyte NEG_ONE = -1;
+
+  public const long SHIFT = 3;
+
+  public const long XBOOL = 1;
+
+  public const long XFALSE = ((0 << SHIFT) | XBOOL);
+
+  public const long XTRUE = (1 << (SHIFT | XBOOL));
+
+  public const byte CAST = (byte)('A');
+
+  public const uint DOUBLE_CAST = (uint)((float)(1));
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo
+  {
+    public fixed int x[FOO];
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo x);
+}
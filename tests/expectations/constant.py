@@ -0,0 +1,55 @@
+import ctypes
+import enum
+
+FOO = 10
+
+DELIMITER = ord(':')
+
+LEFTCURLY = ord('{')
+
+QUOTE = ord('\'')
+
+TAB = ord('\t')
+
+NEWLINE = ord('\n')
+
+HEART = 0x2764
+
+EQUID = 0x10083
+
+ZOM = 3.14
+
+# A single-line doc comment.
+POS_ONE = 1
+
+# A
+# multi-line
+# doc
+# comment.
+NEG_ONE = -1
+
+SHIFT = 3
+
+XBOOL = 1
+
+XFALSE = ((0 << SHIFT) | XBOOL)
+
+XTRUE = (1 << (SHIFT | XBOOL))
+
+CAST = ctypes.c_uint8(int(ord('A'))).value
+
+DOUBLE_CAST = ctypes.c_uint32(int(float(1))).value
+
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32 * FOO),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
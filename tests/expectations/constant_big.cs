@@ -0,0 +1,15 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const ulong UNSIGNED_NEEDS_ULL_SUFFIX = 9223372036854775808UL;
+
+  public const ulong UNSIGNED_DOESNT_NEED_ULL_SUFFIX = 8070450532247928832;
+
+  public const long SIGNED_NEEDS_ULL_SUFFIX = -9223372036854775808UL;
+
+  public const long SIGNED_DOESNT_NEED_ULL_SUFFIX = -9223372036854775807;
+}
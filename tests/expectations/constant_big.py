@@ -0,0 +1,16 @@
+import ctypes
+import enum
+
+UNSIGNED_NEEDS_ULL_SUFFIX = 9223372036854775808
+
+UNSIGNED_DOESNT_NEED_ULL_SUFFIX = 8070450532247928832
+
+SIGNED_NEEDS_ULL_SUFFIX = -9223372036854775808
+
+SIGNED_DOESNT_NEED_ULL_SUFFIX = -9223372036854775807
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  return lib
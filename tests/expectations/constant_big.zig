@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const UNSIGNED_NEEDS_ULL_SUFFIX: u64 = 9223372036854775808;
+
+pub const UNSIGNED_DOESNT_NEED_ULL_SUFFIX: u64 = 8070450532247928832;
+
+pub const SIGNED_NEEDS_ULL_SUFFIX: i64 = -9223372036854775808;
+
+pub const SIGNED_DOESNT_NEED_ULL_SUFFIX: i64 = -9223372036854775807;
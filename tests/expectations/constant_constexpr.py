@@ -0,0 +1,24 @@
+import ctypes
+import enum
+
+CONSTANT_I64 = 216
+
+CONSTANT_FLOAT32 = 312.292
+
+DELIMITER = ord(':')
+
+LEFTCURLY = ord('{')
+
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+  ]
+Foo_CONSTANT_I64_BODY = 216
+
+SomeFoo = Foo(x=99)
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  return lib
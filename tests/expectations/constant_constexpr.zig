@@ -0,0 +1,16 @@
+const std = @import("std");
+
+pub const CONSTANT_I64: i64 = 216;
+
+pub const CONSTANT_FLOAT32: f32 = 312.292;
+
+pub const DELIMITER: u32 = ':';
+
+pub const LEFTCURLY: u32 = '{';
+
+pub const Foo = extern struct {
+  x: i32,
+};
+pub const Foo_CONSTANT_I64_BODY: i64 = 216;
+
+pub const SomeFoo: Foo = Foo{ .x = 99 };
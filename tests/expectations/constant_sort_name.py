@@ -0,0 +1,16 @@
+import ctypes
+import enum
+
+A = 0
+
+B = 0
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.C = ctypes.c_uint8.in_dll(lib, "C")
+
+  lib.D = ctypes.c_uint8.in_dll(lib, "D")
+
+  return lib
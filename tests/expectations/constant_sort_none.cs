@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const byte B = 0;
+
+  public const byte A = 0;
+
+  private static readonly nint LibraryHandle = NativeLibrary.Load(LibraryName, typeof(NativeMethods).Assembly, null);
+
+  public static ref readonly byte D => ref *(byte*)NativeLibrary.GetExport(LibraryHandle, "D");
+
+  public static ref readonly byte C => ref *(byte*)NativeLibrary.GetExport(LibraryHandle, "C");
+}
@@ -0,0 +1,16 @@
+import ctypes
+import enum
+
+B = 0
+
+A = 0
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.D = ctypes.c_uint8.in_dll(lib, "D")
+
+  lib.C = ctypes.c_uint8.in_dll(lib, "C")
+
+  return lib
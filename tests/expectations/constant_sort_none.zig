@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const B: u8 = 0;
+
+pub const A: u8 = 0;
+
+pub extern const D: u8;
+
+pub extern const C: u8;
@@ -0,0 +1,24 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum E : int
+  {
+    V,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct S
+  {
+    public byte field;
+  }
+
+  public static readonly S C1 = new S { field = 0 };
+
+  public static readonly E C2 = V;
+
+  public static readonly byte C3 = 0;
+}
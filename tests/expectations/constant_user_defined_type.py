@@ -0,0 +1,24 @@
+import ctypes
+import enum
+
+class E(enum.IntEnum):
+  V = 0
+
+class S(ctypes.Structure):
+  _fields_ = [
+    ("field", ctypes.c_uint8),
+  ]
+
+A = ctypes.c_uint8
+
+C1 = S(field=0)
+
+C2 = E.V
+
+C3 = 0
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  return lib
@@ -0,0 +1,17 @@
+const std = @import("std");
+
+pub const E = enum(c_int) {
+  V,
+};
+
+pub const S = extern struct {
+  field: u8,
+};
+
+pub const A = u8;
+
+pub const C1: S = S{ .field = 0 };
+
+pub const C2: E = V;
+
+pub const C3: A = 0;
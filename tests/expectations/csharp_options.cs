@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+namespace Geometry.Native
+{
+  public static unsafe partial class Interop
+  {
+    private const string LibraryName = "geometry";
+
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Point
+    {
+      public float x;
+      public float y;
+    }
+
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static extern bool point_is_origin(Point* p);
+  }
+}
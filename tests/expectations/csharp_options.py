@@ -0,0 +1,17 @@
+import ctypes
+import enum
+
+class Point(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_float),
+    ("y", ctypes.c_float),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.point_is_origin.argtypes = [ctypes.POINTER(Point)]
+  lib.point_is_origin.restype = ctypes.c_bool
+
+  return lib
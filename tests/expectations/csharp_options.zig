@@ -0,0 +1,8 @@
+const std = @import("std");
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub extern fn point_is_origin(p: ?*const Point) bool;
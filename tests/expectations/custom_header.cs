@@ -0,0 +1,16 @@
+#if 0
+# This file is generated by cbindgen. DO NOT EDIT
+#endif
+
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root();
+}
+
+#if 0
+# This is a simple test to ensure that trailers do not cause extra newlines in files
+#endif
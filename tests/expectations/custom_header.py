@@ -0,0 +1,17 @@
+#if 0
+# This file is generated by cbindgen. DO NOT EDIT
+#endif
+
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
+
+#if 0
+# This is a simple test to ensure that trailers do not cause extra newlines in files
+#endif
@@ -0,0 +1,22 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum BindingType : uint
+  {
+    Buffer = 0,
+    NotBuffer = 1,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct BindGroupLayoutEntry
+  {
+    public BindingType ty;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(BindGroupLayoutEntry entry);
+}
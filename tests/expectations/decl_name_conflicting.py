@@ -0,0 +1,20 @@
+import ctypes
+import enum
+
+class BindingType(enum.IntEnum):
+  Buffer = 0
+  NotBuffer = 1
+
+class BindGroupLayoutEntry(ctypes.Structure):
+  _fields_ = [
+    ("ty", ctypes.c_uint32),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [BindGroupLayoutEntry]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,114 @@
+#define DEPRECATED_FUNC __attribute__((deprecated))
+#define DEPRECATED_STRUCT __attribute__((deprecated))
+#define DEPRECATED_ENUM __attribute__((deprecated))
+#define DEPRECATED_ENUM_VARIANT __attribute__((deprecated))
+#define DEPRECATED_FUNC_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_STRUCT_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_ENUM_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_ENUM_VARIANT_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum DeprecatedEnum : int
+  {
+    A = 0,
+  }
+
+  public enum DeprecatedEnumWithNote : int
+  {
+    B = 0,
+  }
+
+  public enum EnumWithDeprecatedVariants : int
+  {
+    C = 0,
+    D = 1,
+    E = 2,
+    F = 3,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct DeprecatedStruct
+  {
+    public int a;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct DeprecatedStructWithNote
+  {
+    public int a;
+  }
+
+  public enum EnumWithDeprecatedStructVariants_Tag : byte
+  {
+    Foo,
+    Bar,
+    Baz,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar_Body
+  {
+    public EnumWithDeprecatedStructVariants_Tag tag;
+    public byte x;
+    public short y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Baz_Body
+  {
+    public EnumWithDeprecatedStructVariants_Tag tag;
+    public byte x;
+    public byte y;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct EnumWithDeprecatedStructVariants
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Foo_Body
+    {
+      public EnumWithDeprecatedStructVariants_Tag foo_tag;
+      public short foo;
+    }
+
+    [FieldOffset(0)]
+    public Foo_Body foo;
+    [FieldOffset(0)]
+    public Bar_Body bar;
+    [FieldOffset(0)]
+    public Baz_Body baz;
+    [FieldOffset(0)]
+    public EnumWithDeprecatedStructVariants_Tag tag;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void deprecated_without_note();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void deprecated_without_bracket();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void deprecated_with_note();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void deprecated_with_note_and_since();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void deprecated_with_note_which_requires_to_be_escaped();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern @void dummy(
+    DeprecatedEnum a,
+    DeprecatedEnumWithNote b,
+    EnumWithDeprecatedVariants c,
+    DeprecatedStruct d,
+    DeprecatedStructWithNote e,
+    EnumWithDeprecatedStructVariants f);
+}
@@ -0,0 +1,98 @@
+#define DEPRECATED_FUNC __attribute__((deprecated))
+#define DEPRECATED_STRUCT __attribute__((deprecated))
+#define DEPRECATED_ENUM __attribute__((deprecated))
+#define DEPRECATED_ENUM_VARIANT __attribute__((deprecated))
+#define DEPRECATED_FUNC_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_STRUCT_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_ENUM_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_ENUM_VARIANT_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+
+
+import ctypes
+import enum
+
+class DeprecatedEnum(enum.IntEnum):
+  A = 0
+
+class DeprecatedEnumWithNote(enum.IntEnum):
+  B = 0
+
+class EnumWithDeprecatedVariants(enum.IntEnum):
+  C = 0
+  D = 1
+  E = 2
+  F = 3
+
+class DeprecatedStruct(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_int32),
+  ]
+
+class DeprecatedStructWithNote(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_int32),
+  ]
+
+class EnumWithDeprecatedStructVariants_Tag(enum.IntEnum):
+  Foo = 0
+  Bar = 1
+  Baz = 2
+
+class Foo_Body(ctypes.Structure):
+  _fields_ = [
+    ("foo_tag", ctypes.c_uint8),
+    ("foo", ctypes.c_int16),
+  ]
+
+class Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class Baz_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_uint8),
+  ]
+
+class EnumWithDeprecatedStructVariants(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("foo", Foo_Body),
+    ("bar", Bar_Body),
+    ("baz", Baz_Body),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.deprecated_without_note.argtypes = []
+  lib.deprecated_without_note.restype = None
+
+  lib.deprecated_without_bracket.argtypes = []
+  lib.deprecated_without_bracket.restype = None
+
+  lib.deprecated_with_note.argtypes = []
+  lib.deprecated_with_note.restype = None
+
+  lib.deprecated_with_note_and_since.argtypes = []
+  lib.deprecated_with_note_and_since.restype = None
+
+  lib.deprecated_with_note_which_requires_to_be_escaped.argtypes = []
+  lib.deprecated_with_note_which_requires_to_be_escaped.restype = None
+
+  lib.dummy.argtypes = [
+    ctypes.c_int32,
+    ctypes.c_int32,
+    ctypes.c_int32,
+    DeprecatedStruct,
+    DeprecatedStructWithNote,
+    EnumWithDeprecatedStructVariants,
+  ]
+  lib.dummy.restype = void
+
+  return lib
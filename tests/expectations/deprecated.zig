@@ -0,0 +1,81 @@
+#define DEPRECATED_FUNC __attribute__((deprecated))
+#define DEPRECATED_STRUCT __attribute__((deprecated))
+#define DEPRECATED_ENUM __attribute__((deprecated))
+#define DEPRECATED_ENUM_VARIANT __attribute__((deprecated))
+#define DEPRECATED_FUNC_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_STRUCT_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_ENUM_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_ENUM_VARIANT_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+
+
+const std = @import("std");
+
+pub const DeprecatedEnum = enum(i32) {
+  A = 0,
+};
+
+pub const DeprecatedEnumWithNote = enum(i32) {
+  B = 0,
+};
+
+pub const EnumWithDeprecatedVariants = enum(i32) {
+  C = 0,
+  D = 1,
+  E = 2,
+  F = 3,
+};
+
+pub const DeprecatedStruct = extern struct {
+  a: i32,
+};
+
+pub const DeprecatedStructWithNote = extern struct {
+  a: i32,
+};
+
+pub const EnumWithDeprecatedStructVariants_Tag = enum(u8) {
+  Foo,
+  Bar,
+  Baz,
+};
+
+pub const Bar_Body = extern struct {
+  tag: EnumWithDeprecatedStructVariants_Tag,
+  x: u8,
+  y: i16,
+};
+
+pub const Baz_Body = extern struct {
+  tag: EnumWithDeprecatedStructVariants_Tag,
+  x: u8,
+  y: u8,
+};
+
+pub const EnumWithDeprecatedStructVariants = extern union {
+  tag: EnumWithDeprecatedStructVariants_Tag,
+  foo: extern struct {
+    foo_tag: EnumWithDeprecatedStructVariants_Tag,
+    foo: i16,
+  },
+  bar: Bar_Body,
+  baz: Baz_Body,
+};
+
+pub extern fn deprecated_without_note() void;
+
+pub extern fn deprecated_without_bracket() void;
+
+pub extern fn deprecated_with_note() void;
+
+pub extern fn deprecated_with_note_and_since() void;
+
+pub extern fn deprecated_with_note_which_requires_to_be_escaped() void;
+
+pub extern fn dummy(
+  a: DeprecatedEnum,
+  b: DeprecatedEnumWithNote,
+  c: EnumWithDeprecatedVariants,
+  d: DeprecatedStruct,
+  e: DeprecatedStructWithNote,
+  f: EnumWithDeprecatedStructVariants,
+) @"void";
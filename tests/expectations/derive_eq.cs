@@ -0,0 +1,61 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo
+  {
+    [MarshalAs(UnmanagedType.U1)] public bool a;
+    public int b;
+  }
+
+  public enum Bar_Tag : byte
+  {
+    Baz,
+    Bazz,
+    FooNamed,
+    FooParen,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bazz_Body
+  {
+    public Bar_Tag tag;
+    public Foo named;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct FooNamed_Body
+  {
+    public Bar_Tag tag;
+    public int different;
+    public uint fields;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct FooParen_Body
+  {
+    public Bar_Tag tag;
+    public int _0;
+    public Foo _1;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Bar
+  {
+    [FieldOffset(0)]
+    public Bazz_Body bazz;
+    [FieldOffset(0)]
+    public FooNamed_Body foo_named;
+    [FieldOffset(0)]
+    public FooParen_Body foo_paren;
+    [FieldOffset(0)]
+    public Bar_Tag tag;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Foo root(Bar aBar);
+}
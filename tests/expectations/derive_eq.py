@@ -0,0 +1,51 @@
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_bool),
+    ("b", ctypes.c_int32),
+  ]
+
+class Bar_Tag(enum.IntEnum):
+  Baz = 0
+  Bazz = 1
+  FooNamed = 2
+  FooParen = 3
+
+class Bazz_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("named", Foo),
+  ]
+
+class FooNamed_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("different", ctypes.c_int32),
+    ("fields", ctypes.c_uint32),
+  ]
+
+class FooParen_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("_0", ctypes.c_int32),
+    ("_1", Foo),
+  ]
+
+class Bar(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("bazz", Bazz_Body),
+    ("foo_named", FooNamed_Body),
+    ("foo_paren", FooParen_Body),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Bar]
+  lib.root.restype = Foo
+
+  return lib
@@ -0,0 +1,133 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum C : uint
+  {
+    X = 2,
+    Y,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct A
+  {
+    public int _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct B
+  {
+    public int x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct D
+  {
+    public byte List;
+    public nuint Of;
+    public B Things;
+  }
+
+  public enum F_Tag : byte
+  {
+    Foo,
+    Bar,
+    Baz,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar_Body
+  {
+    public F_Tag tag;
+    public byte x;
+    public short y;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct F
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Foo_Body
+    {
+      public F_Tag foo_tag;
+      public short foo;
+    }
+
+    [FieldOffset(0)]
+    public Foo_Body foo;
+    [FieldOffset(0)]
+    public Bar_Body bar;
+    [FieldOffset(0)]
+    public F_Tag tag;
+  }
+
+  public enum H_Tag : byte
+  {
+    Hello,
+    There,
+    Everyone,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct There_Body
+  {
+    public byte x;
+    public short y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct H
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Hello_Body
+    {
+      public short hello;
+    }
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Data
+    {
+      [FieldOffset(0)]
+      public Hello_Body hello;
+      [FieldOffset(0)]
+      public There_Body there;
+    }
+
+    public H_Tag tag;
+    public Data data;
+  }
+
+  public enum I_Tag : byte
+  {
+    ThereAgain,
+    SomethingElse,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ThereAgain_Body
+  {
+    public byte x;
+    public short y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct I
+  {
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Data
+    {
+      [FieldOffset(0)]
+      public ThereAgain_Body there_again;
+    }
+
+    public I_Tag tag;
+    public Data data;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A a, B b, C c, D d, F f, H h, I i);
+}
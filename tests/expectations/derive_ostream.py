@@ -0,0 +1,107 @@
+import ctypes
+import enum
+
+class C(enum.IntEnum):
+  X = 2
+  Y = 3
+
+class A(ctypes.Structure):
+  _fields_ = [
+    ("_0", ctypes.c_int32),
+  ]
+
+class B(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+class D(ctypes.Structure):
+  _fields_ = [
+    ("List", ctypes.c_uint8),
+    ("Of", ctypes.c_size_t),
+    ("Things", B),
+  ]
+
+class F_Tag(enum.IntEnum):
+  Foo = 0
+  Bar = 1
+  Baz = 2
+
+class Foo_Body(ctypes.Structure):
+  _fields_ = [
+    ("foo_tag", ctypes.c_uint8),
+    ("foo", ctypes.c_int16),
+  ]
+
+class Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class F(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("foo", Foo_Body),
+    ("bar", Bar_Body),
+  ]
+
+class H_Tag(enum.IntEnum):
+  Hello = 0
+  There = 1
+  Everyone = 2
+
+class Hello_Body(ctypes.Structure):
+  _fields_ = [
+    ("hello", ctypes.c_int16),
+  ]
+
+class There_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class H_Data(ctypes.Union):
+  _fields_ = [
+    ("hello", Hello_Body),
+    ("there", There_Body),
+  ]
+
+class H(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", H_Data),
+  ]
+
+class I_Tag(enum.IntEnum):
+  ThereAgain = 0
+  SomethingElse = 1
+
+class ThereAgain_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class I_Data(ctypes.Union):
+  _fields_ = [
+    ("there_again", ThereAgain_Body),
+  ]
+
+class I(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", I_Data),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [A, B, ctypes.c_uint32, D, F, H, I]
+  lib.root.restype = None
+
+  return lib
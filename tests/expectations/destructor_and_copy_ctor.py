@@ -0,0 +1,257 @@
+#define NOINLINE __attribute__((noinline))
+#define NODISCARD [[nodiscard]]
+
+
+import ctypes
+import enum
+
+class FillRule(enum.IntEnum):
+  A = 0
+  B = 1
+
+# This will have a destructor manually implemented via variant_body, and
+# similarly a Drop impl in Rust.
+class OwnedSlice_u32(ctypes.Structure):
+  _fields_ = [
+    ("len", ctypes.c_size_t),
+    ("ptr", ctypes.POINTER(ctypes.c_uint32)),
+  ]
+
+class Polygon_u32(ctypes.Structure):
+  _fields_ = [
+    ("fill", ctypes.c_uint8),
+    ("coordinates", OwnedSlice_u32),
+  ]
+
+# This will have a destructor manually implemented via variant_body, and
+# similarly a Drop impl in Rust.
+class OwnedSlice_i32(ctypes.Structure):
+  _fields_ = [
+    ("len", ctypes.c_size_t),
+    ("ptr", ctypes.POINTER(ctypes.c_int32)),
+  ]
+
+class Foo_u32_Tag(enum.IntEnum):
+  Bar_u32 = 0
+  Polygon1_u32 = 1
+  Slice1_u32 = 2
+  Slice2_u32 = 3
+  Slice3_u32 = 4
+  Slice4_u32 = 5
+
+class Polygon1_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("polygon1", Polygon_u32),
+  ]
+
+class Slice1_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("slice1", OwnedSlice_u32),
+  ]
+
+class Slice2_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("slice2", OwnedSlice_i32),
+  ]
+
+class Slice3_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("fill", ctypes.c_uint8),
+    ("coords", OwnedSlice_u32),
+  ]
+
+class Slice4_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("fill", ctypes.c_uint8),
+    ("coords", OwnedSlice_i32),
+  ]
+
+class Foo_u32_Data(ctypes.Union):
+  _fields_ = [
+    ("polygon1", Polygon1_Body_u32),
+    ("slice1", Slice1_Body_u32),
+    ("slice2", Slice2_Body_u32),
+    ("slice3", Slice3_Body_u32),
+    ("slice4", Slice4_Body_u32),
+  ]
+
+class Foo_u32(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", Foo_u32_Data),
+  ]
+
+class Polygon_i32(ctypes.Structure):
+  _fields_ = [
+    ("fill", ctypes.c_uint8),
+    ("coordinates", OwnedSlice_i32),
+  ]
+
+class Baz_i32_Tag(enum.IntEnum):
+  Bar2_i32 = 0
+  Polygon21_i32 = 1
+  Slice21_i32 = 2
+  Slice22_i32 = 3
+  Slice23_i32 = 4
+  Slice24_i32 = 5
+
+class Polygon21_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("polygon21_tag", ctypes.c_uint8),
+    ("polygon21", Polygon_i32),
+  ]
+
+class Slice21_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("slice21_tag", ctypes.c_uint8),
+    ("slice21", OwnedSlice_i32),
+  ]
+
+class Slice22_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("slice22_tag", ctypes.c_uint8),
+    ("slice22", OwnedSlice_i32),
+  ]
+
+class Slice23_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("fill", ctypes.c_uint8),
+    ("coords", OwnedSlice_i32),
+  ]
+
+class Slice24_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("fill", ctypes.c_uint8),
+    ("coords", OwnedSlice_i32),
+  ]
+
+class Baz_i32(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("polygon21", Polygon21_Body_i32),
+    ("slice21", Slice21_Body_i32),
+    ("slice22", Slice22_Body_i32),
+    ("slice23", Slice23_Body_i32),
+    ("slice24", Slice24_Body_i32),
+  ]
+
+class Taz_Tag(enum.IntEnum):
+  Bar3 = 0
+  Taz1 = 1
+  Taz3 = 2
+
+class Taz1_Body(ctypes.Structure):
+  _fields_ = [
+    ("taz1_tag", ctypes.c_uint8),
+    ("taz1", ctypes.c_int32),
+  ]
+
+class Taz3_Body(ctypes.Structure):
+  _fields_ = [
+    ("taz3_tag", ctypes.c_uint8),
+    ("taz3", OwnedSlice_i32),
+  ]
+
+class Taz(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("taz1", Taz1_Body),
+    ("taz3", Taz3_Body),
+  ]
+
+class Tazz_Tag(enum.IntEnum):
+  Bar4 = 0
+  Taz2 = 1
+
+class Taz2_Body(ctypes.Structure):
+  _fields_ = [
+    ("taz2_tag", ctypes.c_uint8),
+    ("taz2", ctypes.c_int32),
+  ]
+
+class Tazz(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("taz2", Taz2_Body),
+  ]
+
+class Tazzz_Tag(enum.IntEnum):
+  Bar5 = 0
+  Taz5 = 1
+
+class Taz5_Body(ctypes.Structure):
+  _fields_ = [
+    ("taz5_tag", ctypes.c_uint8),
+    ("taz5", ctypes.c_int32),
+  ]
+
+class Tazzz(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("taz5", Taz5_Body),
+  ]
+
+class Tazzzz_Tag(enum.IntEnum):
+  Taz6 = 0
+  Taz7 = 1
+
+class Taz6_Body(ctypes.Structure):
+  _fields_ = [
+    ("taz6_tag", ctypes.c_uint8),
+    ("taz6", ctypes.c_int32),
+  ]
+
+class Taz7_Body(ctypes.Structure):
+  _fields_ = [
+    ("taz7_tag", ctypes.c_uint8),
+    ("taz7", ctypes.c_uint32),
+  ]
+
+class Tazzzz(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("taz6", Taz6_Body),
+    ("taz7", Taz7_Body),
+  ]
+
+class Qux_Tag(enum.IntEnum):
+  Qux1 = 0
+  Qux2 = 1
+
+class Qux1_Body(ctypes.Structure):
+  _fields_ = [
+    ("qux1_tag", ctypes.c_uint8),
+    ("qux1", ctypes.c_int32),
+  ]
+
+class Qux2_Body(ctypes.Structure):
+  _fields_ = [
+    ("qux2_tag", ctypes.c_uint8),
+    ("qux2", ctypes.c_uint32),
+  ]
+
+class Qux(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("qux1", Qux1_Body),
+    ("qux2", Qux2_Body),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    ctypes.POINTER(Foo_u32),
+    ctypes.POINTER(Baz_i32),
+    ctypes.POINTER(Taz),
+    Tazz,
+    ctypes.POINTER(Tazzz),
+    ctypes.POINTER(Tazzzz),
+    ctypes.POINTER(Qux),
+  ]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,53 @@
+import ctypes
+import enum
+
+class Rect(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_float),
+    ("y", ctypes.c_float),
+    ("w", ctypes.c_float),
+    ("h", ctypes.c_float),
+  ]
+
+class Color(ctypes.Structure):
+  _fields_ = [
+    ("r", ctypes.c_uint8),
+    ("g", ctypes.c_uint8),
+    ("b", ctypes.c_uint8),
+    ("a", ctypes.c_uint8),
+  ]
+
+class DisplayItem_Tag(enum.IntEnum):
+  Fill = 0
+  Image = 1
+  ClearScreen = 2
+
+class Fill_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("_0", Rect),
+    ("_1", Color),
+  ]
+
+class Image_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("id", ctypes.c_uint32),
+    ("bounds", Rect),
+  ]
+
+class DisplayItem(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("fill", Fill_Body),
+    ("image", Image_Body),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.push_item.argtypes = [DisplayItem]
+  lib.push_item.restype = ctypes.c_bool
+
+  return lib
@@ -0,0 +1,12 @@
+import ctypes
+import enum
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  # The root of all evil.
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
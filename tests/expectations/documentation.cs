@@ -0,0 +1,37 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  private static readonly nint LibraryHandle = NativeLibrary.Load(LibraryName, typeof(NativeMethods).Assembly, null);
+
+  /// <summary>
+  /// Some docs.
+  /// </summary>
+  public static ref readonly uint FOO => ref *(uint*)NativeLibrary.GetExport(LibraryHandle, "FOO");
+
+  /// <summary>
+  /// The root of all evil.
+  ///
+  /// But at least it contains some more documentation as someone would expect
+  /// from a simple test case like this.
+  ///
+  /// # Hint
+  ///
+  /// Always ensure that everything is properly documented, even if you feel lazy.
+  /// **Sometimes** it is also helpful to include some markdown formatting.
+  ///
+  /// ////////////////////////////////////////////////////////////////////////////
+  ///
+  /// Attention:
+  ///
+  ///    Rust is going to trim all leading `/` symbols. If you want to use them as a
+  ///    marker you need to add at least a single whitespace inbetween the tripple
+  ///    slash doc-comment marker and the rest.
+  ///
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root();
+}
@@ -0,0 +1,24 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  /// <summary>
+  ///With doc attr, each attr contribute to one line of document
+  ///like this one with a new line character at its end
+  ///and this one as well. So they are in the same paragraph
+  ///
+  ///Line ends with one new line should not break
+  ///
+  ///Line ends with two spaces and a new line
+  ///should break to next line
+  ///
+  ///Line ends with two new lines
+  ///
+  ///Should break to next paragraph
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root();
+}
@@ -0,0 +1,291 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class A(enum.IntEnum):
+  a1 = 0
+  a2 = 2
+  a3 = 3
+  a4 = 5
+
+class B(enum.IntEnum):
+  b1 = 0
+  b2 = 2
+  b3 = 3
+  b4 = 5
+
+class C(enum.IntEnum):
+  c1 = 0
+  c2 = 2
+  c3 = 3
+  c4 = 5
+
+class D(enum.IntEnum):
+  d1 = 0
+  d2 = 2
+  d3 = 3
+  d4 = 5
+
+class E(enum.IntEnum):
+  e1 = 0
+  e2 = 2
+  e3 = 3
+  e4 = 5
+
+class F(enum.IntEnum):
+  f1 = 0
+  f2 = 2
+  f3 = 3
+  f4 = 5
+
+class L(enum.IntEnum):
+  l1 = 0
+  l2 = 1
+  l3 = 2
+  l4 = 3
+
+class M(enum.IntEnum):
+  m1 = -1
+  m2 = 0
+  m3 = 1
+
+class N(enum.IntEnum):
+  n1 = 0
+  n2 = 1
+  n3 = 2
+  n4 = 3
+
+class O(enum.IntEnum):
+  o1 = 0
+  o2 = 1
+  o3 = 2
+  o4 = 3
+
+class J(ctypes.Structure):
+  pass
+
+class K(ctypes.Structure):
+  pass
+
+class Opaque(ctypes.Structure):
+  pass
+
+class G_Tag(enum.IntEnum):
+  Foo = 0
+  Bar = 1
+  Baz = 2
+
+class Foo_Body(ctypes.Structure):
+  _fields_ = [
+    ("foo_tag", ctypes.c_uint8),
+    ("foo", ctypes.c_int16),
+  ]
+
+class Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class G(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("foo", Foo_Body),
+    ("bar", Bar_Body),
+  ]
+
+class H_Tag(enum.IntEnum):
+  H_Foo = 0
+  H_Bar = 1
+  H_Baz = 2
+
+class H_Foo_Body(ctypes.Structure):
+  _fields_ = [
+    ("foo", ctypes.c_int16),
+  ]
+
+class H_Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class H_Data(ctypes.Union):
+  _fields_ = [
+    ("foo", H_Foo_Body),
+    ("bar", H_Bar_Body),
+  ]
+
+class H(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_int),
+    ("data", H_Data),
+  ]
+
+class ExI_Tag(enum.IntEnum):
+  ExI_Foo = 0
+  ExI_Bar = 1
+  ExI_Baz = 2
+
+class ExI_Foo_Body(ctypes.Structure):
+  _fields_ = [
+    ("foo", ctypes.c_int16),
+  ]
+
+class ExI_Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class ExI_Data(ctypes.Union):
+  _fields_ = [
+    ("foo", ExI_Foo_Body),
+    ("bar", ExI_Bar_Body),
+  ]
+
+class ExI(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", ExI_Data),
+  ]
+
+class P_Tag(enum.IntEnum):
+  P0 = 0
+  P1 = 1
+
+class P0_Body(ctypes.Structure):
+  _fields_ = [
+    ("p0", ctypes.c_uint8),
+  ]
+
+class P1_Body(ctypes.Structure):
+  _fields_ = [
+    ("_0", ctypes.c_uint8),
+    ("_1", ctypes.c_uint8),
+    ("_2", ctypes.c_uint8),
+  ]
+
+class P_Data(ctypes.Union):
+  _fields_ = [
+    ("p0", P0_Body),
+    ("p1", P1_Body),
+  ]
+
+class P(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", P_Data),
+  ]
+
+class Q_Tag(enum.IntEnum):
+  Ok = 0
+  Err = 1
+
+class Ok_Body(ctypes.Structure):
+  _fields_ = [
+    ("ok", ctypes.POINTER(ctypes.c_uint32)),
+  ]
+
+class Err_Body(ctypes.Structure):
+  _fields_ = [
+    ("err", ctypes.c_uint32),
+  ]
+
+class Q_Data(ctypes.Union):
+  _fields_ = [
+    ("ok", Ok_Body),
+    ("err", Err_Body),
+  ]
+
+class Q(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_int),
+    ("data", Q_Data),
+  ]
+
+class R_Tag(enum.IntEnum):
+  IRFoo = 0
+  IRBar = 1
+  IRBaz = 2
+
+class IRFoo_Body(ctypes.Structure):
+  _fields_ = [
+    ("IRFoo", ctypes.c_int16),
+  ]
+
+class IRBar_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class R_Data(ctypes.Union):
+  _fields_ = [
+    ("IRFoo", IRFoo_Body),
+    ("IRBar", IRBar_Body),
+  ]
+
+class R(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_int),
+    ("data", R_Data),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    ctypes.POINTER(Opaque),
+    ctypes.c_uint64,
+    ctypes.c_uint32,
+    ctypes.c_uint16,
+    ctypes.c_uint8,
+    ctypes.c_size_t,
+    ctypes.c_ssize_t,
+    G,
+    H,
+    ExI,
+    J,
+    K,
+    ctypes.c_int,
+    ctypes.c_int8,
+    ctypes.c_int,
+    ctypes.c_int8,
+    P,
+    Q,
+    R,
+  ]
+  lib.root.restype = None
+
+  return lib
+
+#if 0
+''' '
+#endif
+
+#include <stddef.h>
+#include "testing-helpers.h"
+static_assert(offsetof(CBINDGEN_STRUCT(P), tag) == 0, "unexpected offset for tag");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p0");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p1");
+static_assert(sizeof(CBINDGEN_STRUCT(P)) == 4, "unexpected size for P");
+
+#if 0
+' '''
+#endif
@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const sbyte FOURTY_FOUR = 4;
+
+  public enum E : sbyte
+  {
+    A = 1,
+    B = -1,
+    C = (1 + 2),
+    D = FOURTY_FOUR,
+    F = 5,
+    G = (sbyte)(54),
+    H = (sbyte)(false),
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(E* arg0);
+}
@@ -0,0 +1,22 @@
+import ctypes
+import enum
+
+FOURTY_FOUR = 4
+
+class E(enum.IntEnum):
+  A = 1
+  B = -1
+  C = (1 + 2)
+  D = FOURTY_FOUR
+  F = 5
+  G = ctypes.c_int8(int(54)).value
+  H = ctypes.c_int8(int(False)).value
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(ctypes.c_int8)]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,48 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_Bar
+  {
+    public int* something;
+  }
+
+  public enum Bar_Tag : byte
+  {
+    Min,
+    Max,
+    Other,
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Bar
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Min_Body
+    {
+      public Bar_Tag min_tag;
+      public Foo_Bar min;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Max_Body
+    {
+      public Bar_Tag max_tag;
+      public Foo_Bar max;
+    }
+
+    [FieldOffset(0)]
+    public Min_Body min;
+    [FieldOffset(0)]
+    public Max_Body max;
+    [FieldOffset(0)]
+    public Bar_Tag tag;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Bar b);
+}
@@ -0,0 +1,40 @@
+import ctypes
+import enum
+
+class Foo_Bar(ctypes.Structure):
+  _fields_ = [
+    ("something", ctypes.POINTER(ctypes.c_int32)),
+  ]
+
+class Bar_Tag(enum.IntEnum):
+  Min = 0
+  Max = 1
+  Other = 2
+
+class Min_Body(ctypes.Structure):
+  _fields_ = [
+    ("min_tag", ctypes.c_uint8),
+    ("min", Foo_Bar),
+  ]
+
+class Max_Body(ctypes.Structure):
+  _fields_ = [
+    ("max_tag", ctypes.c_uint8),
+    ("max", Foo_Bar),
+  ]
+
+class Bar(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("min", Min_Body),
+    ("max", Max_Body),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Bar]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,25 @@
+const std = @import("std");
+
+pub const Foo_Bar = extern struct {
+  something: ?*const i32,
+};
+
+pub const Bar_Tag = enum(u8) {
+  Min,
+  Max,
+  Other,
+};
+
+pub const Bar = extern union {
+  tag: Bar_Tag,
+  min: extern struct {
+    min_tag: Bar_Tag,
+    min: Foo_Bar,
+  },
+  max: extern struct {
+    max_tag: Bar_Tag,
+    max: Foo_Bar,
+  },
+};
+
+pub extern fn root(b: Bar) void;
@@ -0,0 +1,116 @@
+const std = @import("std");
+
+pub const TypedLength_f32__UnknownUnit = extern struct {
+  _0: f32,
+};
+
+pub const TypedLength_f32__LayoutUnit = extern struct {
+  _0: f32,
+};
+
+pub const Length_f32 = TypedLength_f32__UnknownUnit;
+
+pub const LayoutLength = TypedLength_f32__LayoutUnit;
+
+pub const TypedSideOffsets2D_f32__UnknownUnit = extern struct {
+  top: f32,
+  right: f32,
+  bottom: f32,
+  left: f32,
+};
+
+pub const TypedSideOffsets2D_f32__LayoutUnit = extern struct {
+  top: f32,
+  right: f32,
+  bottom: f32,
+  left: f32,
+};
+
+pub const SideOffsets2D_f32 = TypedSideOffsets2D_f32__UnknownUnit;
+
+pub const LayoutSideOffsets2D = TypedSideOffsets2D_f32__LayoutUnit;
+
+pub const TypedSize2D_f32__UnknownUnit = extern struct {
+  width: f32,
+  height: f32,
+};
+
+pub const TypedSize2D_f32__LayoutUnit = extern struct {
+  width: f32,
+  height: f32,
+};
+
+pub const Size2D_f32 = TypedSize2D_f32__UnknownUnit;
+
+pub const LayoutSize2D = TypedSize2D_f32__LayoutUnit;
+
+pub const TypedPoint2D_f32__UnknownUnit = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const TypedPoint2D_f32__LayoutUnit = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Point2D_f32 = TypedPoint2D_f32__UnknownUnit;
+
+pub const LayoutPoint2D = TypedPoint2D_f32__LayoutUnit;
+
+pub const TypedRect_f32__UnknownUnit = extern struct {
+  origin: TypedPoint2D_f32__UnknownUnit,
+  size: TypedSize2D_f32__UnknownUnit,
+};
+
+pub const TypedRect_f32__LayoutUnit = extern struct {
+  origin: TypedPoint2D_f32__LayoutUnit,
+  size: TypedSize2D_f32__LayoutUnit,
+};
+
+pub const Rect_f32 = TypedRect_f32__UnknownUnit;
+
+pub const LayoutRect = TypedRect_f32__LayoutUnit;
+
+pub const TypedTransform2D_f32__UnknownUnit__LayoutUnit = extern struct {
+  m11: f32,
+  m12: f32,
+  m21: f32,
+  m22: f32,
+  m31: f32,
+  m32: f32,
+};
+
+pub const TypedTransform2D_f32__LayoutUnit__UnknownUnit = extern struct {
+  m11: f32,
+  m12: f32,
+  m21: f32,
+  m22: f32,
+  m31: f32,
+  m32: f32,
+};
+
+pub extern fn root(
+  length_a: TypedLength_f32__UnknownUnit,
+  length_b: TypedLength_f32__LayoutUnit,
+  length_c: Length_f32,
+  length_d: LayoutLength,
+  side_offsets_a: TypedSideOffsets2D_f32__UnknownUnit,
+  side_offsets_b: TypedSideOffsets2D_f32__LayoutUnit,
+  side_offsets_c: SideOffsets2D_f32,
+  side_offsets_d: LayoutSideOffsets2D,
+  size_a: TypedSize2D_f32__UnknownUnit,
+  size_b: TypedSize2D_f32__LayoutUnit,
+  size_c: Size2D_f32,
+  size_d: LayoutSize2D,
+  point_a: TypedPoint2D_f32__UnknownUnit,
+  point_b: TypedPoint2D_f32__LayoutUnit,
+  point_c: Point2D_f32,
+  point_d: LayoutPoint2D,
+  rect_a: TypedRect_f32__UnknownUnit,
+  rect_b: TypedRect_f32__LayoutUnit,
+  rect_c: Rect_f32,
+  rect_d: LayoutRect,
+  transform_a: TypedTransform2D_f32__UnknownUnit__LayoutUnit,
+  transform_b: TypedTransform2D_f32__LayoutUnit__UnknownUnit,
+) void;
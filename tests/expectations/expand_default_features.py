@@ -0,0 +1,17 @@
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  pass
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.extra_debug_fn.argtypes = []
+  lib.extra_debug_fn.restype = None
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,7 @@
+const std = @import("std");
+
+pub const Foo = extern struct {};
+
+pub extern fn extra_debug_fn() void;
+
+pub extern fn root(a: Foo) void;
@@ -0,0 +1,17 @@
+import ctypes
+import enum
+
+class dep_struct(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint32),
+    ("y", ctypes.c_double),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.get_x.argtypes = [ctypes.POINTER(dep_struct)]
+  lib.get_x.restype = ctypes.c_uint32
+
+  return lib
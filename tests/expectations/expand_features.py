@@ -0,0 +1,22 @@
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  pass
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  if hasattr(lib, "extra_debug_fn"):
+    lib.extra_debug_fn.argtypes = []
+    lib.extra_debug_fn.restype = None
+
+  if hasattr(lib, "cbindgen"):
+    lib.cbindgen.argtypes = []
+    lib.cbindgen.restype = None
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,11 @@
+import ctypes
+import enum
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.do_the_thing_with_export_name.argtypes = []
+  lib.do_the_thing_with_export_name.restype = None
+
+  return lib
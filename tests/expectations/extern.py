@@ -0,0 +1,23 @@
+import ctypes
+import enum
+
+class Normal(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.foo.argtypes = []
+  lib.foo.restype = ctypes.c_int32
+
+  lib.bar.argtypes = [Normal]
+  lib.bar.restype = None
+
+  lib.baz.argtypes = []
+  lib.baz.restype = ctypes.c_int32
+
+  return lib
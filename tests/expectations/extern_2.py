@@ -0,0 +1,14 @@
+import ctypes
+import enum
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.first.argtypes = []
+  lib.first.restype = None
+
+  lib.second.argtypes = []
+  lib.second.restype = None
+
+  return lib
@@ -0,0 +1,16 @@
+import ctypes
+import enum
+
+class ExtType(ctypes.Structure):
+  _fields_ = [
+    ("data", ctypes.c_uint32),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.consume_ext.argtypes = [ExtType]
+  lib.consume_ext.restype = None
+
+  return lib
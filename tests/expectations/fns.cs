@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Fns
+  {
+    public delegate* unmanaged[Cdecl]<void> noArgs;
+    public delegate* unmanaged[Cdecl]<int, void> anonymousArg;
+    public delegate* unmanaged[Cdecl]<int> returnsNumber;
+    public delegate* unmanaged[Cdecl]<int, short, sbyte> namedArgs;
+    public delegate* unmanaged[Cdecl]<int, short, long, sbyte> namedArgsWildcards;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Fns _fns);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void no_return();
+}
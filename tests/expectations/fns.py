@@ -0,0 +1,23 @@
+import ctypes
+import enum
+
+class Fns(ctypes.Structure):
+  _fields_ = [
+    ("noArgs", ctypes.CFUNCTYPE(None)),
+    ("anonymousArg", ctypes.CFUNCTYPE(None, ctypes.c_int32)),
+    ("returnsNumber", ctypes.CFUNCTYPE(ctypes.c_int32)),
+    ("namedArgs", ctypes.CFUNCTYPE(ctypes.c_int8, ctypes.c_int32, ctypes.c_int16)),
+    ("namedArgsWildcards", ctypes.CFUNCTYPE(ctypes.c_int8, ctypes.c_int32, ctypes.c_int16, ctypes.c_int64)),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Fns]
+  lib.root.restype = None
+
+  lib.no_return.argtypes = []
+  lib.no_return.restype = None
+
+  return lib
@@ -0,0 +1,70 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StructInfo
+  {
+    public TypeInfo** fields;
+    public nuint num_fields;
+  }
+
+  public enum TypeData_Tag : int
+  {
+    Primitive,
+    Struct,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TypeData
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Struct_Body
+    {
+      public StructInfo struct_;
+    }
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Data
+    {
+      [FieldOffset(0)]
+      public Struct_Body struct_;
+    }
+
+    public TypeData_Tag tag;
+    public Data data;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TypeInfo
+  {
+    public TypeData data;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(TypeInfo x);
+}
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
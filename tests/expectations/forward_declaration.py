@@ -0,0 +1,65 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class TypeInfo(ctypes.Structure):
+  pass
+
+class StructInfo(ctypes.Structure):
+  _fields_ = [
+    ("fields", ctypes.POINTER(ctypes.POINTER(TypeInfo))),
+    ("num_fields", ctypes.c_size_t),
+  ]
+
+class TypeData_Tag(enum.IntEnum):
+  Primitive = 0
+  Struct = 1
+
+class Struct_Body(ctypes.Structure):
+  _fields_ = [
+    ("struct_", StructInfo),
+  ]
+
+class TypeData_Data(ctypes.Union):
+  _fields_ = [
+    ("struct_", Struct_Body),
+  ]
+
+class TypeData(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_int),
+    ("data", TypeData_Data),
+  ]
+
+TypeInfo._fields_ = [
+  ("data", TypeData),
+]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [TypeInfo]
+  lib.root.restype = None
+
+  return lib
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
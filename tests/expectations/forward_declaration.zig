@@ -0,0 +1,47 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+
+pub const StructInfo = extern struct {
+  fields: ?*const ?*const TypeInfo,
+  num_fields: usize,
+};
+
+pub const TypeData_Tag = enum(c_int) {
+  Primitive,
+  Struct,
+};
+
+pub const TypeData = extern struct {
+  tag: TypeData_Tag,
+  data: extern union {
+    struct_: extern struct {
+      struct_: StructInfo,
+    },
+  },
+};
+
+pub const TypeInfo = extern struct {
+  data: TypeData,
+};
+
+pub extern fn root(x: TypeInfo) void;
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
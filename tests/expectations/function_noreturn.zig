@@ -0,0 +1,17 @@
+const std = @import("std");
+#ifndef NO_RETURN_ATTR
+  #ifdef __GNUC__
+    #define NO_RETURN_ATTR __attribute__ ((noreturn))
+  #else // __GNUC__
+    #define NO_RETURN_ATTR
+  #endif // __GNUC__
+#endif // NO_RETURN_ATTR
+
+
+pub const Example = extern struct {
+  f: *const fn (usize, usize) callconv(.C) noreturn,
+};
+
+pub extern fn loop_forever() noreturn;
+
+pub extern fn normal_return(arg: Example, other: *const fn (u8) callconv(.C) noreturn) u8;
@@ -0,0 +1,12 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void my_function(
+    delegate* unmanaged[Cdecl]<nuint, nuint, void> a,
+    delegate* unmanaged[Cdecl]<nuint, nuint, nuint, nuint, nuint, void> b);
+}
@@ -0,0 +1,15 @@
+import ctypes
+import enum
+
+MyCallback = ctypes.CFUNCTYPE(None, ctypes.c_size_t, ctypes.c_size_t)
+
+MyOtherCallback = ctypes.CFUNCTYPE(None, ctypes.c_size_t, ctypes.c_size_t, ctypes.c_size_t, ctypes.c_size_t, ctypes.c_size_t)
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.my_function.argtypes = [MyCallback, MyOtherCallback]
+  lib.my_function.restype = None
+
+  return lib
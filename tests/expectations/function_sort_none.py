@@ -0,0 +1,20 @@
+import ctypes
+import enum
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.C.argtypes = []
+  lib.C.restype = None
+
+  lib.B.argtypes = []
+  lib.B.restype = None
+
+  lib.D.argtypes = []
+  lib.D.restype = None
+
+  lib.A.argtypes = []
+  lib.A.restype = None
+
+  return lib
@@ -0,0 +1,18 @@
+import ctypes
+import enum
+
+class Foo_____u8(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+Boo = Foo_____u8
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Boo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const Foo_____u8 = extern struct {
+  a: ?*u8,
+};
+
+pub const Boo = Foo_____u8;
+
+pub extern fn root(x: Boo) void;
@@ -0,0 +1,13 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  private static readonly nint LibraryHandle = NativeLibrary.Load(LibraryName, typeof(NativeMethods).Assembly, null);
+
+  public static byte* MUT_GLOBAL_ARRAY => (byte*)NativeLibrary.GetExport(LibraryHandle, "MUT_GLOBAL_ARRAY");
+
+  public static byte* CONST_GLOBAL_ARRAY => (byte*)NativeLibrary.GetExport(LibraryHandle, "CONST_GLOBAL_ARRAY");
+}
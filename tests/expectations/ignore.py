@@ -0,0 +1,11 @@
+import ctypes
+import enum
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.no_ignore_root.argtypes = []
+  lib.no_ignore_root.restype = None
+
+  return lib
@@ -0,0 +1 @@
+pub extern fn root() void;
@@ -0,0 +1,20 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct A
+  {
+    public int x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct B
+  {
+    public A data;
+  }
+}
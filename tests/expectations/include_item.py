@@ -0,0 +1,19 @@
+import ctypes
+import enum
+
+class A(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+class B(ctypes.Structure):
+  _fields_ = [
+    ("data", A),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  return lib
@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  Ok,
+  Invalid,
+} Status;
+
+/**
+ * A message generated from the schema.
+ */
+typedef struct {
+  uint32_t id;
+  uint8_t kind;
+} Message;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Status process(const Message *message);
+
+#if defined(DEFINE_EXTRA)
+void extra(Message *message);
+#endif
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
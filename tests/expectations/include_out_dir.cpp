@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Status {
+  Ok,
+  Invalid,
+};
+
+/// A message generated from the schema.
+struct Message {
+  uint32_t id;
+  uint8_t kind;
+};
+
+extern "C" {
+
+Status process(const Message *message);
+
+#if defined(DEFINE_EXTRA)
+void extra(Message *message);
+#endif
+
+}  // extern "C"
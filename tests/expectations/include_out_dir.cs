@@ -0,0 +1,31 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum Status : int
+  {
+    Ok,
+    Invalid,
+  }
+
+  /// <summary>
+  /// A message generated from the schema.
+  /// </summary>
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Message
+  {
+    public uint id;
+    public byte kind;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Status process(Message* message);
+
+#if DEFINE_EXTRA
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void extra(Message* message);
+#endif
+}
@@ -0,0 +1,28 @@
+import ctypes
+import enum
+
+class Status(enum.IntEnum):
+  Ok = 0
+  Invalid = 1
+
+# A message generated from the schema.
+class Message(ctypes.Structure):
+  _fields_ = [
+    ("id", ctypes.c_uint32),
+    ("kind", ctypes.c_uint8),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.process.argtypes = [ctypes.POINTER(Message)]
+  lib.process.restype = ctypes.c_int
+
+# #if defined(DEFINE_EXTRA)
+  if hasattr(lib, "extra"):
+    lib.extra.argtypes = [ctypes.POINTER(Message)]
+    lib.extra.restype = None
+# #endif
+
+  return lib
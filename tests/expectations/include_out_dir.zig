@@ -0,0 +1,18 @@
+const std = @import("std");
+
+pub const Status = enum(c_int) {
+  Ok,
+  Invalid,
+};
+
+/// A message generated from the schema.
+pub const Message = extern struct {
+  id: u32,
+  kind: u8,
+};
+
+pub extern fn process(message: ?*const Message) Status;
+
+// #if defined(DEFINE_EXTRA)
+pub extern fn extra(message: ?*Message) void;
+// #endif
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Status {
+  Ok,
+  Invalid,
+};
+
+/**
+ * A message generated from the schema.
+ */
+struct Message {
+  uint32_t id;
+  uint8_t kind;
+};
+
+enum Status process(const struct Message *message);
+
+#if defined(DEFINE_EXTRA)
+void extra(struct Message *message);
+#endif
@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum Status:
+    Ok,
+    Invalid,
+
+  # A message generated from the schema.
+  cdef struct Message:
+    uint32_t id;
+    uint8_t kind;
+
+  Status process(const Message *message);
+
+  IF DEFINE_EXTRA:
+    void extra(Message *message);
@@ -0,0 +1,4 @@
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+}
@@ -0,0 +1,16 @@
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_float),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
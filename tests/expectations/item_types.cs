@@ -0,0 +1,13 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum OnlyThisShouldBeGenerated : byte
+  {
+    Foo,
+    Bar,
+  }
+}
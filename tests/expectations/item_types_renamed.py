@@ -0,0 +1,12 @@
+import ctypes
+import enum
+
+class StyleOnlyThisShouldBeGenerated(enum.IntEnum):
+  Foo = 0
+  Bar = 1
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  return lib
@@ -0,0 +1,111 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct RustAlign4Struct { }
+
+  public struct RustAlign4Union { }
+
+  public struct RustPackedStruct { }
+
+  public struct RustPackedUnion { }
+
+  public struct UnsupportedAlign4Enum { }
+
+  public struct UnsupportedPacked4Struct { }
+
+  public struct UnsupportedPacked4Union { }
+
+  // NOTE: align(1) can't be expressed in C#.
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Align1Struct
+  {
+    public nuint arg1;
+    public byte* arg2;
+  }
+
+  // NOTE: align(2) can't be expressed in C#.
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Align2Struct
+  {
+    public nuint arg1;
+    public byte* arg2;
+  }
+
+  // NOTE: align(4) can't be expressed in C#.
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Align4Struct
+  {
+    public nuint arg1;
+    public byte* arg2;
+  }
+
+  // NOTE: align(8) can't be expressed in C#.
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Align8Struct
+  {
+    public nuint arg1;
+    public byte* arg2;
+  }
+
+  // NOTE: align(32) can't be expressed in C#.
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Align32Struct
+  {
+    public nuint arg1;
+    public byte* arg2;
+  }
+
+  [StructLayout(LayoutKind.Sequential, Pack = 1)]
+  public struct PackedStruct
+  {
+    public nuint arg1;
+    public byte* arg2;
+  }
+
+  // NOTE: align(1) can't be expressed in C#.
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Align1Union
+  {
+    [FieldOffset(0)]
+    public nuint variant1;
+    [FieldOffset(0)]
+    public byte* variant2;
+  }
+
+  // NOTE: align(4) can't be expressed in C#.
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Align4Union
+  {
+    [FieldOffset(0)]
+    public nuint variant1;
+    [FieldOffset(0)]
+    public byte* variant2;
+  }
+
+  // NOTE: align(16) can't be expressed in C#.
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Align16Union
+  {
+    [FieldOffset(0)]
+    public nuint variant1;
+    [FieldOffset(0)]
+    public byte* variant2;
+  }
+
+  [StructLayout(LayoutKind.Explicit, Pack = 1)]
+  public struct PackedUnion
+  {
+    [FieldOffset(0)]
+    public nuint variant1;
+    [FieldOffset(0)]
+    public byte* variant2;
+  }
+}
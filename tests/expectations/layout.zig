@@ -0,0 +1,69 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+const std = @import("std");
+
+pub const RustAlign4Struct = opaque {};
+
+pub const RustAlign4Union = opaque {};
+
+pub const RustPackedStruct = opaque {};
+
+pub const RustPackedUnion = opaque {};
+
+pub const UnsupportedAlign4Enum = opaque {};
+
+pub const UnsupportedPacked4Struct = opaque {};
+
+pub const UnsupportedPacked4Union = opaque {};
+
+pub const Align1Struct = extern struct {
+  arg1: usize align(@max(1, @alignOf(usize))),
+  arg2: ?*u8,
+};
+
+pub const Align2Struct = extern struct {
+  arg1: usize align(@max(2, @alignOf(usize))),
+  arg2: ?*u8,
+};
+
+pub const Align4Struct = extern struct {
+  arg1: usize align(@max(4, @alignOf(usize))),
+  arg2: ?*u8,
+};
+
+pub const Align8Struct = extern struct {
+  arg1: usize align(@max(8, @alignOf(usize))),
+  arg2: ?*u8,
+};
+
+pub const Align32Struct = extern struct {
+  arg1: usize align(@max(32, @alignOf(usize))),
+  arg2: ?*u8,
+};
+
+pub const PackedStruct = extern struct {
+  arg1: usize align(1),
+  arg2: ?*u8 align(1),
+};
+
+pub const Align1Union = extern union {
+  variant1: usize align(@max(1, @alignOf(usize))),
+  variant2: ?*u8,
+};
+
+pub const Align4Union = extern union {
+  variant1: usize align(@max(4, @alignOf(usize))),
+  variant2: ?*u8,
+};
+
+pub const Align16Union = extern union {
+  variant1: usize align(@max(16, @alignOf(usize))),
+  variant2: ?*u8,
+};
+
+pub const PackedUnion = extern union {
+  variant1: usize align(1),
+  variant2: ?*u8 align(1),
+};
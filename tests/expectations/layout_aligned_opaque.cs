@@ -0,0 +1,43 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct OpaqueAlign16Union { }
+
+  public struct OpaqueAlign1Struct { }
+
+  public struct OpaqueAlign1Union { }
+
+  public struct OpaqueAlign2Struct { }
+
+  public struct OpaqueAlign32Struct { }
+
+  public struct OpaqueAlign4Struct { }
+
+  public struct OpaqueAlign4Union { }
+
+  public struct OpaqueAlign8Struct { }
+
+  [StructLayout(LayoutKind.Sequential, Pack = 1)]
+  public struct PackedStruct
+  {
+    public nuint arg1;
+    public byte* arg2;
+  }
+
+  [StructLayout(LayoutKind.Explicit, Pack = 1)]
+  public struct PackedUnion
+  {
+    [FieldOffset(0)]
+    public nuint variant1;
+    [FieldOffset(0)]
+    public byte* variant2;
+  }
+}
@@ -0,0 +1,50 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+import ctypes
+import enum
+
+class OpaqueAlign16Union(ctypes.Structure):
+  pass
+
+class OpaqueAlign1Struct(ctypes.Structure):
+  pass
+
+class OpaqueAlign1Union(ctypes.Structure):
+  pass
+
+class OpaqueAlign2Struct(ctypes.Structure):
+  pass
+
+class OpaqueAlign32Struct(ctypes.Structure):
+  pass
+
+class OpaqueAlign4Struct(ctypes.Structure):
+  pass
+
+class OpaqueAlign4Union(ctypes.Structure):
+  pass
+
+class OpaqueAlign8Struct(ctypes.Structure):
+  pass
+
+class PackedStruct(ctypes.Structure):
+  _pack_ = 1
+  _fields_ = [
+    ("arg1", ctypes.c_size_t),
+    ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+class PackedUnion(ctypes.Union):
+  _pack_ = 1
+  _fields_ = [
+    ("variant1", ctypes.c_size_t),
+    ("variant2", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  return lib
@@ -0,0 +1,31 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+const std = @import("std");
+
+pub const OpaqueAlign16Union = opaque {};
+
+pub const OpaqueAlign1Struct = opaque {};
+
+pub const OpaqueAlign1Union = opaque {};
+
+pub const OpaqueAlign2Struct = opaque {};
+
+pub const OpaqueAlign32Struct = opaque {};
+
+pub const OpaqueAlign4Struct = opaque {};
+
+pub const OpaqueAlign4Union = opaque {};
+
+pub const OpaqueAlign8Struct = opaque {};
+
+pub const PackedStruct = extern struct {
+  arg1: usize align(1),
+  arg2: ?*u8 align(1),
+};
+
+pub const PackedUnion = extern union {
+  variant1: usize align(1),
+  variant2: ?*u8 align(1),
+};
@@ -0,0 +1,74 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+import ctypes
+import enum
+
+class OpaquePackedStruct(ctypes.Structure):
+  pass
+
+class OpaquePackedUnion(ctypes.Structure):
+  pass
+
+class Align1Union(ctypes.Union):
+  _align_ = 1
+  _fields_ = [
+    ("variant1", ctypes.c_size_t),
+    ("variant2", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+class Align4Union(ctypes.Union):
+  _align_ = 4
+  _fields_ = [
+    ("variant1", ctypes.c_size_t),
+    ("variant2", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+class Align16Union(ctypes.Union):
+  _align_ = 16
+  _fields_ = [
+    ("variant1", ctypes.c_size_t),
+    ("variant2", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+class Align1Struct(ctypes.Structure):
+  _align_ = 1
+  _fields_ = [
+    ("arg1", ctypes.c_size_t),
+    ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+class Align2Struct(ctypes.Structure):
+  _align_ = 2
+  _fields_ = [
+    ("arg1", ctypes.c_size_t),
+    ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+class Align4Struct(ctypes.Structure):
+  _align_ = 4
+  _fields_ = [
+    ("arg1", ctypes.c_size_t),
+    ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+class Align8Struct(ctypes.Structure):
+  _align_ = 8
+  _fields_ = [
+    ("arg1", ctypes.c_size_t),
+    ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+class Align32Struct(ctypes.Structure):
+  _align_ = 32
+  _fields_ = [
+    ("arg1", ctypes.c_size_t),
+    ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  return lib
@@ -0,0 +1,42 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct A
+  {
+    public int* data;
+  }
+
+  public enum E_Tag : int
+  {
+    V,
+    U,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct E
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct U_Body
+    {
+      public byte* u;
+    }
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Data
+    {
+      [FieldOffset(0)]
+      public U_Body u;
+    }
+
+    public E_Tag tag;
+    public Data data;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A _a, E _e);
+}
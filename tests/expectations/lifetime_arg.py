@@ -0,0 +1,36 @@
+import ctypes
+import enum
+
+class A(ctypes.Structure):
+  _fields_ = [
+    ("data", ctypes.POINTER(ctypes.c_int32)),
+  ]
+
+class E_Tag(enum.IntEnum):
+  V = 0
+  U = 1
+
+class U_Body(ctypes.Structure):
+  _fields_ = [
+    ("u", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+class E_Data(ctypes.Union):
+  _fields_ = [
+    ("u", U_Body),
+  ]
+
+class E(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_int),
+    ("data", E_Data),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [A, E]
+  lib.root.restype = None
+
+  return lib
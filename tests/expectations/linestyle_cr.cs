@@ -0,0 +1 @@
+using System;using System.Runtime.InteropServices;public static unsafe partial class NativeMethods{  private const string LibraryName = "native";  [StructLayout(LayoutKind.Sequential)]  public struct Dummy  {    public int x;    public float y;  }  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]  public static extern void root(Dummy d);}
\ No newline at end of file
@@ -0,0 +1 @@
+import ctypesimport enumclass Dummy(ctypes.Structure):  _fields_ = [    ("x", ctypes.c_int32),    ("y", ctypes.c_float),  ]def load(path):  """Loads the shared library at `path` and declares its exported symbols."""  lib = ctypes.CDLL(path)  lib.root.argtypes = [Dummy]  lib.root.restype = None  return lib
\ No newline at end of file
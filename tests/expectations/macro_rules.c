@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MINUS_ONE -1
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} IntPoint;
+
+typedef struct {
+  double x;
+  double y;
+  double z;
+} Point3;
+
+#if defined(DEFINE_EXTRA)
+typedef struct {
+  uint8_t a;
+  uint8_t b;
+} ExtraPoint;
+#endif
+
+typedef struct {
+  float x;
+  float y;
+} FloatPoint;
+
+/**
+ * Adds two points.
+ */
+IntPoint add(IntPoint a, IntPoint b);
+
+void scale(Point3 *p, double factor);
+
+#if defined(DEFINE_EXTRA)
+void extra(ExtraPoint p, FloatPoint q);
+#endif
@@ -0,0 +1,43 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const int32_t MINUS_ONE = -1;
+
+struct IntPoint {
+  int32_t x;
+  int32_t y;
+};
+
+struct Point3 {
+  double x;
+  double y;
+  double z;
+};
+
+#if defined(DEFINE_EXTRA)
+struct ExtraPoint {
+  uint8_t a;
+  uint8_t b;
+};
+#endif
+
+struct FloatPoint {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+/// Adds two points.
+IntPoint add(IntPoint a, IntPoint b);
+
+void scale(Point3 *p, double factor);
+
+#if defined(DEFINE_EXTRA)
+void extra(ExtraPoint p, FloatPoint q);
+#endif
+
+}  // extern "C"
@@ -0,0 +1,54 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const int MINUS_ONE = -1;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct IntPoint
+  {
+    public int x;
+    public int y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Point3
+  {
+    public double x;
+    public double y;
+    public double z;
+  }
+
+#if DEFINE_EXTRA
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ExtraPoint
+  {
+    public byte a;
+    public byte b;
+  }
+#endif
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct FloatPoint
+  {
+    public float x;
+    public float y;
+  }
+
+  /// <summary>
+  /// Adds two points.
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern IntPoint add(IntPoint a, IntPoint b);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void scale(Point3* p, double factor);
+
+#if DEFINE_EXTRA
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void extra(ExtraPoint p, FloatPoint q);
+#endif
+}
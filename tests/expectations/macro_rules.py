@@ -0,0 +1,50 @@
+import ctypes
+import enum
+
+MINUS_ONE = -1
+
+class IntPoint(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_int32),
+  ]
+
+class Point3(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_double),
+    ("y", ctypes.c_double),
+    ("z", ctypes.c_double),
+  ]
+
+# #if defined(DEFINE_EXTRA)
+class ExtraPoint(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_uint8),
+    ("b", ctypes.c_uint8),
+  ]
+# #endif
+
+class FloatPoint(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_float),
+    ("y", ctypes.c_float),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  # Adds two points.
+  lib.add.argtypes = [IntPoint, IntPoint]
+  lib.add.restype = IntPoint
+
+  lib.scale.argtypes = [ctypes.POINTER(Point3), ctypes.c_double]
+  lib.scale.restype = None
+
+# #if defined(DEFINE_EXTRA)
+  if hasattr(lib, "extra"):
+    lib.extra.argtypes = [ExtraPoint, FloatPoint]
+    lib.extra.restype = None
+# #endif
+
+  return lib
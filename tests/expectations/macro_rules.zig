@@ -0,0 +1,35 @@
+const std = @import("std");
+
+pub const MINUS_ONE: i32 = -1;
+
+pub const IntPoint = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const Point3 = extern struct {
+  x: f64,
+  y: f64,
+  z: f64,
+};
+
+// #if defined(DEFINE_EXTRA)
+pub const ExtraPoint = extern struct {
+  a: u8,
+  b: u8,
+};
+// #endif
+
+pub const FloatPoint = extern struct {
+  x: f32,
+  y: f32,
+};
+
+/// Adds two points.
+pub extern fn add(a: IntPoint, b: IntPoint) IntPoint;
+
+pub extern fn scale(p: ?*Point3, factor: f64) void;
+
+// #if defined(DEFINE_EXTRA)
+pub extern fn extra(p: ExtraPoint, q: FloatPoint) void;
+// #endif
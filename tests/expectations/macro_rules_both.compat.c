@@ -0,0 +1,48 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MINUS_ONE -1
+
+typedef struct IntPoint {
+  int32_t x;
+  int32_t y;
+} IntPoint;
+
+typedef struct Point3 {
+  double x;
+  double y;
+  double z;
+} Point3;
+
+#if defined(DEFINE_EXTRA)
+typedef struct ExtraPoint {
+  uint8_t a;
+  uint8_t b;
+} ExtraPoint;
+#endif
+
+typedef struct FloatPoint {
+  float x;
+  float y;
+} FloatPoint;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Adds two points.
+ */
+struct IntPoint add(struct IntPoint a, struct IntPoint b);
+
+void scale(struct Point3 *p, double factor);
+
+#if defined(DEFINE_EXTRA)
+void extra(struct ExtraPoint p, struct FloatPoint q);
+#endif
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
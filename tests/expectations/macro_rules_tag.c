@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MINUS_ONE -1
+
+struct IntPoint {
+  int32_t x;
+  int32_t y;
+};
+
+struct Point3 {
+  double x;
+  double y;
+  double z;
+};
+
+#if defined(DEFINE_EXTRA)
+struct ExtraPoint {
+  uint8_t a;
+  uint8_t b;
+};
+#endif
+
+struct FloatPoint {
+  float x;
+  float y;
+};
+
+/**
+ * Adds two points.
+ */
+struct IntPoint add(struct IntPoint a, struct IntPoint b);
+
+void scale(struct Point3 *p, double factor);
+
+#if defined(DEFINE_EXTRA)
+void extra(struct ExtraPoint p, struct FloatPoint q);
+#endif
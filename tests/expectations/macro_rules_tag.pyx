@@ -0,0 +1,35 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const int32_t MINUS_ONE # = -1
+
+  cdef struct IntPoint:
+    int32_t x;
+    int32_t y;
+
+  cdef struct Point3:
+    double x;
+    double y;
+    double z;
+
+  IF DEFINE_EXTRA:
+    cdef struct ExtraPoint:
+      uint8_t a;
+      uint8_t b;
+
+  cdef struct FloatPoint:
+    float x;
+    float y;
+
+  # Adds two points.
+  IntPoint add(IntPoint a, IntPoint b);
+
+  void scale(Point3 *p, double factor);
+
+  IF DEFINE_EXTRA:
+    void extra(ExtraPoint p, FloatPoint q);
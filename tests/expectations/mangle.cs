@@ -0,0 +1,22 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum Bar : int
+  {
+    BarSome,
+    BarThing,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct FooU8
+  {
+    public byte a;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(FooU8 x, Bar y);
+}
@@ -0,0 +1,22 @@
+import ctypes
+import enum
+
+class Bar(enum.IntEnum):
+  BarSome = 0
+  BarThing = 1
+
+class FooU8(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_uint8),
+  ]
+
+Boo = FooU8
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Boo, ctypes.c_int]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,44 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using ManuallyDrop = T;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class NotReprC_Point(ctypes.Structure):
+  pass
+
+Foo = NotReprC_Point
+
+class Point(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_int32),
+  ]
+
+class MyStruct(ctypes.Structure):
+  _fields_ = [
+    ("point", Point),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Foo), ctypes.POINTER(MyStruct)]
+  lib.root.restype = None
+
+  lib.take.argtypes = [Point]
+  lib.take.restype = None
+
+  return lib
@@ -0,0 +1,25 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using MaybeUninit = T;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+
+pub const NotReprC______i32 = opaque {};
+
+pub const Foo = NotReprC______i32;
+
+pub const MyStruct = extern struct {
+  number: *const i32,
+};
+
+pub extern fn root(a: *const Foo, with_maybe_uninit: *const MyStruct) void;
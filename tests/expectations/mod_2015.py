@@ -0,0 +1,21 @@
+import ctypes
+import enum
+
+EXPORT_ME_TOO = 42
+
+class ExportMe(ctypes.Structure):
+  _fields_ = [
+    ("val", ctypes.c_uint64),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.export_me.argtypes = [ctypes.POINTER(ExportMe)]
+  lib.export_me.restype = None
+
+  lib.from_really_nested_mod.argtypes = []
+  lib.from_really_nested_mod.restype = None
+
+  return lib
@@ -0,0 +1,30 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const byte EXPORT_ME_TOO = 42;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ExportMe
+  {
+    public ulong val;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ExportMe2
+  {
+    public ulong val;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void export_me(ExportMe* val);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void export_me_2(ExportMe2* arg0);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void from_really_nested_mod();
+}
@@ -0,0 +1,44 @@
+#if 0
+DEF FOO = 0
+DEF BAR = 0
+#endif
+
+
+import ctypes
+import enum
+
+# #if defined(FOO)
+FOO = 1
+# #endif
+
+# #if defined(BAR)
+BAR = 2
+# #endif
+
+# #if defined(FOO)
+class Foo(ctypes.Structure):
+  pass
+# #endif
+
+# #if defined(BAR)
+class Bar(ctypes.Structure):
+  pass
+# #endif
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+# #if defined(FOO)
+  if hasattr(lib, "foo"):
+    lib.foo.argtypes = [ctypes.POINTER(Foo)]
+    lib.foo.restype = None
+# #endif
+
+# #if defined(BAR)
+  if hasattr(lib, "bar"):
+    lib.bar.argtypes = [ctypes.POINTER(Bar)]
+    lib.bar.restype = None
+# #endif
+
+  return lib
@@ -0,0 +1,31 @@
+#if 0
+DEF FOO = 0
+DEF BAR = 0
+#endif
+
+
+const std = @import("std");
+
+// #if defined(FOO)
+pub const FOO: i32 = 1;
+// #endif
+
+// #if defined(BAR)
+pub const BAR: i32 = 2;
+// #endif
+
+// #if defined(FOO)
+pub const Foo = extern struct {};
+// #endif
+
+// #if defined(BAR)
+pub const Bar = extern struct {};
+// #endif
+
+// #if defined(FOO)
+pub extern fn foo(foo: *const Foo) void;
+// #endif
+
+// #if defined(BAR)
+pub extern fn bar(bar: *const Bar) void;
+// #endif
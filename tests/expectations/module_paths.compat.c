@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  bool verbose;
+} Config;
+
+typedef struct {
+  uint8_t octets[4];
+} Address;
+
+typedef struct {
+  uint16_t port;
+  Address address;
+} net_Config;
+
+typedef struct {
+  uint32_t block_size;
+} DiskConfig;
+
+typedef struct {
+  Config main;
+  net_Config net;
+  DiskConfig disk;
+  Address address;
+} Settings;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void apply(const Settings *settings);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
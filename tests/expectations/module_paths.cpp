@@ -0,0 +1,35 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Config {
+  bool verbose;
+};
+
+struct Address {
+  uint8_t octets[4];
+};
+
+struct net_Config {
+  uint16_t port;
+  Address address;
+};
+
+struct DiskConfig {
+  uint32_t block_size;
+};
+
+struct Settings {
+  Config main;
+  net_Config net;
+  DiskConfig disk;
+  Address address;
+};
+
+extern "C" {
+
+void apply(const Settings *settings);
+
+}  // extern "C"
@@ -0,0 +1,44 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Config
+  {
+    [MarshalAs(UnmanagedType.U1)] public bool verbose;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Address
+  {
+    public fixed byte octets[4];
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct net_Config
+  {
+    public ushort port;
+    public Address address;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct DiskConfig
+  {
+    public uint block_size;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Settings
+  {
+    public Config main;
+    public net_Config net;
+    public DiskConfig disk;
+    public Address address;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void apply(Settings* settings);
+}
@@ -0,0 +1,40 @@
+import ctypes
+import enum
+
+class Config(ctypes.Structure):
+  _fields_ = [
+    ("verbose", ctypes.c_bool),
+  ]
+
+class Address(ctypes.Structure):
+  _fields_ = [
+    ("octets", ctypes.c_uint8 * 4),
+  ]
+
+class net_Config(ctypes.Structure):
+  _fields_ = [
+    ("port", ctypes.c_uint16),
+    ("address", Address),
+  ]
+
+class DiskConfig(ctypes.Structure):
+  _fields_ = [
+    ("block_size", ctypes.c_uint32),
+  ]
+
+class Settings(ctypes.Structure):
+  _fields_ = [
+    ("main", Config),
+    ("net", net_Config),
+    ("disk", DiskConfig),
+    ("address", Address),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.apply.argtypes = [ctypes.POINTER(Settings)]
+  lib.apply.restype = None
+
+  return lib
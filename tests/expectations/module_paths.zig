@@ -0,0 +1,27 @@
+const std = @import("std");
+
+pub const Config = extern struct {
+  verbose: bool,
+};
+
+pub const Address = extern struct {
+  octets: [4]u8,
+};
+
+pub const net_Config = extern struct {
+  port: u16,
+  address: Address,
+};
+
+pub const DiskConfig = extern struct {
+  block_size: u32,
+};
+
+pub const Settings = extern struct {
+  main: Config,
+  net: net_Config,
+  disk: DiskConfig,
+  address: Address,
+};
+
+pub extern fn apply(settings: ?*const Settings) void;
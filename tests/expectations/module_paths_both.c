@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Config {
+  bool verbose;
+} Config;
+
+typedef struct Address {
+  uint8_t octets[4];
+} Address;
+
+typedef struct net_Config {
+  uint16_t port;
+  struct Address address;
+} net_Config;
+
+typedef struct DiskConfig {
+  uint32_t block_size;
+} DiskConfig;
+
+typedef struct Settings {
+  struct Config main;
+  struct net_Config net;
+  struct DiskConfig disk;
+  struct Address address;
+} Settings;
+
+void apply(const struct Settings *settings);
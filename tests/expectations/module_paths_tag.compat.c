@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Config {
+  bool verbose;
+};
+
+struct Address {
+  uint8_t octets[4];
+};
+
+struct net_Config {
+  uint16_t port;
+  struct Address address;
+};
+
+struct DiskConfig {
+  uint32_t block_size;
+};
+
+struct Settings {
+  struct Config main;
+  struct net_Config net;
+  struct DiskConfig disk;
+  struct Address address;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void apply(const struct Settings *settings);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Config:
+    bool verbose;
+
+  cdef struct Address:
+    uint8_t octets[4];
+
+  cdef struct net_Config:
+    uint16_t port;
+    Address address;
+
+  cdef struct DiskConfig:
+    uint32_t block_size;
+
+  cdef struct Settings:
+    Config main;
+    net_Config net;
+    DiskConfig disk;
+    Address address;
+
+  void apply(const Settings *settings);
@@ -0,0 +1,56 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Bar_Bar_f32 { }
+
+  public struct Bar_Foo_f32 { }
+
+  public struct Bar_f32 { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_i32
+  {
+    public int* data;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_f32
+  {
+    public float* data;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_Bar_f32
+  {
+    public Bar_f32* data;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Tuple_Foo_f32_____f32
+  {
+    public Foo_f32* a;
+    public float* b;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Tuple_f32__f32
+  {
+    public float* a;
+    public float* b;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(
+    Foo_i32 a,
+    Foo_f32 b,
+    Bar_f32 c,
+    Foo_Bar_f32 d,
+    Bar_Foo_f32 e,
+    Bar_Bar_f32 f,
+    Tuple_Foo_f32_____f32 g,
+    Tuple_f32__f32 h);
+}
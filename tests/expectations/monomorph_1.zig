@@ -0,0 +1,42 @@
+const std = @import("std");
+
+pub const Bar_Bar_f32 = opaque {};
+
+pub const Bar_Foo_f32 = opaque {};
+
+pub const Bar_f32 = opaque {};
+
+pub const Foo_i32 = extern struct {
+  data: ?*const i32,
+};
+
+pub const Foo_f32 = extern struct {
+  data: ?*const f32,
+};
+
+pub const Foo_Bar_f32 = extern struct {
+  data: ?*const Bar_f32,
+};
+
+pub const Tuple_Foo_f32_____f32 = extern struct {
+  a: ?*const Foo_f32,
+  b: ?*const f32,
+};
+
+pub const Tuple_f32__f32 = extern struct {
+  a: ?*const f32,
+  b: ?*const f32,
+};
+
+pub const Indirection_f32 = Tuple_f32__f32;
+
+pub extern fn root(
+  a: Foo_i32,
+  b: Foo_f32,
+  c: Bar_f32,
+  d: Foo_Bar_f32,
+  e: Bar_Foo_f32,
+  f: Bar_Bar_f32,
+  g: Tuple_Foo_f32_____f32,
+  h: Indirection_f32,
+) void;
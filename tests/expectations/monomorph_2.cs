@@ -0,0 +1,31 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct A { }
+
+  public struct B { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct List_A
+  {
+    public A* members;
+    public nuint count;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct List_B
+  {
+    public B* members;
+    public nuint count;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void foo(List_A a);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void bar(List_B b);
+}
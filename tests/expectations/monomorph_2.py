@@ -0,0 +1,32 @@
+import ctypes
+import enum
+
+class A(ctypes.Structure):
+  pass
+
+class B(ctypes.Structure):
+  pass
+
+class List_A(ctypes.Structure):
+  _fields_ = [
+    ("members", ctypes.POINTER(A)),
+    ("count", ctypes.c_size_t),
+  ]
+
+class List_B(ctypes.Structure):
+  _fields_ = [
+    ("members", ctypes.POINTER(B)),
+    ("count", ctypes.c_size_t),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.foo.argtypes = [List_A]
+  lib.foo.restype = None
+
+  lib.bar.argtypes = [List_B]
+  lib.bar.restype = None
+
+  return lib
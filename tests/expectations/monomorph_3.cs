@@ -0,0 +1,63 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Bar_Bar_f32 { }
+
+  public struct Bar_Foo_f32 { }
+
+  public struct Bar_f32 { }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Foo_i32
+  {
+    [FieldOffset(0)]
+    public int* data;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Foo_f32
+  {
+    [FieldOffset(0)]
+    public float* data;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Foo_Bar_f32
+  {
+    [FieldOffset(0)]
+    public Bar_f32* data;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Tuple_Foo_f32_____f32
+  {
+    [FieldOffset(0)]
+    public Foo_f32* a;
+    [FieldOffset(0)]
+    public float* b;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Tuple_f32__f32
+  {
+    [FieldOffset(0)]
+    public float* a;
+    [FieldOffset(0)]
+    public float* b;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(
+    Foo_i32 a,
+    Foo_f32 b,
+    Bar_f32 c,
+    Foo_Bar_f32 d,
+    Bar_Foo_f32 e,
+    Bar_Bar_f32 f,
+    Tuple_Foo_f32_____f32 g,
+    Tuple_f32__f32 h);
+}
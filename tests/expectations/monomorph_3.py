@@ -0,0 +1,58 @@
+import ctypes
+import enum
+
+class Bar_Bar_f32(ctypes.Structure):
+  pass
+
+class Bar_Foo_f32(ctypes.Structure):
+  pass
+
+class Bar_f32(ctypes.Structure):
+  pass
+
+class Foo_i32(ctypes.Union):
+  _fields_ = [
+    ("data", ctypes.POINTER(ctypes.c_int32)),
+  ]
+
+class Foo_f32(ctypes.Union):
+  _fields_ = [
+    ("data", ctypes.POINTER(ctypes.c_float)),
+  ]
+
+class Foo_Bar_f32(ctypes.Union):
+  _fields_ = [
+    ("data", ctypes.POINTER(Bar_f32)),
+  ]
+
+class Tuple_Foo_f32_____f32(ctypes.Union):
+  _fields_ = [
+    ("a", ctypes.POINTER(Foo_f32)),
+    ("b", ctypes.POINTER(ctypes.c_float)),
+  ]
+
+class Tuple_f32__f32(ctypes.Union):
+  _fields_ = [
+    ("a", ctypes.POINTER(ctypes.c_float)),
+    ("b", ctypes.POINTER(ctypes.c_float)),
+  ]
+
+Indirection_f32 = Tuple_f32__f32
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    Foo_i32,
+    Foo_f32,
+    Bar_f32,
+    Foo_Bar_f32,
+    Bar_Foo_f32,
+    Bar_Bar_f32,
+    Tuple_Foo_f32_____f32,
+    Indirection_f32,
+  ]
+  lib.root.restype = None
+
+  return lib
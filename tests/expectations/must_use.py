@@ -0,0 +1,41 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+import ctypes
+import enum
+
+class MaybeOwnedPtr_i32_Tag(enum.IntEnum):
+  Owned_i32 = 0
+  None_i32 = 1
+
+class Owned_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("owned", ctypes.POINTER(ctypes.c_int32)),
+  ]
+
+class MaybeOwnedPtr_i32_Data(ctypes.Union):
+  _fields_ = [
+    ("owned", Owned_Body_i32),
+  ]
+
+class MaybeOwnedPtr_i32(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", MaybeOwnedPtr_i32_Data),
+  ]
+
+class OwnedPtr_i32(ctypes.Structure):
+  _fields_ = [
+    ("ptr", ctypes.POINTER(ctypes.c_int32)),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.maybe_consume.argtypes = [OwnedPtr_i32]
+  lib.maybe_consume.restype = MaybeOwnedPtr_i32
+
+  return lib
@@ -0,0 +1,20 @@
+import ctypes
+import enum
+
+FOO = 10
+
+ZOM = 3.14
+
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32 * FOO),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub const FOO: i32 = 10;
+
+pub const ZOM: f32 = 3.14;
+
+pub const Foo = extern struct {
+  x: [FOO]i32,
+};
+
+pub extern fn root(x: Foo) void;
@@ -0,0 +1,20 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const int FOO = 10;
+
+  public const float ZOM = (float)3.14;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo
+  {
+    public fixed int x[FOO];
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo x);
+}
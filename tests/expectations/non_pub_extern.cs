@@ -0,0 +1,19 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  private static readonly nint LibraryHandle = NativeLibrary.Load(LibraryName, typeof(NativeMethods).Assembly, null);
+
+  public static ref readonly uint FIRST => ref *(uint*)NativeLibrary.GetExport(LibraryHandle, "FIRST");
+
+  public static ref readonly uint RENAMED => ref *(uint*)NativeLibrary.GetExport(LibraryHandle, "RENAMED");
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void first();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void renamed();
+}
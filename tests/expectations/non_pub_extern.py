@@ -0,0 +1,18 @@
+import ctypes
+import enum
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.FIRST = ctypes.c_uint32.in_dll(lib, "FIRST")
+
+  lib.RENAMED = ctypes.c_uint32.in_dll(lib, "RENAMED")
+
+  lib.first.argtypes = []
+  lib.first.restype = None
+
+  lib.renamed.argtypes = []
+  lib.renamed.restype = None
+
+  return lib
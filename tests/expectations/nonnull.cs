@@ -0,0 +1,26 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Opaque { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_u64
+  {
+    public float* a;
+    public ulong* b;
+    public Opaque* c;
+    public ulong** d;
+    public float** e;
+    public Opaque** f;
+    public ulong* g;
+    public int* h;
+    public int** i;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(int* arg, Foo_u64* foo, Opaque** d);
+}
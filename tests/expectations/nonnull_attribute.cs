@@ -0,0 +1,65 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Opaque { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct References
+  {
+    public Opaque* a;
+    public Opaque* b;
+    public Opaque* c;
+    public Opaque* d;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Pointers_u64
+  {
+    public float* a;
+    public ulong* b;
+    public Opaque* c;
+    public ulong** d;
+    public float** e;
+    public Opaque** f;
+    public ulong* g;
+    public int* h;
+    public int** i;
+    public ulong* j;
+    public ulong* k;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void value_arg(References arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void mutltiple_args(int* arg, Pointers_u64* foo, Opaque** d);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ref_arg(Pointers_u64* arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void mut_ref_arg(Pointers_u64* arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void optional_ref_arg(Pointers_u64* arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void optional_mut_ref_arg(Pointers_u64* arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void nullable_const_ptr(Pointers_u64* arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void nullable_mut_ptr(Pointers_u64* arg);
+}
@@ -0,0 +1,69 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+import ctypes
+import enum
+
+class Opaque(ctypes.Structure):
+  pass
+
+class References(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.POINTER(Opaque)),
+    ("b", ctypes.POINTER(Opaque)),
+    ("c", ctypes.POINTER(Opaque)),
+    ("d", ctypes.POINTER(Opaque)),
+  ]
+
+class Pointers_u64(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.POINTER(ctypes.c_float)),
+    ("b", ctypes.POINTER(ctypes.c_uint64)),
+    ("c", ctypes.POINTER(Opaque)),
+    ("d", ctypes.POINTER(ctypes.POINTER(ctypes.c_uint64))),
+    ("e", ctypes.POINTER(ctypes.POINTER(ctypes.c_float))),
+    ("f", ctypes.POINTER(ctypes.POINTER(Opaque))),
+    ("g", ctypes.POINTER(ctypes.c_uint64)),
+    ("h", ctypes.POINTER(ctypes.c_int32)),
+    ("i", ctypes.POINTER(ctypes.POINTER(ctypes.c_int32))),
+    ("j", ctypes.POINTER(ctypes.c_uint64)),
+    ("k", ctypes.POINTER(ctypes.c_uint64)),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.value_arg.argtypes = [References]
+  lib.value_arg.restype = None
+
+  lib.mutltiple_args.argtypes = [
+    ctypes.POINTER(ctypes.c_int32),
+    ctypes.POINTER(Pointers_u64),
+    ctypes.POINTER(ctypes.POINTER(Opaque)),
+  ]
+  lib.mutltiple_args.restype = None
+
+  lib.ref_arg.argtypes = [ctypes.POINTER(Pointers_u64)]
+  lib.ref_arg.restype = None
+
+  lib.mut_ref_arg.argtypes = [ctypes.POINTER(Pointers_u64)]
+  lib.mut_ref_arg.restype = None
+
+  lib.optional_ref_arg.argtypes = [ctypes.POINTER(Pointers_u64)]
+  lib.optional_ref_arg.restype = None
+
+  lib.optional_mut_ref_arg.argtypes = [ctypes.POINTER(Pointers_u64)]
+  lib.optional_mut_ref_arg.restype = None
+
+  lib.nullable_const_ptr.argtypes = [ctypes.POINTER(Pointers_u64)]
+  lib.nullable_const_ptr.restype = None
+
+  lib.nullable_mut_ptr.argtypes = [ctypes.POINTER(Pointers_u64)]
+  lib.nullable_mut_ptr.restype = None
+
+  return lib
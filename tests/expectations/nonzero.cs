@@ -0,0 +1,51 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+struct NonZeroI64;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Option_i64 { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct NonZeroTest
+  {
+    public byte a;
+    public ushort b;
+    public uint c;
+    public ulong d;
+    public sbyte e;
+    public short f;
+    public int g;
+    public long h;
+    public long i;
+    public Option_i64* j;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(
+    NonZeroTest test,
+    byte a,
+    ushort b,
+    uint c,
+    ulong d,
+    sbyte e,
+    short f,
+    int g,
+    long h,
+    long i,
+    Option_i64* j);
+}
@@ -0,0 +1,43 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+struct NonZeroI64;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+
+pub const Option_i64 = opaque {};
+
+pub const NonZeroTest = extern struct {
+  a: u8,
+  b: u16,
+  c: u32,
+  d: u64,
+  e: i8,
+  f: i16,
+  g: i32,
+  h: i64,
+  i: i64,
+  j: ?*const Option_i64,
+};
+
+pub extern fn root(
+  @"test": NonZeroTest,
+  a: u8,
+  b: u16,
+  c: u32,
+  d: u64,
+  e: i8,
+  f: i16,
+  g: i32,
+  h: i64,
+  i: i64,
+  j: ?*const Option_i64,
+) void;
@@ -0,0 +1,18 @@
+# Package version: 0.1.0
+
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("bar", ctypes.c_uint64),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.doit.argtypes = [ctypes.POINTER(Foo)]
+  lib.doit.restype = None
+
+  return lib
@@ -0,0 +1,9 @@
+// Package version: 0.1.0
+
+const std = @import("std");
+
+pub const Foo = extern struct {
+  bar: u64,
+};
+
+pub extern fn doit(*const Foo) void;
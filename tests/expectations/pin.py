@@ -0,0 +1,33 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Pin = T;
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class PinTest(ctypes.Structure):
+  _fields_ = [
+    ("pinned_box", ctypes.POINTER(ctypes.c_int32)),
+    ("pinned_ref", ctypes.POINTER(ctypes.c_int32)),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(ctypes.c_int32), PinTest]
+  lib.root.restype = None
+
+  return lib
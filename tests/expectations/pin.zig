@@ -0,0 +1,24 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Pin = T;
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+
+pub const PinTest = extern struct {
+  pinned_box: *i32,
+  pinned_ref: *i32,
+};
+
+pub extern fn root(s: *i32, p: PinTest) void;
@@ -0,0 +1,3 @@
+const std = @import("std");
+
+pub extern fn root() void;
@@ -0,0 +1,39 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const int PREFIX_LEN = 22;
+
+  public const long PREFIX_X = (22 << 22);
+
+  public const long PREFIX_Y = (PREFIX_X + PREFIX_X);
+
+  public enum PREFIX_AbsoluteFontWeight_Tag : byte
+  {
+    Weight,
+    Normal,
+    Bold,
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct PREFIX_AbsoluteFontWeight
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct PREFIX_Weight_Body
+    {
+      public PREFIX_AbsoluteFontWeight_Tag weight_tag;
+      public float weight;
+    }
+
+    [FieldOffset(0)]
+    public PREFIX_Weight_Body weight;
+    [FieldOffset(0)]
+    public PREFIX_AbsoluteFontWeight_Tag tag;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(int* x, int* y, PREFIX_AbsoluteFontWeight z);
+}
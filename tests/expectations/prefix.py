@@ -0,0 +1,38 @@
+import ctypes
+import enum
+
+PREFIX_LEN = 22
+
+PREFIX_X = (22 << 22)
+
+PREFIX_Y = (PREFIX_X + PREFIX_X)
+
+PREFIX_NamedLenArray = ctypes.c_int32 * PREFIX_LEN
+
+PREFIX_ValuedLenArray = ctypes.c_int32 * 22
+
+class PREFIX_AbsoluteFontWeight_Tag(enum.IntEnum):
+  Weight = 0
+  Normal = 1
+  Bold = 2
+
+class PREFIX_Weight_Body(ctypes.Structure):
+  _fields_ = [
+    ("weight_tag", ctypes.c_uint8),
+    ("weight", ctypes.c_float),
+  ]
+
+class PREFIX_AbsoluteFontWeight(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("weight", PREFIX_Weight_Body),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PREFIX_NamedLenArray, PREFIX_ValuedLenArray, PREFIX_AbsoluteFontWeight]
+  lib.root.restype = None
+
+  return lib
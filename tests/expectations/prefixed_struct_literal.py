@@ -0,0 +1,20 @@
+import ctypes
+import enum
+
+class PREFIXFoo(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_int32),
+    ("b", ctypes.c_uint32),
+  ]
+PREFIXFoo_FOO = PREFIXFoo(a=42, b=47)
+
+PREFIXBAR = PREFIXFoo(a=42, b=1337)
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PREFIXFoo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,25 @@
+import ctypes
+import enum
+
+class PREFIXBar(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_int32),
+  ]
+
+class PREFIXFoo(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_int32),
+    ("b", ctypes.c_uint32),
+    ("bar", PREFIXBar),
+  ]
+
+PREFIXVAL = PREFIXFoo(a=42, b=1337, bar=PREFIXBar(a=323))
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PREFIXFoo]
+  lib.root.restype = None
+
+  return lib
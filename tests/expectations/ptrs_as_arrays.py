@@ -0,0 +1,42 @@
+import ctypes
+import enum
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.ptr_as_array.argtypes = [
+    ctypes.c_uint32,
+    ctypes.POINTER(ctypes.c_uint32),
+    ctypes.POINTER(ctypes.c_uint64),
+  ]
+  lib.ptr_as_array.restype = None
+
+  lib.ptr_as_array1.argtypes = [
+    ctypes.c_uint32,
+    ctypes.POINTER(ctypes.c_uint32),
+    ctypes.POINTER(ctypes.c_uint64),
+  ]
+  lib.ptr_as_array1.restype = None
+
+  lib.ptr_as_array2.argtypes = [
+    ctypes.c_uint32,
+    ctypes.POINTER(ctypes.c_uint32),
+    ctypes.POINTER(ctypes.c_uint64),
+  ]
+  lib.ptr_as_array2.restype = None
+
+  lib.ptr_as_array_wrong_syntax.argtypes = [
+    ctypes.POINTER(ctypes.c_uint32),
+    ctypes.POINTER(ctypes.c_uint32),
+    ctypes.POINTER(ctypes.c_uint32),
+  ]
+  lib.ptr_as_array_wrong_syntax.restype = None
+
+  lib.ptr_as_array_unnamed.argtypes = [
+    ctypes.POINTER(ctypes.c_uint32),
+    ctypes.POINTER(ctypes.c_uint32),
+  ]
+  lib.ptr_as_array_unnamed.restype = None
+
+  return lib
@@ -0,0 +1,26 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum Enum : byte
+  {
+    a,
+    b,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Struct
+  {
+    public Enum field;
+  }
+
+  private static readonly nint LibraryHandle = NativeLibrary.Load(LibraryName, typeof(NativeMethods).Assembly, null);
+
+  public static ref readonly Enum STATIC => ref *(Enum*)NativeLibrary.GetExport(LibraryHandle, "STATIC");
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void fn(Struct arg);
+}
@@ -0,0 +1,22 @@
+import ctypes
+import enum
+
+class Enum(enum.IntEnum):
+  a = 0
+  b = 1
+
+class Struct(ctypes.Structure):
+  _fields_ = [
+    ("field", ctypes.c_uint8),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.STATIC = ctypes.c_uint8.in_dll(lib, "STATIC")
+
+  lib.fn.argtypes = [Struct]
+  lib.fn.restype = None
+
+  return lib
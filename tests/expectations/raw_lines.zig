@@ -0,0 +1,4 @@
+const std = @import("std");
+#define VERSION 1
+
+pub extern fn root() void;
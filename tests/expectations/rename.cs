@@ -0,0 +1,44 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public const int C_H = 10;
+
+  public enum C_E : byte
+  {
+    x = 0,
+    y = 1,
+  }
+
+  public struct C_A { }
+
+  public struct C_C { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct C_AwesomeB
+  {
+    public int x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct C_D
+  {
+    [FieldOffset(0)]
+    public int x;
+    [FieldOffset(0)]
+    public float y;
+  }
+
+  public static readonly nint C_I = (nint)((C_A*)(10));
+
+  private static readonly nint LibraryHandle = NativeLibrary.Load(LibraryName, typeof(NativeMethods).Assembly, null);
+
+  public static ref readonly int G => ref *(int*)NativeLibrary.GetExport(LibraryHandle, "G");
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(C_A* a, C_AwesomeB b, C_C c, C_D d, C_E e, C_A f);
+}
@@ -0,0 +1,41 @@
+import ctypes
+import enum
+
+C_H = 10
+
+class C_E(enum.IntEnum):
+  x = 0
+  y = 1
+
+class C_A(ctypes.Structure):
+  pass
+
+class C_C(ctypes.Structure):
+  pass
+
+class C_AwesomeB(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+class C_D(ctypes.Union):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+C_F = C_A
+
+C_I = ctypes.c_ssize_t(int(10)).value
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.G = ctypes.c_int32.in_dll(lib, "G")
+
+  lib.root.argtypes = [ctypes.POINTER(C_A), C_AwesomeB, C_C, C_D, ctypes.c_uint8, C_F]
+  lib.root.restype = None
+
+  return lib
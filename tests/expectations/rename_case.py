@@ -0,0 +1,23 @@
+import ctypes
+import enum
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.test_camel_case.argtypes = [ctypes.c_int32]
+  lib.test_camel_case.restype = None
+
+  lib.test_pascal_case.argtypes = [ctypes.c_int32]
+  lib.test_pascal_case.restype = None
+
+  lib.test_snake_case.argtypes = [ctypes.c_int32]
+  lib.test_snake_case.restype = None
+
+  lib.test_screaming_snake_case.argtypes = [ctypes.c_int32]
+  lib.test_screaming_snake_case.restype = None
+
+  lib.test_gecko_case.argtypes = [ctypes.c_int32]
+  lib.test_gecko_case.restype = None
+
+  return lib
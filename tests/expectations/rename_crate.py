@@ -0,0 +1,46 @@
+#if 0
+DEF DEFINE_FREEBSD = 0
+#endif
+
+
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+  ]
+
+class RenamedTy(ctypes.Structure):
+  _fields_ = [
+    ("y", ctypes.c_uint64),
+  ]
+
+# #if !defined(DEFINE_FREEBSD)
+class NoExternTy(ctypes.Structure):
+  _fields_ = [
+    ("field", ctypes.c_uint8),
+  ]
+# #endif
+
+# #if !defined(DEFINE_FREEBSD)
+class ContainsNoExternTy(ctypes.Structure):
+  _fields_ = [
+    ("field", NoExternTy),
+  ]
+# #endif
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  lib.renamed_func.argtypes = [RenamedTy]
+  lib.renamed_func.restype = None
+
+  lib.no_extern_func.argtypes = [ContainsNoExternTy]
+  lib.no_extern_func.restype = None
+
+  return lib
@@ -0,0 +1,19 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct StyleA { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct B
+  {
+    public int x;
+    public float y;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(StyleA* a, B b);
+}
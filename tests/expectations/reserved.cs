@@ -0,0 +1,118 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct A
+  {
+    public int namespace_;
+    public float float_;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct B
+  {
+    public int namespace_;
+    public float float_;
+  }
+
+  public enum C_Tag : byte
+  {
+    D,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct D_Body
+  {
+    public int namespace_;
+    public float float_;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct C
+  {
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Data
+    {
+      [FieldOffset(0)]
+      public D_Body d;
+    }
+
+    public C_Tag tag;
+    public Data data;
+  }
+
+  public enum E_Tag : byte
+  {
+    Double,
+    Float,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct E
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Double_Body
+    {
+      public double double_;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Float_Body
+    {
+      public float float_;
+    }
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Data
+    {
+      [FieldOffset(0)]
+      public Double_Body double_;
+      [FieldOffset(0)]
+      public Float_Body float_;
+    }
+
+    public E_Tag tag;
+    public Data data;
+  }
+
+  public enum F_Tag : byte
+  {
+    double_,
+    float_,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct F
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct double_Body
+    {
+      public double double_;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    public struct float_Body
+    {
+      public float float_;
+    }
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Data
+    {
+      [FieldOffset(0)]
+      public double_Body double_;
+      [FieldOffset(0)]
+      public float_Body float_;
+    }
+
+    public F_Tag tag;
+    public Data data;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A a, B b, C c, E e, F f, int namespace_, float float_);
+}
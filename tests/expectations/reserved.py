@@ -0,0 +1,95 @@
+import ctypes
+import enum
+
+class A(ctypes.Structure):
+  _fields_ = [
+    ("namespace_", ctypes.c_int32),
+    ("float_", ctypes.c_float),
+  ]
+
+class B(ctypes.Structure):
+  _fields_ = [
+    ("namespace_", ctypes.c_int32),
+    ("float_", ctypes.c_float),
+  ]
+
+class C_Tag(enum.IntEnum):
+  D = 0
+
+class D_Body(ctypes.Structure):
+  _fields_ = [
+    ("namespace_", ctypes.c_int32),
+    ("float_", ctypes.c_float),
+  ]
+
+class C_Data(ctypes.Union):
+  _fields_ = [
+    ("d", D_Body),
+  ]
+
+class C(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", C_Data),
+  ]
+
+class E_Tag(enum.IntEnum):
+  Double = 0
+  Float = 1
+
+class Double_Body(ctypes.Structure):
+  _fields_ = [
+    ("double_", ctypes.c_double),
+  ]
+
+class Float_Body(ctypes.Structure):
+  _fields_ = [
+    ("float_", ctypes.c_float),
+  ]
+
+class E_Data(ctypes.Union):
+  _fields_ = [
+    ("double_", Double_Body),
+    ("float_", Float_Body),
+  ]
+
+class E(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", E_Data),
+  ]
+
+class F_Tag(enum.IntEnum):
+  double_ = 0
+  float_ = 1
+
+class double_Body(ctypes.Structure):
+  _fields_ = [
+    ("double_", ctypes.c_double),
+  ]
+
+class float_Body(ctypes.Structure):
+  _fields_ = [
+    ("float_", ctypes.c_float),
+  ]
+
+class F_Data(ctypes.Union):
+  _fields_ = [
+    ("double_", double_Body),
+    ("float_", float_Body),
+  ]
+
+class F(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", F_Data),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [A, B, C, E, F, ctypes.c_int32, ctypes.c_float]
+  lib.root.restype = None
+
+  return lib
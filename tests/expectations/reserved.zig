@@ -0,0 +1,63 @@
+const std = @import("std");
+
+pub const A = extern struct {
+  namespace_: i32,
+  float_: f32,
+};
+
+pub const B = extern struct {
+  namespace_: i32,
+  float_: f32,
+};
+
+pub const C_Tag = enum(u8) {
+  D,
+};
+
+pub const D_Body = extern struct {
+  namespace_: i32,
+  float_: f32,
+};
+
+pub const C = extern struct {
+  tag: C_Tag,
+  data: extern union {
+    d: D_Body,
+  },
+};
+
+pub const E_Tag = enum(u8) {
+  Double,
+  Float,
+};
+
+pub const E = extern struct {
+  tag: E_Tag,
+  data: extern union {
+    double_: extern struct {
+      double_: f64,
+    },
+    float_: extern struct {
+      float_: f32,
+    },
+  },
+};
+
+pub const F_Tag = enum(u8) {
+  double_,
+  float_,
+};
+
+pub const F = extern struct {
+  tag: F_Tag,
+  data: extern union {
+    double_: extern struct {
+      double_: f64,
+    },
+    float_: extern struct {
+      float_: f32,
+    },
+  },
+};
+
+pub extern fn root(a: A, b: B, c: C, e: E, f: F, namespace_: i32, float_: f32) void;
@@ -0,0 +1,57 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t value;
+  Node *next;
+} Node;
+
+typedef struct {
+  const Pair *left;
+  const Pair *right;
+} Pair;
+
+typedef enum {
+  Leaf,
+  Branch,
+} Tree_Tag;
+
+typedef struct {
+  const Tree *_0;
+  const Tree *_1;
+} Branch_Body;
+
+typedef struct {
+  Tree_Tag tag;
+  union {
+    struct {
+      int32_t leaf;
+    };
+    Branch_Body branch;
+  };
+} Tree;
+
+void root(Node *node, Pair pair, Tree tree);
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
@@ -0,0 +1,64 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Node {
+  int32_t value;
+  Node *next;
+};
+
+struct Pair {
+  const Pair *left;
+  const Pair *right;
+};
+
+struct Tree {
+  enum class Tag {
+    Leaf,
+    Branch,
+  };
+
+  struct Leaf_Body {
+    int32_t _0;
+  };
+
+  struct Branch_Body {
+    const Tree *_0;
+    const Tree *_1;
+  };
+
+  Tag tag;
+  union {
+    Leaf_Body leaf;
+    Branch_Body branch;
+  };
+};
+
+extern "C" {
+
+void root(Node *node, Pair pair, Tree tree);
+
+}  // extern "C"
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
@@ -0,0 +1,80 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Node
+  {
+    public int value;
+    public Node* next;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Pair
+  {
+    public Pair* left;
+    public Pair* right;
+  }
+
+  public enum Tree_Tag : int
+  {
+    Leaf,
+    Branch,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Branch_Body
+  {
+    public Tree* _0;
+    public Tree* _1;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Tree
+  {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Leaf_Body
+    {
+      public int leaf;
+    }
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Data
+    {
+      [FieldOffset(0)]
+      public Leaf_Body leaf;
+      [FieldOffset(0)]
+      public Branch_Body branch;
+    }
+
+    public Tree_Tag tag;
+    public Data data;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Node* node, Pair pair, Tree tree);
+}
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
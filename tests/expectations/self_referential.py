@@ -0,0 +1,77 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class Node(ctypes.Structure):
+  pass
+
+Node._fields_ = [
+  ("value", ctypes.c_int32),
+  ("next", ctypes.POINTER(Node)),
+]
+
+class Pair(ctypes.Structure):
+  pass
+
+Pair._fields_ = [
+  ("left", ctypes.POINTER(Pair)),
+  ("right", ctypes.POINTER(Pair)),
+]
+
+class Tree_Tag(enum.IntEnum):
+  Leaf = 0
+  Branch = 1
+
+class Leaf_Body(ctypes.Structure):
+  _fields_ = [
+    ("leaf", ctypes.c_int32),
+  ]
+
+class Tree(ctypes.Structure):
+  pass
+
+class Branch_Body(ctypes.Structure):
+  _fields_ = [
+    ("_0", ctypes.POINTER(Tree)),
+    ("_1", ctypes.POINTER(Tree)),
+  ]
+
+class Tree_Data(ctypes.Union):
+  _fields_ = [
+    ("leaf", Leaf_Body),
+    ("branch", Branch_Body),
+  ]
+
+Tree._fields_ = [
+  ("tag", ctypes.c_int),
+  ("data", Tree_Data),
+]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Node), Pair, Tree]
+  lib.root.restype = None
+
+  return lib
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
@@ -0,0 +1,51 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Node:
+    int32_t value;
+    Node *next;
+
+  ctypedef struct Pair:
+    const Pair *left;
+    const Pair *right;
+
+  ctypedef enum Tree_Tag:
+    Leaf,
+    Branch,
+
+  ctypedef struct Branch_Body:
+    const Tree *_0;
+    const Tree *_1;
+
+  ctypedef struct Tree:
+    Tree_Tag tag;
+    int32_t leaf;
+    Branch_Body branch;
+
+  void root(Node *node, Pair pair, Tree tree);
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
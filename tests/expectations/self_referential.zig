@@ -0,0 +1,54 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+
+pub const Node = extern struct {
+  value: i32,
+  next: ?*Node,
+};
+
+pub const Pair = extern struct {
+  left: ?*const Pair,
+  right: ?*const Pair,
+};
+
+pub const Tree_Tag = enum(c_int) {
+  Leaf,
+  Branch,
+};
+
+pub const Branch_Body = extern struct {
+  _0: ?*const Tree,
+  _1: ?*const Tree,
+};
+
+pub const Tree = extern struct {
+  tag: Tree_Tag,
+  data: extern union {
+    leaf: extern struct {
+      leaf: i32,
+    },
+    branch: Branch_Body,
+  },
+};
+
+pub extern fn root(node: ?*Node, pair: Pair, tree: Tree) void;
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
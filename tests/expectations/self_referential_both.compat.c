@@ -0,0 +1,65 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Node {
+  int32_t value;
+  struct Node *next;
+} Node;
+
+typedef struct Pair {
+  const struct Pair *left;
+  const struct Pair *right;
+} Pair;
+
+typedef enum Tree_Tag {
+  Leaf,
+  Branch,
+} Tree_Tag;
+
+typedef struct Branch_Body {
+  const struct Tree *_0;
+  const struct Tree *_1;
+} Branch_Body;
+
+typedef struct Tree {
+  Tree_Tag tag;
+  union {
+    struct {
+      int32_t leaf;
+    };
+    Branch_Body branch;
+  };
+} Tree;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Node *node, struct Pair pair, struct Tree tree);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
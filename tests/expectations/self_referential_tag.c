@@ -0,0 +1,57 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Node {
+  int32_t value;
+  struct Node *next;
+};
+
+struct Pair {
+  const struct Pair *left;
+  const struct Pair *right;
+};
+
+enum Tree_Tag {
+  Leaf,
+  Branch,
+};
+
+struct Branch_Body {
+  const struct Tree *_0;
+  const struct Tree *_1;
+};
+
+struct Tree {
+  enum Tree_Tag tag;
+  union {
+    struct {
+      int32_t leaf;
+    };
+    struct Branch_Body branch;
+  };
+};
+
+void root(struct Node *node, struct Pair pair, struct Tree tree);
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
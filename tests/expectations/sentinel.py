@@ -0,0 +1,51 @@
+import ctypes
+import enum
+
+class A(enum.IntEnum):
+  A_A1 = 0
+  A_A2 = 1
+  A_A3 = 2
+  # Must be last for serialization purposes
+  A_Sentinel = 3
+
+class B(enum.IntEnum):
+  B_B1 = 0
+  B_B2 = 1
+  B_B3 = 2
+  # Must be last for serialization purposes
+  B_Sentinel = 3
+
+class C_Tag(enum.IntEnum):
+  C_C1 = 0
+  C_C2 = 1
+  C_C3 = 2
+  # Must be last for serialization purposes
+  C_Sentinel = 3
+
+class C_C1_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("a", ctypes.c_uint32),
+  ]
+
+class C_C2_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("b", ctypes.c_uint32),
+  ]
+
+class C(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("c1", C_C1_Body),
+    ("c2", C_C2_Body),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.c_uint8, ctypes.c_uint8, C]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,42 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Opaque { }
+
+  public struct Option_____Opaque { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo
+  {
+    public Opaque* x;
+    public Opaque* y;
+    public delegate* unmanaged[Cdecl]<void> z;
+    public delegate* unmanaged[Cdecl]<void>* zz;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Bar
+  {
+    [FieldOffset(0)]
+    public Opaque* x;
+    [FieldOffset(0)]
+    public Opaque* y;
+    [FieldOffset(0)]
+    public delegate* unmanaged[Cdecl]<void> z;
+    [FieldOffset(0)]
+    public delegate* unmanaged[Cdecl]<void>* zz;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(
+    Opaque* a,
+    Opaque* b,
+    Foo c,
+    Bar d,
+    Option_____Opaque* e,
+    delegate* unmanaged[Cdecl]<Opaque*, void> f);
+}
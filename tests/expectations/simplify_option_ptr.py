@@ -0,0 +1,40 @@
+import ctypes
+import enum
+
+class Opaque(ctypes.Structure):
+  pass
+
+class Option_____Opaque(ctypes.Structure):
+  pass
+
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.POINTER(Opaque)),
+    ("y", ctypes.POINTER(Opaque)),
+    ("z", ctypes.CFUNCTYPE(None)),
+    ("zz", ctypes.POINTER(ctypes.CFUNCTYPE(None))),
+  ]
+
+class Bar(ctypes.Union):
+  _fields_ = [
+    ("x", ctypes.POINTER(Opaque)),
+    ("y", ctypes.POINTER(Opaque)),
+    ("z", ctypes.CFUNCTYPE(None)),
+    ("zz", ctypes.POINTER(ctypes.CFUNCTYPE(None))),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    ctypes.POINTER(Opaque),
+    ctypes.POINTER(Opaque),
+    Foo,
+    Bar,
+    ctypes.POINTER(Option_____Opaque),
+    ctypes.CFUNCTYPE(None, ctypes.POINTER(Opaque)),
+  ]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,20 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public enum IE : long
+  {
+    IV,
+  }
+
+  public enum UE : ulong
+  {
+    UV,
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(nuint arg0, nint arg1, UE arg2, IE arg3);
+}
@@ -0,0 +1,21 @@
+import ctypes
+import enum
+
+class IE(enum.IntEnum):
+  IV = 0
+
+class UE(enum.IntEnum):
+  UV = 0
+
+Usize = ctypes.c_size_t
+
+Isize = ctypes.c_ssize_t
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Usize, Isize, ctypes.c_size_t, ctypes.c_ssize_t]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Bar { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo { }
+
+  private static readonly nint LibraryHandle = NativeLibrary.Load(LibraryName, typeof(NativeMethods).Assembly, null);
+
+  public static ref readonly int NUMBER => ref *(int*)NativeLibrary.GetExport(LibraryHandle, "NUMBER");
+
+  public static ref Foo FOO => ref *(Foo*)NativeLibrary.GetExport(LibraryHandle, "FOO");
+
+  public static ref readonly Bar BAR => ref *(Bar*)NativeLibrary.GetExport(LibraryHandle, "BAR");
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root();
+}
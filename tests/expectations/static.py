@@ -0,0 +1,23 @@
+import ctypes
+import enum
+
+class Bar(ctypes.Structure):
+  pass
+
+class Foo(ctypes.Structure):
+  pass
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.NUMBER = ctypes.c_int32.in_dll(lib, "NUMBER")
+
+  lib.FOO = Foo.in_dll(lib, "FOO")
+
+  lib.BAR = Bar.in_dll(lib, "BAR")
+
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
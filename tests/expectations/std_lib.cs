@@ -0,0 +1,16 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Option_i32 { }
+
+  public struct Result_i32__String { }
+
+  public struct Vec_String { }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Vec_String* a, Option_i32* b, Result_i32__String* c);
+}
@@ -0,0 +1,45 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Opaque { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Normal
+  {
+    public int x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct NormalWithZST
+  {
+    public int x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TupleRenamed
+  {
+    public int m0;
+    public float m1;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TupleNamed
+  {
+    public int x;
+    public float y;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(
+    Opaque* a,
+    Normal b,
+    NormalWithZST c,
+    TupleRenamed d,
+    TupleNamed e);
+}
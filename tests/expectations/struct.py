@@ -0,0 +1,38 @@
+import ctypes
+import enum
+
+class Opaque(ctypes.Structure):
+  pass
+
+class Normal(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+class NormalWithZST(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+class TupleRenamed(ctypes.Structure):
+  _fields_ = [
+    ("m0", ctypes.c_int32),
+    ("m1", ctypes.c_float),
+  ]
+
+class TupleNamed(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Opaque), Normal, NormalWithZST, TupleRenamed, TupleNamed]
+  lib.root.restype = None
+
+  return lib
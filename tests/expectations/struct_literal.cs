@@ -0,0 +1,27 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Bar { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo
+  {
+    public int a;
+    public uint b;
+  }
+  public static readonly Foo Foo_FOO = new Foo { a = 42, b = 47 };
+  public static readonly Foo Foo_FOO2 = new Foo { a = 42, b = 47 };
+  public static readonly Foo Foo_FOO3 = new Foo { a = 42, b = 47 };
+
+
+  public static readonly Foo BAR = new Foo { a = 42, b = 1337 };
+
+
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo x, Bar bar);
+}
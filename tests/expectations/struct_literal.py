@@ -0,0 +1,28 @@
+import ctypes
+import enum
+
+class Bar(ctypes.Structure):
+  pass
+
+class Foo(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_int32),
+    ("b", ctypes.c_uint32),
+  ]
+Foo_FOO = Foo(a=42, b=47)
+Foo_FOO2 = Foo(a=42, b=47)
+Foo_FOO3 = Foo(a=42, b=47)
+
+
+BAR = Foo(a=42, b=1337)
+
+
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo, Bar]
+  lib.root.restype = None
+
+  return lib
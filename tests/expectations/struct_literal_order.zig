@@ -0,0 +1,21 @@
+const std = @import("std");
+
+pub const ABC = extern struct {
+  a: f32,
+  b: u32,
+  c: u32,
+};
+pub const ABC_abc: ABC = ABC{ .a = 1.0, .b = 2, .c = 3 };
+pub const ABC_bac: ABC = ABC{ .a = 1.0, .b = 2, .c = 3 };
+pub const ABC_cba: ABC = ABC{ .a = 1.0, .b = 2, .c = 3 };
+
+pub const BAC = extern struct {
+  b: u32,
+  a: f32,
+  c: i32,
+};
+pub const BAC_abc: BAC = BAC{ .b = 1, .a = 2.0, .c = 3 };
+pub const BAC_bac: BAC = BAC{ .b = 1, .a = 2.0, .c = 3 };
+pub const BAC_cba: BAC = BAC{ .b = 1, .a = 2.0, .c = 3 };
+
+pub extern fn root(a1: ABC, a2: BAC) void;
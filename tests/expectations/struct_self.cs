@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_Bar
+  {
+    public int* something;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar
+  {
+    public int something;
+    public Foo_Bar subexpressions;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Bar b);
+}
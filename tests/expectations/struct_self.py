@@ -0,0 +1,22 @@
+import ctypes
+import enum
+
+class Foo_Bar(ctypes.Structure):
+  _fields_ = [
+    ("something", ctypes.POINTER(ctypes.c_int32)),
+  ]
+
+class Bar(ctypes.Structure):
+  _fields_ = [
+    ("something", ctypes.c_int32),
+    ("subexpressions", Foo_Bar),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Bar]
+  lib.root.restype = None
+
+  return lib
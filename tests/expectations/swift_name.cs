@@ -0,0 +1,86 @@
+#define CF_SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Opaque { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct SelfTypeTestStruct
+  {
+    public byte times;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct PointerToOpaque
+  {
+    public Opaque* ptr;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void rust_print_hello_world();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_ref(SelfTypeTestStruct* self);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_ref_mut(SelfTypeTestStruct* self);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_not_exist_box(SelfTypeTestStruct* self);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern SelfTypeTestStruct* SelfTypeTestStruct_should_not_exist_return_box();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_annotated_self(SelfTypeTestStruct self);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_annotated_mut_self(
+    SelfTypeTestStruct self);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_annotated_by_name(
+    SelfTypeTestStruct self);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_annotated_mut_by_name(
+    SelfTypeTestStruct self);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_unannotated(SelfTypeTestStruct self);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_mut_unannotated(
+    SelfTypeTestStruct self);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void free_function_should_exist_ref(SelfTypeTestStruct* test_struct);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void free_function_should_exist_ref_mut(SelfTypeTestStruct* test_struct);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void unnamed_argument(SelfTypeTestStruct* arg0);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void free_function_should_not_exist_box(SelfTypeTestStruct* boxed);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void free_function_should_exist_annotated_by_name(
+    SelfTypeTestStruct test_struct);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void free_function_should_exist_annotated_mut_by_name(
+    SelfTypeTestStruct test_struct);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern PointerToOpaque PointerToOpaque_create(byte times);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void PointerToOpaque_sayHello(PointerToOpaque self);
+}
@@ -0,0 +1,80 @@
+#define CF_SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+
+import ctypes
+import enum
+
+class Opaque(ctypes.Structure):
+  pass
+
+class SelfTypeTestStruct(ctypes.Structure):
+  _fields_ = [
+    ("times", ctypes.c_uint8),
+  ]
+
+class PointerToOpaque(ctypes.Structure):
+  _fields_ = [
+    ("ptr", ctypes.POINTER(Opaque)),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.rust_print_hello_world.argtypes = []
+  lib.rust_print_hello_world.restype = None
+
+  lib.SelfTypeTestStruct_should_exist_ref.argtypes = [ctypes.POINTER(SelfTypeTestStruct)]
+  lib.SelfTypeTestStruct_should_exist_ref.restype = None
+
+  lib.SelfTypeTestStruct_should_exist_ref_mut.argtypes = [ctypes.POINTER(SelfTypeTestStruct)]
+  lib.SelfTypeTestStruct_should_exist_ref_mut.restype = None
+
+  lib.SelfTypeTestStruct_should_not_exist_box.argtypes = [ctypes.POINTER(SelfTypeTestStruct)]
+  lib.SelfTypeTestStruct_should_not_exist_box.restype = None
+
+  lib.SelfTypeTestStruct_should_not_exist_return_box.argtypes = []
+  lib.SelfTypeTestStruct_should_not_exist_return_box.restype = ctypes.POINTER(SelfTypeTestStruct)
+
+  lib.SelfTypeTestStruct_should_exist_annotated_self.argtypes = [SelfTypeTestStruct]
+  lib.SelfTypeTestStruct_should_exist_annotated_self.restype = None
+
+  lib.SelfTypeTestStruct_should_exist_annotated_mut_self.argtypes = [SelfTypeTestStruct]
+  lib.SelfTypeTestStruct_should_exist_annotated_mut_self.restype = None
+
+  lib.SelfTypeTestStruct_should_exist_annotated_by_name.argtypes = [SelfTypeTestStruct]
+  lib.SelfTypeTestStruct_should_exist_annotated_by_name.restype = None
+
+  lib.SelfTypeTestStruct_should_exist_annotated_mut_by_name.argtypes = [SelfTypeTestStruct]
+  lib.SelfTypeTestStruct_should_exist_annotated_mut_by_name.restype = None
+
+  lib.SelfTypeTestStruct_should_exist_unannotated.argtypes = [SelfTypeTestStruct]
+  lib.SelfTypeTestStruct_should_exist_unannotated.restype = None
+
+  lib.SelfTypeTestStruct_should_exist_mut_unannotated.argtypes = [SelfTypeTestStruct]
+  lib.SelfTypeTestStruct_should_exist_mut_unannotated.restype = None
+
+  lib.free_function_should_exist_ref.argtypes = [ctypes.POINTER(SelfTypeTestStruct)]
+  lib.free_function_should_exist_ref.restype = None
+
+  lib.free_function_should_exist_ref_mut.argtypes = [ctypes.POINTER(SelfTypeTestStruct)]
+  lib.free_function_should_exist_ref_mut.restype = None
+
+  lib.unnamed_argument.argtypes = [ctypes.POINTER(SelfTypeTestStruct)]
+  lib.unnamed_argument.restype = None
+
+  lib.free_function_should_not_exist_box.argtypes = [ctypes.POINTER(SelfTypeTestStruct)]
+  lib.free_function_should_not_exist_box.restype = None
+
+  lib.free_function_should_exist_annotated_by_name.argtypes = [SelfTypeTestStruct]
+  lib.free_function_should_exist_annotated_by_name.restype = None
+
+  lib.free_function_should_exist_annotated_mut_by_name.argtypes = [SelfTypeTestStruct]
+  lib.free_function_should_exist_annotated_mut_by_name.restype = None
+
+  lib.PointerToOpaque_create.argtypes = [ctypes.c_uint8]
+  lib.PointerToOpaque_create.restype = PointerToOpaque
+
+  lib.PointerToOpaque_sayHello.argtypes = [PointerToOpaque]
+  lib.PointerToOpaque_sayHello.restype = None
+
+  return lib
@@ -0,0 +1,53 @@
+#define CF_SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+
+const std = @import("std");
+
+pub const Opaque = opaque {};
+
+pub const SelfTypeTestStruct = extern struct {
+  times: u8,
+};
+
+pub const PointerToOpaque = extern struct {
+  ptr: ?*Opaque,
+};
+
+pub extern fn rust_print_hello_world() void;
+
+pub extern fn SelfTypeTestStruct_should_exist_ref(self: *const SelfTypeTestStruct) void;
+
+pub extern fn SelfTypeTestStruct_should_exist_ref_mut(self: *SelfTypeTestStruct) void;
+
+pub extern fn SelfTypeTestStruct_should_not_exist_box(self: *SelfTypeTestStruct) void;
+
+pub extern fn SelfTypeTestStruct_should_not_exist_return_box() *SelfTypeTestStruct;
+
+pub extern fn SelfTypeTestStruct_should_exist_annotated_self(self: SelfTypeTestStruct) void;
+
+pub extern fn SelfTypeTestStruct_should_exist_annotated_mut_self(self: SelfTypeTestStruct) void;
+
+pub extern fn SelfTypeTestStruct_should_exist_annotated_by_name(self: SelfTypeTestStruct) void;
+
+pub extern fn SelfTypeTestStruct_should_exist_annotated_mut_by_name(self: SelfTypeTestStruct) void;
+
+pub extern fn SelfTypeTestStruct_should_exist_unannotated(self: SelfTypeTestStruct) void;
+
+pub extern fn SelfTypeTestStruct_should_exist_mut_unannotated(self: SelfTypeTestStruct) void;
+
+pub extern fn free_function_should_exist_ref(test_struct: *const SelfTypeTestStruct) void;
+
+pub extern fn free_function_should_exist_ref_mut(test_struct: *SelfTypeTestStruct) void;
+
+pub extern fn unnamed_argument(*SelfTypeTestStruct) void;
+
+pub extern fn free_function_should_not_exist_box(boxed: *SelfTypeTestStruct) void;
+
+pub extern fn free_function_should_exist_annotated_by_name(test_struct: SelfTypeTestStruct) void;
+
+pub extern fn free_function_should_exist_annotated_mut_by_name(
+  test_struct: SelfTypeTestStruct,
+) void;
+
+pub extern fn PointerToOpaque_create(times: u8) PointerToOpaque;
+
+pub extern fn PointerToOpaque_sayHello(self: PointerToOpaque) void;
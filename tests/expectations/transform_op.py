@@ -0,0 +1,193 @@
+import ctypes
+import enum
+
+class StylePoint_i32(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_int32),
+  ]
+
+class StylePoint_f32(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_float),
+    ("y", ctypes.c_float),
+  ]
+
+class StyleFoo_i32_Tag(enum.IntEnum):
+  Foo_i32 = 0
+  Bar_i32 = 1
+  Baz_i32 = 2
+  Bazz_i32 = 3
+
+class StyleFoo_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_int32),
+    ("y", StylePoint_i32),
+    ("z", StylePoint_f32),
+  ]
+
+class StyleBar_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("bar_tag", ctypes.c_uint8),
+    ("bar", ctypes.c_int32),
+  ]
+
+class StyleBaz_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("baz_tag", ctypes.c_uint8),
+    ("baz", StylePoint_i32),
+  ]
+
+class StyleFoo_i32(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("foo", StyleFoo_Body_i32),
+    ("bar", StyleBar_Body_i32),
+    ("baz", StyleBaz_Body_i32),
+  ]
+
+class StyleBar_i32_Tag(enum.IntEnum):
+  Bar1_i32 = 0
+  Bar2_i32 = 1
+  Bar3_i32 = 2
+  Bar4_i32 = 3
+
+class StyleBar1_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", StylePoint_i32),
+    ("z", StylePoint_f32),
+    ("u", ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32)),
+  ]
+
+class StyleBar2_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("bar2", ctypes.c_int32),
+  ]
+
+class StyleBar3_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("bar3", StylePoint_i32),
+  ]
+
+class StyleBar_i32_Data(ctypes.Union):
+  _fields_ = [
+    ("bar1", StyleBar1_Body_i32),
+    ("bar2", StyleBar2_Body_i32),
+    ("bar3", StyleBar3_Body_i32),
+  ]
+
+class StyleBar_i32(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_int),
+    ("data", StyleBar_i32_Data),
+  ]
+
+class StylePoint_u32(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint32),
+    ("y", ctypes.c_uint32),
+  ]
+
+class StyleBar_u32_Tag(enum.IntEnum):
+  Bar1_u32 = 0
+  Bar2_u32 = 1
+  Bar3_u32 = 2
+  Bar4_u32 = 3
+
+class StyleBar1_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", StylePoint_u32),
+    ("z", StylePoint_f32),
+    ("u", ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32)),
+  ]
+
+class StyleBar2_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("bar2", ctypes.c_uint32),
+  ]
+
+class StyleBar3_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("bar3", StylePoint_u32),
+  ]
+
+class StyleBar_u32_Data(ctypes.Union):
+  _fields_ = [
+    ("bar1", StyleBar1_Body_u32),
+    ("bar2", StyleBar2_Body_u32),
+    ("bar3", StyleBar3_Body_u32),
+  ]
+
+class StyleBar_u32(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_int),
+    ("data", StyleBar_u32_Data),
+  ]
+
+class StyleBaz_Tag(enum.IntEnum):
+  Baz1 = 0
+  Baz2 = 1
+  Baz3 = 2
+
+class StyleBaz1_Body(ctypes.Structure):
+  _fields_ = [
+    ("baz1_tag", ctypes.c_uint8),
+    ("baz1", StyleBar_u32),
+  ]
+
+class StyleBaz2_Body(ctypes.Structure):
+  _fields_ = [
+    ("baz2_tag", ctypes.c_uint8),
+    ("baz2", StylePoint_i32),
+  ]
+
+class StyleBaz(ctypes.Union):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("baz1", StyleBaz1_Body),
+    ("baz2", StyleBaz2_Body),
+  ]
+
+class StyleTaz_Tag(enum.IntEnum):
+  Taz1 = 0
+  Taz2 = 1
+  Taz3 = 2
+
+class StyleTaz1_Body(ctypes.Structure):
+  _fields_ = [
+    ("taz1", StyleBar_u32),
+  ]
+
+class StyleTaz2_Body(ctypes.Structure):
+  _fields_ = [
+    ("taz2", StyleBaz),
+  ]
+
+class StyleTaz_Data(ctypes.Union):
+  _fields_ = [
+    ("taz1", StyleTaz1_Body),
+    ("taz2", StyleTaz2_Body),
+  ]
+
+class StyleTaz(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("data", StyleTaz_Data),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.foo.argtypes = [
+    ctypes.POINTER(StyleFoo_i32),
+    ctypes.POINTER(StyleBar_i32),
+    ctypes.POINTER(StyleBaz),
+    ctypes.POINTER(StyleTaz),
+  ]
+  lib.foo.restype = None
+
+  return lib
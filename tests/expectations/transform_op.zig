@@ -0,0 +1,140 @@
+const std = @import("std");
+
+pub const StylePoint_i32 = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const StylePoint_f32 = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const StyleFoo_i32_Tag = enum(u8) {
+  Foo_i32,
+  Bar_i32,
+  Baz_i32,
+  Bazz_i32,
+};
+
+pub const StyleFoo_Body_i32 = extern struct {
+  tag: StyleFoo_i32_Tag,
+  x: i32,
+  y: StylePoint_i32,
+  z: StylePoint_f32,
+};
+
+pub const StyleFoo_i32 = extern union {
+  tag: StyleFoo_i32_Tag,
+  foo: StyleFoo_Body_i32,
+  bar: extern struct {
+    bar_tag: StyleFoo_i32_Tag,
+    bar: i32,
+  },
+  baz: extern struct {
+    baz_tag: StyleFoo_i32_Tag,
+    baz: StylePoint_i32,
+  },
+};
+
+pub const StyleBar_i32_Tag = enum(c_int) {
+  Bar1_i32,
+  Bar2_i32,
+  Bar3_i32,
+  Bar4_i32,
+};
+
+pub const StyleBar1_Body_i32 = extern struct {
+  x: i32,
+  y: StylePoint_i32,
+  z: StylePoint_f32,
+  u: *const fn (i32) callconv(.C) i32,
+};
+
+pub const StyleBar_i32 = extern struct {
+  tag: StyleBar_i32_Tag,
+  data: extern union {
+    bar1: StyleBar1_Body_i32,
+    bar2: extern struct {
+      bar2: i32,
+    },
+    bar3: extern struct {
+      bar3: StylePoint_i32,
+    },
+  },
+};
+
+pub const StylePoint_u32 = extern struct {
+  x: u32,
+  y: u32,
+};
+
+pub const StyleBar_u32_Tag = enum(c_int) {
+  Bar1_u32,
+  Bar2_u32,
+  Bar3_u32,
+  Bar4_u32,
+};
+
+pub const StyleBar1_Body_u32 = extern struct {
+  x: i32,
+  y: StylePoint_u32,
+  z: StylePoint_f32,
+  u: *const fn (i32) callconv(.C) i32,
+};
+
+pub const StyleBar_u32 = extern struct {
+  tag: StyleBar_u32_Tag,
+  data: extern union {
+    bar1: StyleBar1_Body_u32,
+    bar2: extern struct {
+      bar2: u32,
+    },
+    bar3: extern struct {
+      bar3: StylePoint_u32,
+    },
+  },
+};
+
+pub const StyleBaz_Tag = enum(u8) {
+  Baz1,
+  Baz2,
+  Baz3,
+};
+
+pub const StyleBaz = extern union {
+  tag: StyleBaz_Tag,
+  baz1: extern struct {
+    baz1_tag: StyleBaz_Tag,
+    baz1: StyleBar_u32,
+  },
+  baz2: extern struct {
+    baz2_tag: StyleBaz_Tag,
+    baz2: StylePoint_i32,
+  },
+};
+
+pub const StyleTaz_Tag = enum(u8) {
+  Taz1,
+  Taz2,
+  Taz3,
+};
+
+pub const StyleTaz = extern struct {
+  tag: StyleTaz_Tag,
+  data: extern union {
+    taz1: extern struct {
+      taz1: StyleBar_u32,
+    },
+    taz2: extern struct {
+      taz2: StyleBaz,
+    },
+  },
+};
+
+pub extern fn foo(
+  foo: ?*const StyleFoo_i32,
+  bar: ?*const StyleBar_i32,
+  baz: ?*const StyleBaz,
+  taz: ?*const StyleTaz,
+) void;
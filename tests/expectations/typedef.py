@@ -0,0 +1,19 @@
+import ctypes
+import enum
+
+class Foo_i32__i32(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_int32),
+  ]
+
+IntFoo_i32 = Foo_i32__i32
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [IntFoo_i32]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,30 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods
+{
+  private const string LibraryName = "native";
+
+  public struct Opaque { }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Normal
+  {
+    [FieldOffset(0)]
+    public int x;
+    [FieldOffset(0)]
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct NormalWithZST
+  {
+    [FieldOffset(0)]
+    public int x;
+    [FieldOffset(0)]
+    public float y;
+  }
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Opaque* a, Normal b, NormalWithZST c);
+}
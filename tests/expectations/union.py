@@ -0,0 +1,26 @@
+import ctypes
+import enum
+
+class Opaque(ctypes.Structure):
+  pass
+
+class Normal(ctypes.Union):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+class NormalWithZST(ctypes.Union):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", ctypes.c_float),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Opaque), Normal, NormalWithZST]
+  lib.root.restype = None
+
+  return lib
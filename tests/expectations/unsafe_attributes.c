@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t a;
+  int32_t b;
+} Pair;
+
+extern const int32_t COUNTER;
+
+int32_t root(Pair p);
+
+void renamed(void);
+
+void classic(void);
+
+extern int32_t checked(int32_t x);
+
+extern void unchecked(Pair *p);
+
+extern void plain(void);
@@ -0,0 +1,28 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Pair {
+  int32_t a;
+  int32_t b;
+};
+
+extern "C" {
+
+extern const int32_t COUNTER;
+
+int32_t root(Pair p);
+
+void renamed();
+
+void classic();
+
+extern int32_t checked(int32_t x);
+
+extern void unchecked(Pair *p);
+
+extern void plain();
+
+}  // extern "C"
@@ -0,0 +1,34 @@
+import ctypes
+import enum
+
+class Pair(ctypes.Structure):
+  _fields_ = [
+    ("a", ctypes.c_int32),
+    ("b", ctypes.c_int32),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.COUNTER = ctypes.c_int32.in_dll(lib, "COUNTER")
+
+  lib.root.argtypes = [Pair]
+  lib.root.restype = ctypes.c_int32
+
+  lib.renamed.argtypes = []
+  lib.renamed.restype = None
+
+  lib.classic.argtypes = []
+  lib.classic.restype = None
+
+  lib.checked.argtypes = [ctypes.c_int32]
+  lib.checked.restype = ctypes.c_int32
+
+  lib.unchecked.argtypes = [ctypes.POINTER(Pair)]
+  lib.unchecked.restype = None
+
+  lib.plain.argtypes = []
+  lib.plain.restype = None
+
+  return lib
@@ -0,0 +1,20 @@
+const std = @import("std");
+
+pub const Pair = extern struct {
+  a: i32,
+  b: i32,
+};
+
+pub extern const COUNTER: i32;
+
+pub extern fn root(p: Pair) i32;
+
+pub extern fn renamed() void;
+
+pub extern fn classic() void;
+
+pub extern fn checked(x: i32) i32;
+
+pub extern fn unchecked(p: ?*Pair) void;
+
+pub extern fn plain() void;
@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Pair:
+    int32_t a;
+    int32_t b;
+
+  extern const int32_t COUNTER;
+
+  int32_t root(Pair p);
+
+  void renamed();
+
+  void classic();
+
+  extern int32_t checked(int32_t x);
+
+  extern void unchecked(Pair *p);
+
+  extern void plain();
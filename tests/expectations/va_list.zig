@@ -0,0 +1,5 @@
+const std = @import("std");
+
+pub extern fn va_list_test(ap: std.builtin.VaList) i32;
+
+pub extern fn va_list_test2(ap: std.builtin.VaList) i32;
@@ -0,0 +1,17 @@
+import ctypes
+import enum
+
+class TraitObject(ctypes.Structure):
+  _fields_ = [
+    ("data", ctypes.c_void_p),
+    ("vtable", ctypes.c_void_p),
+  ]
+
+def load(path):
+  """Loads the shared library at `path` and declares its exported symbols."""
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.c_void_p, TraitObject]
+  lib.root.restype = ctypes.c_void_p
+
+  return lib
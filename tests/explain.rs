@@ -0,0 +1,74 @@
+mod common;
+
+use cbindgen::*;
+
+fn bindings() -> Bindings {
+    common::generate(
+        Builder::new()
+            .with_language(Language::C)
+            .with_src(common::test_file("explain", "lib.rs"))
+            .exclude_item("Excluded"),
+    )
+}
+
+#[test]
+fn included() {
+    let bindings = bindings();
+
+    let explanation = bindings.explain("process");
+    assert_eq!(
+        explanation.status,
+        Status::Included {
+            root: Root::Function("process".to_owned()),
+            chain: vec![],
+        }
+    );
+
+    let explanation = bindings.explain("Inner");
+    assert_eq!(
+        explanation.status,
+        Status::Included {
+            root: Root::Function("process".to_owned()),
+            chain: vec!["Outer".to_owned(), "Inner".to_owned()],
+        }
+    );
+    assert_eq!(
+        explanation.to_string(),
+        "`Inner` is included, because fn `process` uses it:\n    fn `process`\n    \
+         -> `Outer`\n    -> `Inner`\n"
+    );
+
+    let explanation = bindings.explain("Handle");
+    assert!(matches!(explanation.status, Status::Included { .. }));
+    assert!(explanation.opaque.is_some());
+}
+
+#[test]
+fn dropped() {
+    let bindings = bindings();
+
+    assert_eq!(
+        bindings.explain("Excluded").status,
+        Status::Dropped(Filter::ExportExclude)
+    );
+    assert_eq!(
+        bindings.explain("Ignored").status,
+        Status::Dropped(Filter::Ignored)
+    );
+    assert_eq!(
+        bindings.explain("Unused").status,
+        Status::Dropped(Filter::Unused)
+    );
+    match bindings.explain("not_exported").status {
+        Status::Dropped(Filter::Skipped(diagnostic)) => {
+            assert_eq!(diagnostic.code, DiagnosticCode::UnexportedFn)
+        }
+        status => panic!("unexpected status: {:?}", status),
+    }
+    assert_eq!(
+        bindings.explain("Missing").status,
+        Status::NotFound {
+            unparsed_crates: vec![]
+        }
+    );
+}
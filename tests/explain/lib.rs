@@ -0,0 +1,37 @@
+#[repr(C)]
+pub struct Inner {
+    x: i32,
+}
+
+#[repr(C)]
+pub struct Outer {
+    inner: Inner,
+}
+
+pub struct Handle {
+    data: Vec<u8>,
+}
+
+#[repr(C)]
+pub struct Unused {
+    x: i32,
+}
+
+#[repr(C)]
+pub struct Excluded {
+    x: i32,
+}
+
+/// cbindgen:ignore
+#[repr(C)]
+pub struct Ignored {
+    x: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn process(outer: Outer, handle: *mut Handle) {}
+
+#[no_mangle]
+pub extern "C" fn remove(excluded: Excluded) {}
+
+pub extern "C" fn not_exported() {}
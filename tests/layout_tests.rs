@@ -0,0 +1,95 @@
+mod common;
+
+use cbindgen::*;
+use common::run;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn test_file(name: &str) -> PathBuf {
+    common::test_file("layout_tests", name)
+}
+
+fn generate(language: Language, style: Style) -> Bindings {
+    let config = Config::from_file(test_file("cbindgen.toml")).unwrap();
+    common::generate(
+        Builder::new()
+            .with_config(config)
+            .with_language(language)
+            .with_style(style)
+            .with_src(test_file("types.rs")),
+    )
+}
+
+/// Generates the bindings and the C layout tests in `dir`, and compiles the
+/// latter.
+fn compile_c(dir: &Path, language: Language, style: Style) {
+    let bindings = generate(language, style);
+    bindings.write_to_file(dir.join("bindings.h"));
+    let source = dir.join("layout.c");
+    bindings.write_c_layout_tests_to_file(&source, "bindings.h");
+
+    let mut command = if language == Language::Cxx {
+        let mut command = Command::new(std::env::var("CXX").unwrap_or_else(|_| "c++".into()));
+        command.args(["-std=c++11", "-x", "c++"]);
+        command
+    } else {
+        let mut command = Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".into()));
+        command.arg("-std=c11");
+        command
+    };
+    command
+        .arg("-c")
+        .arg(&source)
+        .arg("-o")
+        .arg(dir.join("layout.o"));
+    run(&mut command);
+}
+
+#[test]
+fn c_layout_tests_compile() {
+    let dir = tempfile::tempdir().unwrap();
+    compile_c(dir.path(), Language::C, Style::Both);
+    compile_c(dir.path(), Language::C, Style::Tag);
+    compile_c(dir.path(), Language::Cxx, Style::Both);
+}
+
+#[test]
+fn rust_layout_tests_pass() {
+    let dir = tempfile::tempdir().unwrap();
+    let tests = dir.path().join("layout.rs");
+    generate(Language::C, Style::Both).write_rust_layout_tests_to_file(&tests);
+
+    let generated = fs::read_to_string(&tests).unwrap();
+    assert!(generated.contains("fn layout_WithLong()"));
+    assert!(generated.contains("if cfg!(windows) {"));
+    assert!(generated.contains("offset_of!(Keyword, r#type)"));
+    // A test for each version of `Partial`, with and without `c`.
+    assert!(generated.contains("#[cfg(all(target_pointer_width = \"64\", feature = \"x\"))]"));
+    assert!(generated.contains("assert_eq!(::std::mem::offset_of!(Partial, d), 16"));
+    assert!(generated.contains("#[cfg(all(target_pointer_width = \"64\", not(feature = \"x\")))]"));
+    assert!(generated.contains("assert_eq!(::std::mem::offset_of!(Partial, d), 5"));
+    // In Rust, even without a `[defines]` entry.
+    assert!(generated.contains("assert_eq!(::std::mem::offset_of!(Unmapped, c), 4"));
+
+    let main = dir.path().join("main.rs");
+    fs::write(
+        &main,
+        format!(
+            "include!({:?});\nmod layout {{\n    use super::*;\n    include!({:?});\n}}\n",
+            test_file("types.rs"),
+            tests
+        ),
+    )
+    .unwrap();
+    let binary = dir.path().join("layout_tests");
+    run(
+        Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into()))
+            .args(["--edition", "2021", "--test", "-A", "warnings"])
+            .arg(&main)
+            .arg("-o")
+            .arg(&binary),
+    );
+    run(&mut Command::new(&binary));
+}
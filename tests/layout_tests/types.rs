@@ -0,0 +1,90 @@
+use std::os::raw::{c_char, c_long};
+
+#[repr(C)]
+pub struct Point {
+    pub x: i32,
+    pub y: f64,
+    pub flag: bool,
+}
+
+#[repr(C, packed)]
+pub struct Packed {
+    pub a: u8,
+    pub b: u32,
+}
+
+#[repr(C, align(16))]
+pub struct Aligned {
+    pub a: u8,
+}
+
+#[repr(C)]
+pub struct WithLong {
+    pub a: u8,
+    pub b: c_long,
+    pub name: *const c_char,
+    pub points: [Point; 3],
+}
+
+#[repr(C)]
+pub struct Partial {
+    pub a: u32,
+    pub b: u8,
+    #[cfg(feature = "x")]
+    pub c: u64,
+    pub d: u8,
+}
+
+// No `[defines]` entry for `feature = "y"`.
+#[repr(C)]
+pub struct Unmapped {
+    pub a: u32,
+    #[cfg(feature = "y")]
+    pub b: u64,
+    pub c: u8,
+}
+
+#[repr(C)]
+pub struct Keyword {
+    pub r#type: u32,
+    pub callback: Option<extern "C" fn(i32) -> i32>,
+}
+
+#[repr(C)]
+pub struct Tuple(pub u16, pub u64);
+
+#[repr(C)]
+pub union Value {
+    pub i: i64,
+    pub bytes: [u8; 12],
+}
+
+#[repr(C)]
+pub enum Shape {
+    Circle(f32),
+    Rect { w: u32, h: u64 },
+    Empty,
+}
+
+#[repr(u8)]
+pub enum Small {
+    A(u16),
+    B { x: u8 },
+    C,
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    _point: Point,
+    _packed: Packed,
+    _aligned: Aligned,
+    _with_long: WithLong,
+    _partial: Partial,
+    _unmapped: Unmapped,
+    _keyword: Keyword,
+    _tuple: Tuple,
+    _value: Value,
+    _shape: Shape,
+    _small: Small,
+) {
+}
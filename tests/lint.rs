@@ -0,0 +1,67 @@
+mod common;
+
+use cbindgen::*;
+
+fn lints(enabled: bool, allow: &[&str]) -> Vec<(DiagnosticCode, String)> {
+    let mut config = Config::default();
+    config.diagnostics.lints = enabled;
+    config.diagnostics.allow = allow.iter().map(|lint| lint.to_string()).collect();
+    let bindings = common::generate(
+        Builder::new()
+            .with_config(config)
+            .with_language(Language::C)
+            .with_src(common::test_file("lint", "lib.rs")),
+    );
+    let mut lints = bindings
+        .diagnostics()
+        .iter()
+        .map(|d| (d.code, d.item.clone().unwrap()))
+        .collect::<Vec<_>>();
+    lints.sort_by(|a, b| (a.0.code(), &a.1).cmp(&(b.0.code(), &b.1)));
+    lints
+}
+
+fn lint(code: DiagnosticCode, item: &str) -> (DiagnosticCode, String) {
+    (code, item.to_owned())
+}
+
+#[test]
+fn lints_are_reported() {
+    assert_eq!(
+        lints(true, &[]),
+        [
+            lint(DiagnosticCode::BoolOrChar, "Options::verbose"),
+            // For its `bool`, and its `char`.
+            lint(DiagnosticCode::BoolOrChar, "set_flag"),
+            lint(DiagnosticCode::BoolOrChar, "set_flag"),
+            lint(DiagnosticCode::OpaqueByValue, "take_handle"),
+            lint(DiagnosticCode::OpaquePointerReturn, "new_handle"),
+            lint(DiagnosticCode::OpaqueGeneric, "wrapped"),
+            lint(DiagnosticCode::RustAbiFnPointer, "Options::callback"),
+            lint(DiagnosticCode::ZeroSizedField, "Options::empty"),
+            lint(DiagnosticCode::ZeroSizedField, "Options::padding"),
+            lint(DiagnosticCode::ImplicitEnumRepr, "set_mode"),
+        ]
+    );
+}
+
+#[test]
+fn lints_can_be_allowed_by_config() {
+    assert_eq!(
+        lints(
+            true,
+            &["bool-or-char", "zero-sized-field", "opaque-generic"]
+        ),
+        [
+            lint(DiagnosticCode::OpaqueByValue, "take_handle"),
+            lint(DiagnosticCode::OpaquePointerReturn, "new_handle"),
+            lint(DiagnosticCode::RustAbiFnPointer, "Options::callback"),
+            lint(DiagnosticCode::ImplicitEnumRepr, "set_mode"),
+        ]
+    );
+}
+
+#[test]
+fn lints_are_not_run_by_default() {
+    assert_eq!(lints(false, &[]), []);
+}
@@ -0,0 +1,60 @@
+pub struct Handle {
+    data: Vec<u8>,
+}
+
+pub struct Wrapper<T> {
+    value: T,
+}
+
+pub enum Mode {
+    Fast,
+    Slow,
+}
+
+#[repr(C)]
+pub struct Empty {}
+
+#[repr(C)]
+pub struct Options {
+    verbose: bool,
+    empty: Empty,
+    padding: [u8; 0],
+    callback: fn(i32),
+    /// cbindgen:allow=bool-or-char
+    quiet: bool,
+    data: [u8; 0],
+}
+
+/// cbindgen:allow=[bool-or-char, opaque-by-value]
+#[repr(C)]
+pub struct Allowed {
+    flag: bool,
+    handle: Handle,
+}
+
+#[no_mangle]
+pub extern "C" fn set_flag(flag: bool, letter: char) {}
+
+#[no_mangle]
+pub extern "C" fn get_flag(flag: *mut bool) {}
+
+#[no_mangle]
+pub extern "C" fn take_handle(handle: Handle) {}
+
+#[no_mangle]
+pub extern "C" fn new_handle() -> Box<Handle> {
+    unimplemented!()
+}
+
+#[no_mangle]
+pub extern "C" fn wrapped(wrapper: *mut Wrapper<i32>) {}
+
+#[no_mangle]
+pub extern "C" fn set_mode(mode: Mode) {}
+
+#[no_mangle]
+pub extern "C" fn configure(options: Options, allowed: Allowed) {}
+
+/// cbindgen:allow=opaque-by-value
+#[no_mangle]
+pub extern "C" fn take_handle_allowed(handle: Handle) {}
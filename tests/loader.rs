@@ -0,0 +1,91 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use cbindgen::*;
+use common::run;
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn test_file(name: &str) -> PathBuf {
+    common::test_file("loader", name)
+}
+
+/// Writes the bindings and the loader to `dir`, and builds the library and
+/// the test program loading it, with `defines` defined.
+fn build(dir: &Path, language: Language, defines: &[&str]) -> PathBuf {
+    let bindings = common::generate(
+        Builder::new()
+            .with_config(Config::from_file(test_file("cbindgen.toml")).unwrap())
+            .with_language(language)
+            .with_src(test_file("lib.rs")),
+    );
+    bindings.write_to_file(dir.join("bindings.h"));
+    bindings.write_loader_to_file(dir.join("loader.h"), "bindings.h");
+
+    run(
+        Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".into()))
+            .args([
+                "--crate-type",
+                "cdylib",
+                "--crate-name",
+                "geo",
+                "-A",
+                "warnings",
+            ])
+            .arg("--out-dir")
+            .arg(dir)
+            .arg(test_file("lib.rs")),
+    );
+
+    let program = dir.join("main");
+    let mut command = if language == Language::Cxx {
+        let mut command = Command::new(env::var("CXX").unwrap_or_else(|_| "c++".into()));
+        command.args(["-std=c++11", "-x", "c++"]);
+        command
+    } else {
+        let mut command = Command::new(env::var("CC").unwrap_or_else(|_| "cc".into()));
+        command.args(["-std=c11", "-Wall", "-Werror"]);
+        command
+    };
+    for define in defines {
+        command.arg(format!("-D{}", define));
+    }
+    run(command
+        .arg("-I")
+        .arg(dir)
+        .arg(test_file("main.c"))
+        .arg("-o")
+        .arg(&program)
+        .arg("-ldl"));
+    program
+}
+
+#[test]
+fn loads_functions() {
+    for language in [Language::C, Language::Cxx] {
+        let dir = tempfile::tempdir().unwrap();
+        let program = build(dir.path(), language, &[]);
+        let output = run(Command::new(program).arg(dir.path().join("libgeo.so")));
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "3 14\n");
+    }
+}
+
+#[test]
+fn reports_missing_function() {
+    // The library is built without the `extra` feature.
+    let dir = tempfile::tempdir().unwrap();
+    let program = build(dir.path(), Language::C, &["HAVE_EXTRA"]);
+    let output = Command::new(program)
+        .arg(dir.path().join("libgeo.so"))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "error: extra\n");
+
+    let loader = std::fs::read_to_string(dir.path().join("loader.h")).unwrap();
+    assert!(loader.contains("#if defined(HAVE_EXTRA)\n  void (*extra)(void);\n#endif"));
+    assert!(!loader.contains("host_callback"));
+}
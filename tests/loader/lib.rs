@@ -0,0 +1,26 @@
+use std::os::raw::c_int;
+
+#[repr(C)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[no_mangle]
+pub extern "C" fn manhattan(p: *const Point, scale: Option<extern "C" fn(c_int) -> c_int>) -> c_int {
+    let p = unsafe { &*p };
+    scale.unwrap()(p.x.abs() + p.y.abs())
+}
+
+#[cfg(feature = "extra")]
+#[no_mangle]
+pub extern "C" fn extra() {}
+
+extern "C" {
+    pub fn host_callback(x: c_int) -> c_int;
+}
@@ -0,0 +1,18 @@
+#include <stdio.h>
+
+#include "loader.h"
+
+static int twice(int x) { return 2 * x; }
+
+int main(int argc, char **argv) {
+  geo_api api;
+  const char *error = geo_load(&api, argv[1]);
+  if (error) {
+    printf("error: %s\n", error);
+    return 1;
+  }
+  Point p = {3, -4};
+  printf("%d %d\n", api.add(1, 2), api.manhattan(&p, twice));
+  geo_unload(&api);
+  return api.add != NULL;
+}
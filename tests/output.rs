@@ -0,0 +1,109 @@
+mod common;
+
+use cbindgen::*;
+
+use std::fs;
+use std::path::Path;
+
+fn generate(dir: &Path) -> Bindings {
+    let src = dir.join("lib.rs");
+    fs::write(
+        &src,
+        "#[no_mangle]\npub extern \"C\" fn answer() -> i32 { 42 }\n",
+    )
+    .unwrap();
+    common::generate(Builder::new().with_language(Language::C).with_src(src))
+}
+
+#[test]
+fn write_to_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let bindings = generate(dir.path());
+    let header = dir.path().join("include").join("answer.h");
+
+    assert!(bindings.try_write_to_file(&header).unwrap());
+    assert!(!bindings.try_write_to_file(&header).unwrap());
+    assert!(fs::read_to_string(&header)
+        .unwrap()
+        .contains("int32_t answer(void);"));
+
+    // Only the header is left in its directory.
+    let entries: Vec<_> = fs::read_dir(header.parent().unwrap())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(entries, ["answer.h"]);
+}
+
+#[test]
+fn write_to_file_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let bindings = generate(dir.path());
+    // The parent of the header is a file.
+    let header = dir.path().join("lib.rs").join("answer.h");
+
+    match bindings.try_write_to_file(&header) {
+        Err(Error::Io { path, .. }) => assert_eq!(Path::new(&path), header),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("writing should fail"),
+    }
+}
+
+#[test]
+fn write_symbol_file_to_file_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let bindings = generate(dir.path());
+    let symbols = dir.path().join("lib.rs").join("answer.map");
+
+    assert!(matches!(
+        bindings.try_write_symbol_file_to_file(&symbols, SymbolFileFormat::VersionScript, &[]),
+        Err(Error::Io { .. })
+    ));
+}
+
+#[test]
+fn write_to_file_from_threads() {
+    let dir = tempfile::tempdir().unwrap();
+    generate(dir.path());
+    let header = dir.path().join("answer.h");
+
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                let bindings = common::generate(
+                    Builder::new()
+                        .with_language(Language::C)
+                        .with_src(dir.path().join("lib.rs")),
+                );
+                bindings.try_write_to_file(&header).unwrap();
+            });
+        }
+    });
+
+    let mut entries: Vec<_> = fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    entries.sort();
+    assert_eq!(entries, ["answer.h", "lib.rs"]);
+}
+
+#[test]
+fn depfile_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let bindings = generate(dir.path());
+    let header = dir.path().join("answer.h");
+    let depfile = dir.path().join("answer.d");
+
+    // The header doesn't exist yet.
+    assert!(matches!(
+        bindings.try_generate_depfile(&header, &depfile),
+        Err(Error::Io { .. })
+    ));
+
+    bindings.try_write_to_file(&header).unwrap();
+    bindings.try_generate_depfile(&header, &depfile).unwrap();
+    let depfile = fs::read_to_string(depfile).unwrap();
+    assert!(depfile.starts_with(&format!("{}:", header.canonicalize().unwrap().display())));
+    assert!(depfile.ends_with("lib.rs\n"));
+}
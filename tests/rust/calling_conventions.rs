@@ -0,0 +1,33 @@
+pub type Callback = extern "system" fn(code: i32) -> i32;
+
+#[repr(C)]
+pub struct Handlers {
+    on_event: Option<extern "stdcall" fn(*mut Handlers)>,
+    on_error: extern "C" fn(i32),
+    on_unwind: extern "fastcall-unwind" fn() -> *const u8,
+}
+
+#[no_mangle]
+pub extern "system" fn register_handlers(callback: Callback, handlers: *mut Handlers) -> i32 {
+    0
+}
+
+#[no_mangle]
+pub extern "stdcall" fn window_proc(message: u32) -> *mut Handlers {
+    std::ptr::null_mut()
+}
+
+#[no_mangle]
+pub extern "vectorcall" fn unmapped(x: f32) -> f32 {
+    x
+}
+
+#[no_mangle]
+pub extern "C-unwind" fn default_abi() {}
+
+#[no_mangle]
+pub extern "Rust" fn not_c() {}
+
+extern "efiapi" {
+    fn firmware_call(arg: u64) -> u64;
+}
@@ -0,0 +1,57 @@
+#[cfg_attr(target_os = "windows", repr(C, packed))]
+#[cfg_attr(not(target_os = "windows"), repr(C))]
+pub struct Packet {
+    tag: u8,
+    len: u32,
+}
+
+/// Always documented.
+#[cfg_attr(feature = "ffi", repr(C))]
+#[cfg_attr(feature = "ffi", doc = "Only with `ffi` in Rust, but always in C.")]
+pub struct Handle {
+    #[cfg_attr(feature = "ffi", doc = "The raw id.")]
+    id: u64,
+}
+
+#[cfg_attr(feature = "ffi", no_mangle)]
+pub extern "C" fn open(packet: *const Packet) -> *mut Handle {
+    std::ptr::null_mut()
+}
+
+#[cfg_attr(feature = "ffi", export_name = "handle_close")]
+#[cfg_attr(feature = "ffi", must_use)]
+pub extern "C" fn close(handle: *mut Handle) -> bool {
+    true
+}
+
+#[no_mangle]
+#[cfg_attr(feature = "deprecations", deprecated)]
+pub extern "C" fn legacy() {}
+
+#[cfg_attr(feature = "ffi", cfg_attr(unix, unsafe(no_mangle)))]
+pub extern "C" fn unix_only() {}
+
+/// cbindgen:rename-all=ScreamingSnakeCase
+#[cfg_attr(feature = "ffi", repr(u8))]
+pub enum Mode {
+    #[cfg_attr(feature = "ffi", doc = "Read only.")]
+    ReadOnly,
+    #[cfg_attr(feature = "deprecations", deprecated)]
+    ReadWrite,
+}
+
+#[cfg_attr(feature = "ffi", no_mangle)]
+pub extern "C" fn mode(m: Mode) {}
+
+#[repr(C)]
+pub struct Stats {
+    count: u32,
+    #[cfg_attr(feature = "ffi", cfg(unix))]
+    fd: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn stats(stats: *const Stats) {}
+
+#[cfg_attr(feature = "ffi", cfg(unix))]
+pub const LIMIT: u32 = 8;
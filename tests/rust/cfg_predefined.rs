@@ -0,0 +1,29 @@
+#[cfg(all(unix, target_pointer_width = "64"))]
+type Handle = u64;
+
+#[cfg(all(unix, target_pointer_width = "32"))]
+type Handle = u32;
+
+#[cfg(windows)]
+type Handle = *mut ::std::ffi::c_void;
+
+#[repr(C)]
+pub struct Options {
+    pub handle: Handle,
+    #[cfg(target_os = "linux")]
+    pub epoll_fd: i32,
+    #[cfg(target_os = "macos")]
+    pub kqueue_fd: i32,
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    pub simd: bool,
+    #[cfg(feature = "tracing")]
+    pub trace_level: u8,
+}
+
+#[cfg(target_os = "windows")]
+#[no_mangle]
+pub extern "C" fn win_only(options: *const Options) {}
+
+#[cfg(debug_assertions)]
+#[no_mangle]
+pub extern "C" fn check_invariants(options: *const Options) {}
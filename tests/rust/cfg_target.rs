@@ -0,0 +1,46 @@
+#[cfg(all(unix, target_pointer_width = "64"))]
+type Handle = u64;
+
+#[cfg(windows)]
+type Handle = *mut ::std::ffi::c_void;
+
+#[repr(C)]
+pub struct Options {
+    pub handle: Handle,
+    #[cfg(target_os = "linux")]
+    pub epoll_fd: i32,
+    #[cfg(target_os = "macos")]
+    pub kqueue_fd: i32,
+    #[cfg(feature = "tracing")]
+    pub trace_level: u8,
+    #[cfg(not(feature = "legacy"))]
+    pub flags: u32,
+}
+
+#[repr(u8)]
+pub enum Backend {
+    #[cfg(target_os = "linux")]
+    Epoll,
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    Kqueue,
+    Poll,
+}
+
+#[cfg(target_arch = "x86_64")]
+pub const VECTOR_WIDTH: u32 = 256;
+
+#[cfg(not(target_arch = "x86_64"))]
+pub const VECTOR_WIDTH: u32 = 128;
+
+#[cfg(windows)]
+#[no_mangle]
+pub extern "C" fn win_only() {}
+
+#[cfg(all(target_os = "linux", feature = "tracing"))]
+mod tracing {
+    #[no_mangle]
+    pub extern "C" fn set_trace_level(level: u8) {}
+}
+
+#[no_mangle]
+pub extern "C" fn open_backend(options: *const Options, backend: Backend) {}
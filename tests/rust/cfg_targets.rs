@@ -0,0 +1,43 @@
+#[cfg(unix)]
+pub type Handle = i32;
+
+#[cfg(windows)]
+pub type Handle = *mut ::std::ffi::c_void;
+
+#[cfg(target_pointer_width = "64")]
+pub const WORD_SIZE: u32 = 8;
+
+#[cfg(target_pointer_width = "32")]
+pub const WORD_SIZE: u32 = 4;
+
+pub const VERSION: u32 = 3;
+
+#[cfg_attr(windows, repr(u32))]
+#[cfg_attr(not(windows), repr(u8))]
+pub enum Mode {
+    Read,
+    Write,
+}
+
+#[repr(C)]
+pub struct Options {
+    pub handle: Handle,
+    pub mode: Mode,
+    #[cfg(target_os = "linux")]
+    pub epoll_fd: i32,
+}
+
+#[repr(C)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[cfg(target_os = "macos")]
+#[no_mangle]
+pub extern "C" fn use_kqueue(options: *mut Options) {}
+
+#[no_mangle]
+pub extern "C" fn open(options: *const Options, origin: Point) -> Handle {
+    0
+}
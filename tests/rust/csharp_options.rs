@@ -0,0 +1,10 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn point_is_origin(p: *const Point) -> bool {
+    unimplemented!()
+}
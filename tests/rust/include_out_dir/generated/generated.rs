@@ -0,0 +1,6 @@
+/// A message generated from the schema.
+#[repr(C)]
+pub struct Message {
+    pub id: u32,
+    pub kind: u8,
+}
@@ -0,0 +1,4 @@
+include!("ffi/status.rs");
+
+#[cfg(feature = "extra")]
+include!("ffi/extra.rs");
@@ -0,0 +1,8 @@
+include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+
+mod ffi;
+
+#[no_mangle]
+pub extern "C" fn process(message: *const Message) -> Status {
+    Status::Ok
+}
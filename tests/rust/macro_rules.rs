@@ -0,0 +1,58 @@
+macro_rules! ffi_fn {
+    ($(#[$meta:meta])* fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $body:block) => {
+        $(#[$meta])*
+        #[no_mangle]
+        pub extern "C" fn $name($($arg: $ty),*) $(-> $ret)? $body
+    };
+}
+
+macro_rules! point {
+    ($name:ident { $($field:ident),+ } : $ty:ty) => {
+        #[repr(C)]
+        pub struct $name {
+            $(pub $field: $ty,)+
+        }
+    };
+}
+
+macro_rules! points {
+    ($($name:ident: $ty:ty;)*) => {
+        $(point!($name { x, y } : $ty);)*
+    };
+}
+
+macro_rules! constant {
+    ($name:ident = $value:literal) => {
+        pub const $name: i32 = $value;
+    };
+}
+
+points! {
+    IntPoint: i32;
+    FloatPoint: f32;
+}
+
+point!(Point3 { x, y, z } : f64);
+
+constant!(MINUS_ONE = -1);
+
+#[cfg(feature = "extra")]
+point!(ExtraPoint { a, b } : u8);
+
+ffi_fn! {
+    /// Adds two points.
+    fn add(a: IntPoint, b: IntPoint) -> IntPoint {
+        IntPoint { x: a.x + b.x, y: a.y + b.y }
+    }
+}
+
+ffi_fn! {
+    fn scale(p: *mut Point3, factor: f64,) {
+        unsafe { (*p).x *= factor; }
+    }
+}
+
+#[cfg(feature = "extra")]
+ffi_fn! {
+    fn extra(p: ExtraPoint, q: FloatPoint) {}
+}
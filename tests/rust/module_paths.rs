@@ -0,0 +1,52 @@
+mod net {
+    #[repr(C)]
+    pub struct Config {
+        pub port: u16,
+        pub address: Address,
+    }
+
+    pub mod addr {
+        #[repr(C)]
+        pub struct Address {
+            pub octets: [u8; 4],
+        }
+    }
+
+    pub use self::addr::*;
+}
+
+mod disk {
+    #[repr(C)]
+    pub struct Config {
+        pub block_size: u32,
+    }
+}
+
+use disk::Config as DiskConfig;
+
+#[repr(C)]
+pub struct Config {
+    pub verbose: bool,
+}
+
+#[repr(C)]
+pub struct Settings {
+    pub main: Config,
+    pub net: net::Config,
+    pub disk: DiskConfig,
+    pub address: crate::net::Address,
+}
+
+#[no_mangle]
+pub extern "C" fn apply(settings: *const Settings) {}
+
+mod cache {
+    // Not used by the bindings, so it isn't renamed.
+    pub struct Config {
+        entries: Vec<u32>,
+    }
+
+    pub fn new_config() -> Config {
+        Config { entries: Vec::new() }
+    }
+}
@@ -0,0 +1,20 @@
+#[repr(C)]
+pub struct Node {
+    value: i32,
+    next: *mut Node,
+}
+
+#[repr(C)]
+pub struct Pair {
+    left: *const Pair,
+    right: *const Pair,
+}
+
+#[repr(C)]
+pub enum Tree {
+    Leaf(i32),
+    Branch(*const Tree, *const Tree),
+}
+
+#[no_mangle]
+pub extern "C" fn root(node: *mut Node, pair: Pair, tree: Tree) {}
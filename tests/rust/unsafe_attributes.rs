@@ -0,0 +1,26 @@
+#[repr(C)]
+pub struct Pair {
+    a: i32,
+    b: i32,
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn root(p: Pair) -> i32 {
+    p.a + p.b
+}
+
+#[unsafe(export_name = "renamed")]
+pub extern "C" fn original() {}
+
+#[no_mangle]
+pub extern "C" fn classic() {}
+
+#[unsafe(no_mangle)]
+pub static COUNTER: i32 = 0;
+
+unsafe extern "C" {
+    pub safe fn checked(x: i32) -> i32;
+    pub unsafe fn unchecked(p: *mut Pair);
+    fn plain();
+    pub safe static LIMIT: i32;
+}
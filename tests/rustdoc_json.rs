@@ -0,0 +1,106 @@
+mod common;
+
+use cbindgen::*;
+use common::run;
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn test_file(name: &str) -> PathBuf {
+    common::test_file("rustdoc_json", name)
+}
+
+/// Writes the rustdoc JSON documents of the `lib` and `dep` fixture crates in
+/// `dir`, and returns their paths.
+fn document(dir: &Path, private_items: bool) -> (PathBuf, PathBuf) {
+    run(
+        Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into()))
+            .args([
+                "--edition",
+                "2021",
+                "--crate-type",
+                "lib",
+                "--emit",
+                "metadata",
+            ])
+            .arg(test_file("dep.rs"))
+            .arg("--out-dir")
+            .arg(dir),
+    );
+    for (name, extern_dep) in [("dep", false), ("lib", true)] {
+        let mut command =
+            Command::new(std::env::var("RUSTDOC").unwrap_or_else(|_| "rustdoc".into()));
+        // The JSON output is unstable.
+        command
+            .env("RUSTC_BOOTSTRAP", "1")
+            .args(["-Z", "unstable-options", "--output-format", "json"])
+            .args([
+                "--edition",
+                "2021",
+                "--crate-type",
+                "lib",
+                "--crate-name",
+                name,
+            ])
+            .arg(test_file(&format!("{}.rs", name)))
+            .arg("-o")
+            .arg(dir);
+        if private_items {
+            command.arg("--document-private-items");
+        }
+        if extern_dep {
+            command
+                .arg("--extern")
+                .arg(format!("dep={}", dir.join("libdep.rmeta").display()));
+        }
+        run(&mut command);
+    }
+    (dir.join("lib.json"), dir.join("dep.json"))
+}
+
+fn header(builder: Builder) -> String {
+    let mut config = Config {
+        language: Language::C,
+        ..Config::default()
+    };
+    config.function.sort_by = Some(SortKey::Name);
+    let mut out = Vec::new();
+    builder
+        .with_config(config)
+        .generate()
+        .expect("build should succeed")
+        .write(&mut out);
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn matches_source() {
+    let dir = tempfile::tempdir().unwrap();
+    let (lib, dep) = document(dir.path(), true);
+
+    let from_json = header(Builder::new().with_rustdoc_json(lib).with_rustdoc_json(dep));
+    let from_source = header(
+        Builder::new()
+            .with_src(test_file("lib.rs"))
+            .with_src(test_file("dep.rs")),
+    );
+    assert_eq!(from_json, from_source);
+    assert!(from_json.contains("Point CENTER;"));
+    assert!(from_json.contains("uint32_t secret;"));
+    assert!(from_json.contains("#define Shape_MAX_SIDES 16"));
+    assert!(from_json.contains("struct Hidden hidden_of(struct Handle handle);"));
+}
+
+#[test]
+fn without_private_items() {
+    let dir = tempfile::tempdir().unwrap();
+    let (lib, dep) = document(dir.path(), false);
+
+    let from_json = header(Builder::new().with_rustdoc_json(lib).with_rustdoc_json(dep));
+    // The fields of `Hidden` and `Shape` aren't all known, and the bindings
+    // can't get their layout wrong.
+    assert!(from_json.contains("typedef struct Hidden Hidden;"));
+    assert!(from_json.contains("typedef struct Shape Shape;"));
+    // `Handle` is only documented where it is re-exported.
+    assert!(from_json.contains("typedef struct Handle {\n  uint8_t *_0;\n} Handle;"));
+}
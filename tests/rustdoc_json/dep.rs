@@ -0,0 +1,11 @@
+#[repr(C)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[repr(C)]
+pub struct Hidden {
+    pub visible: u32,
+    secret: u32,
+}
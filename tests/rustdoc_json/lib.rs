@@ -0,0 +1,44 @@
+pub mod shapes {
+    /// A shape.
+    /// cbindgen:rename-all=UpperCase
+    #[repr(C)]
+    pub struct Shape {
+        pub kind: Kind,
+        pub center: dep::Point,
+        sides: u8,
+    }
+
+    #[repr(u8)]
+    pub enum Kind {
+        Circle = 1,
+        Polygon(u32),
+    }
+
+    pub type Callback = Option<extern "C" fn(shape: *const Shape) -> bool>;
+
+    impl Shape {
+        pub const MAX_SIDES: u8 = 16;
+    }
+}
+
+mod private {
+    #[repr(C)]
+    pub struct Handle(pub *mut u8);
+}
+
+pub use private::Handle;
+
+pub const VERSION: u32 = 3;
+
+#[no_mangle]
+pub static mut COUNT: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn area(shape: &shapes::Shape, cb: shapes::Callback) -> f32 {
+    0.0
+}
+
+#[export_name = "hidden_of"]
+pub extern "C" fn hidden(handle: Handle) -> dep::Hidden {
+    unimplemented!()
+}
@@ -0,0 +1,132 @@
+mod common;
+
+use cbindgen::*;
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn lib_path() -> PathBuf {
+    common::test_file("symbols", "lib.rs")
+}
+
+fn generate() -> Bindings {
+    common::generate(
+        Builder::new()
+            .with_language(Language::C)
+            .with_define("feature", "gpu", "HAVE_GPU")
+            .with_src(lib_path()),
+    )
+}
+
+fn symbol_file(format: SymbolFileFormat, defines: &[&str]) -> String {
+    let mut out = Vec::new();
+    generate().write_symbol_file(&mut out, format, defines);
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn version_script() {
+    assert_eq!(
+        symbol_file(SymbolFileFormat::VersionScript, &[]),
+        "\
+{
+  global:
+    COUNTER;
+    cpu_render;
+    init;
+    lib_shutdown;
+    unmapped;
+  local:
+    *;
+};
+"
+    );
+}
+
+#[test]
+fn exported_symbols_list() {
+    assert_eq!(
+        symbol_file(SymbolFileFormat::ExportedSymbolsList, &["HAVE_GPU"]),
+        "\
+_COUNTER
+_gpu_render
+_init
+_lib_shutdown
+_unmapped
+"
+    );
+}
+
+#[test]
+fn def_file() {
+    assert_eq!(
+        symbol_file(SymbolFileFormat::Def, &["HAVE_GPU"]),
+        "\
+EXPORTS
+    COUNTER DATA
+    gpu_render
+    init
+    lib_shutdown
+    unmapped
+"
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn check_symbols() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into()))
+        .args([
+            "--crate-type",
+            "cdylib",
+            "--crate-name",
+            "symbols",
+            "-A",
+            "warnings",
+        ])
+        .arg("--out-dir")
+        .arg(dir.path())
+        .arg(lib_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let lib = dir.path().join("libsymbols.so");
+
+    let check = SymbolCheck::new(&generate(), &lib, &[]).unwrap();
+    assert!(!check.is_ok());
+    assert_eq!(
+        check.to_string(),
+        "\
+declared but not exported: `unmapped`
+exported but not declared: `hidden_from_header`
+"
+    );
+
+    let check = SymbolCheck::new(&generate(), &lib, &["HAVE_GPU"]).unwrap();
+    assert_eq!(check.not_exported, ["gpu_render", "unmapped"]);
+    assert_eq!(check.undeclared, ["cpu_render", "hidden_from_header"]);
+
+    assert!(SymbolCheck::new(&generate(), lib_path(), &[]).is_err());
+}
+
+#[test]
+fn check_symbols_of_a_malformed_library() {
+    // A 64-bit little-endian ELF header whose section header table is at the
+    // end of the address space.
+    let mut header = vec![0; 64];
+    header[..6].copy_from_slice(b"\x7fELF\x02\x01");
+    header[0x28..0x30].copy_from_slice(&u64::MAX.to_le_bytes());
+    header[0x3a..0x3c].copy_from_slice(&64u16.to_le_bytes());
+    header[0x3c..0x3e].copy_from_slice(&2u16.to_le_bytes());
+    let dir = tempfile::tempdir().unwrap();
+    let lib = dir.path().join("libmalformed.so");
+    fs::write(&lib, header).unwrap();
+
+    assert!(SymbolCheck::new(&generate(), &lib, &[]).is_err());
+}
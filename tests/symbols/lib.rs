@@ -0,0 +1,30 @@
+use std::os::raw::c_int;
+
+#[no_mangle]
+pub extern "C" fn init() {}
+
+#[export_name = "lib_shutdown"]
+pub extern "C" fn shutdown() {}
+
+#[cfg(feature = "gpu")]
+#[no_mangle]
+pub extern "C" fn gpu_render() {}
+
+#[cfg(not(feature = "gpu"))]
+#[no_mangle]
+pub extern "C" fn cpu_render() {}
+
+#[cfg(feature = "unmapped")]
+#[no_mangle]
+pub extern "C" fn unmapped() {}
+
+#[no_mangle]
+pub static mut COUNTER: c_int = 0;
+
+extern "C" {
+    fn imported(x: c_int) -> c_int;
+}
+
+/// cbindgen:ignore
+#[no_mangle]
+pub extern "C" fn hidden_from_header() {}
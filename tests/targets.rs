@@ -0,0 +1,62 @@
+mod common;
+
+use cbindgen::*;
+
+fn generate(language: Language, targets: &[&str], defines: &[(&str, &str)]) -> Result<(), Error> {
+    let mut config = Config::default();
+    config.parse.targets = targets.iter().map(|target| target.to_string()).collect();
+    config.defines = defines
+        .iter()
+        .map(|&(cfg, define)| (cfg.to_owned(), define.to_owned()))
+        .collect();
+    Builder::new()
+        .with_config(config)
+        .with_language(language)
+        .with_src(common::test_file("rust", "cfg_targets.rs"))
+        .generate()
+        .map(|_| ())
+}
+
+fn is_ambiguous(result: Result<(), Error>) -> bool {
+    matches!(result, Err(Error::AmbiguousTargets { .. }))
+}
+
+#[test]
+fn targets_without_a_condition_are_ambiguous() {
+    const GNU_AND_MUSL: &[&str] = &["x86_64-unknown-linux-gnu", "x86_64-unknown-linux-musl"];
+
+    // No macro is predefined for `target_env = "gnu"` or `"musl"`.
+    assert!(is_ambiguous(generate(Language::C, GNU_AND_MUSL, &[])));
+    // `target_env = "msvc"` has one, but it can't tell `gnu` apart from it.
+    assert!(is_ambiguous(generate(
+        Language::C,
+        &["x86_64-pc-windows-msvc", "x86_64-pc-windows-gnu"],
+        &[]
+    )));
+    assert!(generate(
+        Language::C,
+        GNU_AND_MUSL,
+        &[
+            ("target_env = gnu", "__GLIBC__"),
+            ("target_env = musl", "MUSL")
+        ]
+    )
+    .is_ok());
+}
+
+#[test]
+fn targets_need_defines_outside_of_c() {
+    const TARGETS: &[&str] = &["x86_64-unknown-linux-gnu", "x86_64-pc-windows-msvc"];
+
+    assert!(generate(Language::C, TARGETS, &[]).is_ok());
+    assert!(is_ambiguous(generate(Language::Zig, TARGETS, &[])));
+    assert!(generate(
+        Language::Zig,
+        TARGETS,
+        &[
+            ("target_os = linux", "LINUX"),
+            ("target_os = windows", "WINDOWS")
+        ]
+    )
+    .is_ok());
+}
@@ -2,6 +2,7 @@ extern crate cbindgen;
 
 use cbindgen::*;
 use std::collections::HashSet;
+use std::ffi::OsStr;
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
@@ -57,6 +58,15 @@ fn run_cbindgen(
         Language::Cython => {
             command.arg("--lang").arg("cython");
         }
+        Language::Zig => {
+            command.arg("--lang").arg("zig");
+        }
+        Language::CSharp => {
+            command.arg("--lang").arg("csharp");
+        }
+        Language::Python => {
+            command.arg("--lang").arg("python");
+        }
     }
 
     if package_version {
@@ -95,9 +105,10 @@ fn run_cbindgen(
         cbindgen_output.stdout
     };
 
-    let depfile_contents = if let Some(mut depfile) = cbindgen_depfile {
-        let mut raw = Vec::new();
-        depfile.read_to_end(&mut raw).unwrap();
+    let depfile_contents = if let Some(depfile) = cbindgen_depfile {
+        // The depfile is replaced rather than written in place, so it has to
+        // be read again from its path.
+        let raw = fs::read(depfile.path()).unwrap();
         Some(
             str::from_utf8(raw.as_slice())
                 .expect("Invalid encoding encountered in depfile")
@@ -121,6 +132,9 @@ fn compile(
         Language::Cxx => env::var("CXX").unwrap_or_else(|_| "g++".to_owned()),
         Language::C => env::var("CC").unwrap_or_else(|_| "gcc".to_owned()),
         Language::Cython => env::var("CYTHON").unwrap_or_else(|_| "cython".to_owned()),
+        Language::Zig => env::var("ZIG").unwrap_or_else(|_| "zig".to_owned()),
+        Language::CSharp => env::var("CSC").unwrap_or_else(|_| "csc".to_owned()),
+        Language::Python => env::var("PYTHON").unwrap_or_else(|_| "python3".to_owned()),
     };
 
     let file_name = cbindgen_output
@@ -183,6 +197,27 @@ fn compile(
             command.arg("-o").arg(&object);
             command.arg(cbindgen_output);
         }
+        Language::Zig => {
+            // Zig analyzes declarations lazily, so only check that the output parses.
+            command.arg("ast-check");
+            command.arg(cbindgen_output);
+        }
+        Language::CSharp => {
+            command.arg("-nologo");
+            command.arg("-target:library");
+            command.arg("-unsafe");
+            command.arg("-langversion:latest");
+            command.arg("-define:DEFINED");
+            if !skip_warning_as_error {
+                command.arg("-warnaserror");
+            }
+            command.arg(format!("-out:{}", object.display()));
+            command.arg(cbindgen_output);
+        }
+        Language::Python => {
+            // Running the module builds all the ctypes classes without loading any library.
+            command.arg(cbindgen_output);
+        }
     }
 
     println!("Running: {:?}", command);
@@ -196,6 +231,55 @@ fn compile(
 
 const SKIP_WARNING_AS_ERROR_SUFFIX: &str = ".skip_warning_as_error";
 
+/// Tests whose config embeds C or Cython source in the generated file (through
+/// `header`, `after_includes`, `export.body`, ...), so their output in other
+/// languages is only compared with the expectations, and not compiled.
+const C_SOURCE_IN_CONFIG: &[&str] = &[
+    "asserted_cast",
+    "body",
+    "box",
+    "calling_conventions",
+    "cfg",
+    "cfg_2",
+    "custom_header",
+    "deprecated",
+    "destructor_and_copy_ctor",
+    "enum",
+    "exclude_generic_monomorph",
+    "forward_declaration",
+    "function_noreturn",
+    "layout",
+    "layout_aligned_opaque",
+    "layout_packed_opaque",
+    "manuallydrop",
+    "maybeuninit",
+    "mod_attr",
+    "must_use",
+    "nonnull_attribute",
+    "nonzero",
+    "opaque",
+    "pin",
+    "raw_lines",
+    "rename_crate",
+    "self_referential",
+    "swift_name",
+];
+
+/// Tests whose config embeds C or Cython source in the generated file (through
+/// `header`, `export.body`, ...), which can't double as Python.
+const PYTHON_SKIP_RUN: &[&str] = &[
+    "body",
+    "cfg",
+    "cfg_2",
+    "exclude_generic_monomorph",
+    "mod_attr",
+    "rename_crate",
+];
+
+/// Tests merging several targets, which only C and C++ can tell apart without
+/// `[defines]`, through the macros their compilers predefine.
+const C_FAMILY_ONLY: &[&str] = &["cfg_targets"];
+
 #[allow(clippy::too_many_arguments)]
 fn run_compile_test(
     name: &'static str,
@@ -207,6 +291,10 @@ fn run_compile_test(
     cbindgen_outputs: &mut HashSet<Vec<u8>>,
     package_version: bool,
 ) {
+    if !matches!(language, Language::C | Language::Cxx) && C_FAMILY_ONLY.contains(&name) {
+        return;
+    }
+
     let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
     let tests_path = Path::new(&crate_dir).join("tests");
     let mut generated_file = tests_path.join("expectations");
@@ -228,6 +316,9 @@ fn run_compile_test(
         // is extension-sensitive and won't work on them, so we use implementation files (`.pyx`)
         // in the test suite.
         Language::Cython => ".pyx",
+        Language::Zig => ".zig",
+        Language::CSharp => ".cs",
+        Language::Python => ".py",
     };
 
     let skip_warning_as_error = name.rfind(SKIP_WARNING_AS_ERROR_SUFFIX).is_some();
@@ -294,6 +385,16 @@ fn run_compile_test(
             return;
         }
 
+        if matches!(language, Language::Zig | Language::CSharp)
+            && C_SOURCE_IN_CONFIG.contains(&name)
+        {
+            return;
+        }
+
+        if language == Language::Python && PYTHON_SKIP_RUN.contains(&name) {
+            return;
+        }
+
         compile(
             &generated_file,
             &tests_path,
@@ -316,6 +417,75 @@ fn run_compile_test(
     }
 }
 
+/// Runs cbindgen with `args`, and the test's config if it has one, returning its stdout.
+fn run_cbindgen_with_args(path: &Path, args: &[&OsStr]) -> Vec<u8> {
+    let mut command = Command::new(CBINDGEN_PATH);
+    command.args(args);
+    let config = path.with_extension("toml");
+    if config.exists() {
+        command.arg("--config").arg(config);
+    }
+
+    println!("Running: {:?}", command);
+    let cbindgen_output = command.output().expect("failed to execute process");
+    assert!(
+        cbindgen_output.status.success(),
+        "cbindgen failed: {:?} with error: {}",
+        path,
+        str::from_utf8(&cbindgen_output.stderr).unwrap_or_default()
+    );
+    cbindgen_output.stdout
+}
+
+/// Checks the `--format json` dump of the IR against its expectation, that it
+/// is a document of the current schema version, and that generating bindings
+/// from it with `--ir-file` gives the same output as parsing the crate.
+fn run_json_test(name: &'static str, path: &Path, tmp_dir: &Path) {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let generated_file = Path::new(&crate_dir)
+        .join("tests")
+        .join("expectations")
+        .join(format!("{}.json", name).replace(SKIP_WARNING_AS_ERROR_SUFFIX, ""));
+
+    let json = run_cbindgen_with_args(path, &["--format".as_ref(), "json".as_ref(), path.as_ref()]);
+
+    if env::var_os("CBINDGEN_TEST_VERIFY").is_some() {
+        let prev_json = fs::read(&generated_file).unwrap();
+        assert_eq!(json, prev_json);
+    } else {
+        fs::write(&generated_file, &json).unwrap();
+    }
+
+    let document: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    assert_eq!(document["schema_version"], JSON_SCHEMA_VERSION);
+
+    let ir_file = tmp_dir.join(format!("{}.json", name));
+    fs::write(&ir_file, &json).unwrap();
+    // There is no crate to find the config of when generating from the IR.
+    let crate_config = path.join("cbindgen.toml");
+    // Cover both the C++ output, which keeps generics, and the monomorphized C one.
+    for lang in ["c++", "c"] {
+        let parsed =
+            run_cbindgen_with_args(path, &["--lang".as_ref(), lang.as_ref(), path.as_ref()]);
+        let mut args = vec![
+            "--lang".as_ref(),
+            lang.as_ref(),
+            "--ir-file".as_ref(),
+            ir_file.as_os_str(),
+        ];
+        if crate_config.exists() {
+            args.extend(["--config".as_ref(), crate_config.as_os_str()]);
+        }
+        let from_ir = run_cbindgen_with_args(path, &args);
+        assert_eq!(
+            str::from_utf8(&parsed).unwrap(),
+            str::from_utf8(&from_ir).unwrap(),
+            "Bindings generated from the IR differ for {}",
+            lang
+        );
+    }
+}
+
 fn test_file(name: &'static str, filename: &'static str) {
     let test = Path::new(filename);
     let tmp_dir = tempfile::Builder::new()
@@ -366,6 +536,41 @@ fn test_file(name: &'static str, filename: &'static str) {
             false,
         );
     }
+
+    run_compile_test(
+        name,
+        test,
+        tmp_dir,
+        Language::Zig,
+        /* cpp_compat = */ false,
+        None,
+        &mut HashSet::new(),
+        false,
+    );
+
+    run_compile_test(
+        name,
+        test,
+        tmp_dir,
+        Language::CSharp,
+        /* cpp_compat = */ false,
+        None,
+        &mut HashSet::new(),
+        false,
+    );
+
+    run_compile_test(
+        name,
+        test,
+        tmp_dir,
+        Language::Python,
+        /* cpp_compat = */ false,
+        None,
+        &mut HashSet::new(),
+        false,
+    );
+
+    run_json_test(name, test, tmp_dir);
 }
 
 macro_rules! test_file {
@@ -0,0 +1,61 @@
+mod common;
+
+use cbindgen::*;
+
+fn bindings() -> Bindings {
+    common::generate(
+        Builder::new()
+            .with_language(Language::C)
+            .with_src(common::test_file("unreachable", "lib.rs"))
+            .include_item("Forgotten")
+            .exclude_item("HIDDEN"),
+    )
+}
+
+#[test]
+fn unreachable() {
+    let report = bindings().unreachable();
+    assert_eq!(
+        report
+            .items
+            .iter()
+            .map(|item| (item.name.as_str(), item.kind.clone(), item.filter.clone()))
+            .collect::<Vec<_>>(),
+        [
+            ("Dead", ItemType::Structs, Filter::Unused),
+            ("DeadEnum", ItemType::Enums, Filter::Unused),
+            ("DeadHandle", ItemType::Structs, Filter::Unused),
+            ("DeadUnion", ItemType::Unions, Filter::Unused),
+            ("HIDDEN", ItemType::Globals, Filter::ExportExclude),
+        ]
+    );
+    assert_eq!(
+        report
+            .skipped_functions
+            .iter()
+            .map(|d| (d.code, d.item.as_deref()))
+            .collect::<Vec<_>>(),
+        [
+            (DiagnosticCode::UnexportedFn, Some("lib::not_exported")),
+            (DiagnosticCode::NonCAbi, Some("lib::rust_abi")),
+        ]
+    );
+    assert_eq!(report.missing_includes, ["Forgotten"]);
+    assert!(report
+        .to_string()
+        .starts_with("Unreachable items:\n    struct `Dead`: no exported function"));
+}
+
+#[test]
+fn generic_instances_are_reachable() {
+    let bindings = bindings();
+    assert!(bindings
+        .unreachable()
+        .items
+        .iter()
+        .all(|item| item.name != "Generic"));
+    assert!(matches!(
+        bindings.explain("Generic").status,
+        Status::Included { .. }
+    ));
+}
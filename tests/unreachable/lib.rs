@@ -0,0 +1,44 @@
+#[repr(C)]
+pub struct Used {
+    x: i32,
+}
+
+#[repr(C)]
+pub struct Generic<T> {
+    value: T,
+}
+
+#[repr(C)]
+pub struct Dead {
+    x: i32,
+}
+
+#[repr(transparent)]
+pub struct DeadHandle(u64);
+
+#[repr(u8)]
+pub enum DeadEnum {
+    A,
+    B,
+}
+
+#[repr(C)]
+pub union DeadUnion {
+    x: i32,
+    y: f32,
+}
+
+#[no_mangle]
+pub static mut HIDDEN: i32 = 0;
+
+pub struct NotFfi {
+    data: Vec<u8>,
+}
+
+#[no_mangle]
+pub extern "C" fn run(used: Used, generic: Generic<i32>) {}
+
+pub extern "C" fn not_exported() {}
+
+#[no_mangle]
+pub extern "Rust" fn rust_abi() {}